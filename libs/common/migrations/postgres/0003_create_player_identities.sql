@@ -0,0 +1,16 @@
+-- The uuid of every player and the names the player used.
+-- The tables got created at runtime before, so they might already exist.
+CREATE TABLE IF NOT EXISTS player_identities (
+    uuid TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    first_seen TIMESTAMPTZ NOT NULL,
+    last_seen TIMESTAMPTZ NOT NULL,
+    last_ip TEXT
+);
+CREATE TABLE IF NOT EXISTS player_names (
+    uuid TEXT NOT NULL REFERENCES player_identities (uuid) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    first_seen TIMESTAMPTZ NOT NULL,
+    last_seen TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (uuid, name)
+);
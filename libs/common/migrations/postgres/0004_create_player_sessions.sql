@@ -0,0 +1,12 @@
+-- Every session of a player on a Minecraft server. Open sessions have no left_at.
+-- The table got created at runtime before, so it might already exist.
+CREATE TABLE IF NOT EXISTS player_sessions (
+    id BIGSERIAL PRIMARY KEY,
+    server TEXT NOT NULL,
+    uuid TEXT NOT NULL,
+    player TEXT NOT NULL,
+    joined_at TIMESTAMPTZ NOT NULL,
+    left_at TIMESTAMPTZ,
+    close_reason TEXT
+);
+CREATE INDEX IF NOT EXISTS player_sessions_uuid ON player_sessions (uuid);
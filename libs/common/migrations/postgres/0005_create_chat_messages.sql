@@ -0,0 +1,11 @@
+-- Every message sent to the chat of a Minecraft server.
+-- The table got created at runtime before, so it might already exist.
+CREATE TABLE IF NOT EXISTS chat_messages (
+    id BIGSERIAL PRIMARY KEY,
+    server TEXT NOT NULL,
+    uuid TEXT,
+    sender TEXT NOT NULL,
+    message TEXT NOT NULL,
+    sent_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX IF NOT EXISTS chat_messages_sent_at ON chat_messages (sent_at);
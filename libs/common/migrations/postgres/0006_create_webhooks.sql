@@ -0,0 +1,21 @@
+-- Every webhook and the results of sending events to it.
+-- The filter and template columns hold json. The tables got created at runtime before, so they might already exist.
+CREATE TABLE IF NOT EXISTS webhooks (
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    url TEXT NOT NULL,
+    filter TEXT NOT NULL,
+    secret TEXT,
+    template TEXT NOT NULL,
+    enabled BOOLEAN NOT NULL
+);
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    id BIGSERIAL PRIMARY KEY,
+    webhook BIGINT NOT NULL REFERENCES webhooks (id) ON DELETE CASCADE,
+    event TEXT NOT NULL,
+    success BOOLEAN NOT NULL,
+    attempts BIGINT NOT NULL,
+    status_code INTEGER,
+    error TEXT,
+    delivered_at TIMESTAMPTZ NOT NULL
+);
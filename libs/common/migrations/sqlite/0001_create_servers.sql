@@ -0,0 +1,19 @@
+-- Every Minecraft server managed by the ServerList.
+-- Durations are saved in seconds. The depends_on column holds a json array of server names.
+CREATE TABLE servers (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE,
+    args TEXT NOT NULL,
+    download_from TEXT NOT NULL,
+    version TEXT,
+    build TEXT,
+    update_policy TEXT NOT NULL,
+    server_type TEXT NOT NULL,
+    restart_time INTEGER NOT NULL,
+    eula_accepted_by TEXT,
+    network TEXT,
+    network_role TEXT,
+    kind TEXT NOT NULL,
+    depends_on TEXT NOT NULL,
+    start_delay INTEGER NOT NULL
+);
@@ -0,0 +1,11 @@
+-- Every change to the servers table. The revision of the ServerList is the highest revision in this table.
+-- The change column holds a json string. ( "added", "modified" or "removed" )
+CREATE TABLE server_changes (
+    revision INTEGER PRIMARY KEY AUTOINCREMENT,
+    server INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    change TEXT NOT NULL
+);
+
+-- servers saved before their changes got recorded count as added
+INSERT INTO server_changes (server, name, change) SELECT id, name, '"added"' FROM servers ORDER BY id;
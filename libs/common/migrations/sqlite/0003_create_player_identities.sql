@@ -0,0 +1,16 @@
+-- The uuid of every player and the names the player used.
+-- Timestamps are saved as RFC 3339 strings.
+CREATE TABLE player_identities (
+    uuid TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    first_seen TEXT NOT NULL,
+    last_seen TEXT NOT NULL,
+    last_ip TEXT
+);
+CREATE TABLE player_names (
+    uuid TEXT NOT NULL REFERENCES player_identities (uuid) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    first_seen TEXT NOT NULL,
+    last_seen TEXT NOT NULL,
+    PRIMARY KEY (uuid, name)
+);
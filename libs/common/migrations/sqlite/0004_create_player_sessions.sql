@@ -0,0 +1,12 @@
+-- Every session of a player on a Minecraft server. Open sessions have no left_at.
+-- Timestamps are saved as RFC 3339 strings.
+CREATE TABLE player_sessions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    server TEXT NOT NULL,
+    uuid TEXT NOT NULL,
+    player TEXT NOT NULL,
+    joined_at TEXT NOT NULL,
+    left_at TEXT,
+    close_reason TEXT
+);
+CREATE INDEX player_sessions_uuid ON player_sessions (uuid);
@@ -0,0 +1,11 @@
+-- Every message sent to the chat of a Minecraft server.
+-- Timestamps are saved as RFC 3339 strings.
+CREATE TABLE chat_messages (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    server TEXT NOT NULL,
+    uuid TEXT,
+    sender TEXT NOT NULL,
+    message TEXT NOT NULL,
+    sent_at TEXT NOT NULL
+);
+CREATE INDEX chat_messages_sent_at ON chat_messages (sent_at);
@@ -0,0 +1,21 @@
+-- Every webhook and the results of sending events to it.
+-- The filter and template columns hold json. Booleans are saved as 0 or 1 and timestamps as RFC 3339 strings.
+CREATE TABLE webhooks (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    url TEXT NOT NULL,
+    filter TEXT NOT NULL,
+    secret TEXT,
+    template TEXT NOT NULL,
+    enabled INTEGER NOT NULL
+);
+CREATE TABLE webhook_deliveries (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    webhook INTEGER NOT NULL REFERENCES webhooks (id) ON DELETE CASCADE,
+    event TEXT NOT NULL,
+    success INTEGER NOT NULL,
+    attempts INTEGER NOT NULL,
+    status_code INTEGER,
+    error TEXT,
+    delivered_at TEXT NOT NULL
+);
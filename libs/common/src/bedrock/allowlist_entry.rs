@@ -0,0 +1,19 @@
+//! This module provides the [`AllowlistEntry`] struct.
+
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This struct represents one player in the `allowlist.json` file of a Bedrock Dedicated Server.
+#[add_convert]
+#[serde(rename_all = "camelCase")]
+pub struct AllowlistEntry {
+    /// The gamertag of the player
+    pub name: String,
+    /// The xuid of the player. The server fills it in once the player joined for the first time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub xuid: Option<String>,
+    /// Whether the player can join even if the server is full
+    #[serde(default)]
+    pub ignores_player_limit: bool,
+}
@@ -0,0 +1,59 @@
+//! This module provides the [`BedrockProperties`] struct.
+
+use proc_macros::add_convert;
+
+use crate::{
+    existing_server::property,
+    mcmanage_error::MCManageError,
+};
+
+/// This struct represents the settings of a Bedrock Dedicated Server read from its `server.properties` file. \
+/// Settings missing from the file will be set to the defaults of the Bedrock Dedicated Server.
+#[add_convert]
+pub struct BedrockProperties {
+    /// The name of the server shown in the server list of the players
+    pub server_name: String,
+    /// The game mode new players get, like `survival` or `creative`
+    pub gamemode: String,
+    /// The difficulty of the world, like `easy` or `hard`
+    pub difficulty: String,
+    /// The maximum number of players that can be on the server at the same time
+    pub max_players: u64,
+    /// The IPv4 port the server listens on
+    pub server_port: u16,
+    /// The IPv6 port the server listens on
+    pub server_portv6: u16,
+    /// The name of the world, which is also the name of its directory in the `worlds` directory
+    pub level_name: String,
+    /// Whether players have to be authenticated with Xbox Live
+    pub online_mode: bool,
+    /// Whether only players in the `allowlist.json` file can join
+    pub allow_list: bool,
+}
+impl BedrockProperties {
+    /// Read the [`BedrockProperties`] from the content of a `server.properties` file. Invalid values will be replaced by their defaults.
+    pub fn from_properties(properties: &str) -> Self {
+        let string = |key: &str, default: &str| property(properties, key).unwrap_or(default.to_string());
+
+        Self {
+            server_name: string("server-name", "Dedicated Server"),
+            gamemode: string("gamemode", "survival"),
+            difficulty: string("difficulty", "easy"),
+            max_players: property(properties, "max-players")
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(10),
+            server_port: property(properties, "server-port")
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(19132),
+            server_portv6: property(properties, "server-portv6")
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(19133),
+            level_name: string("level-name", "Bedrock level"),
+            online_mode: property(properties, "online-mode").map_or(true, |value| value == "true"),
+            // older versions of the Bedrock Dedicated Server called the allowlist a whitelist
+            allow_list: property(properties, "allow-list")
+                .or_else(|| property(properties, "white-list"))
+                .map_or(false, |value| value == "true"),
+        }
+    }
+}
@@ -0,0 +1,228 @@
+//! This module provides the [`BedrockServer`] struct, which manages the files of a Bedrock Dedicated Server. \
+//! Unlike Java Edition servers, the Bedrock Dedicated Server is a native binary shipped as a zip archive together with the libraries it needs.
+
+use std::{
+    fs::{
+        create_dir_all,
+        File,
+    },
+    io::{
+        self,
+        ErrorKind,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use tokio::{
+    fs,
+    process::Command,
+    task::spawn_blocking,
+};
+use zip::ZipArchive;
+
+use self::{
+    allowlist_entry::AllowlistEntry,
+    bedrock_properties::BedrockProperties,
+};
+use crate::mcmanage_error::MCManageError;
+
+pub mod allowlist_entry;
+pub mod bedrock_properties;
+mod tests;
+
+/// The name of the Bedrock Dedicated Server binary.
+#[cfg(not(windows))]
+pub const BEDROCK_BINARY: &str = "bedrock_server";
+/// The name of the Bedrock Dedicated Server binary.
+#[cfg(windows)]
+pub const BEDROCK_BINARY: &str = "bedrock_server.exe";
+/// The file listing every player allowed to join a Bedrock Dedicated Server.
+pub const ALLOWLIST_JSON: &str = "allowlist.json";
+/// The file containing the settings of a Bedrock Dedicated Server.
+pub const SERVER_PROPERTIES: &str = "server.properties";
+/// The files of the Bedrock Dedicated Server archive, which will not replace existing ones, since they contain the settings of the server.
+const KEPT_FILES: [&str; 3] = [SERVER_PROPERTIES, ALLOWLIST_JSON, "permissions.json"];
+
+/// This struct manages the files of the Bedrock Dedicated Server in one server directory.
+pub struct BedrockServer {
+    /// The directory of the server
+    path: PathBuf,
+}
+// actions
+impl BedrockServer {
+    /// Create a new [`BedrockServer`] for the server in the given directory.
+    pub fn new(server_path: &Path) -> Self {
+        Self {
+            path: server_path.to_path_buf(),
+        }
+    }
+    /// Extract the Bedrock Dedicated Server archive at the given path to the server directory. \
+    /// Its `server.properties`, `allowlist.json` and `permissions.json` files will only be extracted if they do not exist yet, so installing a newer
+    /// version keeps the settings of the server.
+    ///
+    /// # Returns
+    ///
+    /// | Return                         | Description                                       |
+    /// |--------------------------------|---------------------------------------------------|
+    /// | `Ok(())`                       | The server got installed.                         |
+    /// | [`MCManageError::InvalidFile`] | The archive contains no Bedrock Dedicated Server. |
+    /// | `Err(...)`                     | The archive could not be read or extracted.       |
+    pub async fn install(&self, archive_path: &Path) -> Result<(), MCManageError> {
+        let archive_path = archive_path.to_path_buf();
+        let server_path = self.path.clone();
+        spawn_blocking(move || Self::extract(&archive_path, &server_path))
+            .await
+            .map_err(|error| MCManageError::IOError(io::Error::new(ErrorKind::Other, error)))??;
+
+        if !self.is_installed() {
+            return Err(MCManageError::InvalidFile);
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            fs::set_permissions(self.binary_path(), std::fs::Permissions::from_mode(0o755)).await?;
+        }
+        Ok(())
+    }
+    /// Add the player with the given gamertag to the `allowlist.json` file. The xuid will be filled in by the server once the player joined.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                             |
+    /// |-----------------------------------|---------------------------------------------------------|
+    /// | `Ok(AllowlistEntry)`              | The player got added.                                   |
+    /// | [`MCManageError::InvalidRequest`] | The gamertag is empty or already in the allowlist.      |
+    /// | `Err(...)`                        | The `allowlist.json` file could not be read or written. |
+    pub async fn allow(&self, name: &str, ignores_player_limit: bool) -> Result<AllowlistEntry, MCManageError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(MCManageError::InvalidRequest(
+                "The gamertag of the player is required.".to_string(),
+            ));
+        }
+
+        let mut allowlist = self.allowlist().await?;
+        if allowlist.iter().any(|entry| entry.name.eq_ignore_ascii_case(name)) {
+            return Err(MCManageError::InvalidRequest(format!(
+                "The player {name} is already in the allowlist."
+            )));
+        }
+        let entry = AllowlistEntry {
+            name: name.to_string(),
+            xuid: None,
+            ignores_player_limit,
+        };
+        allowlist.push(entry.clone());
+        self.write_allowlist(&allowlist).await?;
+        Ok(entry)
+    }
+    /// Remove the player with the given gamertag from the `allowlist.json` file. Gamertags are not case-sensitive.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                                             |
+    /// |-----------------------------|---------------------------------------------------------|
+    /// | `Ok(())`                    | The player got removed.                                 |
+    /// | [`MCManageError::NotFound`] | The player is not in the allowlist.                     |
+    /// | `Err(...)`                  | The `allowlist.json` file could not be read or written. |
+    pub async fn disallow(&self, name: &str) -> Result<(), MCManageError> {
+        let allowlist = self.allowlist().await?;
+        let allowlist_len = allowlist.len();
+        let allowlist: Vec<AllowlistEntry> = allowlist
+            .into_iter()
+            .filter(|entry| !entry.name.eq_ignore_ascii_case(name.trim()))
+            .collect();
+        if allowlist.len() == allowlist_len {
+            return Err(MCManageError::NotFound);
+        }
+        self.write_allowlist(&allowlist).await
+    }
+}
+// info
+impl BedrockServer {
+    /// Return the path to the Bedrock Dedicated Server binary. The binary itself may not exist.
+    pub fn binary_path(&self) -> PathBuf {
+        self.path.join(BEDROCK_BINARY)
+    }
+    /// Returns true if the Bedrock Dedicated Server binary exists.
+    pub fn is_installed(&self) -> bool {
+        self.binary_path().exists()
+    }
+    /// Create the [`Command`] starting the Bedrock Dedicated Server. \
+    /// The binary gets linked against libraries shipped next to it, so the server directory gets used as the `LD_LIBRARY_PATH`.
+    pub fn command(&self) -> Command {
+        let mut command = Command::new(self.binary_path());
+        command.current_dir(&self.path).env("LD_LIBRARY_PATH", &self.path);
+        command
+    }
+    /// Read the settings of the server from its `server.properties` file.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                                     |
+    /// |-----------------------------|-------------------------------------------------|
+    /// | `Ok(BedrockProperties)`     | The settings got read.                          |
+    /// | [`MCManageError::NotFound`] | The server has no `server.properties` file yet. |
+    pub async fn properties(&self) -> Result<BedrockProperties, MCManageError> {
+        let properties = fs::read_to_string(self.path.join(SERVER_PROPERTIES))
+            .await
+            .map_err(|_| MCManageError::NotFound)?;
+        Ok(BedrockProperties::from_properties(&properties))
+    }
+    /// Read every player in the `allowlist.json` file. If the file does not exist, the allowlist is empty.
+    ///
+    /// # Returns
+    ///
+    /// | Return                         | Description                                  |
+    /// |--------------------------------|----------------------------------------------|
+    /// | `Ok(Vec<AllowlistEntry>)`      | The allowlist got read.                      |
+    /// | [`MCManageError::InvalidFile`] | The `allowlist.json` file is no allowlist.   |
+    /// | `Err(...)`                     | The `allowlist.json` file could not be read. |
+    pub async fn allowlist(&self) -> Result<Vec<AllowlistEntry>, MCManageError> {
+        let content = match fs::read(self.path.join(ALLOWLIST_JSON)).await {
+            Ok(content) => content,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(error) => return Err(error.into()),
+        };
+        serde_json::from_slice(&content).map_err(|_| MCManageError::InvalidFile)
+    }
+}
+// internal
+impl BedrockServer {
+    /// Write the given players to the `allowlist.json` file.
+    async fn write_allowlist(&self, allowlist: &[AllowlistEntry]) -> Result<(), MCManageError> {
+        fs::create_dir_all(&self.path).await?;
+        fs::write(self.path.join(ALLOWLIST_JSON), serde_json::to_vec_pretty(allowlist)?).await?;
+        Ok(())
+    }
+    /// Extract every entry of the given archive to the server directory, except for the [`KEPT_FILES`] which already exist.
+    fn extract(archive_path: &Path, server_path: &Path) -> Result<(), MCManageError> {
+        let mut archive = ZipArchive::new(File::open(archive_path)?)?;
+
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i)?;
+            // entries leaving the server directory will be skipped
+            let Some(relative_path) = entry.enclosed_name().map(Path::to_path_buf) else {
+                continue;
+            };
+            let destination = server_path.join(&relative_path);
+
+            if entry.is_dir() {
+                create_dir_all(destination)?;
+                continue;
+            }
+            if KEPT_FILES.iter().any(|kept_file| relative_path == Path::new(kept_file)) && destination.exists() {
+                continue;
+            }
+            if let Some(parent) = destination.parent() {
+                create_dir_all(parent)?;
+            }
+            io::copy(&mut entry, &mut File::create(destination)?)?;
+        }
+        Ok(())
+    }
+}
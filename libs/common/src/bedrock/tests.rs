@@ -0,0 +1,144 @@
+#![cfg(test)]
+
+use std::ffi::OsStr;
+
+use super::*;
+use crate::{
+    generated_files::paths::SERVERS_DIR,
+    test_functions::*,
+};
+
+/// Create a [`BedrockServer`] for the server `myBedrockServer`.
+fn setup() -> BedrockServer {
+    start_test();
+    BedrockServer::new(&SERVERS_DIR.join("myBedrockServer"))
+}
+
+#[tokio::test]
+async fn install() {
+    let bedrock_server = setup();
+    let server_path = SERVERS_DIR.join("myBedrockServer");
+    fs::create_dir_all(&server_path).await.unwrap();
+    fs::write(server_path.join(SERVER_PROPERTIES), "server-name=My Server\n").await.unwrap();
+
+    let archive_path = SERVERS_DIR.join("bedrock-server.zip");
+    fs::write(
+        &archive_path,
+        jar(&[
+            (BEDROCK_BINARY, "#!/bin/sh\n"),
+            ("libssl.so.1.1", ""),
+            (SERVER_PROPERTIES, "server-name=Dedicated Server\n"),
+            (ALLOWLIST_JSON, "[]"),
+            ("behavior_packs/vanilla/manifest.json", "{}"),
+            ("../outside.txt", ""),
+        ]),
+    )
+    .await
+    .unwrap();
+    assert!(!bedrock_server.is_installed());
+    bedrock_server.install(&archive_path).await.unwrap();
+
+    assert!(bedrock_server.is_installed());
+    assert!(server_path.join("libssl.so.1.1").exists());
+    assert!(server_path.join("behavior_packs/vanilla/manifest.json").exists());
+    assert!(!SERVERS_DIR.join("outside.txt").exists());
+    // the settings of the server have to be kept
+    assert_eq!(bedrock_server.properties().await.unwrap().server_name, "My Server");
+    assert_eq!(fs::read_to_string(server_path.join(ALLOWLIST_JSON)).await.unwrap(), "[]");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = fs::metadata(bedrock_server.binary_path()).await.unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0o111);
+    }
+
+    // an archive without the binary is no Bedrock Dedicated Server
+    fs::write(&archive_path, jar(&[("README.txt", "")])).await.unwrap();
+    let bedrock_server = BedrockServer::new(&SERVERS_DIR.join("myOtherServer"));
+    assert!(matches!(bedrock_server.install(&archive_path).await, Err(MCManageError::InvalidFile)));
+
+    cleanup();
+}
+#[tokio::test]
+async fn command() {
+    let bedrock_server = setup();
+
+    let command = bedrock_server.command();
+    let command = command.as_std();
+    assert_eq!(command.get_program(), bedrock_server.binary_path().as_os_str());
+    assert_eq!(command.get_current_dir(), Some(SERVERS_DIR.join("myBedrockServer").as_path()));
+    assert!(command
+        .get_envs()
+        .any(|(key, value)| key == OsStr::new("LD_LIBRARY_PATH") && value == Some(SERVERS_DIR.join("myBedrockServer").as_os_str())));
+
+    cleanup();
+}
+#[tokio::test]
+async fn properties() {
+    let bedrock_server = setup();
+    assert!(matches!(bedrock_server.properties().await, Err(MCManageError::NotFound)));
+
+    let properties = BedrockProperties::from_properties(
+        "server-name=My Bedrock Server\n# the gamemode of new players\ngamemode=creative\nmax-players=30\nserver-port=19200\nonline-mode=false\nwhite-list=true\nlevel-name=invalid=name\n",
+    );
+    assert_eq!(
+        properties,
+        BedrockProperties {
+            server_name: "My Bedrock Server".to_string(),
+            gamemode: "creative".to_string(),
+            difficulty: "easy".to_string(),
+            max_players: 30,
+            server_port: 19200,
+            server_portv6: 19133,
+            level_name: "invalid=name".to_string(),
+            online_mode: false,
+            allow_list: true,
+        }
+    );
+    assert_eq!(BedrockProperties::from_properties("max-players=many\n").max_players, 10);
+
+    cleanup();
+}
+#[tokio::test]
+async fn allowlist() {
+    let bedrock_server = setup();
+    assert_eq!(bedrock_server.allowlist().await.unwrap(), vec![]);
+
+    bedrock_server.allow("Gooxey", false).await.unwrap();
+    bedrock_server.allow(" Steve Miner ", true).await.unwrap();
+    assert!(matches!(bedrock_server.allow("gooxey", false).await, Err(MCManageError::InvalidRequest(_))));
+    assert!(matches!(bedrock_server.allow(" ", false).await, Err(MCManageError::InvalidRequest(_))));
+
+    // the server fills in the xuid once the player joined
+    let allowlist_path = SERVERS_DIR.join("myBedrockServer").join(ALLOWLIST_JSON);
+    let content = fs::read_to_string(&allowlist_path).await.unwrap();
+    assert!(content.contains("\"ignoresPlayerLimit\": true"));
+    fs::write(&allowlist_path, content.replacen("\"name\": \"Gooxey\"", "\"name\": \"Gooxey\", \"xuid\": \"2535416409208343\"", 1))
+        .await
+        .unwrap();
+    assert_eq!(
+        bedrock_server.allowlist().await.unwrap(),
+        vec![
+            AllowlistEntry {
+                name: "Gooxey".to_string(),
+                xuid: Some("2535416409208343".to_string()),
+                ignores_player_limit: false,
+            },
+            AllowlistEntry {
+                name: "Steve Miner".to_string(),
+                xuid: None,
+                ignores_player_limit: true,
+            },
+        ]
+    );
+
+    bedrock_server.disallow("GOOXEY").await.unwrap();
+    assert!(matches!(bedrock_server.disallow("Gooxey").await, Err(MCManageError::NotFound)));
+    assert_eq!(bedrock_server.allowlist().await.unwrap().len(), 1);
+
+    fs::write(&allowlist_path, "{}").await.unwrap();
+    assert!(matches!(bedrock_server.allowlist().await, Err(MCManageError::InvalidFile)));
+
+    cleanup();
+}
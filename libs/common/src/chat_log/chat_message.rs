@@ -0,0 +1,26 @@
+//! This module provides the [`ChatMessage`] struct.
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This struct represents a message a player sent to the chat of a Minecraft server.
+#[add_convert]
+pub struct ChatMessage {
+    /// The id of this message in the database. This will be `None` if the message could not be saved.
+    pub id: Option<i64>,
+    /// The name of the server the message got sent on
+    pub server: String,
+    /// The uuid of the sender, if it is known
+    pub uuid: Option<String>,
+    /// The name of the sender
+    pub sender: String,
+    /// The content of the message
+    pub message: String,
+    /// The time the message got sent at
+    pub sent_at: DateTime<Utc>,
+}
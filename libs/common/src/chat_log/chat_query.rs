@@ -0,0 +1,29 @@
+//! This module provides the [`ChatQuery`] struct.
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This struct describes which [`ChatMessages`](super::chat_message::ChatMessage) a search should return. \
+/// Every filter which is not set matches every message.
+#[add_convert]
+#[derive(Default)]
+#[serde(default)]
+pub struct ChatQuery {
+    /// Only return messages sent on the server with this name.
+    pub server: Option<String>,
+    /// Only return messages sent by the player with this name or uuid.
+    pub sender: Option<String>,
+    /// Only return messages containing this text. The case will be ignored.
+    pub text: Option<String>,
+    /// Only return messages sent at or after this time.
+    pub since: Option<DateTime<Utc>>,
+    /// Only return messages sent before this time.
+    pub until: Option<DateTime<Utc>>,
+    /// The maximum number of messages to return. It defaults to 100 and can not be higher than 1000.
+    pub limit: Option<i64>,
+}
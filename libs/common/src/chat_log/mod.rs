@@ -0,0 +1,172 @@
+//! This module provides the [`ChatLog`] struct, which saves the chat of every Minecraft server to the database.
+
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+
+use chrono::Utc;
+use goolog::*;
+use tokio::{
+    sync::{
+        Mutex,
+        OnceCell,
+    },
+    time::Instant,
+};
+
+use self::{
+    chat_message::ChatMessage,
+    chat_query::ChatQuery,
+};
+use crate::{
+    config::Config,
+    event_bus::{
+        event::Event,
+        EventBus,
+    },
+    mcmanage_error::MCManageError,
+    storage::HistoryStorage,
+};
+
+pub mod chat_message;
+pub mod chat_query;
+mod tests;
+
+const GOOLOG_CALLER: &str = "ChatLog";
+/// The number of messages a search returns if no limit got set
+const DEFAULT_LIMIT: i64 = 100;
+/// The maximum number of messages a search can return
+const MAX_LIMIT: i64 = 1000;
+/// How often messages older than the [`chat retention`](Config::chat_retention) get deleted
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// The storage the messages get saved to. It gets set by the [`init`](ChatLog::init) method.
+static CHAT_LOG_STORAGE: OnceCell<Arc<dyn HistoryStorage>> = OnceCell::const_new();
+/// The time the old messages got deleted the last time
+static LAST_PRUNE: Mutex<Option<Instant>> = Mutex::const_new(None);
+
+/// This struct provides access to the `chat_messages` table. New messages get published to the [`EventBus`] as [`Event::Chat`]. \
+/// Messages older than the [`chat retention`](Config::chat_retention) will be deleted regularly.
+pub struct ChatLog;
+// actions
+impl ChatLog {
+    /// Start saving the messages to the given storage and delete every message older than the [`chat retention`](Config::chat_retention). \
+    /// This method can only be called once. Every other method using the database will return an [`MCManageError::NotReady`] until then.
+    pub async fn init(storage: Arc<dyn HistoryStorage>) -> Result<(), MCManageError> {
+        if CHAT_LOG_STORAGE.set(storage).is_err() {
+            fatal!("The chat log has already been initialized.")
+        }
+        Self::prune().await?;
+        Ok(())
+    }
+    /// Save the given message sent on the given server and publish it to the [`EventBus`]. \
+    /// The message will be published even if it could not be saved.
+    pub async fn record(
+        server: &str,
+        uuid: Option<&str>,
+        sender: &str,
+        message: &str,
+    ) -> Result<ChatMessage, MCManageError> {
+        let mut chat_message = ChatMessage {
+            id: None,
+            server: server.to_string(),
+            uuid: uuid.map(str::to_string),
+            sender: sender.to_string(),
+            message: message.to_string(),
+            sent_at: Utc::now(),
+        };
+
+        let result = Self::insert(&chat_message).await;
+        if let Ok(id) = result {
+            chat_message.id = Some(id);
+        }
+        EventBus::publish(
+            server,
+            Event::Chat {
+                message: chat_message.clone(),
+            },
+        );
+        result?;
+
+        let prune_due = LAST_PRUNE
+            .lock()
+            .await
+            .map_or(true, |last_prune| last_prune.elapsed() >= PRUNE_INTERVAL);
+        if prune_due {
+            Self::prune().await?;
+        }
+        Ok(chat_message)
+    }
+    /// Move every message sent on the server with the given old name to its new name. This has to be done every time a server gets renamed,
+    /// since the messages are saved by the name of their server.
+    pub async fn rename_server(old_name: &str, new_name: &str) -> Result<(), MCManageError> {
+        let moved = Self::storage()?.rename_chat_messages(old_name, new_name).await?;
+        if moved > 0 {
+            info!("Moved {moved} chat messages of the server {old_name} to its new name {new_name}.");
+        }
+        Ok(())
+    }
+}
+// info
+impl ChatLog {
+    /// Return every message matching the given [`ChatQuery`], starting with the latest one.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                          |
+    /// |-----------------------------------|--------------------------------------|
+    /// | `Ok(Vec<ChatMessage>)`            | The search succeeded.                |
+    /// | [`MCManageError::InvalidRequest`] | The limit is not between 1 and 1000. |
+    /// | `Err(...)`                        | The database could not be queried.   |
+    pub async fn search(query: &ChatQuery) -> Result<Vec<ChatMessage>, MCManageError> {
+        let limit = query.limit.unwrap_or(DEFAULT_LIMIT);
+        if !(1..=MAX_LIMIT).contains(&limit) {
+            return Err(MCManageError::InvalidRequest(format!(
+                "The limit has to be between 1 and {MAX_LIMIT}."
+            )));
+        }
+
+        Self::storage()?.chat_messages(query, limit).await
+    }
+}
+// internal
+impl ChatLog {
+    /// Return the storage the messages get saved to.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                                              |
+    /// |-----------------------------|----------------------------------------------------------|
+    /// | `Ok(&dyn HistoryStorage)`   | The chat log got initialized.                            |
+    /// | [`MCManageError::NotReady`] | The [`init`](Self::init) method has not been called yet. |
+    fn storage() -> Result<&'static dyn HistoryStorage, MCManageError> {
+        CHAT_LOG_STORAGE
+            .get()
+            .map(|storage| storage.as_ref())
+            .ok_or(MCManageError::NotReady)
+    }
+    /// Save the given message and return its id.
+    async fn insert(chat_message: &ChatMessage) -> Result<i64, MCManageError> {
+        Self::storage()?.add_chat_message(chat_message).await
+    }
+    /// Delete every message older than the [`chat retention`](Config::chat_retention). A retention of 0 keeps every message.
+    async fn prune() -> Result<(), MCManageError> {
+        *LAST_PRUNE.lock().await = Some(Instant::now());
+
+        let retention = Config::chat_retention().await;
+        if retention.is_zero() {
+            return Ok(());
+        }
+        let Ok(retention) = chrono::Duration::from_std(retention) else {
+            return Ok(());
+        };
+
+        let deleted = Self::storage()?
+            .remove_chat_messages(Utc::now() - retention)
+            .await?;
+        if deleted > 0 {
+            info!("Deleted {deleted} chat messages older than the chat retention.");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+#![cfg(test)]
+
+use chrono::{
+    DateTime,
+    TimeZone,
+};
+
+use super::*;
+use crate::{
+    storage::sqlite_storage::SqliteStorage,
+    test_functions::*,
+};
+
+/// The uuid of the player `Notch`
+const NOTCH: &str = "069a79f4-44e9-4726-a5be-fca90e38aaf5";
+
+/// Save a message of the player `jeb_` sent at the given time on the server `myLobby`.
+async fn insert_message(storage: &dyn HistoryStorage, message: &str, sent_at: DateTime<Utc>) {
+    storage
+        .add_chat_message(&ChatMessage {
+            id: None,
+            server: "myLobby".to_string(),
+            uuid: None,
+            sender: "jeb_".to_string(),
+            message: message.to_string(),
+            sent_at,
+        })
+        .await
+        .unwrap();
+}
+/// Return the content of every message found by the given [`ChatQuery`].
+async fn search(query: ChatQuery) -> Vec<String> {
+    ChatLog::search(&query)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|chat_message| chat_message.message)
+        .collect()
+}
+
+#[tokio::test]
+async fn search_limit() {
+    for limit in [0, -1, MAX_LIMIT + 1] {
+        assert!(matches!(
+            ChatLog::search(&ChatQuery {
+                limit: Some(limit),
+                ..Default::default()
+            })
+            .await,
+            Err(MCManageError::InvalidRequest(_))
+        ));
+    }
+    // valid limits only fail if the chat log has not been initialized
+    for limit in [None, Some(1), Some(MAX_LIMIT)] {
+        assert!(!matches!(
+            ChatLog::search(&ChatQuery {
+                limit,
+                ..Default::default()
+            })
+            .await,
+            Err(MCManageError::InvalidRequest(_))
+        ));
+    }
+}
+/// The [`ChatLog`] can only be initialized once per process, so every part of it using the database gets tested in this one test.
+#[tokio::test]
+async fn chat_log() {
+    let storage = Arc::new(SqliteStorage::in_memory().await.unwrap());
+    init_config().await;
+    ChatLog::init(storage.clone()).await.unwrap();
+
+    // record
+    let chat_message = ChatLog::record("myLobby", Some(NOTCH), "Notch", "Hello World!")
+        .await
+        .unwrap();
+    assert!(chat_message.id.is_some());
+    let found = ChatLog::search(&ChatQuery::default()).await.unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(
+        (found[0].id, found[0].uuid.as_deref(), found[0].sender.as_str()),
+        (chat_message.id, Some(NOTCH), "Notch")
+    );
+    ChatLog::record("myLobby", None, "jeb_", "hello there").await.unwrap();
+    ChatLog::record("mySurvival", Some(NOTCH), "Notch", "Bye").await.unwrap();
+    // renaming the server moves its messages
+    ChatLog::rename_server("mySurvival", "myCreative").await.unwrap();
+    assert!(search(ChatQuery {
+        server: Some("mySurvival".to_string()),
+        ..Default::default()
+    })
+    .await
+    .is_empty());
+    assert_eq!(
+        search(ChatQuery {
+            server: Some("myCreative".to_string()),
+            ..Default::default()
+        })
+        .await,
+        vec!["Bye"]
+    );
+
+    // the latest message comes first
+    assert_eq!(search(ChatQuery::default()).await, vec!["Bye", "hello there", "Hello World!"]);
+    assert_eq!(
+        search(ChatQuery {
+            limit: Some(1),
+            ..Default::default()
+        })
+        .await,
+        vec!["Bye"]
+    );
+
+    // filters
+    assert_eq!(
+        search(ChatQuery {
+            server: Some("myLobby".to_string()),
+            ..Default::default()
+        })
+        .await,
+        vec!["hello there", "Hello World!"]
+    );
+    assert_eq!(
+        search(ChatQuery {
+            sender: Some("notch".to_string()),
+            ..Default::default()
+        })
+        .await,
+        vec!["Bye", "Hello World!"]
+    );
+    assert_eq!(
+        search(ChatQuery {
+            sender: Some(NOTCH.to_uppercase()),
+            server: Some("myLobby".to_string()),
+            ..Default::default()
+        })
+        .await,
+        vec!["Hello World!"]
+    );
+    assert_eq!(
+        search(ChatQuery {
+            text: Some("HELLO".to_string()),
+            ..Default::default()
+        })
+        .await,
+        vec!["hello there", "Hello World!"]
+    );
+
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    insert_message(storage.as_ref(), "before", start - chrono::Duration::seconds(1)).await;
+    insert_message(storage.as_ref(), "at the start", start).await;
+    insert_message(storage.as_ref(), "at the end", start + chrono::Duration::hours(1)).await;
+    assert_eq!(
+        search(ChatQuery {
+            since: Some(start),
+            until: Some(start + chrono::Duration::hours(1)),
+            ..Default::default()
+        })
+        .await,
+        vec!["at the start"]
+    );
+    assert_eq!(
+        search(ChatQuery {
+            until: Some(start),
+            ..Default::default()
+        })
+        .await,
+        vec!["before"]
+    );
+
+    // prune
+    let retention = chrono::Duration::from_std(Config::chat_retention().await).unwrap();
+    insert_message(storage.as_ref(), "expired", Utc::now() - retention - chrono::Duration::minutes(1)).await;
+    insert_message(storage.as_ref(), "kept", Utc::now() - retention + chrono::Duration::minutes(1)).await;
+    ChatLog::prune().await.unwrap();
+    let messages = search(ChatQuery::default()).await;
+    assert!(!messages.contains(&"expired".to_string()));
+    assert!(messages.contains(&"kept".to_string()));
+}
@@ -30,6 +30,7 @@ use crate::{
         paths::CONFIG_FILE,
     },
     mcmanage_error::MCManageError,
+    storage::database_backend::DatabaseBackend,
 };
 
 mod tests;
@@ -41,13 +42,23 @@ static CONFIG: OnceLock<Mutex<Config>> = OnceLock::new();
 /// See the fields functions for more information on them.
 #[add_toml_convert]
 pub struct Config {
-    agree_to_eula: bool,
     buffsize: usize,
     cooldown: Duration,
     max_tries: u64,
     shutdown_time: Duration,
     website_port: u16,
-    database_port: i16
+    database_port: i16,
+    database_backend: DatabaseBackend,
+    enable_history_and_webhooks: bool,
+    mojang_api_url: String,
+    papermc_api_url: String,
+    purpur_api_url: String,
+    fabric_meta_url: String,
+    quilt_meta_url: String,
+    update_check_interval: Duration,
+    update_start_timeout: Duration,
+    store_player_ips: bool,
+    chat_retention: Duration,
 }
 impl Config {
     /// This method will do two things:
@@ -135,13 +146,6 @@ impl Config {
             .await
     }
 
-    /// Return whether or not all EULAs for the Minecraft servers get accepted automatically. \
-    /// The following line is copied from the vanilla Minecraft server's EULA. \
-    /// ' By changing the setting below to TRUE you are indicating your agreement to our EULA <https://aka.ms/MinecraftEULA>. ' \
-    /// In other words:, when this function returns true, you are agreeing to any EULA this application automatically accepts.
-    pub async fn agree_to_eula() -> bool {
-        Self::get_config().await.agree_to_eula
-    }
     /// Return the size of the buffers created by this application. (If set too low, it can cause many different kinds of information to only be partially transmitted.)
     pub async fn buffsize() -> usize {
         Self::get_config().await.buffsize
@@ -167,4 +171,50 @@ impl Config {
     pub async fn database_port() -> i16 {
         Self::get_config().await.database_port
     }
+    /// The database the server list gets saved to.
+    pub async fn database_backend() -> DatabaseBackend {
+        Self::get_config().await.database_backend
+    }
+    /// Whether or not the player history, the chat log and the webhooks should be enabled. They get saved to the database of the selected
+    /// [`database backend`](Self::database_backend).
+    pub async fn enable_history_and_webhooks() -> bool {
+        Self::get_config().await.enable_history_and_webhooks
+    }
+    /// The base url of the api serving Mojang's version manifest. (Used to resolve vanilla server jars)
+    pub async fn mojang_api_url() -> String {
+        Self::get_config().await.mojang_api_url.clone()
+    }
+    /// The base url of the PaperMC api. (Used to resolve paper server jars)
+    pub async fn papermc_api_url() -> String {
+        Self::get_config().await.papermc_api_url.clone()
+    }
+    /// The base url of the Purpur api. (Used to resolve purpur server jars)
+    pub async fn purpur_api_url() -> String {
+        Self::get_config().await.purpur_api_url.clone()
+    }
+    /// The base url of the Fabric meta api. (Used to resolve Fabric server launchers)
+    pub async fn fabric_meta_url() -> String {
+        Self::get_config().await.fabric_meta_url.clone()
+    }
+    /// The base url of the Quilt meta api. (Used to resolve the Quilt installer, which installs Quilt server launchers)
+    pub async fn quilt_meta_url() -> String {
+        Self::get_config().await.quilt_meta_url.clone()
+    }
+    /// How often every Minecraft server should be checked for a newer build.
+    pub async fn update_check_interval() -> Duration {
+        Self::get_config().await.update_check_interval
+    }
+    /// How long an updated Minecraft server may take to start before the previous build gets restored.
+    pub async fn update_start_timeout() -> Duration {
+        Self::get_config().await.update_start_timeout
+    }
+    /// Whether or not the ip a player joined from should be saved to the database.
+    pub async fn store_player_ips() -> bool {
+        Self::get_config().await.store_player_ips
+    }
+    /// How long chat messages are kept before they get deleted. \
+    /// If the value is 0, chat messages will be kept forever.
+    pub async fn chat_retention() -> Duration {
+        Self::get_config().await.chat_retention
+    }
 }
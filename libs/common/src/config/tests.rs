@@ -32,12 +32,12 @@ async fn field_gets_changed() {
 
     Config::init().await;
     let mut config_default: Config = toml::from_str(get_valid_content(&CONFIG_FILE)).unwrap();
-    config_default.agree_to_eula = false;
+    config_default.max_tries = 5;
 
     sleep(Duration::new(1, 0)).await;
 
     let mut config_toml = load_toml_replace(&CONFIG_FILE, "test", true).await;
-    *config_toml.get_mut("agree_to_eula").unwrap() = toml::Value::from(false);
+    *config_toml.get_mut("max_tries").unwrap() = toml::Value::from(5);
 
     fs::write(
         CONFIG_FILE.as_path(),
@@ -64,7 +64,7 @@ async fn file_gets_invalid() {
     sleep(Duration::new(1, 0)).await;
 
     let mut config_toml = load_toml_replace(&CONFIG_FILE, "test", true).await;
-    *config_toml.get_mut("agree_to_eula").unwrap() = toml::Value::from("should be a bool");
+    *config_toml.get_mut("max_tries").unwrap() = toml::Value::from("should be a number");
 
     fs::write(
         CONFIG_FILE.as_path(),
@@ -63,9 +63,9 @@ pub async fn init_db_server() {
         database.create_database("config").await;
     }
     if !database.database_exists("server_types").await.unwrap_or_else(|error| {
-        fatal!("Could not check if the server_list database exists. Error: {error}")
+        fatal!("Could not check if the server_types database exists. Error: {error}")
     }) {
-        database.create_database("server_list").await;
+        database.create_database("server_types").await;
     }
     if !database.database_exists("server_list").await.unwrap_or_else(|error| {
         fatal!("Could not check if the server_list database exists. Error: {error}")
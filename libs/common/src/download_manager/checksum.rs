@@ -0,0 +1,57 @@
+//! This module provides the [`Checksum`] enum, which is used to verify files downloaded by the [`DownloadManager`](super::DownloadManager).
+
+use proc_macros::add_convert;
+
+use super::download_index::CachedFile;
+use crate::mcmanage_error::MCManageError;
+
+/// This enum represents a checksum a downloaded file has to match. \
+/// The hash itself is stored as a hex string. Upper and lower case letters are treated the same.
+#[add_convert]
+pub enum Checksum {
+    /// A sha256 hash of the file.
+    Sha256(String),
+    /// A sha1 hash of the file.
+    Sha1(String),
+    /// A md5 hash of the file.
+    Md5(String),
+}
+impl Checksum {
+    /// Return the expected hash as a lowercase hex string.
+    pub fn value(&self) -> String {
+        match self {
+            Self::Sha256(hash) | Self::Sha1(hash) | Self::Md5(hash) => hash.to_lowercase(),
+        }
+    }
+    /// Return whether or not the given [`CachedFile`] has the hash described by this checksum.
+    pub fn matches(&self, file: &CachedFile) -> bool {
+        match self {
+            Self::Sha256(_) => file.sha256 == self.value(),
+            Self::Sha1(_) => file.sha1 == self.value(),
+            Self::Md5(_) => file.md5 == self.value(),
+        }
+    }
+    /// Check whether or not the given [`CachedFile`] has the hash described by this checksum.
+    ///
+    /// # Returns
+    ///
+    /// | Return                              | Description                                    |
+    /// |-------------------------------------|------------------------------------------------|
+    /// | `Ok(())`                            | The file has the expected hash.                |
+    /// | [`MCManageError::ChecksumMismatch`] | The file's hash differs from the expected one. |
+    pub fn verify(&self, file: &CachedFile) -> Result<(), MCManageError> {
+        if self.matches(file) {
+            return Ok(());
+        }
+
+        let found = match self {
+            Self::Sha256(_) => file.sha256.clone(),
+            Self::Sha1(_) => file.sha1.clone(),
+            Self::Md5(_) => file.md5.clone(),
+        };
+        Err(MCManageError::ChecksumMismatch {
+            expected: self.value(),
+            found,
+        })
+    }
+}
@@ -0,0 +1,35 @@
+//! This module provides the [`DownloadEvent`] enum, which describes the progress of a [`Download`](super::Download).
+
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This enum represents an event sent by a [`Download`](super::Download) to inform about its progress.
+#[add_convert]
+pub enum DownloadEvent {
+    /// The file was found in the cache. No download was necessary.
+    Cached,
+    /// The download has started.
+    Started {
+        /// The number of bytes which got downloaded by an earlier, interrupted attempt.
+        resumed_from: u64,
+        /// The size of the complete file in bytes, if announced by the server.
+        total: Option<u64>,
+    },
+    /// A new part of the file has been downloaded.
+    Progress {
+        /// The number of bytes downloaded so far.
+        downloaded: u64,
+        /// The size of the complete file in bytes, if announced by the server.
+        total: Option<u64>,
+    },
+    /// The download failed and will be retried.
+    Retrying {
+        /// The number of the attempt which failed, starting at 1.
+        attempt: u64,
+        /// The maximum number of attempts.
+        max_tries: u64,
+    },
+    /// The file has been downloaded, verified and copied to its destination.
+    Finished,
+}
@@ -0,0 +1,117 @@
+//! This module provides the [`DownloadIndex`] struct, which keeps track of every file in the [`DOWNLOADS_DIR`] and where it came from.
+
+use std::path::PathBuf;
+
+use chrono::prelude::*;
+use goolog::*;
+use proc_macros::add_convert;
+use tokio::fs;
+
+use crate::{
+    generated_files::paths::{
+        DOWNLOADS_DIR,
+        DOWNLOAD_INDEX_FILE,
+    },
+    mcmanage_error::MCManageError,
+};
+
+/// The name of the [`DownloadIndex`] used in log messages.
+const GOOLOG_CALLER: &str = "DownloadIndex";
+
+/// This struct represents one file saved in the [`DOWNLOADS_DIR`].
+#[add_convert]
+pub struct CachedFile {
+    /// The url this file got downloaded from.
+    pub url: String,
+    /// The sha256 hash of this file as a lowercase hex string.
+    pub sha256: String,
+    /// The sha1 hash of this file as a lowercase hex string.
+    pub sha1: String,
+    /// The md5 hash of this file as a lowercase hex string.
+    #[serde(default)]
+    pub md5: String,
+    /// The size of this file in bytes.
+    pub size: u64,
+    /// The time this file got downloaded.
+    pub downloaded_at: DateTime<Utc>,
+}
+impl CachedFile {
+    /// Return the path to this file. \
+    /// Files in the cache are named after their sha256 hash, so that identical files from different origins only get stored once.
+    pub fn path(&self) -> PathBuf {
+        DOWNLOADS_DIR.join(&self.sha256)
+    }
+}
+
+/// This struct represents the file at [`DOWNLOAD_INDEX_FILE`]. It saves the origin of every file in the [`DOWNLOADS_DIR`].
+#[add_convert]
+#[derive(Default)]
+pub struct DownloadIndex {
+    /// Every file in the [`DOWNLOADS_DIR`]
+    files: Vec<CachedFile>,
+}
+impl DownloadIndex {
+    /// Load the index from the [`DOWNLOAD_INDEX_FILE`]. \
+    /// Entries whose files got deleted in the meantime will be removed. If the index is missing or invalid, an empty one will be returned.
+    pub async fn load() -> Self {
+        let mut index = match fs::read(DOWNLOAD_INDEX_FILE.as_path()).await {
+            Ok(content) => Self::try_from(content).unwrap_or_else(|error| {
+                warn!(
+                    "The file at '{}' is invalid. A new index will be created. Error: {error}",
+                    DOWNLOAD_INDEX_FILE.display()
+                );
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        };
+
+        let file_count = index.files.len();
+        index.files.retain(|file| file.path().exists());
+        if index.files.len() != file_count {
+            info!(
+                "Removed {} entries of deleted files from the index.",
+                file_count - index.files.len()
+            );
+            if let Err(error) = index.save().await {
+                error!("Failed to save the refreshed index. Error: {error}");
+            }
+        }
+
+        index
+    }
+    /// Save the index to the [`DOWNLOAD_INDEX_FILE`].
+    pub async fn save(&self) -> Result<(), MCManageError> {
+        fs::create_dir_all(DOWNLOADS_DIR.as_path()).await?;
+        fs::write(
+            DOWNLOAD_INDEX_FILE.as_path(),
+            serde_json::to_vec_pretty(self)?,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Get the newest file downloaded from the given url. \
+    /// Files which got deleted since the index got loaded will be ignored.
+    pub fn get_by_url(&self, url: &str) -> Option<CachedFile> {
+        self.files
+            .iter()
+            .rev()
+            .find(|file| file.url == url && file.path().exists())
+            .cloned()
+    }
+    /// Get a file which satisfies the given predicate, no matter where it came from. \
+    /// Files which got deleted since the index got loaded will be ignored.
+    pub fn find(&self, predicate: impl Fn(&CachedFile) -> bool) -> Option<CachedFile> {
+        self.files
+            .iter()
+            .find(|file| predicate(file) && file.path().exists())
+            .cloned()
+    }
+    /// Add a file to the index and save it. \
+    /// Any older entry with the same url will be replaced.
+    pub async fn insert(&mut self, file: CachedFile) -> Result<(), MCManageError> {
+        self.files.retain(|cached| cached.url != file.url);
+        self.files.push(file);
+        self.save().await
+    }
+}
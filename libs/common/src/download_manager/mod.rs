@@ -0,0 +1,397 @@
+//! This module provides the [`DownloadManager`], which is responsible for downloading every file this application needs from the internet. \
+//! Every downloaded file gets saved in the [`DOWNLOADS_DIR`] together with its origin, so that it only has to be downloaded once.
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+};
+
+use futures_util::StreamExt;
+use goolog::*;
+use md5::Md5;
+use once_cell::sync::Lazy;
+use reqwest::{
+    header::RANGE,
+    Client,
+    Response,
+    StatusCode,
+};
+use sha1::Sha1;
+use sha2::{
+    Digest,
+    Sha256,
+};
+use tokio::{
+    fs::{
+        self,
+        File,
+        OpenOptions,
+    },
+    io::{
+        AsyncReadExt,
+        AsyncWriteExt,
+    },
+    select,
+    spawn,
+    sync::{
+        broadcast,
+        Mutex,
+        MutexGuard,
+        OnceCell,
+    },
+    task::JoinHandle,
+    time::sleep,
+};
+use tokio_util::sync::CancellationToken;
+
+use self::{
+    checksum::Checksum,
+    download_event::DownloadEvent,
+    download_index::{
+        CachedFile,
+        DownloadIndex,
+    },
+};
+use crate::{
+    config::Config,
+    generated_files::paths::DOWNLOADS_DIR,
+    mcmanage_error::MCManageError,
+};
+
+pub mod checksum;
+pub mod download_event;
+pub mod download_index;
+mod tests;
+
+const GOOLOG_CALLER: &str = "DownloadManager";
+/// The index of every file in the [`DOWNLOADS_DIR`]. It gets loaded on first use.
+static DOWNLOAD_INDEX: OnceCell<Mutex<DownloadIndex>> = OnceCell::const_new();
+/// One lock per url to prevent the same file from being downloaded twice at the same time.
+static URL_LOCKS: Lazy<Mutex<HashMap<String, Arc<Mutex<()>>>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+/// The client used for every download.
+static CLIENT: Lazy<Client> = Lazy::new(Client::new);
+
+/// This struct describes a file to download.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DownloadRequest {
+    /// The url to download the file from.
+    pub url: String,
+    /// The checksum the downloaded file has to match. If set, any cached file with the same hash can be used, no matter where it came from.
+    pub checksum: Option<Checksum>,
+}
+impl DownloadRequest {
+    /// Create a new [`DownloadRequest`] without a checksum.
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            checksum: None,
+        }
+    }
+    /// Set the [`Checksum`] the downloaded file has to match.
+    pub fn with_checksum(mut self, checksum: Checksum) -> Self {
+        self.checksum = Some(checksum);
+        self
+    }
+}
+
+/// This struct represents a running download started by the [`DownloadManager`].
+pub struct Download {
+    /// The token used to cancel this download
+    cancellation_token: CancellationToken,
+    /// The channel every [`DownloadEvent`] of this download gets sent to
+    events: broadcast::Sender<DownloadEvent>,
+    /// A receiver created before the download started. It makes sure the first subscriber does not miss any event.
+    first_receiver: Option<broadcast::Receiver<DownloadEvent>>,
+    /// The task executing this download
+    task: JoinHandle<Result<CachedFile, MCManageError>>,
+}
+impl Download {
+    /// Get a receiver for every [`DownloadEvent`] of this download. \
+    /// Only the first receiver is guaranteed to get every event. Later ones will only get the events sent after their creation.
+    pub fn subscribe(&mut self) -> broadcast::Receiver<DownloadEvent> {
+        self.first_receiver
+            .take()
+            .unwrap_or_else(|| self.events.subscribe())
+    }
+    /// Get the token which can be used to cancel this download from anywhere.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation_token.clone()
+    }
+    /// Cancel this download. \
+    /// Already downloaded parts of the file will be kept, so that the next download of the same url can resume where this one stopped.
+    pub fn cancel(&self) {
+        self.cancellation_token.cancel();
+    }
+    /// Wait for this download to finish.
+    ///
+    /// # Returns
+    ///
+    /// | Return                              | Description                                                    |
+    /// |-------------------------------------|----------------------------------------------------------------|
+    /// | `Ok(CachedFile)`                    | The file got copied to its destination.                        |
+    /// | [`MCManageError::Cancelled`]        | The download got cancelled.                                    |
+    /// | [`MCManageError::ChecksumMismatch`] | The downloaded file does not match the checksum requested.     |
+    /// | [`MCManageError::ReqwestError`]     | The file could not be downloaded, even after multiple retries. |
+    /// | [`MCManageError::IOError`]          | The file could not be saved.                                   |
+    pub async fn finish(self) -> Result<CachedFile, MCManageError> {
+        self.task.await.unwrap_or_else(|error| {
+            fatal!("The download task stopped unexpectedly. Error: {error}")
+        })
+    }
+}
+
+/// This struct is responsible for downloading files and caching them in the [`DOWNLOADS_DIR`]. \
+/// Files in the cache can be found by their url or, if a [`Checksum`] was requested, by their hash. Interrupted downloads will be resumed using HTTP range requests.
+pub struct DownloadManager;
+// actions
+impl DownloadManager {
+    /// Download the file described by the [`DownloadRequest`] and copy it to the given destination. \
+    /// If the file can be found in the cache, no download will be performed. \
+    /// \
+    /// The download runs in its own task. Use the returned [`Download`] to follow its progress, cancel it or wait for it to finish.
+    pub fn download(request: DownloadRequest, destination: &Path) -> Download {
+        Self::download_cancellable(request, destination, CancellationToken::new())
+    }
+    /// Same as the [`download`](Self::download) method, but the download can be cancelled using the given token. \
+    /// This way the token can be handed out before the download gets started. If it already got cancelled, the download will not be started at all.
+    pub fn download_cancellable(
+        request: DownloadRequest,
+        destination: &Path,
+        cancellation_token: CancellationToken,
+    ) -> Download {
+        let (events, first_receiver) = broadcast::channel(64);
+
+        let task = spawn(Self::run(
+            request,
+            destination.to_path_buf(),
+            cancellation_token.clone(),
+            events.clone(),
+        ));
+
+        Download {
+            cancellation_token,
+            events,
+            first_receiver: Some(first_receiver),
+            task,
+        }
+    }
+    /// Get the newest cached file downloaded from the given url.
+    pub async fn cached(url: &str) -> Option<CachedFile> {
+        Self::index().await.get_by_url(url)
+    }
+}
+// internal
+impl DownloadManager {
+    /// Get the [`DownloadIndex`]. It will be loaded from the disk on first use.
+    async fn index<'a>() -> MutexGuard<'a, DownloadIndex> {
+        DOWNLOAD_INDEX
+            .get_or_init(|| async { DownloadIndex::load().await.into() })
+            .await
+            .lock()
+            .await
+    }
+    /// Get the lock preventing the given url from being downloaded twice at the same time.
+    async fn url_lock(url: &str) -> Arc<Mutex<()>> {
+        URL_LOCKS
+            .lock()
+            .await
+            .entry(url.to_string())
+            .or_default()
+            .clone()
+    }
+    /// Get the path unfinished downloads of the given url get saved to.
+    fn part_path(url: &str) -> PathBuf {
+        DOWNLOADS_DIR.join(format!(
+            "{}.part",
+            hex::encode(Sha256::digest(url.as_bytes()))
+        ))
+    }
+    /// Run the given future until it finishes or the download gets cancelled.
+    async fn cancellable<T>(
+        cancellation_token: &CancellationToken,
+        future: impl Future<Output = T>,
+    ) -> Result<T, MCManageError> {
+        select! {
+            biased;
+            _ = cancellation_token.cancelled() => Err(MCManageError::Cancelled),
+            output = future => Ok(output),
+        }
+    }
+    /// Send an event to every subscriber of a [`Download`]. \
+    /// It does not matter if nobody is listening.
+    fn emit(events: &broadcast::Sender<DownloadEvent>, event: DownloadEvent) {
+        let _ = events.send(event);
+    }
+
+    /// This is the task behind every [`Download`].
+    async fn run(
+        request: DownloadRequest,
+        destination: PathBuf,
+        cancellation_token: CancellationToken,
+        events: broadcast::Sender<DownloadEvent>,
+    ) -> Result<CachedFile, MCManageError> {
+        let url_lock = Self::url_lock(&request.url).await;
+        let _url_guard = Self::cancellable(&cancellation_token, url_lock.lock()).await?;
+
+        let cached = {
+            let index = Self::index().await;
+            if let Some(checksum) = &request.checksum {
+                index.find(|file| checksum.matches(file))
+            } else {
+                index.get_by_url(&request.url)
+            }
+        };
+
+        let file = if let Some(file) = cached {
+            Self::emit(&events, DownloadEvent::Cached);
+            file
+        } else {
+            Self::fetch(&request, &cancellation_token, &events).await?
+        };
+
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::copy(file.path(), &destination).await?;
+
+        Self::emit(&events, DownloadEvent::Finished);
+        Ok(file)
+    }
+    /// Download the file described by the [`DownloadRequest`] into the cache. \
+    /// Failed attempts will be retried up to [`Config::max_tries`] times.
+    async fn fetch(
+        request: &DownloadRequest,
+        cancellation_token: &CancellationToken,
+        events: &broadcast::Sender<DownloadEvent>,
+    ) -> Result<CachedFile, MCManageError> {
+        fs::create_dir_all(DOWNLOADS_DIR.as_path()).await?;
+        let part_path = Self::part_path(&request.url);
+
+        let max_tries = Config::max_tries().await;
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            match Self::fetch_part(&request.url, &part_path, cancellation_token, events).await {
+                Ok(()) => break,
+                Err(MCManageError::ReqwestError(error))
+                    if attempt < max_tries
+                        && error
+                            .status()
+                            .map_or(true, |status| !status.is_client_error()) =>
+                {
+                    warn!("Failed to download '{}'. Error: {error}", request.url);
+                    warn!("This was attempt {attempt} out of {max_tries}.");
+                    Self::emit(events, DownloadEvent::Retrying { attempt, max_tries });
+                    Self::cancellable(cancellation_token, sleep(Config::cooldown().await)).await?;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        let file = Self::hash_file(&part_path, &request.url).await?;
+        if let Some(checksum) = &request.checksum {
+            if let Err(error) = checksum.verify(&file) {
+                // a corrupted file can not be resumed
+                fs::remove_file(&part_path).await?;
+                return Err(error);
+            }
+        }
+
+        fs::rename(&part_path, file.path()).await?;
+        Self::index().await.insert(file.clone()).await?;
+
+        info!("Downloaded '{}' ({} bytes).", request.url, file.size);
+        Ok(file)
+    }
+    /// Download the given url to the given part file. \
+    /// If the part file already holds the beginning of the file, only the missing rest will be requested.
+    async fn fetch_part(
+        url: &str,
+        part_path: &Path,
+        cancellation_token: &CancellationToken,
+        events: &broadcast::Sender<DownloadEvent>,
+    ) -> Result<(), MCManageError> {
+        /// Request the given url starting at the given byte.
+        async fn send(url: &str, resume_from: u64) -> Result<Response, reqwest::Error> {
+            let mut request = CLIENT.get(url);
+            if resume_from > 0 {
+                request = request.header(RANGE, format!("bytes={resume_from}-"));
+            }
+            request.send().await
+        }
+
+        let mut resumed_from = match fs::metadata(part_path).await {
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        };
+
+        let mut response = Self::cancellable(cancellation_token, send(url, resumed_from)).await??;
+        if response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+            // the part file does not belong to the file behind this url anymore
+            fs::remove_file(part_path).await?;
+            resumed_from = 0;
+            response = Self::cancellable(cancellation_token, send(url, resumed_from)).await??;
+        }
+        let response = response.error_for_status()?;
+
+        let mut file = if response.status() == StatusCode::PARTIAL_CONTENT {
+            OpenOptions::new().append(true).open(part_path).await?
+        } else {
+            // the server does not support range requests
+            resumed_from = 0;
+            File::create(part_path).await?
+        };
+
+        let total = response
+            .content_length()
+            .map(|length| length + resumed_from);
+        Self::emit(events, DownloadEvent::Started { resumed_from, total });
+
+        let mut downloaded = resumed_from;
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = Self::cancellable(cancellation_token, stream.next()).await? {
+            let chunk = chunk?;
+            file.write_all(&chunk).await?;
+
+            downloaded += chunk.len() as u64;
+            Self::emit(events, DownloadEvent::Progress { downloaded, total });
+        }
+        file.flush().await?;
+
+        Ok(())
+    }
+    /// Create a [`CachedFile`] for the file at the given path by hashing its content.
+    async fn hash_file(path: &Path, url: &str) -> Result<CachedFile, MCManageError> {
+        let mut file = File::open(path).await?;
+        let mut sha256 = Sha256::new();
+        let mut sha1 = Sha1::new();
+        let mut md5 = Md5::new();
+        let mut size = 0;
+
+        let mut buffer = vec![0; 64 * 1024];
+        loop {
+            let read = file.read(&mut buffer).await?;
+            if read == 0 {
+                break;
+            }
+            sha256.update(&buffer[..read]);
+            sha1.update(&buffer[..read]);
+            md5.update(&buffer[..read]);
+            size += read as u64;
+        }
+
+        Ok(CachedFile {
+            url: url.to_string(),
+            sha256: hex::encode(sha256.finalize()),
+            sha1: hex::encode(sha1.finalize()),
+            md5: hex::encode(md5.finalize()),
+            size,
+            downloaded_at: chrono::Utc::now(),
+        })
+    }
+}
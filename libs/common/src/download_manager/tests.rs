@@ -0,0 +1,167 @@
+#![cfg(test)]
+
+use super::*;
+use crate::{
+    generated_files::paths::SERVERS_DIR,
+    test_functions::*,
+};
+
+/// The content served by the [`serve`] function.
+const CONTENT: &[u8] = b"This is not really a Minecraft server jar, but it will do for these tests.";
+
+/// Serve the [`CONTENT`] using the [`mock_server`] function and return the url to it, together with a list of every request made. \
+/// Range requests are supported. If `stall` is set, the server will never answer.
+async fn serve(stall: bool) -> (String, Arc<Mutex<Vec<ReceivedRequest>>>) {
+    let (base_url, requests) = mock_server(move |request| {
+        if stall {
+            return None;
+        }
+
+        let range_start = request
+            .headers
+            .get("range")
+            .and_then(|range| range.strip_prefix("bytes="))
+            .and_then(|range| range.trim_end_matches('-').parse::<usize>().ok());
+        Some(match range_start {
+            Some(start) if start >= CONTENT.len() => MockResponse::new(416, b""),
+            Some(start) => MockResponse::new(206, &CONTENT[start..]).with_header(
+                "Content-Range",
+                &format!("bytes {start}-{}/{}", CONTENT.len() - 1, CONTENT.len()),
+            ),
+            None => MockResponse::new(200, CONTENT),
+        })
+    })
+    .await;
+
+    (format!("{base_url}/server.jar"), requests)
+}
+
+async fn start_download_test() {
+    start_test();
+    init_config().await;
+}
+
+#[tokio::test]
+async fn download_and_reuse_cache() {
+    start_download_test().await;
+    let (url, requests) = serve(false).await;
+
+    let first_destination = SERVERS_DIR.join("first").join("server.jar");
+    DownloadManager::download(DownloadRequest::new(&url), &first_destination)
+        .finish()
+        .await
+        .unwrap();
+    assert_eq!(fs::read(&first_destination).await.unwrap(), CONTENT);
+
+    let second_destination = SERVERS_DIR.join("second").join("server.jar");
+    let mut download = DownloadManager::download(DownloadRequest::new(&url), &second_destination);
+    let mut events = download.subscribe();
+    download.finish().await.unwrap();
+
+    assert_eq!(events.recv().await.unwrap(), DownloadEvent::Cached);
+    assert_eq!(fs::read(&second_destination).await.unwrap(), CONTENT);
+    assert_eq!(requests.lock().await.len(), 1);
+
+    cleanup();
+}
+#[tokio::test]
+async fn download_with_valid_checksum() {
+    start_download_test().await;
+    let (url, _) = serve(false).await;
+
+    let checksum = Checksum::Sha1(hex::encode(Sha1::digest(CONTENT)).to_uppercase());
+    let file = DownloadManager::download(
+        DownloadRequest::new(&url).with_checksum(checksum),
+        &SERVERS_DIR.join("server.jar"),
+    )
+    .finish()
+    .await
+    .unwrap();
+
+    assert_eq!(file.sha256, hex::encode(Sha256::digest(CONTENT)));
+    assert!(file.path().exists());
+
+    cleanup();
+}
+#[tokio::test]
+async fn download_with_invalid_checksum() {
+    start_download_test().await;
+    let (url, _) = serve(false).await;
+
+    let destination = SERVERS_DIR.join("server.jar");
+    let result = DownloadManager::download(
+        DownloadRequest::new(&url).with_checksum(Checksum::Sha256("0".repeat(64))),
+        &destination,
+    )
+    .finish()
+    .await;
+
+    assert!(matches!(result, Err(MCManageError::ChecksumMismatch { .. })));
+    assert!(!destination.exists());
+    assert!(DownloadManager::cached(&url).await.is_none());
+
+    cleanup();
+}
+#[tokio::test]
+async fn resume_download() {
+    start_download_test().await;
+    let (url, _) = serve(false).await;
+
+    fs::create_dir_all(DOWNLOADS_DIR.as_path()).await.unwrap();
+    fs::write(DownloadManager::part_path(&url), &CONTENT[..10])
+        .await
+        .unwrap();
+
+    let destination = SERVERS_DIR.join("server.jar");
+    let mut download = DownloadManager::download(DownloadRequest::new(&url), &destination);
+    let mut events = download.subscribe();
+    download.finish().await.unwrap();
+
+    assert_eq!(
+        events.recv().await.unwrap(),
+        DownloadEvent::Started {
+            resumed_from: 10,
+            total: Some(CONTENT.len() as u64)
+        }
+    );
+    assert_eq!(fs::read(&destination).await.unwrap(), CONTENT);
+
+    cleanup();
+}
+#[tokio::test]
+async fn cancel_download() {
+    start_download_test().await;
+    let (url, _) = serve(true).await;
+
+    let destination = SERVERS_DIR.join("server.jar");
+    let download = DownloadManager::download(DownloadRequest::new(&url), &destination);
+    sleep(std::time::Duration::new(1, 0)).await;
+    download.cancel();
+
+    assert!(matches!(
+        download.finish().await,
+        Err(MCManageError::Cancelled)
+    ));
+    assert!(!destination.exists());
+
+    cleanup();
+}
+#[tokio::test]
+async fn cancel_download_before_start() {
+    start_download_test().await;
+    let (url, requests) = serve(false).await;
+
+    let cancellation_token = CancellationToken::new();
+    cancellation_token.cancel();
+    let destination = SERVERS_DIR.join("server.jar");
+    let download = DownloadManager::download_cancellable(DownloadRequest::new(&url), &destination, cancellation_token);
+
+    assert!(matches!(
+        download.finish().await,
+        Err(MCManageError::Cancelled)
+    ));
+    assert!(!destination.exists());
+    assert_eq!(requests.lock().await.len(), 0);
+
+    cleanup();
+}
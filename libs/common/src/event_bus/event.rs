@@ -0,0 +1,138 @@
+//! This module provides the [`Event`] enum.
+
+use std::path::PathBuf;
+
+use proc_macros::add_convert;
+
+use super::event_kind::EventKind;
+use crate::{
+    chat_log::chat_message::ChatMessage,
+    mcmanage_error::MCManageError,
+    status::Status,
+};
+
+/// This enum describes everything which can happen to a [`Server`](crate::server_manager::server::Server). \
+/// It gets serialized with a `kind` field naming the variant, next to the fields of the variant.
+#[add_convert]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Event {
+    /// The [`Status`] of the server changed.
+    StatusChanged {
+        /// The new status of the server
+        status: Status,
+    },
+    /// The Minecraft server finished starting and players can join.
+    Started,
+    /// The Minecraft server got stopped on purpose.
+    Stopped,
+    /// The Minecraft server stopped unexpectedly.
+    Crashed,
+    /// The Minecraft server announced that it is stopping. This also happens if the server got stopped from inside the game.
+    Stopping,
+    /// The Minecraft server finished saving its worlds.
+    SaveCompleted,
+    /// A player joined the Minecraft server.
+    PlayerJoined {
+        /// The uuid of the player
+        uuid: String,
+        /// The name of the player
+        player: String,
+    },
+    /// A player left the Minecraft server.
+    PlayerLeft {
+        /// The uuid of the player
+        uuid: String,
+        /// The name of the player
+        player: String,
+    },
+    /// A player connected to a backend of the network of this proxy. This also happens when the player joins the network.
+    PlayerSwitched {
+        /// The uuid of the player
+        uuid: String,
+        /// The name of the player
+        player: String,
+        /// The name of the backend the player connected to
+        server: String,
+    },
+    /// A player died.
+    PlayerDied {
+        /// The uuid of the player
+        uuid: String,
+        /// The name of the player
+        player: String,
+        /// The death message, like `Gooxey was slain by Zombie`
+        message: String,
+    },
+    /// A player made an advancement, completed a challenge or reached a goal.
+    AdvancementMade {
+        /// The uuid of the player
+        uuid: String,
+        /// The name of the player
+        player: String,
+        /// The name of the advancement, like `Stone Age`
+        advancement: String,
+    },
+    /// The Minecraft server can not keep up with its ticks.
+    Overloaded {
+        /// How many milliseconds the server is behind, if it got announced
+        milliseconds: Option<u64>,
+        /// How many ticks the server is behind, if it got announced
+        ticks: Option<u64>,
+    },
+    /// The Minecraft server printed an exception.
+    Exception {
+        /// The message of the exception, like `java.lang.NullPointerException: ...`
+        message: String,
+        /// The lines of the stack trace printed after the exception
+        stack_trace: Vec<String>,
+    },
+    /// A plugin could not be loaded or enabled.
+    PluginLoadFailed {
+        /// The name of the plugin, or its file name if it could not be loaded at all
+        plugin: String,
+    },
+    /// A player sent a message to the chat.
+    Chat {
+        /// The message sent
+        message: ChatMessage,
+    },
+    /// A backup of the Minecraft server got created.
+    BackupFinished {
+        /// The path to the backup
+        path: PathBuf,
+    },
+    /// A newer build of the server software got installed.
+    UpdateApplied {
+        /// The build installed before the update
+        previous_build: String,
+        /// The build installed by the update
+        new_build: String,
+    },
+    /// An event sent to check whether a webhook works. It never gets published to the [`EventBus`](super::EventBus).
+    Test,
+}
+impl Event {
+    /// Return the [`EventKind`] of this event.
+    pub fn kind(&self) -> EventKind {
+        match self {
+            Self::StatusChanged { .. } => EventKind::StatusChanged,
+            Self::Started => EventKind::Started,
+            Self::Stopped => EventKind::Stopped,
+            Self::Crashed => EventKind::Crashed,
+            Self::Stopping => EventKind::Stopping,
+            Self::SaveCompleted => EventKind::SaveCompleted,
+            Self::PlayerJoined { .. } => EventKind::PlayerJoined,
+            Self::PlayerLeft { .. } => EventKind::PlayerLeft,
+            Self::PlayerSwitched { .. } => EventKind::PlayerSwitched,
+            Self::PlayerDied { .. } => EventKind::PlayerDied,
+            Self::AdvancementMade { .. } => EventKind::AdvancementMade,
+            Self::Overloaded { .. } => EventKind::Overloaded,
+            Self::Exception { .. } => EventKind::Exception,
+            Self::PluginLoadFailed { .. } => EventKind::PluginLoadFailed,
+            Self::Chat { .. } => EventKind::Chat,
+            Self::BackupFinished { .. } => EventKind::BackupFinished,
+            Self::UpdateApplied { .. } => EventKind::UpdateApplied,
+            Self::Test => EventKind::Test,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! This module provides the [`EventFilter`] struct.
+
+use proc_macros::add_convert;
+
+use super::{
+    event_kind::EventKind,
+    server_event::ServerEvent,
+};
+use crate::mcmanage_error::MCManageError;
+
+/// This struct describes which [`ServerEvents`](ServerEvent) a subscriber of the [`EventBus`](super::EventBus) wants to receive. \
+/// An empty list of servers or kinds matches every server or kind.
+#[add_convert]
+#[derive(Default)]
+#[serde(default)]
+pub struct EventFilter {
+    /// The names of the servers whose events should be received
+    pub servers: Vec<String>,
+    /// The kinds of events which should be received
+    pub kinds: Vec<EventKind>,
+}
+impl EventFilter {
+    /// Only receive events of the server with the given name, in addition to the servers already set.
+    pub fn with_server(mut self, server: &str) -> Self {
+        self.servers.push(server.to_string());
+        self
+    }
+    /// Only receive events of the given kind, in addition to the kinds already set.
+    pub fn with_kind(mut self, kind: EventKind) -> Self {
+        self.kinds.push(kind);
+        self
+    }
+    /// Return whether or not the given event passes this filter.
+    pub fn matches(&self, server_event: &ServerEvent) -> bool {
+        (self.servers.is_empty() || self.servers.contains(&server_event.server))
+            && (self.kinds.is_empty() || self.kinds.contains(&server_event.event.kind()))
+    }
+}
@@ -0,0 +1,48 @@
+//! This module provides the [`EventKind`] enum.
+
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This enum describes the kind of an [`Event`](super::event::Event). It gets used to filter events without looking at their data.
+#[add_convert]
+#[derive(Copy, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    /// See [`Event::StatusChanged`](super::event::Event::StatusChanged).
+    StatusChanged,
+    /// See [`Event::Started`](super::event::Event::Started).
+    Started,
+    /// See [`Event::Stopped`](super::event::Event::Stopped).
+    Stopped,
+    /// See [`Event::Crashed`](super::event::Event::Crashed).
+    Crashed,
+    /// See [`Event::Stopping`](super::event::Event::Stopping).
+    Stopping,
+    /// See [`Event::SaveCompleted`](super::event::Event::SaveCompleted).
+    SaveCompleted,
+    /// See [`Event::PlayerJoined`](super::event::Event::PlayerJoined).
+    PlayerJoined,
+    /// See [`Event::PlayerLeft`](super::event::Event::PlayerLeft).
+    PlayerLeft,
+    /// See [`Event::PlayerSwitched`](super::event::Event::PlayerSwitched).
+    PlayerSwitched,
+    /// See [`Event::PlayerDied`](super::event::Event::PlayerDied).
+    PlayerDied,
+    /// See [`Event::AdvancementMade`](super::event::Event::AdvancementMade).
+    AdvancementMade,
+    /// See [`Event::Overloaded`](super::event::Event::Overloaded).
+    Overloaded,
+    /// See [`Event::Exception`](super::event::Event::Exception).
+    Exception,
+    /// See [`Event::PluginLoadFailed`](super::event::Event::PluginLoadFailed).
+    PluginLoadFailed,
+    /// See [`Event::Chat`](super::event::Event::Chat).
+    Chat,
+    /// See [`Event::BackupFinished`](super::event::Event::BackupFinished).
+    BackupFinished,
+    /// See [`Event::UpdateApplied`](super::event::Event::UpdateApplied).
+    UpdateApplied,
+    /// See [`Event::Test`](super::event::Event::Test).
+    Test,
+}
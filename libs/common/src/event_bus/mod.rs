@@ -0,0 +1,76 @@
+//! This module provides the [`EventBus`] struct, which delivers every [`ServerEvent`] to the parts of the application interested in it.
+
+use chrono::Utc;
+use goolog::*;
+use once_cell::sync::Lazy;
+use tokio::sync::broadcast::{
+    self,
+    error::RecvError,
+};
+
+use self::{
+    event::Event,
+    event_filter::EventFilter,
+    server_event::ServerEvent,
+};
+
+pub mod event;
+pub mod event_filter;
+pub mod event_kind;
+pub mod server_event;
+mod tests;
+
+const GOOLOG_CALLER: &str = "EventBus";
+/// The number of events a subscriber can fall behind before it misses events
+const CHANNEL_CAPACITY: usize = 1024;
+/// The channel every event gets sent to
+static EVENT_CHANNEL: Lazy<broadcast::Sender<ServerEvent>> = Lazy::new(|| broadcast::channel(CHANNEL_CAPACITY).0);
+
+/// This struct delivers the [`Events`](Event) of every [`Server`](crate::server_manager::server::Server) to its subscribers. \
+/// Instead of polling the state of a server, features like the api, webhooks or the metrics can subscribe to the events they are interested in.
+pub struct EventBus;
+impl EventBus {
+    /// Send the given event, which happened to the server with the given name, to every subscriber.
+    pub fn publish(server: &str, event: Event) {
+        let server_event = ServerEvent {
+            server: server.to_string(),
+            time: Utc::now(),
+            event,
+        };
+        // an error only means that nobody is subscribed
+        if EVENT_CHANNEL.send(server_event).is_err() {}
+    }
+    /// Subscribe to every event published from now on, which passes the given [`EventFilter`].
+    pub fn subscribe(filter: EventFilter) -> EventSubscription {
+        EventSubscription {
+            receiver: EVENT_CHANNEL.subscribe(),
+            filter,
+        }
+    }
+}
+
+/// This struct receives the events of the [`EventBus`] passing its [`EventFilter`].
+pub struct EventSubscription {
+    /// The receiver of the event channel
+    receiver: broadcast::Receiver<ServerEvent>,
+    /// The filter every event has to pass
+    filter: EventFilter,
+}
+impl EventSubscription {
+    /// Wait for the next event passing the [`EventFilter`] of this subscription. \
+    /// If this subscriber fell too far behind, the missed events will be skipped.
+    pub async fn recv(&mut self) -> ServerEvent {
+        loop {
+            match self.receiver.recv().await {
+                Ok(server_event) if self.filter.matches(&server_event) => return server_event,
+                Ok(_) => {}
+                Err(RecvError::Lagged(missed)) => warn!("A subscriber fell behind and missed {missed} events."),
+                Err(RecvError::Closed) => fatal!("The event channel is held by a static and should never be closed."),
+            }
+        }
+    }
+    /// Return the [`EventFilter`] of this subscription.
+    pub fn filter(&self) -> &EventFilter {
+        &self.filter
+    }
+}
@@ -0,0 +1,58 @@
+//! This module provides the [`ServerEvent`] struct.
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use proc_macros::add_convert;
+
+use super::event::Event;
+use crate::mcmanage_error::MCManageError;
+
+/// This struct represents an [`Event`] which happened to a [`Server`](crate::server_manager::server::Server).
+#[add_convert]
+pub struct ServerEvent {
+    /// The name of the server the event happened to
+    pub server: String,
+    /// The time the event happened at
+    pub time: DateTime<Utc>,
+    /// What happened
+    #[serde(flatten)]
+    pub event: Event,
+}
+impl ServerEvent {
+    /// Return a short sentence describing this event, like `Gooxey joined the server myServer.`
+    pub fn summary(&self) -> String {
+        let server = &self.server;
+        match &self.event {
+            Event::StatusChanged { status } => format!("The server {server} is now {}.", format!("{status:?}").to_lowercase()),
+            Event::Started => format!("The server {server} started."),
+            Event::Stopped => format!("The server {server} stopped."),
+            Event::Crashed => format!("The server {server} crashed!"),
+            Event::Stopping => format!("The server {server} is stopping."),
+            Event::SaveCompleted => format!("The server {server} saved its worlds."),
+            Event::PlayerJoined { player, .. } => format!("{player} joined the server {server}."),
+            Event::PlayerLeft { player, .. } => format!("{player} left the server {server}."),
+            Event::PlayerSwitched { player, server: backend, .. } => {
+                format!("{player} connected to the server {backend} through the proxy {server}.")
+            }
+            Event::PlayerDied { message, .. } => format!("[{server}] {message}"),
+            Event::AdvancementMade { player, advancement, .. } => {
+                format!("{player} made the advancement [{advancement}] on the server {server}.")
+            }
+            Event::Overloaded { milliseconds, .. } => match milliseconds {
+                Some(milliseconds) => format!("The server {server} is overloaded and running {milliseconds}ms behind!"),
+                None => format!("The server {server} is overloaded!"),
+            },
+            Event::Exception { message, .. } => format!("The server {server} threw an exception: {message}"),
+            Event::PluginLoadFailed { plugin } => format!("The plugin {plugin} failed to load on the server {server}!"),
+            Event::Chat { message } => format!("[{server}] <{}> {}", message.sender, message.message),
+            Event::BackupFinished { path } => format!("A backup of the server {server} got saved to {}.", path.display()),
+            Event::UpdateApplied {
+                previous_build,
+                new_build,
+            } => format!("The server {server} got updated from build {previous_build} to build {new_build}."),
+            Event::Test => format!("This is a test event for the server {server}."),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+#![cfg(test)]
+
+use std::time::Duration;
+
+use tokio::time::timeout;
+
+use super::*;
+use crate::{
+    event_bus::event_kind::EventKind,
+    status::Status,
+};
+
+/// Return the next event of the given subscription, or `None` if none arrives in time.
+async fn next(subscription: &mut EventSubscription) -> Option<ServerEvent> {
+    timeout(Duration::from_millis(100), subscription.recv()).await.ok()
+}
+
+#[tokio::test]
+async fn filter_by_server_and_kind() {
+    let mut everything = EventBus::subscribe(EventFilter::default().with_server("myEventServer"));
+    let mut started = EventBus::subscribe(
+        EventFilter::default()
+            .with_server("myEventServer")
+            .with_kind(EventKind::Started),
+    );
+
+    EventBus::publish("anotherEventServer", Event::Started);
+    EventBus::publish(
+        "myEventServer",
+        Event::StatusChanged {
+            status: Status::Starting,
+        },
+    );
+    EventBus::publish("myEventServer", Event::Started);
+
+    assert_eq!(
+        next(&mut everything).await.unwrap().event,
+        Event::StatusChanged {
+            status: Status::Starting
+        }
+    );
+    assert_eq!(next(&mut everything).await.unwrap().event, Event::Started);
+    let server_event = next(&mut started).await.unwrap();
+    assert_eq!(server_event.server, "myEventServer");
+    assert_eq!(server_event.event, Event::Started);
+    assert!(next(&mut started).await.is_none());
+}
+#[test]
+fn serialize() {
+    let server_event = ServerEvent {
+        server: "myServer".to_string(),
+        time: Utc::now(),
+        event: Event::PlayerJoined {
+            uuid: "069a79f4-44e9-4726-a5be-fca90e38aaf5".to_string(),
+            player: "Gooxey".to_string(),
+        },
+    };
+
+    let json = serde_json::to_value(&server_event).unwrap();
+    assert_eq!(json["kind"], "player_joined");
+    assert_eq!(json["player"], "Gooxey");
+    assert_eq!(ServerEvent::try_from(json.to_string()).unwrap(), server_event);
+}
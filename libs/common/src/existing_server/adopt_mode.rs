@@ -0,0 +1,17 @@
+//! This module provides the [`AdoptMode`] enum.
+
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This enum describes how an [`ExistingServer`](super::ExistingServer) gets brought into the `servers` directory.
+#[add_convert]
+#[derive(Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum AdoptMode {
+    /// Move the server directory into the `servers` directory.
+    #[default]
+    Move,
+    /// Leave the server directory where it is and link to it from the `servers` directory.
+    Symlink,
+}
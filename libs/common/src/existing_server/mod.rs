@@ -0,0 +1,344 @@
+//! This module provides the [`ExistingServer`] struct, which adopts Minecraft servers set up without MCManage into the server list.
+
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::Duration,
+};
+
+use goolog::*;
+use proc_macros::add_convert;
+use serde_json::Value as JsonValue;
+use tokio::fs;
+#[cfg(unix)]
+use tokio::fs::symlink as symlink_dir;
+#[cfg(windows)]
+use tokio::fs::symlink_dir;
+
+use self::adopt_mode::AdoptMode;
+use crate::{
+    generated_files::paths::SERVERS_DIR,
+    jar_file::{
+        manifest_attribute,
+        read_entry,
+    },
+    mcmanage_error::MCManageError,
+    server_manager::{
+        server::{
+            server_kind::ServerKind,
+            update_policy::UpdatePolicy,
+        },
+        server_item::ServerItem,
+        server_list::{
+            server_data::ServerData,
+            ServerList,
+        },
+    },
+};
+
+pub mod adopt_mode;
+mod tests;
+
+const GOOLOG_CALLER: &str = "ExistingServer";
+/// The directory modern Forge servers keep their launch arguments in. Since Minecraft 1.17, these servers get started using argument files
+/// instead of a jar.
+const FORGE_LIBRARIES_DIR: &str = "libraries/net/minecraftforge/forge";
+/// Every server type which can be detected, ordered by how specific their jars are. \
+/// A Fabric or Forge server also contains the vanilla jar, so the more specific type has to win.
+const SERVER_TYPES: [&str; 5] = ["vanilla", "paper", "purpur", "forge", "fabric"];
+
+/// This struct describes a Minecraft server found in an existing directory.
+#[add_convert]
+pub struct ExistingServer {
+    /// The directory of the Minecraft server
+    pub path: PathBuf,
+    /// The type of the Minecraft server. Either `vanilla`, `paper`, `purpur`, `fabric` or `forge`.
+    pub server_type: String,
+    /// The args the Minecraft server has to be started with
+    pub args: String,
+    /// The Minecraft version of the server, if it could be detected
+    pub version: Option<String>,
+    /// The build of the server software, if it could be detected. For Fabric and Forge servers, this is the version of the mod loader.
+    pub build: Option<String>,
+    /// The port set in the `server.properties` file, if one is set
+    pub port: Option<u16>,
+}
+// actions
+impl ExistingServer {
+    /// Detect the Minecraft server in the given directory. \
+    /// Every jar in the directory will be read to find the server jar, its type and its Minecraft version. Modern Forge servers, which have no server
+    /// jar, will be detected using their `libraries` directory.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                                  |
+    /// |-----------------------------------|--------------------------------------------------------------|
+    /// | `Ok(ExistingServer)`              | A Minecraft server got found.                                |
+    /// | [`MCManageError::InvalidRequest`] | The path is no directory or it contains no known server jar. |
+    /// | `Err(...)`                        | The directory could not be read.                             |
+    pub async fn detect(path: &Path) -> Result<Self, MCManageError> {
+        if !path.is_dir() {
+            return Err(MCManageError::InvalidRequest(format!(
+                "`{}` is no directory.",
+                path.display()
+            )));
+        }
+
+        let mut server_jar: Option<ServerJar> = None;
+        let mut entries = fs::read_dir(path).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if !file_name.ends_with(".jar") || !entry.file_type().await?.is_file() {
+                continue;
+            }
+            let Some(jar) = ServerJar::read(&entry.path(), &file_name).await else {
+                continue;
+            };
+
+            // the most specific jar wins. Equally specific jars get sorted by name, so the result does not depend on the order of the directory.
+            let replaces_best = server_jar.as_ref().map_or(true, |best| {
+                (jar.priority(), &best.file_name) > (best.priority(), &jar.file_name)
+            });
+            if replaces_best {
+                server_jar = Some(jar);
+            }
+        }
+
+        let port = match fs::read_to_string(path.join("server.properties")).await {
+            Ok(server_properties) => property(&server_properties, "server-port").and_then(|port| port.parse().ok()),
+            Err(_) => None,
+        };
+
+        if server_jar
+            .as_ref()
+            .map_or(true, |jar| jar.server_type != "fabric" && jar.server_type != "forge")
+        {
+            if let Some(forge_version) = Self::forge_library_version(path).await {
+                let (version, build) = split_version(&forge_version);
+
+                let mut args = vec![];
+                if path.join("user_jvm_args.txt").exists() {
+                    args.push("@user_jvm_args.txt".to_string());
+                }
+                args.push(format!(
+                    "@{FORGE_LIBRARIES_DIR}/{forge_version}/{}",
+                    if cfg!(windows) { "win_args.txt" } else { "unix_args.txt" }
+                ));
+                args.push("nogui".to_string());
+
+                return Ok(Self {
+                    path: path.to_path_buf(),
+                    server_type: "forge".to_string(),
+                    args: args.join(" "),
+                    version,
+                    build,
+                    port,
+                });
+            }
+        }
+
+        let Some(server_jar) = server_jar else {
+            return Err(MCManageError::InvalidRequest(format!(
+                "No Minecraft server jar could be found in `{}`.",
+                path.display()
+            )));
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            server_type: server_jar.server_type.to_string(),
+            args: format!("-jar {} nogui", server_jar.file_name),
+            version: server_jar.version,
+            build: server_jar.build,
+            port,
+        })
+    }
+    /// Adopt the Minecraft server in the given directory as a new server with the given name. \
+    /// Depending on the [`AdoptMode`], the directory will either be moved to `servers/{server_name}` or linked from there. Afterwards the server will be
+    /// added to the [`ServerList`]. If this fails, the directory will be restored.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                                          |
+    /// |-----------------------------------|----------------------------------------------------------------------|
+    /// | `Ok(ExistingServer)`              | The server got adopted. Its path points to the `servers` directory.  |
+    /// | [`MCManageError::InvalidRequest`] | The name is invalid or taken, or no Minecraft server could be found. |
+    /// | `Err(...)`                        | The directory could not be moved or linked, or the server not saved. |
+    pub async fn adopt(path: &Path, server_name: &str, mode: AdoptMode) -> Result<Self, MCManageError> {
+        ServerItem::check_name(server_name)?;
+        let mut existing_server = Self::detect(path).await?;
+        let server_path = SERVERS_DIR.join(server_name);
+
+        fs::create_dir_all(SERVERS_DIR.as_path()).await?;
+        match mode {
+            AdoptMode::Move => fs::rename(path, &server_path).await?,
+            AdoptMode::Symlink => symlink_dir(dunce::canonicalize(path)?, &server_path).await?,
+        }
+
+        let server_data = ServerData::from_server_item(server_name, existing_server.server_item());
+        if let Err(error) = ServerList::add(server_data).await {
+            let restored = match mode {
+                AdoptMode::Move => fs::rename(&server_path, path).await,
+                AdoptMode::Symlink => fs::remove_file(&server_path).await,
+            };
+            if let Err(restore_error) = restored {
+                error!("Failed to restore the directory {}. Error: {restore_error}", path.display());
+            }
+            return Err(error);
+        }
+
+        info!(
+            "Adopted the {} server at {} as the server {server_name}.",
+            existing_server.server_type,
+            path.display()
+        );
+        existing_server.path = server_path;
+        Ok(existing_server)
+    }
+}
+// info
+impl ExistingServer {
+    /// Create the [`ServerItem`] for this Minecraft server. Updates will be disabled, since the build of the existing jar is usually unknown.
+    pub fn server_item(&self) -> ServerItem {
+        ServerItem {
+            args: self.args.clone(),
+            download_from: "".to_string(),
+            version: self.version.clone(),
+            build: self.build.clone(),
+            update_policy: UpdatePolicy::Disabled,
+            server_type: self.server_type.clone(),
+            restart_time: Duration::ZERO,
+            eula_accepted_by: None,
+            network: None,
+            network_role: None,
+            kind: ServerKind::Java,
+            depends_on: vec![],
+            start_delay: Duration::ZERO,
+        }
+    }
+}
+// internal
+impl ExistingServer {
+    /// Return the Forge version ( `{minecraft version}-{forge version}` ) installed in the `libraries` directory of the given server directory.
+    async fn forge_library_version(path: &Path) -> Option<String> {
+        let mut entries = fs::read_dir(path.join(FORGE_LIBRARIES_DIR)).await.ok()?;
+        let mut versions = vec![];
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            versions.push(entry.file_name().to_string_lossy().to_string());
+        }
+        versions.sort();
+        versions.pop()
+    }
+}
+
+/// This struct represents a Minecraft server jar found in a server directory.
+struct ServerJar {
+    /// The file name of the jar
+    file_name: String,
+    /// The server type the jar belongs to. One of the [`SERVER_TYPES`].
+    server_type: &'static str,
+    /// The Minecraft version of the jar
+    version: Option<String>,
+    /// The build of the server software
+    build: Option<String>,
+}
+impl ServerJar {
+    /// Read the jar at the given path. If it is no known Minecraft server jar, `None` will be returned.
+    async fn read(jar_path: &Path, file_name: &str) -> Option<Self> {
+        let entry = |name: &'static str| async move {
+            read_entry(jar_path, &[name])
+                .await
+                .ok()?
+                .map(|(_, content)| String::from_utf8_lossy(&content).to_string())
+        };
+        let lowercase_name = file_name.to_lowercase();
+        if lowercase_name.ends_with("-installer.jar") {
+            // installers are often kept next to the server they installed
+            return None;
+        }
+        let main_class = manifest_attribute(jar_path, "Main-Class").await.unwrap_or_default();
+        let version_json_id = entry("version.json").await.and_then(|version_json| {
+            serde_json::from_str::<JsonValue>(&version_json)
+                .ok()?
+                .get("id")?
+                .as_str()
+                .map(str::to_string)
+        });
+
+        let (server_type, version, build) = if let Some(install_properties) = entry("install.properties").await {
+            // the Fabric server launcher
+            (
+                "fabric",
+                property(&install_properties, "game-version"),
+                property(&install_properties, "fabric-loader-version"),
+            )
+        } else if main_class.starts_with("net.fabricmc") {
+            ("fabric", None, None)
+        } else if main_class.contains("minecraftforge") || lowercase_name.starts_with("forge-") {
+            // the name of a Forge jar looks like `forge-1.12.2-14.23.5.2859.jar` or `forge-1.16.5-36.2.39-universal.jar`
+            let forge_version = lowercase_name
+                .trim_end_matches(".jar")
+                .trim_end_matches("-universal")
+                .trim_end_matches("-shim");
+            let (version, build) = split_version(forge_version.strip_prefix("forge-").unwrap_or_default());
+            ("forge", version, build)
+        } else if let Some(versions_list) = entry("META-INF/versions.list")
+            .await
+            .or_else(|| main_class.contains("paperclip").then(String::new))
+        {
+            // a Paperclip jar. Every line of the versions list looks like `{hash}\t{id}\t{path}`, for example `...\tpurpur-1.20.4\tpurpur-1.20.4.jar`.
+            let server_type = if versions_list.contains("purpur") || lowercase_name.contains("purpur") {
+                "purpur"
+            } else {
+                "paper"
+            };
+            let version = version_json_id.or_else(|| {
+                versions_list
+                    .lines()
+                    .find_map(|line| line.split('\t').nth(1))
+                    .and_then(|id| id.rsplit('-').next())
+                    .map(str::to_string)
+            });
+            (server_type, version, None)
+        } else if version_json_id.is_some() || main_class.starts_with("net.minecraft.") {
+            ("vanilla", version_json_id, None)
+        } else {
+            return None;
+        };
+
+        Some(Self {
+            file_name: file_name.to_string(),
+            server_type,
+            version,
+            build,
+        })
+    }
+    /// Return how specific this jar's server type is. See the [`SERVER_TYPES`] constant.
+    fn priority(&self) -> usize {
+        SERVER_TYPES
+            .iter()
+            .position(|server_type| *server_type == self.server_type)
+            .unwrap_or_default()
+    }
+}
+
+/// Read the value of the given key from the given properties file. Comments will be ignored.
+pub(crate) fn property(properties: &str, key: &str) -> Option<String> {
+    properties
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .find(|(line_key, _)| line_key.trim() == key)
+        .map(|(_, value)| value.trim().to_string())
+}
+/// Split a mod loader version like `1.20.1-47.2.0` into the Minecraft version and the version of the mod loader.
+fn split_version(version: &str) -> (Option<String>, Option<String>) {
+    match version.split_once('-') {
+        Some((minecraft_version, loader_version)) => {
+            (Some(minecraft_version.to_string()), Some(loader_version.to_string()))
+        }
+        None if !version.is_empty() => (Some(version.to_string()), None),
+        None => (None, None),
+    }
+}
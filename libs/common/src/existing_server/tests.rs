@@ -0,0 +1,165 @@
+#![cfg(test)]
+
+use super::*;
+use crate::{
+    generated_files::paths::ROOT_DIR,
+    test_functions::*,
+};
+
+/// The `version.json` file of a vanilla server jar.
+const VERSION_JSON: &str = r#"{ "id": "1.20.4", "name": "1.20.4", "world_version": 3700, "protocol_version": 765 }"#;
+/// The manifest of a vanilla server jar.
+const VANILLA_MANIFEST: &str = "Manifest-Version: 1.0\r\nMain-Class: net.minecraft.bundler.Main\r\n";
+
+/// Create a legacy server directory with the given files and return its path.
+async fn legacy_server(files: Vec<(&str, Vec<u8>)>) -> PathBuf {
+    let path = ROOT_DIR.join("legacy").join("myLegacyServer");
+    for (name, content) in files {
+        let file_path = path.join(name);
+        fs::create_dir_all(file_path.parent().unwrap()).await.unwrap();
+        fs::write(file_path, content).await.unwrap();
+    }
+    path
+}
+/// Return the content of a vanilla server jar.
+fn vanilla_jar() -> Vec<u8> {
+    jar(&[("version.json", VERSION_JSON), ("META-INF/MANIFEST.MF", VANILLA_MANIFEST)])
+}
+
+#[tokio::test]
+async fn adopt_vanilla() {
+    start_test();
+    init_server_list().await;
+    let path = legacy_server(vec![
+        ("server.jar", vanilla_jar()),
+        ("server.properties", b"#Minecraft server properties\nserver-port=25566\nmotd=Hello\n".to_vec()),
+        ("world/level.dat", b"level".to_vec()),
+    ])
+    .await;
+
+    let existing_server = ExistingServer::adopt(&path, "myLegacyServer", AdoptMode::Move)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        existing_server,
+        ExistingServer {
+            path: SERVERS_DIR.join("myLegacyServer"),
+            server_type: "vanilla".to_string(),
+            args: "-jar server.jar nogui".to_string(),
+            version: Some("1.20.4".to_string()),
+            build: None,
+            port: Some(25566),
+        }
+    );
+    assert!(!path.exists());
+    assert!(SERVERS_DIR.join("myLegacyServer/world/level.dat").exists());
+    assert_eq!(
+        ServerList::get("myLegacyServer").await.unwrap().name(),
+        "myLegacyServer"
+    );
+
+    cleanup();
+}
+#[cfg(unix)]
+#[tokio::test]
+async fn adopt_fabric_as_symlink() {
+    start_test();
+    init_server_list().await;
+    let path = legacy_server(vec![
+        ("server.jar", vanilla_jar()),
+        (
+            "fabric-server-launch.jar",
+            jar(&[
+                ("install.properties", "fabric-loader-version=0.15.6\ngame-version=1.20.4\n"),
+                (
+                    "META-INF/MANIFEST.MF",
+                    "Manifest-Version: 1.0\r\nMain-Class: net.fabricmc.loader.impl.launch.server.FabricServerLauncher\r\n",
+                ),
+            ]),
+        ),
+        ("notes.jar", b"no zip archive".to_vec()),
+    ])
+    .await;
+
+    let existing_server = ExistingServer::adopt(&path, "myLinkedServer", AdoptMode::Symlink)
+        .await
+        .unwrap();
+
+    assert_eq!(existing_server.server_type, "fabric");
+    assert_eq!(existing_server.args, "-jar fabric-server-launch.jar nogui");
+    assert_eq!(existing_server.build.as_deref(), Some("0.15.6"));
+    assert_eq!(existing_server.port, None);
+    assert!(path.exists());
+    assert!(fs::symlink_metadata(SERVERS_DIR.join("myLinkedServer"))
+        .await
+        .unwrap()
+        .file_type()
+        .is_symlink());
+    assert!(ServerList::get("myLinkedServer").await.is_ok());
+
+    cleanup();
+}
+#[tokio::test]
+async fn detect_purpur() {
+    start_test();
+    let path = legacy_server(vec![(
+        "server.jar",
+        jar(&[
+            ("META-INF/versions.list", "abcdef\tpurpur-1.20.4\tpurpur-1.20.4.jar\n"),
+            ("META-INF/MANIFEST.MF", "Manifest-Version: 1.0\r\nMain-Class: io.papermc.paperclip.Main\r\n"),
+        ]),
+    )])
+    .await;
+
+    let existing_server = ExistingServer::detect(&path).await.unwrap();
+
+    assert_eq!(existing_server.server_type, "purpur");
+    assert_eq!(existing_server.version.as_deref(), Some("1.20.4"));
+
+    cleanup();
+}
+#[tokio::test]
+async fn detect_forge() {
+    start_test();
+    let path = legacy_server(vec![
+        ("forge-1.20.1-47.2.0-installer.jar", jar(&[("install_profile.json", "{}")])),
+        ("user_jvm_args.txt", b"# -Xmx4G".to_vec()),
+        (
+            "libraries/net/minecraftforge/forge/1.20.1-47.2.0/unix_args.txt",
+            b"-p libraries/...".to_vec(),
+        ),
+    ])
+    .await;
+
+    let existing_server = ExistingServer::detect(&path).await.unwrap();
+
+    assert_eq!(existing_server.server_type, "forge");
+    assert_eq!(existing_server.version.as_deref(), Some("1.20.1"));
+    assert_eq!(existing_server.build.as_deref(), Some("47.2.0"));
+    if cfg!(unix) {
+        assert_eq!(
+            existing_server.args,
+            "@user_jvm_args.txt @libraries/net/minecraftforge/forge/1.20.1-47.2.0/unix_args.txt nogui"
+        );
+    }
+
+    cleanup();
+}
+#[tokio::test]
+async fn no_server() {
+    start_test();
+    let path = legacy_server(vec![("readme.txt", b"This is no server.".to_vec())]).await;
+
+    assert!(matches!(
+        ExistingServer::detect(&path).await,
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    assert!(matches!(
+        ExistingServer::adopt(&path, "my server", AdoptMode::Move).await,
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    assert!(path.exists());
+
+    cleanup();
+}
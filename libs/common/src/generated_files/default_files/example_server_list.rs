@@ -1,7 +1,8 @@
 //! This module provides the [`EXAMPLE_SERVER_LIST`] constant.
 
 /// This constant represents the `example file` content of the [`SERVER_LIST_FILE`](crate::crate::generated_files::paths::SERVER_LIST_FILE) file.
-pub const EXAMPLE_SERVER_LIST: &str = r#"# Restart the application to apply the changes made to the 'config/server_list.toml' file.
+pub const EXAMPLE_SERVER_LIST: &str = r#"# The servers defined in the 'config/server_list.toml' file get imported into the database at the next start of the application.
+# Afterwards, the file gets renamed to 'config/server_list.toml.migrated'. Use the api to change the servers from then on.
 
 # This represents one Minecraft server
 # The name in the brackets is also the name of the Minecraft server
@@ -9,17 +10,47 @@ pub const EXAMPLE_SERVER_LIST: &str = r#"# Restart the application to apply the
 # Because of the second use case, avoid using spaces or any special characters.
 [myFirstServer]
 # These are the args passed to the 'java' command.
-# That means that this Minecraft server will be started using the command 'java -jar purpur-1.19.3-1876.jar nogui'
+# That means that this Minecraft server will be started using the command 'java -jar purpur-1.20.4.jar nogui'
 #
 # Note: When specifying a ram limit like '-Xmx=4G', the Minecraft server will likely fail to start.
-args = "-jar purpur-1.19.3-1933.jar nogui"
+# Fabric servers can leave this field empty. They will be started using the args '-jar fabric-server-launch.jar nogui'.
+args = "-jar purpur-1.20.4.jar nogui"
 # This is a link from which the Minecraft server should be downloaded if none can be found.
 # A download can be avoided by leaving this field empty. (For example: download_from = "")
-download_from = "https://api.purpurmc.org/v2/purpur/1.19.3/1933/download"
+download_from = ""
+# This is the Minecraft version of this server.
+# If no download link is set, the link will be resolved automatically for the vanilla, paper, purpur and fabric server_types.
+# For fabric, the build is the version of the Fabric loader and the downloaded jar is the Fabric server launcher.
+version = "1.20.4"
+# This is the build of the server software to use. Use "latest" to always get the newest one.
+build = "latest"
+# This sets what should happen once a newer build of the version is available. Only works if the build is set to "latest".
+# "disabled": Never check for updates. "notify": Only report newer builds. "apply": Install newer builds at the next start or restart.
+# If an installed update fails to start, the previous build will be restored.
+update_policy = "notify"
 # This is the type of the Minecraft server. Depending on what value got set,
 # the application will register events like the joining of a player based on different log messages.
 # See the 'config/server_types.toml' file for all available types.
+# If left empty, the server_type will be detected from the server jar and the first lines the Minecraft server prints.
+# If the detected type differs from the set one, a warning will be logged.
 server_type = "purpur"
+# This is the name of the person accepting the Minecraft EULA (https://aka.ms/MinecraftEULA) for this server.
+# The acceptance will be recorded at the next start. Without it, the server will refuse to start.
+# Uncomment the following line and set it to your name, or accept the EULA using the api instead.
+# eula_accepted_by = "Jane Doe"
+# This is the name of the proxy network this server belongs to, together with its role in it.
+# Every network needs exactly one "proxy" ( a Velocity or BungeeCord server ) and at least one "backend".
+# The backends get written to the config of the proxy, their online-mode gets disabled and the forwarding of the players gets set up.
+# Starting the network will start its backends first and its proxy last.
+# network = "myNetwork"
+# network_role = "backend"
+# This is the edition of Minecraft this server runs. Bedrock servers get started by running the 'bedrock_server' binary of the
+# Bedrock Dedicated Server, which has to lie in the server folder or be downloaded from the link set above as a zip archive.
+# Their args are passed to the binary and their server_type defaults to "bedrock". If not set, the server runs the Java Edition.
+# kind = "bedrock"
+# These are the names of the servers which have to be started before this server. The server will wait for them to be started,
+# and they will be stopped after it. Servers depending on each other in a cycle will be rejected.
+# depends_on = ["auth"]
 # This is the amount of time the application should wait between restarts of this Minecraft server.
 # If both the secs and nanos values are 0, no restarts will be performed.
 [myFirstServer.restart_time]
@@ -30,6 +61,12 @@ nanos = 0
 args = "-jar purpur-1.19.3-1933.jar nogui"
 download_from = "https://api.purpurmc.org/v2/purpur/1.19.3/1933/download"
 server_type = "purpur"
+# This server will only be started once myFirstServer got started.
+depends_on = ["myFirstServer"]
 [mySecondServer.restart_time]
 secs = 86400
+nanos = 0
+# This is the amount of time to wait after the servers this server depends on got started, before starting it.
+[mySecondServer.start_delay]
+secs = 10
 nanos = 0"#;
@@ -1,13 +1,7 @@
 //! This module provides the [`VALID_CONFIG`] constant.
 
 /// This constant represents the `valid file` content of the [`CONFIG_FILE`](crate::crate::generated_files::paths::CONFIG_FILE) file.
-pub const VALID_CONFIG: &str = r#"# Sets whether or not all EULAs for the Minecraft servers get accepted automatically.
-# The following line is copied from the vanilla Minecraft server's EULA.
-# ' By changing the setting below to TRUE you are indicating your agreement to our EULA https://aka.ms/MinecraftEULA. '
-# In other words:, when this function returns true, you are agreeing to any EULA this application automatically accepts.
-agree_to_eula = true
-
-# The size of the buffers created by this application. (If set too low, it can cause many different kinds of information to only be partially transmitted.)
+pub const VALID_CONFIG: &str = r#"# The size of the buffers created by this application. (If set too low, it can cause many different kinds of information to only be partially transmitted.)
 buffsize = 100000000
 
 # The maximum number of times an operation gets retried.
@@ -16,6 +10,29 @@ max_tries = 3
 # The port the webserver should run on.
 website_port = 80
 
+# The port the database server should run on.
+database_port = 5432
+
+# The database the server list gets saved to. Either "postgres" or "sqlite".
+# "postgres" runs an embedded PostgreSQL server on the database_port. "sqlite" saves the server list to the `data.sqlite` file and needs no
+# database server.
+database_backend = "postgres"
+
+# Whether or not the player history, the chat log and the webhooks should be enabled.
+# They get saved to the database selected by the database_backend.
+enable_history_and_webhooks = true
+
+# The base urls of the apis used to find the download links of Minecraft server jars.
+# Change them only if you want to use a mirror.
+mojang_api_url = "https://piston-meta.mojang.com"
+papermc_api_url = "https://api.papermc.io"
+purpur_api_url = "https://api.purpurmc.org"
+fabric_meta_url = "https://meta.fabricmc.net"
+quilt_meta_url = "https://meta.quiltmc.org"
+
+# Whether or not the ip a player joined from should be saved together with the player's uuid.
+store_player_ips = false
+
 # Sets how long the application wait to give other tasks a chance to execute.
 [cooldown]
 secs = 0
@@ -25,4 +42,21 @@ nanos = 100000000
 # If the value is 0, no shutdowns will be performed.
 [shutdown_time]
 secs = 0
+nanos = 0
+
+# How often every Minecraft server should be checked for a newer build of its version.
+# Only servers with an update_policy other than "disabled" will be checked.
+[update_check_interval]
+secs = 3600
+nanos = 0
+
+# How long an updated Minecraft server may take to start. If it does not start in time, the previous build will be restored.
+[update_start_timeout]
+secs = 300
+nanos = 0
+
+# How long chat messages are kept before they get deleted.
+# If the value is 0, chat messages will be kept forever.
+[chat_retention]
+secs = 2592000
 nanos = 0"#;
@@ -3,7 +3,7 @@
 /// This constant represents the `valid file` content of the [`MCSERVER_TYPES_FILE`](crate::crate::generated_files::paths::MCSERVER_TYPES_FILE) file.
 pub const VALID_MCSERVER_TYPES: &str = r#"# Restart the application to apply the changes made to the 'config/server_types.toml' file.
 
-# This file describes all server_types that can be used in the 'config/server_list.toml' file.
+# This file describes all server_types a server can be set to. The server_type of a server can be changed using the server list api.
 # They are very important for the application because it registers all events of a Minecraft server via the console.
 #
 # In case you want to define your own server_type you need to follow fill all of the following fields:
@@ -16,18 +16,265 @@ pub const VALID_MCSERVER_TYPES: &str = r#"# Restart the application to apply the
 #                                  For the message '[13:53:51 INFO]: Gooxey joined the game' the player name is at the position 2.
 # player_name_left_pos = 2      => This is the position, starting from 0, of the player name in the player-joined message.
 #                                  For the message '[13:53:51 INFO]: Gooxey left the game' the player name is at the position 2.
+# player_uuid = [""]            => This is a list of every string the message announcing the uuid of a joining player has to contain.
+# player_uuid_name_pos = 5      => This is the position, starting from 0, of the player name in the player-uuid message.
+#                                  For the message '[13:53:51 INFO]: UUID of player Gooxey is 069a79f4-44e9-4726-a5be-fca90e38aaf5' the player name is at the position 5.
+# player_uuid_pos = 7           => This is the position, starting from 0, of the uuid in the player-uuid message.
+# player_logged_in = [""]       => This is a list of every string the message announcing the address of a joining player has to contain.
+# player_logged_in_pos = 2      => This is the position, starting from 0, of the player name and address in the player-logged-in message.
+#                                  For the message '[13:53:51 INFO]: Gooxey[/127.0.0.1:51234] logged in with entity id 42 at ...' they are at the position 2.
+# chat = [""]                   => This is a list of every string a chat message has to contain.
+# chat_sender_start = "<"       => This is the string in front of the sender of a chat message.
+# chat_sender_end = "> "        => This is the string between the sender and the content of a chat message.
+#                                  For the message '[13:53:51 INFO]: <Gooxey> hello' the sender is Gooxey and the content is hello.
+#                                  Tags in front of the sender, like the '[Not Secure]' tag of unsigned messages, will be ignored.
+#
+# Instead of a list of strings, every message can also be described by a regex written as a single string. Its named groups replace the positions:
+#
+# player_joined = '\]: (?:\[[^\]]*\] )*(?P<player>\w+) joined the game$'
+#                               => The groups 'player' of the player_joined and player_left messages contain the player name.
+# player_uuid = 'UUID of player (?P<player>\w+) is (?P<uuid>[0-9a-f-]+)'
+#                               => The groups 'player' and 'uuid' of the player_uuid message contain the player name and the uuid.
+#                                  The groups 'player' and 'ip' of the player_logged_in message contain the player name and the ip.
+#                                  The groups 'sender' and 'message' of the chat message contain the sender and the content.
+#                                  Bedrock servers only announce the xuid of a player. Their player_uuid message captures it in the group 'xuid'
+#                                  instead of the group 'uuid'. It will be converted to the uuid Floodgate would assign.
+#
+# Regex patterns are able to read lines containing ranks or other prefixes. A message written as a regex does not need its position fields
+# ( player_name_joined_pos, chat_sender_start, etc. ). Color codes get removed from every line before it gets matched.
+#
+# The following messages are optional. If one of them is missing, the event it describes will not be detected:
+#
+# stopping = ''                 => This is the message announcing that the server is stopping.
+# save_complete = ''            => This is the message announcing that the worlds got saved.
+# player_death = ''             => This is the message of a player dying. The group 'player' contains the player name and the group 'message' the
+#                                  death message. Without them, the content of the line is the death message and its first word the player name.
+# advancement = ''              => This is the message of a player making an advancement. The group 'player' contains the player name and the
+#                                  group 'advancement' the name of the advancement.
+# overloaded = ''               => This is the message announcing that the server can not keep up. The groups 'milliseconds' and 'ticks' contain
+#                                  how far the server is behind.
+# exception = ''                => This is the first line of an exception. The group 'message' contains the message of the exception.
+# stack_trace = ''              => Every line of the stack trace following an exception has to match this message.
+# plugin_load_failed = ''       => This is the message announcing that a plugin could not be loaded. The group 'plugin' contains the plugin.
+# branding = ''                 => This is a message only this server_type prints while starting, like 'This server is running Paper version'.
+#                                  Servers without a server_type use it to detect their server_type.
+# player_switched = ''          => This is the message of a proxy announcing that a player connected to one of its backends. The group 'player'
+#                                  contains the player name and the group 'server' the name of the backend.
+# stop_command = "stop"         => This is the command used to stop the server. If it is missing, 'stop' will be used.
+#
+# Proxies like Velocity and BungeeCord and the Bedrock Dedicated Server do not print every message of a Minecraft server. A message they never print can be disabled with a regex
+# which can never match, like '[^\s\S]'.
 
 
 [vanilla]
 started = ["] [Server thread/INFO]: Done (", ")! For help, type \"help\""]
 player_joined = [" joined the game"]
 player_left = ["left the game"]
-player_name_joined_pos = 2
-player_name_left_pos = 2
+player_name_joined_pos = 3
+player_name_left_pos = 3
+player_uuid = ["UUID of player ", " is "]
+player_uuid_name_pos = 7
+player_uuid_pos = 9
+player_logged_in = ["[/", "] logged in with entity id "]
+player_logged_in_pos = 3
+chat = ["]: ", "<", "> "]
+chat_sender_start = "<"
+chat_sender_end = "> "
+stopping = '\]: Stopping server$'
+save_complete = '\]: (?:Saved the game|ThreadedAnvilChunkStorage: All dimensions are saved)$'
+player_death = '\]: (?P<message>(?P<player>\w{1,16}) (?:was |died|drowned|blew up|burned to death|fell |froze to death|hit the ground|starved to death|suffocated|went up in flames|went off with a bang|walked into|tried to swim in lava|withered away|experienced kinetic energy|left the confines of this world|discovered the floor was lava|is no more).*)$'
+advancement = '\]: (?P<player>\w{1,16}) has (?:made the advancement|completed the challenge|reached the goal) \[(?P<advancement>.+)\]$'
+overloaded = 'Can.t keep up! Is the server overloaded\? Running (?P<milliseconds>\d+)ms or (?P<ticks>\d+) ticks behind'
+exception = '^(?:\[[^\]]*\]:? )*(?P<message>(?:[a-z_$][\w$]*\.)+[A-Z][\w$]*(?:Exception|Error)\b.*)$'
+stack_trace = '^\s+(?:at |\.\.\. \d+ more)|^\s*(?:Caused by|Suppressed): '
+
+[fabric]
+branding = 'Loading Minecraft \S+ with Fabric Loader'
+started = ["] [Server thread/INFO]: Done (", ")! For help, type \"help\""]
+player_joined = [" joined the game"]
+player_left = ["left the game"]
+player_name_joined_pos = 3
+player_name_left_pos = 3
+player_uuid = ["UUID of player ", " is "]
+player_uuid_name_pos = 7
+player_uuid_pos = 9
+player_logged_in = ["[/", "] logged in with entity id "]
+player_logged_in_pos = 3
+chat = ["]: ", "<", "> "]
+chat_sender_start = "<"
+chat_sender_end = "> "
+stopping = '\]: Stopping server$'
+save_complete = '\]: (?:Saved the game|ThreadedAnvilChunkStorage: All dimensions are saved)$'
+player_death = '\]: (?P<message>(?P<player>\w{1,16}) (?:was |died|drowned|blew up|burned to death|fell |froze to death|hit the ground|starved to death|suffocated|went up in flames|went off with a bang|walked into|tried to swim in lava|withered away|experienced kinetic energy|left the confines of this world|discovered the floor was lava|is no more).*)$'
+advancement = '\]: (?P<player>\w{1,16}) has (?:made the advancement|completed the challenge|reached the goal) \[(?P<advancement>.+)\]$'
+overloaded = 'Can.t keep up! Is the server overloaded\? Running (?P<milliseconds>\d+)ms or (?P<ticks>\d+) ticks behind'
+exception = '^(?:\[[^\]]*\]:? )*(?P<message>(?:[a-z_$][\w$]*\.)+[A-Z][\w$]*(?:Exception|Error)\b.*)$'
+stack_trace = '^\s+(?:at |\.\.\. \d+ more)|^\s*(?:Caused by|Suppressed): '
+
+[quilt]
+branding = 'Loading Minecraft \S+ with Quilt Loader'
+started = ["] [Server thread/INFO]: Done (", ")! For help, type \"help\""]
+player_joined = [" joined the game"]
+player_left = ["left the game"]
+player_name_joined_pos = 3
+player_name_left_pos = 3
+player_uuid = ["UUID of player ", " is "]
+player_uuid_name_pos = 7
+player_uuid_pos = 9
+player_logged_in = ["[/", "] logged in with entity id "]
+player_logged_in_pos = 3
+chat = ["]: ", "<", "> "]
+chat_sender_start = "<"
+chat_sender_end = "> "
+stopping = '\]: Stopping server$'
+save_complete = '\]: (?:Saved the game|ThreadedAnvilChunkStorage: All dimensions are saved)$'
+player_death = '\]: (?P<message>(?P<player>\w{1,16}) (?:was |died|drowned|blew up|burned to death|fell |froze to death|hit the ground|starved to death|suffocated|went up in flames|went off with a bang|walked into|tried to swim in lava|withered away|experienced kinetic energy|left the confines of this world|discovered the floor was lava|is no more).*)$'
+advancement = '\]: (?P<player>\w{1,16}) has (?:made the advancement|completed the challenge|reached the goal) \[(?P<advancement>.+)\]$'
+overloaded = 'Can.t keep up! Is the server overloaded\? Running (?P<milliseconds>\d+)ms or (?P<ticks>\d+) ticks behind'
+exception = '^(?:\[[^\]]*\]:? )*(?P<message>(?:[a-z_$][\w$]*\.)+[A-Z][\w$]*(?:Exception|Error)\b.*)$'
+stack_trace = '^\s+(?:at |\.\.\. \d+ more)|^\s*(?:Caused by|Suppressed): '
 
 [purpur]
+branding = 'This server is running Purpur version'
 started = [" INFO]: Done (", ")! For help, type \"help\""]
 player_joined = [" joined the game"]
 player_left = ["left the game"]
 player_name_joined_pos = 2
-player_name_left_pos = 2"#;
+player_name_left_pos = 2
+player_uuid = ["UUID of player ", " is "]
+player_uuid_name_pos = 5
+player_uuid_pos = 7
+player_logged_in = ["[/", "] logged in with entity id "]
+player_logged_in_pos = 2
+chat = ["]: ", "<", "> "]
+chat_sender_start = "<"
+chat_sender_end = "> "
+stopping = '\]: Stopping server$'
+save_complete = '\]: (?:Saved the game|ThreadedAnvilChunkStorage: All dimensions are saved)$'
+player_death = '\]: (?P<message>(?P<player>\w{1,16}) (?:was |died|drowned|blew up|burned to death|fell |froze to death|hit the ground|starved to death|suffocated|went up in flames|went off with a bang|walked into|tried to swim in lava|withered away|experienced kinetic energy|left the confines of this world|discovered the floor was lava|is no more).*)$'
+advancement = '\]: (?P<player>\w{1,16}) has (?:made the advancement|completed the challenge|reached the goal) \[(?P<advancement>.+)\]$'
+overloaded = 'Can.t keep up! Is the server overloaded\? Running (?P<milliseconds>\d+)ms or (?P<ticks>\d+) ticks behind'
+exception = '^(?:\[[^\]]*\]:? )*(?P<message>(?:[a-z_$][\w$]*\.)+[A-Z][\w$]*(?:Exception|Error)\b.*)$'
+stack_trace = '^\s+(?:at |\.\.\. \d+ more)|^\s*(?:Caused by|Suppressed): '
+plugin_load_failed = '''\]: (?:Could not load 'plugins[/\\]|Error occurred while enabling )(?P<plugin>[^' ]+)'''
+
+[paper]
+branding = 'This server is running Paper version'
+started = '\]: Done \([0-9.,]+s\)! For help, type "help"'
+player_joined = '\]: (?P<player>\w{1,16})(?: \(formerly known as \w{1,16}\))? joined the game$'
+player_left = '\]: (?P<player>\w{1,16}) left the game$'
+player_uuid = 'UUID of player (?P<player>\w{1,16}) is (?P<uuid>[0-9a-f-]{36})'
+player_logged_in = '\]: (?P<player>\w{1,16})\[/(?P<ip>[^\]]+):\d+\] logged in with entity id '
+chat = '\]: (?:\[Not Secure\] )?<(?P<sender>\w{1,16})> (?P<message>.*)$'
+stopping = '\]: Stopping server$'
+save_complete = '\]: (?:Saved the game|ThreadedAnvilChunkStorage: All dimensions are saved)$'
+player_death = '\]: (?P<message>(?P<player>\w{1,16}) (?:was |died|drowned|blew up|burned to death|fell |froze to death|hit the ground|starved to death|suffocated|went up in flames|went off with a bang|walked into|tried to swim in lava|withered away|experienced kinetic energy|left the confines of this world|discovered the floor was lava|is no more).*)$'
+advancement = '\]: (?P<player>\w{1,16}) has (?:made the advancement|completed the challenge|reached the goal) \[(?P<advancement>.+)\]$'
+overloaded = 'Can.t keep up! Is the server overloaded\? Running (?P<milliseconds>\d+)ms or (?P<ticks>\d+) ticks behind'
+exception = '^(?:\[[^\]]*\]:? )*(?P<message>(?:[a-z_$][\w$]*\.)+[A-Z][\w$]*(?:Exception|Error)\b.*)$'
+stack_trace = '^\s+(?:at |\.\.\. \d+ more)|^\s*(?:Caused by|Suppressed): '
+plugin_load_failed = '''\]: (?:Could not load 'plugins[/\\]|Error occurred while enabling )(?P<plugin>[^' ]+)'''
+
+[spigot]
+branding = 'This server is running CraftBukkit version \S*-Spigot-'
+started = '\]: Done \([0-9.,]+s\)! For help, type "help"'
+player_joined = '\]: (?P<player>\w{1,16})(?: \(formerly known as \w{1,16}\))? joined the game$'
+player_left = '\]: (?P<player>\w{1,16}) left the game$'
+player_uuid = 'UUID of player (?P<player>\w{1,16}) is (?P<uuid>[0-9a-f-]{36})'
+player_logged_in = '\]: (?P<player>\w{1,16})\[/(?P<ip>[^\]]+):\d+\] logged in with entity id '
+chat = '\]: (?:\[Not Secure\] )?<(?P<sender>\w{1,16})> (?P<message>.*)$'
+stopping = '\]: Stopping server$'
+save_complete = '\]: (?:Saved the game|ThreadedAnvilChunkStorage: All dimensions are saved)$'
+player_death = '\]: (?P<message>(?P<player>\w{1,16}) (?:was |died|drowned|blew up|burned to death|fell |froze to death|hit the ground|starved to death|suffocated|went up in flames|went off with a bang|walked into|tried to swim in lava|withered away|experienced kinetic energy|left the confines of this world|discovered the floor was lava|is no more).*)$'
+advancement = '\]: (?P<player>\w{1,16}) has (?:made the advancement|completed the challenge|reached the goal) \[(?P<advancement>.+)\]$'
+overloaded = 'Can.t keep up! Is the server overloaded\? Running (?P<milliseconds>\d+)ms or (?P<ticks>\d+) ticks behind'
+exception = '^(?:\[[^\]]*\]:? )*(?P<message>(?:[a-z_$][\w$]*\.)+[A-Z][\w$]*(?:Exception|Error)\b.*)$'
+stack_trace = '^\s+(?:at |\.\.\. \d+ more)|^\s*(?:Caused by|Suppressed): '
+plugin_load_failed = '''\]: (?:Could not load 'plugins[/\\]|Error occurred while enabling )(?P<plugin>[^' ]+)'''
+
+[quilt]
+branding = 'Loading Minecraft \S+ with Quilt Loader'
+started = '\]: Done \([0-9.,]+s\)! For help, type "help"'
+player_joined = '\]: (?P<player>\w{1,16})(?: \(formerly known as \w{1,16}\))? joined the game$'
+player_left = '\]: (?P<player>\w{1,16}) left the game$'
+player_uuid = 'UUID of player (?P<player>\w{1,16}) is (?P<uuid>[0-9a-f-]{36})'
+player_logged_in = '\]: (?P<player>\w{1,16})\[/(?P<ip>[^\]]+):\d+\] logged in with entity id '
+chat = '\]: (?:\[Not Secure\] )?<(?P<sender>\w{1,16})> (?P<message>.*)$'
+stopping = '\]: Stopping server$'
+save_complete = '\]: (?:Saved the game|ThreadedAnvilChunkStorage: All dimensions are saved)$'
+player_death = '\]: (?P<message>(?P<player>\w{1,16}) (?:was |died|drowned|blew up|burned to death|fell |froze to death|hit the ground|starved to death|suffocated|went up in flames|went off with a bang|walked into|tried to swim in lava|withered away|experienced kinetic energy|left the confines of this world|discovered the floor was lava|is no more).*)$'
+advancement = '\]: (?P<player>\w{1,16}) has (?:made the advancement|completed the challenge|reached the goal) \[(?P<advancement>.+)\]$'
+overloaded = 'Can.t keep up! Is the server overloaded\? Running (?P<milliseconds>\d+)ms or (?P<ticks>\d+) ticks behind'
+exception = '^(?:\[[^\]]*\]:? )*(?P<message>(?:[a-z_$][\w$]*\.)+[A-Z][\w$]*(?:Exception|Error)\b.*)$'
+stack_trace = '^\s+(?:at |\.\.\. \d+ more)|^\s*(?:Caused by|Suppressed): '
+
+[forge]
+branding = '--launchTarget, forgeserver|\bForge mod loading, version'
+started = '\]: Done \([0-9.,]+s\)! For help, type "help"'
+player_joined = '\]: (?P<player>\w{1,16})(?: \(formerly known as \w{1,16}\))? joined the game$'
+player_left = '\]: (?P<player>\w{1,16}) left the game$'
+player_uuid = 'UUID of player (?P<player>\w{1,16}) is (?P<uuid>[0-9a-f-]{36})'
+player_logged_in = '\]: (?P<player>\w{1,16})\[/(?P<ip>[^\]]+):\d+\] logged in with entity id '
+chat = '\]: (?:\[Not Secure\] )?<(?P<sender>\w{1,16})> (?P<message>.*)$'
+stopping = '\]: Stopping server$'
+save_complete = '\]: (?:Saved the game|ThreadedAnvilChunkStorage: All dimensions are saved)$'
+player_death = '\]: (?P<message>(?P<player>\w{1,16}) (?:was |died|drowned|blew up|burned to death|fell |froze to death|hit the ground|starved to death|suffocated|went up in flames|went off with a bang|walked into|tried to swim in lava|withered away|experienced kinetic energy|left the confines of this world|discovered the floor was lava|is no more).*)$'
+advancement = '\]: (?P<player>\w{1,16}) has (?:made the advancement|completed the challenge|reached the goal) \[(?P<advancement>.+)\]$'
+overloaded = 'Can.t keep up! Is the server overloaded\? Running (?P<milliseconds>\d+)ms or (?P<ticks>\d+) ticks behind'
+exception = '^(?:\[[^\]]*\]:? )*(?P<message>(?:[a-z_$][\w$]*\.)+[A-Z][\w$]*(?:Exception|Error)\b.*)$'
+stack_trace = '^\s+(?:at |\.\.\. \d+ more)|^\s*(?:Caused by|Suppressed): '
+
+[neoforge]
+branding = '--launchTarget, neoforgeserver|\bNeoForge mod loading, version'
+started = '\]: Done \([0-9.,]+s\)! For help, type "help"'
+player_joined = '\]: (?P<player>\w{1,16})(?: \(formerly known as \w{1,16}\))? joined the game$'
+player_left = '\]: (?P<player>\w{1,16}) left the game$'
+player_uuid = 'UUID of player (?P<player>\w{1,16}) is (?P<uuid>[0-9a-f-]{36})'
+player_logged_in = '\]: (?P<player>\w{1,16})\[/(?P<ip>[^\]]+):\d+\] logged in with entity id '
+chat = '\]: (?:\[Not Secure\] )?<(?P<sender>\w{1,16})> (?P<message>.*)$'
+stopping = '\]: Stopping server$'
+save_complete = '\]: (?:Saved the game|ThreadedAnvilChunkStorage: All dimensions are saved)$'
+player_death = '\]: (?P<message>(?P<player>\w{1,16}) (?:was |died|drowned|blew up|burned to death|fell |froze to death|hit the ground|starved to death|suffocated|went up in flames|went off with a bang|walked into|tried to swim in lava|withered away|experienced kinetic energy|left the confines of this world|discovered the floor was lava|is no more).*)$'
+advancement = '\]: (?P<player>\w{1,16}) has (?:made the advancement|completed the challenge|reached the goal) \[(?P<advancement>.+)\]$'
+overloaded = 'Can.t keep up! Is the server overloaded\? Running (?P<milliseconds>\d+)ms or (?P<ticks>\d+) ticks behind'
+exception = '^(?:\[[^\]]*\]:? )*(?P<message>(?:[a-z_$][\w$]*\.)+[A-Z][\w$]*(?:Exception|Error)\b.*)$'
+stack_trace = '^\s+(?:at |\.\.\. \d+ more)|^\s*(?:Caused by|Suppressed): '
+
+[velocity]
+branding = 'Booting up Velocity '
+started = '\]: Done \([0-9.,]+s\)!$'
+player_joined = '\]: \[connected player\] (?P<player>\w{1,16}) \(/[^)]+\) has connected$'
+player_left = '\]: \[connected player\] (?P<player>\w{1,16}) \(/[^)]+\) has disconnected$'
+player_uuid = '[^\s\S]'
+player_logged_in = '\]: \[connected player\] (?P<player>\w{1,16}) \(/(?P<ip>[^)]+):\d+\) has connected$'
+chat = '[^\s\S]'
+player_switched = '\]: \[server connection\] (?P<player>\w{1,16}) -> (?P<server>\S+) has connected$'
+stopping = '\]: Shutting down the proxy\.\.\.$'
+stop_command = "end"
+exception = '^(?:\[[^\]]*\]:? )*(?P<message>(?:[a-z_$][\w$]*\.)+[A-Z][\w$]*(?:Exception|Error)\b.*)$'
+stack_trace = '^\s+(?:at |\.\.\. \d+ more)|^\s*(?:Caused by|Suppressed): '
+
+[bungeecord]
+branding = 'Enabled (?:BungeeCord|Waterfall) version '
+started = '\]: Listening on /'
+player_joined = '\]: \[(?P<player>\w{1,16})(?:[,|]/[^\]]+)?\] <-> InitialHandler has connected$'
+player_left = '\]: \[(?P<player>\w{1,16})\] -> UpstreamBridge has disconnected$'
+player_uuid = '[^\s\S]'
+player_logged_in = '\]: \[(?P<player>\w{1,16})[,|]/(?P<ip>[^\]]+):\d+\] <-> InitialHandler has connected$'
+chat = '[^\s\S]'
+player_switched = '\]: \[(?P<player>\w{1,16})\] <-> ServerConnector \[(?P<server>[^\]]+)\] has connected$'
+stopping = '\]: Closing pending connections$'
+stop_command = "end"
+exception = '^(?:\[[^\]]*\]:? )*(?P<message>(?:[a-z_$][\w$]*\.)+[A-Z][\w$]*(?:Exception|Error)\b.*)$'
+stack_trace = '^\s+(?:at |\.\.\. \d+ more)|^\s*(?:Caused by|Suppressed): '
+
+[bedrock]
+started = '\] Server started\.$'
+player_joined = '\] Player connected: (?P<player>[^,]+), xuid: '
+player_left = '\] Player disconnected: (?P<player>[^,]+), xuid: '
+player_uuid = '\] Player connected: (?P<player>[^,]+), xuid: (?P<xuid>\d*)'
+player_logged_in = '[^\s\S]'
+chat = '[^\s\S]'
+stopping = '\] Server stop requested\.$'
+stop_command = "stop"
+"#;
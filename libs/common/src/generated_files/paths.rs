@@ -78,50 +78,67 @@ pub static ROOT_DIR: Lazy<PathBuf> = Lazy::new(|| {
 /// **Note:** If not initialized by the [`init_paths`] function, the PathBuf will be empty.
 pub static DATA_DIR: Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("data"));
 
+/// The path to the SQLite database at `./data.sqlite`, which gets used if the [`Config`](crate::config::Config) selects the `sqlite` database
+/// backend. \
+/// \
+/// **Note:** If not initialized by the [`init_paths`] function, the PathBuf will be empty.
+pub static SQLITE_DATABASE_FILE: Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("data.sqlite"));
+
 /// The path to the config directory at `./config` \
 /// \
 /// **Note:** If not initialized by the [`init_paths`] function, the PathBuf will be empty.
-pub static CONFIG_DIR:  Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("data"));
+pub static CONFIG_DIR:  Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("config"));
 /// The path to the config directory at `./config/config.toml` \
 /// \
 /// **Note:** If not initialized by the [`init_paths`] function, the PathBuf will be empty.
-pub static CONFIG_FILE:  Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("data"));
+pub static CONFIG_FILE:  Lazy<PathBuf> = Lazy::new(|| CONFIG_DIR.join("config.toml"));
 /// The path to the config directory at `./config/server_list.toml` \
 /// \
 /// **Note:** If not initialized by the [`init_paths`] function, the PathBuf will be empty.
-pub static SERVER_LIST_FILE:  Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("data"));
+pub static SERVER_LIST_FILE:  Lazy<PathBuf> = Lazy::new(|| CONFIG_DIR.join("server_list.toml"));
 /// The path to the config directory at `./config/server_types.toml` \
 /// \
 /// **Note:** If not initialized by the [`init_paths`] function, the PathBuf will be empty.
-pub static MCSERVER_TYPES_FILE:  Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("data"));
+pub static MCSERVER_TYPES_FILE:  Lazy<PathBuf> = Lazy::new(|| CONFIG_DIR.join("server_types.toml"));
 
 /// The path to the logs directory at `./logs` \
 /// \
 /// **Note:** If not initialized by the [`init_paths`] function, the PathBuf will be empty.
-pub static LOGS_DIR:  Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("data"));
+pub static LOGS_DIR:  Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("logs"));
 /// The path to the server logs directory at `./logs/servers` \
 /// This directory is intended for log files from Minecraft servers. \
 /// \
 /// **Note:** If not initialized by the [`init_paths`] function, the PathBuf will be empty.
-pub static SERVER_LOGS_DIR:  Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("data"));
+pub static SERVER_LOGS_DIR:  Lazy<PathBuf> = Lazy::new(|| LOGS_DIR.join("servers"));
 /// The path to the log file at `./logs/mcmanage.log` \
 /// \
 /// **Note:** If not initialized by the [`init_paths`] function, the PathBuf will be empty.
-pub static LOG_FILE:  Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("data"));
+pub static LOG_FILE:  Lazy<PathBuf> = Lazy::new(|| LOGS_DIR.join("mcmanage.log"));
 
 /// The path to the share directory at `./share` \
 /// \
 /// **Note:** If not initialized by the [`init_paths`] function, the PathBuf will be empty.
-pub static SHARE_DIR:  Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("data"));
+pub static SHARE_DIR:  Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("share"));
 /// The path to the frontend directory at `./share/frontend` \
 /// \
 /// **Note:** If not initialized by the [`init_paths`] function, the PathBuf will be empty.
-pub static FRONTEND_DIR:  Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("data"));
+pub static FRONTEND_DIR:  Lazy<PathBuf> = Lazy::new(|| SHARE_DIR.join("frontend"));
 
 /// The path to the servers directory at `./servers` \
 /// \
 /// **Note:** If not initialized by the [`init_paths`] function, the PathBuf will be empty.
-pub static SERVERS_DIR:  Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("data"));
+pub static SERVERS_DIR:  Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("servers"));
+
+/// The path to the downloads directory at `./downloads` \
+/// This directory holds every file downloaded from the internet. \
+/// \
+/// **Note:** If not initialized by the [`init_paths`] function, the PathBuf will be empty.
+pub static DOWNLOADS_DIR:  Lazy<PathBuf> = Lazy::new(|| ROOT_DIR.join("downloads"));
+/// The path to the download index at `./downloads/index.json` \
+/// This file keeps track of where every file in the [`struct@DOWNLOADS_DIR`] came from. \
+/// \
+/// **Note:** If not initialized by the [`init_paths`] function, the PathBuf will be empty.
+pub static DOWNLOAD_INDEX_FILE:  Lazy<PathBuf> = Lazy::new(|| DOWNLOADS_DIR.join("index.json"));
 
 // pub fn init_paths() {
 //     fn root_dir() -> PathBuf {
@@ -0,0 +1,137 @@
+//! This module provides the [`JarDirectory`] struct, which manages a directory of jars that can be enabled and disabled, like the `plugins` or `mods`
+//! directory of a Minecraft server.
+
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use tokio::fs;
+
+use crate::{
+    jar_file::check_jar_name,
+    mcmanage_error::MCManageError,
+};
+
+/// This struct manages a directory of jars. Disabled jars get moved to the `.disabled` directory inside it, so the Minecraft server will not load them.
+pub struct JarDirectory {
+    /// The directory the enabled jars lie in
+    dir: PathBuf,
+}
+// actions
+impl JarDirectory {
+    /// Create a new [`JarDirectory`] for the given directory. The directory does not need to exist yet.
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+        }
+    }
+    /// Enable the given jar by moving it from the `.disabled` directory back to this directory.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                  |
+    /// |-----------------------------------|----------------------------------------------|
+    /// | `Ok(())`                          | The jar got enabled.                         |
+    /// | [`MCManageError::NotFound`]       | No disabled jar with the given name exists.  |
+    /// | [`MCManageError::InvalidRequest`] | The file name is not the name of a jar file. |
+    pub async fn enable(&self, file_name: &str) -> Result<(), MCManageError> {
+        check_jar_name(file_name)?;
+        let disabled_path = self.disabled_dir().join(file_name);
+        if !disabled_path.exists() {
+            return Err(MCManageError::NotFound);
+        }
+
+        fs::rename(disabled_path, self.dir.join(file_name)).await?;
+        Ok(())
+    }
+    /// Disable the given jar by moving it to the `.disabled` directory.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                  |
+    /// |-----------------------------------|----------------------------------------------|
+    /// | `Ok(())`                          | The jar got disabled.                        |
+    /// | [`MCManageError::NotFound`]       | No enabled jar with the given name exists.   |
+    /// | [`MCManageError::InvalidRequest`] | The file name is not the name of a jar file. |
+    pub async fn disable(&self, file_name: &str) -> Result<(), MCManageError> {
+        check_jar_name(file_name)?;
+        let enabled_path = self.dir.join(file_name);
+        if !enabled_path.exists() {
+            return Err(MCManageError::NotFound);
+        }
+
+        fs::create_dir_all(self.disabled_dir()).await?;
+        fs::rename(enabled_path, self.disabled_dir().join(file_name)).await?;
+        Ok(())
+    }
+    /// Delete the given jar, no matter if it is enabled or not.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                  |
+    /// |-----------------------------------|----------------------------------------------|
+    /// | `Ok(())`                          | The jar got deleted.                         |
+    /// | [`MCManageError::NotFound`]       | No jar with the given name exists.           |
+    /// | [`MCManageError::InvalidRequest`] | The file name is not the name of a jar file. |
+    pub async fn delete(&self, file_name: &str) -> Result<(), MCManageError> {
+        check_jar_name(file_name)?;
+        for path in [self.dir.join(file_name), self.disabled_dir().join(file_name)] {
+            if path.exists() {
+                fs::remove_file(path).await?;
+                return Ok(());
+            }
+        }
+        Err(MCManageError::NotFound)
+    }
+    /// Write the given content to a hidden file in this directory and return its path. \
+    /// This way the Minecraft server never sees an incomplete jar. Use the [`install`](Self::install) method to install the jar once it got checked.
+    pub async fn stage(&self, file_name: &str, content: &[u8]) -> Result<PathBuf, MCManageError> {
+        check_jar_name(file_name)?;
+        fs::create_dir_all(&self.dir).await?;
+
+        let staged_path = self.dir.join(format!(".{file_name}.upload"));
+        fs::write(&staged_path, content).await?;
+        Ok(staged_path)
+    }
+    /// Install a jar created by the [`stage`](Self::stage) method as an enabled jar. \
+    /// An existing jar with the same file name will be replaced, no matter if it is enabled or not.
+    pub async fn install(&self, staged_path: &Path, file_name: &str) -> Result<(), MCManageError> {
+        check_jar_name(file_name)?;
+        let disabled_path = self.disabled_dir().join(file_name);
+        if disabled_path.exists() {
+            fs::remove_file(disabled_path).await?;
+        }
+        fs::rename(staged_path, self.dir.join(file_name)).await?;
+        Ok(())
+    }
+}
+// info
+impl JarDirectory {
+    /// Return the path, file name and enabled state of every jar in this directory, sorted by their file name.
+    pub async fn jars(&self) -> Result<Vec<(PathBuf, String, bool)>, MCManageError> {
+        let mut jars = vec![];
+        for (dir, enabled) in [(self.dir.clone(), true), (self.disabled_dir(), false)] {
+            if !dir.exists() {
+                continue;
+            }
+
+            let mut entries = fs::read_dir(dir).await?;
+            while let Some(entry) = entries.next_entry().await? {
+                let file_name = entry.file_name().to_string_lossy().to_string();
+                if check_jar_name(&file_name).is_ok() && entry.file_type().await?.is_file() {
+                    jars.push((entry.path(), file_name, enabled));
+                }
+            }
+        }
+        jars.sort_by(|a, b| a.1.cmp(&b.1));
+        Ok(jars)
+    }
+}
+// internal
+impl JarDirectory {
+    /// Return the directory disabled jars get moved to.
+    fn disabled_dir(&self) -> PathBuf {
+        self.dir.join(".disabled")
+    }
+}
@@ -0,0 +1,87 @@
+//! This module provides functions to read the content of jar files, like plugins, mods or Minecraft server jars.
+
+use std::{
+    fs::File,
+    io::{
+        ErrorKind,
+        Read,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use tokio::task::spawn_blocking;
+use zip::{
+    result::ZipError,
+    ZipArchive,
+};
+
+use crate::mcmanage_error::MCManageError;
+
+/// Read the first of the given entries found in the jar at the given path. \
+/// The entries will be searched in the order given.
+///
+/// # Returns
+///
+/// | Return                    | Description                                                   |
+/// |---------------------------|---------------------------------------------------------------|
+/// | `Ok(Some((name, bytes)))` | The name of the entry found and its content.                  |
+/// | `Ok(None)`                | The jar does not contain any of the given entries.            |
+/// | `Err(...)`                | The jar could not be read. It may not be a valid zip archive. |
+pub async fn read_entry(
+    jar_path: &Path,
+    entries: &[&str],
+) -> Result<Option<(String, Vec<u8>)>, MCManageError> {
+    let jar_path = jar_path.to_path_buf();
+    let entries: Vec<String> = entries.iter().map(|entry| entry.to_string()).collect();
+
+    spawn_blocking(move || {
+        let mut archive = ZipArchive::new(File::open(jar_path)?)?;
+        for entry in entries {
+            let mut file = match archive.by_name(&entry) {
+                Ok(file) => file,
+                Err(ZipError::FileNotFound) => continue,
+                Err(error) => return Err(error.into()),
+            };
+
+            let mut content = vec![];
+            file.read_to_end(&mut content)?;
+            return Ok(Some((entry, content)));
+        }
+        Ok(None)
+    })
+    .await
+    .map_err(|error| MCManageError::IOError(std::io::Error::new(ErrorKind::Other, error)))?
+}
+/// Read the given attribute from the manifest ( `META-INF/MANIFEST.MF` ) of the jar at the given path. (For example: `Main-Class`) \
+/// If the jar, its manifest or the attribute can not be read, `None` will be returned.
+pub async fn manifest_attribute(jar_path: &Path, attribute: &str) -> Option<String> {
+    let (_, manifest) = read_entry(jar_path, &["META-INF/MANIFEST.MF"]).await.ok()??;
+    String::from_utf8_lossy(&manifest)
+        .lines()
+        .find_map(|line| line.strip_prefix(attribute)?.strip_prefix(':'))
+        .map(|value| value.trim().to_string())
+}
+/// Check that the given file name is the plain name of a jar file. This prevents an api user from reading or writing files outside of a given directory.
+///
+/// # Returns
+///
+/// | Return                            | Description                                   |
+/// |-----------------------------------|-----------------------------------------------|
+/// | `Ok(())`                          | The file name can be safely joined to a path. |
+/// | [`MCManageError::InvalidRequest`] | The file name is not the name of a jar file.  |
+pub fn check_jar_name(file_name: &str) -> Result<(), MCManageError> {
+    let is_plain_name = PathBuf::from(file_name)
+        .file_name()
+        .map_or(false, |name| name == file_name);
+
+    if is_plain_name && !file_name.starts_with('.') && file_name.ends_with(".jar") {
+        Ok(())
+    } else {
+        Err(MCManageError::InvalidRequest(format!(
+            "`{file_name}` is not the name of a jar file."
+        )))
+    }
+}
@@ -16,16 +16,48 @@
     clippy::expect_used,
 )]
 
+#[cfg(not(feature = "frontend"))]
+pub mod bedrock;
+#[cfg(not(feature = "frontend"))]
+pub mod chat_log;
 #[cfg(not(feature = "frontend"))]
 pub mod config;
 #[cfg(not(feature = "frontend"))]
+pub mod download_manager;
+#[cfg(not(feature = "frontend"))]
+pub mod event_bus;
+#[cfg(not(feature = "frontend"))]
+pub mod existing_server;
+#[cfg(not(feature = "frontend"))]
 pub mod generated_files;
 #[cfg(not(feature = "frontend"))]
+pub mod jar_directory;
+#[cfg(not(feature = "frontend"))]
+pub mod jar_file;
+#[cfg(not(feature = "frontend"))]
+pub mod mod_manager;
+#[cfg(not(feature = "frontend"))]
+pub mod modrinth_pack;
+#[cfg(not(feature = "frontend"))]
+pub mod player_identities;
+#[cfg(not(feature = "frontend"))]
+pub mod player_sessions;
+#[cfg(not(feature = "frontend"))]
+pub mod plugin_manager;
+#[cfg(not(feature = "frontend"))]
+pub mod proxy_network;
+#[cfg(not(feature = "frontend"))]
 pub mod server_manager;
 #[cfg(not(feature = "frontend"))]
+pub mod storage;
+#[cfg(not(feature = "frontend"))]
 pub mod test_functions;
 #[cfg(not(feature = "frontend"))]
 pub mod types;
+#[cfg(not(feature = "frontend"))]
+pub mod version_provider;
+#[cfg(not(feature = "frontend"))]
+pub mod webhooks;
 
 pub mod rest_api;
 pub mod mcmanage_error;
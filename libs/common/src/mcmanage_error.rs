@@ -28,6 +28,23 @@ pub enum MCManageError {
     /// The struct needs to be started before executing anything. Please execute the start function first.
     #[error("The struct needs to be started before executing anything. Please execute the start function first.")]
     NotStarted,
+    /// The operation got cancelled before it could finish.
+    #[error("The operation got cancelled before it could finish.")]
+    Cancelled,
+    /// The Minecraft EULA has not been accepted for the server.
+    #[error("The Minecraft EULA (https://aka.ms/MinecraftEULA) has not been accepted for this server yet.")]
+    EulaNotAccepted,
+    /// The checksum of a downloaded file does not match the expected one.
+    #[error("The checksum of the downloaded file is `{found}`, but `{expected}` was expected.")]
+    ChecksumMismatch {
+        /// The checksum the file should have had.
+        expected: String,
+        /// The checksum the file actually has.
+        found: String,
+    },
+    /// Some servers failed. Every failed server is given by its name and the error it failed with.
+    #[error("The servers {} failed.", failed_servers(.0))]
+    ServersFailed(Vec<(String, MCManageError)>),
     /// The api request has invalid arguments.
     #[error("{0}")]
     InvalidRequest(String),
@@ -46,4 +63,33 @@ pub enum MCManageError {
     /// An error of kind SerdeJsonError occurred.
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    /// An error of kind ReqwestError occurred.
+    #[cfg(not(feature = "frontend"))]
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+    /// An error of kind ZipError occurred.
+    #[cfg(not(feature = "frontend"))]
+    #[error(transparent)]
+    ZipError(#[from] zip::result::ZipError),
+    /// An error of kind SerdeYamlError occurred.
+    #[cfg(not(feature = "frontend"))]
+    #[error(transparent)]
+    SerdeYamlError(#[from] serde_yaml::Error),
+    /// An error of kind SqlxError occurred.
+    #[cfg(not(feature = "frontend"))]
+    #[error(transparent)]
+    SqlxError(#[from] sqlx::Error),
+    /// An error of kind RegexError occurred.
+    #[cfg(not(feature = "frontend"))]
+    #[error(transparent)]
+    RegexError(#[from] regex::Error),
+}
+
+/// Join the names and errors of the given servers, so they can be displayed by an [`MCManageError::ServersFailed`].
+fn failed_servers(servers: &[(String, MCManageError)]) -> String {
+    servers
+        .iter()
+        .map(|(name, error)| format!("{name} ( {error} )"))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
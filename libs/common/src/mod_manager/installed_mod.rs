@@ -0,0 +1,22 @@
+//! This module provides the [`InstalledMod`] struct.
+
+use proc_macros::add_convert;
+
+use super::{
+    mod_descriptor::ModDescriptor,
+    mod_warning::ModWarning,
+};
+use crate::mcmanage_error::MCManageError;
+
+/// This struct represents one mod jar found in a server's `mods` directory.
+#[add_convert]
+pub struct InstalledMod {
+    /// The name of the jar file.
+    pub file_name: String,
+    /// Whether the mod will be loaded by the server. Disabled mods lie in the `mods/.disabled` directory.
+    pub enabled: bool,
+    /// The information the mod provides about itself. It is `None` if the jar contains no valid descriptor.
+    pub descriptor: Option<ModDescriptor>,
+    /// Every problem detected with this mod. Only enabled mods are checked.
+    pub warnings: Vec<ModWarning>,
+}
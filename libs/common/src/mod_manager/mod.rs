@@ -0,0 +1,176 @@
+//! This module provides the [`ModManager`], which manages the mods of Fabric, Forge and NeoForge servers. \
+//! Mods get read from the `mods` directory of a server. Disabled mods get moved to the `mods/.disabled` directory, so they will not be loaded.
+
+use std::path::Path;
+
+use tokio::fs;
+
+use self::{
+    installed_mod::InstalledMod,
+    mod_descriptor::{
+        ModDescriptor,
+        ModEnvironment,
+        ModLoader,
+        FABRIC_MOD_JSON,
+        MODS_TOML,
+        NEOFORGE_MODS_TOML,
+    },
+    mod_warning::ModWarning,
+};
+use crate::{
+    jar_directory::JarDirectory,
+    jar_file::{
+        manifest_attribute,
+        read_entry,
+    },
+    mcmanage_error::MCManageError,
+};
+
+pub mod installed_mod;
+pub mod mod_descriptor;
+pub mod mod_warning;
+mod tests;
+
+/// The placeholder Forge replaces with the `Implementation-Version` of the jar's manifest.
+const JAR_VERSION_PLACEHOLDER: &str = "${file.jarVersion}";
+
+/// This struct manages the mods installed on one Minecraft server.
+pub struct ModManager {
+    /// The `mods` directory of the server
+    mods_dir: JarDirectory,
+}
+// actions
+impl ModManager {
+    /// Create a new [`ModManager`] for the server in the given directory.
+    pub fn new(server_path: &Path) -> Self {
+        Self {
+            mods_dir: JarDirectory::new(&server_path.join("mods")),
+        }
+    }
+    /// Enable the given mod by moving it from the `mods/.disabled` directory back to the `mods` directory. \
+    /// See the [`JarDirectory::enable`] method for the possible errors.
+    pub async fn enable(&self, file_name: &str) -> Result<(), MCManageError> {
+        self.mods_dir.enable(file_name).await
+    }
+    /// Disable the given mod by moving it to the `mods/.disabled` directory. \
+    /// See the [`JarDirectory::disable`] method for the possible errors.
+    pub async fn disable(&self, file_name: &str) -> Result<(), MCManageError> {
+        self.mods_dir.disable(file_name).await
+    }
+    /// Save the given jar as a new, enabled mod. An existing mod with the same file name will be replaced, no matter if it is enabled or not.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                                       |
+    /// |-----------------------------------|-------------------------------------------------------------------|
+    /// | `Ok(InstalledMod)`                | The mod got saved. Its warnings are not checked yet.              |
+    /// | [`MCManageError::InvalidRequest`] | The file name is not the name of a jar file or the jar is no mod. |
+    pub async fn upload(&self, file_name: &str, content: &[u8]) -> Result<InstalledMod, MCManageError> {
+        let staged_path = self.mods_dir.stage(file_name, content).await?;
+
+        let installed_mod = Self::read_mod(&staged_path, file_name, true).await;
+        if installed_mod.descriptor.is_none() {
+            fs::remove_file(&staged_path).await?;
+            return Err(MCManageError::InvalidRequest(format!(
+                "`{file_name}` is no mod. It contains neither a `{FABRIC_MOD_JSON}`, a `{MODS_TOML}` nor a `{NEOFORGE_MODS_TOML}` file."
+            )));
+        }
+
+        self.mods_dir.install(&staged_path, file_name).await?;
+        Ok(installed_mod)
+    }
+    /// Delete the given mod, no matter if it is enabled or not. \
+    /// See the [`JarDirectory::delete`] method for the possible errors.
+    pub async fn delete(&self, file_name: &str) -> Result<(), MCManageError> {
+        self.mods_dir.delete(file_name).await
+    }
+}
+// info
+impl ModManager {
+    /// Return every mod installed, enabled or disabled, sorted by their file name. \
+    /// If the mod loader of the server is given, every enabled mod written for another mod loader will get a [`ModWarning::WrongLoader`].
+    pub async fn mods(&self, server_loader: Option<ModLoader>) -> Result<Vec<InstalledMod>, MCManageError> {
+        let mut mods = vec![];
+        for (path, file_name, enabled) in self.mods_dir.jars().await? {
+            mods.push(Self::read_mod(&path, &file_name, enabled).await);
+        }
+
+        // every id an enabled mod can be depended on with
+        let available: Vec<String> = mods
+            .iter()
+            .filter(|installed_mod| installed_mod.enabled)
+            .filter_map(|installed_mod| installed_mod.descriptor.as_ref())
+            .flat_map(|descriptor| {
+                [descriptor.id.clone()]
+                    .into_iter()
+                    .chain(descriptor.provides.clone())
+            })
+            .collect();
+
+        for installed_mod in mods.iter_mut().filter(|installed_mod| installed_mod.enabled) {
+            let Some(descriptor) = &installed_mod.descriptor else {
+                continue;
+            };
+
+            if let ModEnvironment::Client = descriptor.environment {
+                installed_mod.warnings.push(ModWarning::ClientOnly);
+            }
+            if let Some(server_loader) = server_loader {
+                if !server_loader.can_load(descriptor.loader) {
+                    installed_mod.warnings.push(ModWarning::WrongLoader {
+                        loader: descriptor.loader,
+                    });
+                }
+            }
+            for dependency in descriptor.dependencies.iter().filter(|dependency| dependency.required) {
+                if !available.contains(&dependency.id) {
+                    installed_mod.warnings.push(ModWarning::MissingDependency {
+                        dependency: dependency.id.clone(),
+                        version_range: dependency.version_range.clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(mods)
+    }
+}
+// internal
+impl ModManager {
+    /// Read the descriptor of the mod jar at the given path. If it can not be read, the mod will get a [`ModWarning::InvalidDescriptor`].
+    async fn read_mod(path: &Path, file_name: &str, enabled: bool) -> InstalledMod {
+        let descriptor = match read_entry(path, &[FABRIC_MOD_JSON, NEOFORGE_MODS_TOML, MODS_TOML]).await {
+            Ok(Some((entry, content))) => ModDescriptor::parse(&entry, &content),
+            Ok(None) => Err(MCManageError::NotFound),
+            Err(error) => Err(error),
+        };
+
+        match descriptor {
+            Ok(mut descriptor) => {
+                if descriptor.version == JAR_VERSION_PLACEHOLDER {
+                    descriptor.version = manifest_attribute(path, "Implementation-Version")
+                        .await
+                        .unwrap_or_default();
+                }
+                InstalledMod {
+                    file_name: file_name.to_string(),
+                    enabled,
+                    descriptor: Some(descriptor),
+                    warnings: vec![],
+                }
+            }
+            Err(error) => InstalledMod {
+                file_name: file_name.to_string(),
+                enabled,
+                descriptor: None,
+                warnings: vec![ModWarning::InvalidDescriptor {
+                    error: if let MCManageError::NotFound = error {
+                        format!("Neither a `{FABRIC_MOD_JSON}`, a `{MODS_TOML}` nor a `{NEOFORGE_MODS_TOML}` file could be found.")
+                    } else {
+                        error.to_string()
+                    },
+                }],
+            },
+        }
+    }
+}
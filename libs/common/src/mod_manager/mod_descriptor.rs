@@ -0,0 +1,264 @@
+//! This module provides the [`ModDescriptor`] struct, which represents the metadata of a Fabric, Forge or NeoForge mod.
+
+use proc_macros::add_convert;
+use serde_json::Value as JsonValue;
+use toml::Value as TomlValue;
+
+use crate::mcmanage_error::MCManageError;
+
+/// The name of the descriptor file of Fabric mods.
+pub const FABRIC_MOD_JSON: &str = "fabric.mod.json";
+/// The name of the descriptor file of Forge mods. NeoForge used this file too, before switching to the [`NEOFORGE_MODS_TOML`].
+pub const MODS_TOML: &str = "META-INF/mods.toml";
+/// The name of the descriptor file of NeoForge mods.
+pub const NEOFORGE_MODS_TOML: &str = "META-INF/neoforge.mods.toml";
+
+/// Dependencies every mod can declare, which are not mods themselves.
+const PLATFORM_DEPENDENCIES: [&str; 7] = [
+    "minecraft",
+    "java",
+    "fabricloader",
+    "quilt_loader",
+    "forge",
+    "neoforge",
+    "javafml",
+];
+
+/// This enum represents the mod loaders supported by the [`ModManager`](super::ModManager).
+#[add_convert]
+#[derive(Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ModLoader {
+    /// Fabric mods, which also run on Quilt
+    Fabric,
+    /// Forge mods
+    Forge,
+    /// NeoForge mods
+    NeoForge,
+}
+impl ModLoader {
+    /// Return the mod loader used by the given server type, if it uses one.
+    pub fn from_server_type(server_type: &str) -> Option<Self> {
+        match server_type.to_lowercase().as_str() {
+            "fabric" | "quilt" => Some(Self::Fabric),
+            "forge" => Some(Self::Forge),
+            "neoforge" => Some(Self::NeoForge),
+            _ => None,
+        }
+    }
+    /// Return whether a server running this mod loader can load mods written for the given one. \
+    /// NeoForge is a fork of Forge, so both are treated as compatible.
+    pub fn can_load(&self, loader: ModLoader) -> bool {
+        match self {
+            Self::Fabric => loader == Self::Fabric,
+            Self::Forge | Self::NeoForge => loader != Self::Fabric,
+        }
+    }
+}
+
+/// This enum represents the side a mod has to be installed on.
+#[add_convert]
+#[derive(Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ModEnvironment {
+    /// The mod works on both the client and the server.
+    Both,
+    /// The mod only works on the client. It will crash a dedicated server.
+    Client,
+    /// The mod only works on the server.
+    Server,
+}
+
+/// This struct represents one dependency of a mod.
+#[add_convert]
+pub struct ModDependency {
+    /// The id of the mod depended on
+    pub id: String,
+    /// The versions of the mod depended on, in the format used by the mod loader. (For example: `>=0.15.0` or `[47,)`)
+    pub version_range: String,
+    /// Whether the mod can not be loaded without this dependency
+    pub required: bool,
+}
+
+/// This struct represents the information a mod provides about itself in its `fabric.mod.json`, `META-INF/mods.toml` or `META-INF/neoforge.mods.toml`
+/// file.
+#[add_convert]
+pub struct ModDescriptor {
+    /// The mod loader this mod got written for
+    pub loader: ModLoader,
+    /// The id of the mod
+    pub id: String,
+    /// The display name of the mod
+    pub name: String,
+    /// The version of the mod
+    pub version: String,
+    /// Everyone who developed the mod
+    pub authors: Vec<String>,
+    /// Every mod this mod depends on. Dependencies on the game or the mod loader itself are not included.
+    pub dependencies: Vec<ModDependency>,
+    /// Other ids this mod can be depended on with. This includes the ids of other mods in the same jar.
+    pub provides: Vec<String>,
+    /// The side this mod has to be installed on
+    pub environment: ModEnvironment,
+}
+impl ModDescriptor {
+    /// Parse the descriptor file with the given name.
+    ///
+    /// # Returns
+    ///
+    /// | Return                         | Description                                          |
+    /// |--------------------------------|------------------------------------------------------|
+    /// | `Ok(ModDescriptor)`            | The descriptor got parsed.                           |
+    /// | [`MCManageError::InvalidFile`] | The descriptor does not contain a mod id.            |
+    /// | [`MCManageError::NotFound`]    | The file name is not the name of a known descriptor. |
+    /// | `Err(...)`                     | The descriptor is no valid json or toml.             |
+    pub fn parse(file_name: &str, content: &[u8]) -> Result<Self, MCManageError> {
+        match file_name {
+            FABRIC_MOD_JSON => Self::parse_fabric(content),
+            MODS_TOML => Self::parse_forge(content, ModLoader::Forge),
+            NEOFORGE_MODS_TOML => Self::parse_forge(content, ModLoader::NeoForge),
+            _ => Err(MCManageError::NotFound),
+        }
+    }
+    /// Parse a `fabric.mod.json` file.
+    fn parse_fabric(content: &[u8]) -> Result<Self, MCManageError> {
+        /// Read the dependencies of one of the dependency blocks. ( `depends`, `recommends`, ... )
+        fn dependencies(descriptor: &JsonValue, block: &str, required: bool) -> Vec<ModDependency> {
+            let Some(JsonValue::Object(block)) = descriptor.get(block) else {
+                return vec![];
+            };
+            block
+                .iter()
+                .map(|(id, version_range)| ModDependency {
+                    id: id.clone(),
+                    version_range: match version_range {
+                        JsonValue::String(version_range) => version_range.clone(),
+                        JsonValue::Array(version_ranges) => version_ranges
+                            .iter()
+                            .filter_map(JsonValue::as_str)
+                            .collect::<Vec<&str>>()
+                            .join(" || "),
+                        _ => "*".to_string(),
+                    },
+                    required,
+                })
+                .collect()
+        }
+
+        let descriptor: JsonValue = serde_json::from_slice(content)?;
+        let string = |key: &str| descriptor.get(key).and_then(JsonValue::as_str).map(str::to_string);
+
+        let id = string("id").ok_or(MCManageError::InvalidFile)?;
+        let authors = descriptor
+            .get("authors")
+            .and_then(JsonValue::as_array)
+            .map(|authors| {
+                authors
+                    .iter()
+                    .filter_map(|author| {
+                        author
+                            .as_str()
+                            .or_else(|| author.get("name").and_then(JsonValue::as_str))
+                            .map(str::to_string)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut all_dependencies = dependencies(&descriptor, "depends", true);
+        all_dependencies.append(&mut dependencies(&descriptor, "recommends", false));
+        all_dependencies.append(&mut dependencies(&descriptor, "suggests", false));
+
+        Ok(Self {
+            loader: ModLoader::Fabric,
+            name: string("name").unwrap_or(id.clone()),
+            version: string("version").unwrap_or_default(),
+            authors,
+            dependencies: without_platform(all_dependencies),
+            provides: descriptor
+                .get("provides")
+                .and_then(JsonValue::as_array)
+                .map(|ids| ids.iter().filter_map(JsonValue::as_str).map(str::to_string).collect())
+                .unwrap_or_default(),
+            environment: match string("environment").as_deref() {
+                Some("client") => ModEnvironment::Client,
+                Some("server") => ModEnvironment::Server,
+                _ => ModEnvironment::Both,
+            },
+            id,
+        })
+    }
+    /// Parse a `META-INF/mods.toml` or `META-INF/neoforge.mods.toml` file. \
+    /// If the jar contains more than one mod, the first one will be described and the ids of the others will be added to the provided ids.
+    fn parse_forge(content: &[u8], loader: ModLoader) -> Result<Self, MCManageError> {
+        let descriptor: TomlValue = toml::from_str(std::str::from_utf8(content)?)?;
+        let string = |table: &TomlValue, key: &str| table.get(key).and_then(TomlValue::as_str).map(str::to_string);
+
+        let mods = descriptor
+            .get("mods")
+            .and_then(TomlValue::as_array)
+            .ok_or(MCManageError::InvalidFile)?;
+        let first_mod = mods.first().ok_or(MCManageError::InvalidFile)?;
+        let id = string(first_mod, "modId").ok_or(MCManageError::InvalidFile)?;
+
+        let mut dependencies = vec![];
+        if let Some(TomlValue::Table(dependency_tables)) = descriptor.get("dependencies") {
+            for dependency in dependency_tables.values().filter_map(TomlValue::as_array).flatten() {
+                let Some(dependency_id) = string(dependency, "modId") else {
+                    continue;
+                };
+                // Forge uses `mandatory`, while NeoForge uses `type`
+                let mut required = match string(dependency, "type").map(|dependency_type| dependency_type.to_lowercase()) {
+                    // these are no dependencies, but mods which should not be installed together
+                    Some(dependency_type) if dependency_type == "incompatible" || dependency_type == "discouraged" => continue,
+                    Some(dependency_type) => dependency_type == "required",
+                    None => dependency
+                        .get("mandatory")
+                        .and_then(TomlValue::as_bool)
+                        .unwrap_or(true),
+                };
+                if string(dependency, "side").as_deref() == Some("CLIENT") {
+                    // the dedicated server does not need client side dependencies
+                    required = false;
+                }
+
+                dependencies.push(ModDependency {
+                    id: dependency_id,
+                    version_range: string(dependency, "versionRange").unwrap_or("*".to_string()),
+                    required,
+                });
+            }
+        }
+
+        Ok(Self {
+            loader,
+            name: string(first_mod, "displayName").unwrap_or(id.clone()),
+            version: string(first_mod, "version").unwrap_or_default(),
+            authors: string(first_mod, "authors").into_iter().collect(),
+            dependencies: without_platform(dependencies),
+            provides: mods
+                .iter()
+                .skip(1)
+                .filter_map(|other_mod| string(other_mod, "modId"))
+                .collect(),
+            environment: if descriptor
+                .get("clientSideOnly")
+                .and_then(TomlValue::as_bool)
+                .unwrap_or(false)
+            {
+                ModEnvironment::Client
+            } else {
+                ModEnvironment::Both
+            },
+            id,
+        })
+    }
+}
+
+/// Remove every dependency on the game or a mod loader from the given list.
+fn without_platform(dependencies: Vec<ModDependency>) -> Vec<ModDependency> {
+    dependencies
+        .into_iter()
+        .filter(|dependency| !PLATFORM_DEPENDENCIES.contains(&dependency.id.as_str()))
+        .collect()
+}
@@ -0,0 +1,31 @@
+//! This module provides the [`ModWarning`] enum.
+
+use proc_macros::add_convert;
+
+use super::mod_descriptor::ModLoader;
+use crate::mcmanage_error::MCManageError;
+
+/// This enum represents a problem detected with an installed mod.
+#[add_convert]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ModWarning {
+    /// The jar does not contain a valid `fabric.mod.json`, `META-INF/mods.toml` or `META-INF/neoforge.mods.toml` file.
+    InvalidDescriptor {
+        /// Why the descriptor could not be read
+        error: String,
+    },
+    /// A mod required by this mod is not installed or disabled.
+    MissingDependency {
+        /// The id of the missing mod
+        dependency: String,
+        /// The versions of the missing mod which would be accepted
+        version_range: String,
+    },
+    /// The mod only works on the client. It will crash the dedicated server.
+    ClientOnly,
+    /// The mod got written for a mod loader the server does not run.
+    WrongLoader {
+        /// The mod loader the mod got written for
+        loader: ModLoader,
+    },
+}
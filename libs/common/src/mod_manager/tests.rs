@@ -0,0 +1,224 @@
+#![cfg(test)]
+
+use super::*;
+use crate::{
+    generated_files::paths::SERVERS_DIR,
+    test_functions::*,
+};
+
+/// A `fabric.mod.json` of a mod requiring the Fabric API.
+const SODIUM: &str = r#"{
+    "schemaVersion": 1,
+    "id": "sodium",
+    "version": "0.5.8",
+    "name": "Sodium",
+    "authors": ["JellySquid", { "name": "IMS" }],
+    "environment": "client",
+    "depends": { "minecraft": ["1.20.3", "1.20.4"], "fabricloader": ">=0.12.0", "fabric-api": "*" }
+}"#;
+/// A `fabric.mod.json` of the Fabric API.
+const FABRIC_API: &str = r#"{
+    "schemaVersion": 1,
+    "id": "fabric-api",
+    "version": "0.96.4+1.20.4",
+    "name": "Fabric API",
+    "provides": ["fabric"],
+    "depends": { "fabricloader": ">=0.15.6" }
+}"#;
+/// A `META-INF/mods.toml` of a Forge mod.
+const JEI: &str = r#"
+modLoader = "javafml"
+loaderVersion = "[47,)"
+
+[[mods]]
+modId = "jei"
+version = "${file.jarVersion}"
+displayName = "Just Enough Items"
+authors = "mezz"
+
+[[dependencies.jei]]
+modId = "forge"
+mandatory = true
+versionRange = "[47,)"
+side = "BOTH"
+
+[[dependencies.jei]]
+modId = "minecraft"
+mandatory = true
+versionRange = "[1.20.1,1.20.2)"
+side = "BOTH"
+"#;
+/// A `META-INF/neoforge.mods.toml` of a NeoForge mod.
+const CREATE: &str = r#"
+modLoader = "javafml"
+loaderVersion = "[1,)"
+
+[[mods]]
+modId = "create"
+version = "0.5.1"
+displayName = "Create"
+
+[[dependencies.create]]
+modId = "flywheel"
+type = "required"
+versionRange = "[0.6.10,)"
+side = "BOTH"
+
+[[dependencies.create]]
+modId = "jei"
+type = "optional"
+versionRange = "*"
+side = "CLIENT"
+"#;
+
+/// Create a [`ModManager`] for the server `myServer` with two Fabric mods installed.
+async fn setup() -> ModManager {
+    start_test();
+    let mod_manager = ModManager::new(&SERVERS_DIR.join("myServer"));
+
+    mod_manager
+        .upload("sodium.jar", &jar(&[(FABRIC_MOD_JSON, SODIUM)]))
+        .await
+        .unwrap();
+    mod_manager
+        .upload("fabric-api.jar", &jar(&[(FABRIC_MOD_JSON, FABRIC_API)]))
+        .await
+        .unwrap();
+
+    mod_manager
+}
+
+#[tokio::test]
+async fn parse_fabric_mod_json() {
+    let descriptor = ModDescriptor::parse(FABRIC_MOD_JSON, SODIUM.as_bytes()).unwrap();
+
+    assert_eq!(
+        descriptor,
+        ModDescriptor {
+            loader: ModLoader::Fabric,
+            id: "sodium".to_string(),
+            name: "Sodium".to_string(),
+            version: "0.5.8".to_string(),
+            authors: vec!["JellySquid".to_string(), "IMS".to_string()],
+            dependencies: vec![mod_descriptor::ModDependency {
+                id: "fabric-api".to_string(),
+                version_range: "*".to_string(),
+                required: true,
+            }],
+            provides: vec![],
+            environment: ModEnvironment::Client,
+        }
+    );
+}
+#[tokio::test]
+async fn parse_mods_toml() {
+    let descriptor = ModDescriptor::parse(MODS_TOML, JEI.as_bytes()).unwrap();
+
+    assert_eq!(descriptor.loader, ModLoader::Forge);
+    assert_eq!(descriptor.id, "jei");
+    assert_eq!(descriptor.name, "Just Enough Items");
+    assert_eq!(descriptor.authors, vec!["mezz"]);
+    assert!(descriptor.dependencies.is_empty());
+    assert_eq!(descriptor.environment, ModEnvironment::Both);
+}
+#[tokio::test]
+async fn parse_neoforge_mods_toml() {
+    let descriptor = ModDescriptor::parse(NEOFORGE_MODS_TOML, CREATE.as_bytes()).unwrap();
+
+    assert_eq!(descriptor.loader, ModLoader::NeoForge);
+    assert_eq!(
+        descriptor
+            .dependencies
+            .iter()
+            .map(|dependency| (dependency.id.as_str(), dependency.required))
+            .collect::<Vec<(&str, bool)>>(),
+        vec![("flywheel", true), ("jei", false)]
+    );
+}
+#[tokio::test]
+async fn client_only_mod() {
+    let mod_manager = setup().await;
+
+    let mods = mod_manager.mods(Some(ModLoader::Fabric)).await.unwrap();
+
+    assert_eq!(mods.len(), 2);
+    assert!(mods[0].warnings.is_empty());
+    assert_eq!(mods[1].file_name, "sodium.jar");
+    assert_eq!(mods[1].warnings, vec![ModWarning::ClientOnly]);
+
+    cleanup();
+}
+#[tokio::test]
+async fn missing_dependency() {
+    let mod_manager = setup().await;
+
+    mod_manager.disable("fabric-api.jar").await.unwrap();
+    let mods = mod_manager.mods(None).await.unwrap();
+
+    assert!(!mods[0].enabled);
+    assert_eq!(
+        mods[1].warnings,
+        vec![
+            ModWarning::ClientOnly,
+            ModWarning::MissingDependency {
+                dependency: "fabric-api".to_string(),
+                version_range: "*".to_string()
+            }
+        ]
+    );
+
+    mod_manager.enable("fabric-api.jar").await.unwrap();
+    assert!(mod_manager.mods(None).await.unwrap()[0].enabled);
+
+    cleanup();
+}
+#[tokio::test]
+async fn wrong_loader_and_jar_version() {
+    let mod_manager = setup().await;
+
+    mod_manager
+        .upload(
+            "jei.jar",
+            &jar(&[
+                (MODS_TOML, JEI),
+                ("META-INF/MANIFEST.MF", "Manifest-Version: 1.0\r\nImplementation-Version: 15.3.0.4\r\n"),
+            ]),
+        )
+        .await
+        .unwrap();
+    let jei = mod_manager
+        .mods(Some(ModLoader::Fabric))
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|installed_mod| installed_mod.file_name == "jei.jar")
+        .unwrap();
+
+    assert_eq!(jei.descriptor.unwrap().version, "15.3.0.4");
+    assert_eq!(
+        jei.warnings,
+        vec![ModWarning::WrongLoader {
+            loader: ModLoader::Forge
+        }]
+    );
+
+    cleanup();
+}
+#[tokio::test]
+async fn upload_and_delete() {
+    let mod_manager = setup().await;
+
+    assert!(matches!(
+        mod_manager.upload("plugin.jar", &jar(&[("plugin.yml", "name: Plugin")])).await,
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    mod_manager.delete("sodium.jar").await.unwrap();
+
+    assert_eq!(mod_manager.mods(None).await.unwrap().len(), 1);
+    assert!(matches!(
+        mod_manager.delete("sodium.jar").await,
+        Err(MCManageError::NotFound)
+    ));
+
+    cleanup();
+}
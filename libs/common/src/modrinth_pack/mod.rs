@@ -0,0 +1,291 @@
+//! This module provides the [`ModrinthPack`] struct, which imports Modrinth modpacks ( `.mrpack` archives ) as new Minecraft servers.
+
+use std::{
+    fs::{
+        create_dir_all,
+        File,
+    },
+    io::{
+        self,
+        ErrorKind,
+    },
+    path::{
+        Component,
+        Path,
+        PathBuf,
+    },
+    time::Duration,
+};
+
+use futures_util::future::try_join_all;
+use goolog::*;
+use tokio::{
+    fs,
+    task::spawn_blocking,
+};
+use zip::ZipArchive;
+
+use self::modrinth_index::{
+    ModrinthFile,
+    ModrinthIndex,
+    MODRINTH_INDEX_JSON,
+};
+use crate::{
+    download_manager::{
+        checksum::Checksum,
+        DownloadManager,
+        DownloadRequest,
+    },
+    generated_files::paths::SERVERS_DIR,
+    jar_file::read_entry,
+    mcmanage_error::MCManageError,
+    server_manager::{
+        server::{
+            server_kind::ServerKind,
+            update_policy::UpdatePolicy,
+        },
+        server_item::ServerItem,
+        server_list::{
+            server_data::ServerData,
+            ServerList,
+        },
+    },
+};
+
+pub mod modrinth_index;
+mod tests;
+
+const GOOLOG_CALLER: &str = "ModrinthPack";
+/// The directories of a `.mrpack` archive whose content gets copied to the server directory. \
+/// The `server-overrides` get applied last, so they replace files of the `overrides`.
+const OVERRIDE_DIRS: [&str; 2] = ["overrides", "server-overrides"];
+
+/// This struct represents a Modrinth modpack saved as a `.mrpack` archive.
+pub struct ModrinthPack {
+    /// The path to the `.mrpack` archive
+    path: PathBuf,
+    /// The index of the modpack
+    index: ModrinthIndex,
+}
+// actions
+impl ModrinthPack {
+    /// Open the `.mrpack` archive at the given path and read its `modrinth.index.json` file.
+    ///
+    /// # Returns
+    ///
+    /// | Return                         | Description                                                              |
+    /// |--------------------------------|--------------------------------------------------------------------------|
+    /// | `Ok(ModrinthPack)`             | The modpack got read.                                                    |
+    /// | [`MCManageError::InvalidFile`] | The archive contains no valid index or the index uses an unknown format. |
+    /// | `Err(...)`                     | The archive could not be read.                                           |
+    pub async fn open(path: &Path) -> Result<Self, MCManageError> {
+        let (_, content) = read_entry(path, &[MODRINTH_INDEX_JSON])
+            .await?
+            .ok_or(MCManageError::InvalidFile)?;
+        let index = ModrinthIndex::try_from(content).map_err(|_| MCManageError::InvalidFile)?;
+
+        if index.format_version != 1 || index.game != "minecraft" {
+            return Err(MCManageError::InvalidFile);
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            index,
+        })
+    }
+    /// Import the `.mrpack` archive at the given path as a new Minecraft server with the given name. \
+    /// The modpack gets installed to `servers/{server_name}.import` first and only gets moved to `servers/{server_name}` once it got installed
+    /// completely. Afterwards the server gets added to the [`ServerList`]. If anything goes wrong, every directory created by the import will be
+    /// removed again, while existing directories are never touched.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                                                               |
+    /// |-----------------------------------|-------------------------------------------------------------------------------------------|
+    /// | `Ok(ServerData)`                  | The server got imported. The returned data got added to the server list.                  |
+    /// | [`MCManageError::InvalidRequest`] | The name is invalid or taken, or the modpack uses an unsupported mod loader.              |
+    /// | `Err(...)`                        | See the [`open`](Self::open), [`install`](Self::install) and [`ServerList::add`] methods. |
+    pub async fn import(mrpack_path: &Path, server_name: &str) -> Result<ServerData, MCManageError> {
+        ServerItem::check_name(server_name)?;
+        let server_path = SERVERS_DIR.join(server_name);
+        // server names can not contain a `.`, so no server can use this directory
+        let import_path = SERVERS_DIR.join(format!("{server_name}.import"));
+
+        let modpack = Self::open(mrpack_path).await?;
+        let server_item = modpack.server_item()?;
+        if ServerList::get(server_name).await.is_ok() {
+            return Err(MCManageError::InvalidRequest(format!(
+                "A server named `{server_name}` already exists."
+            )));
+        }
+        info!("Importing the modpack {} {} as the server {server_name}...", modpack.index.name, modpack.index.version_id);
+
+        // the remains of an interrupted import
+        if fs::remove_dir_all(&import_path).await.is_err() {}
+        let result = async {
+            modpack.install(&import_path).await?;
+
+            // the name could have been taken while the modpack got installed
+            ServerItem::check_name(server_name)?;
+            fs::rename(&import_path, &server_path).await?;
+            let added = ServerList::add(ServerData::from_server_item(server_name, server_item)).await;
+            if added.is_err() {
+                // the server directory got created by the rename above
+                if fs::remove_dir_all(&server_path).await.is_err() {}
+            }
+            added
+        }
+        .await;
+        match result {
+            Ok(server_data) => {
+                info!("Imported the modpack {} as the server {server_name}.", modpack.index.name);
+                Ok(server_data)
+            }
+            Err(error) => {
+                if fs::remove_dir_all(&import_path).await.is_err() {}
+                Err(error)
+            }
+        }
+    }
+    /// Install the content of this modpack to the given server directory. \
+    /// Every server-side file gets downloaded using the [`DownloadManager`] and verified against its sha1 hash. Afterwards the `overrides` and
+    /// `server-overrides` directories get copied to the server directory.
+    ///
+    /// # Returns
+    ///
+    /// | Return                         | Description                                                                           |
+    /// |--------------------------------|---------------------------------------------------------------------------------------|
+    /// | `Ok(())`                       | The modpack got installed.                                                            |
+    /// | [`MCManageError::InvalidFile`] | A file has no sha1 hash or its path points outside of the server directory.           |
+    /// | `Err(...)`                     | A file could not be downloaded from any of its urls or the archive could not be read. |
+    pub async fn install(&self, server_path: &Path) -> Result<(), MCManageError> {
+        fs::create_dir_all(server_path).await?;
+
+        let files: Vec<&ModrinthFile> = self.index.files.iter().filter(|file| file.server_side()).collect();
+        info!(
+            "Downloading {} files. {} client-only files will be skipped.",
+            files.len(),
+            self.index.files.len() - files.len()
+        );
+        try_join_all(files.into_iter().map(|file| Self::download_file(file, server_path))).await?;
+
+        let mrpack_path = self.path.clone();
+        let server_path = server_path.to_path_buf();
+        spawn_blocking(move || Self::extract_overrides(&mrpack_path, &server_path))
+            .await
+            .map_err(|error| MCManageError::IOError(io::Error::new(ErrorKind::Other, error)))?
+    }
+}
+// info
+impl ModrinthPack {
+    /// Return the index of this modpack.
+    pub fn index(&self) -> &ModrinthIndex {
+        &self.index
+    }
+    /// Return the Minecraft version this modpack got made for.
+    pub fn minecraft_version(&self) -> Result<&str, MCManageError> {
+        self.index
+            .dependencies
+            .get("minecraft")
+            .map(String::as_str)
+            .ok_or(MCManageError::InvalidFile)
+    }
+    /// Return the server type and the version of the mod loader this modpack got made for. \
+    /// Only Fabric and Quilt are supported.
+    pub fn loader(&self) -> Result<(&'static str, &str), MCManageError> {
+        for (dependency, server_type) in [("fabric-loader", "fabric"), ("quilt-loader", "quilt")] {
+            if let Some(loader_version) = self.index.dependencies.get(dependency) {
+                return Ok((server_type, loader_version));
+            }
+        }
+        Err(MCManageError::InvalidRequest(format!(
+            "The modpack {} does not use a supported mod loader. Only Fabric and Quilt modpacks can be imported.",
+            self.index.name
+        )))
+    }
+    /// Create the [`ServerItem`] for a server running this modpack. The version of the mod loader will be used as the server's build.
+    pub fn server_item(&self) -> Result<ServerItem, MCManageError> {
+        let (server_type, loader_version) = self.loader()?;
+
+        Ok(ServerItem {
+            args: format!("-jar {server_type}-server-launch.jar nogui"),
+            download_from: "".to_string(),
+            version: Some(self.minecraft_version()?.to_string()),
+            build: Some(loader_version.to_string()),
+            update_policy: UpdatePolicy::Disabled,
+            server_type: server_type.to_string(),
+            restart_time: Duration::ZERO,
+            eula_accepted_by: None,
+            network: None,
+            network_role: None,
+            kind: ServerKind::Java,
+            depends_on: vec![],
+            start_delay: Duration::ZERO,
+        })
+    }
+}
+// internal
+impl ModrinthPack {
+    /// Download the given file to the given server directory. Every download url will be tried until one succeeds.
+    async fn download_file(file: &ModrinthFile, server_path: &Path) -> Result<(), MCManageError> {
+        let destination = Self::destination(server_path, Path::new(&file.path))?;
+        let Some(sha1) = file.hashes.get("sha1") else {
+            error!("The file {} has no sha1 hash and can therefore not be verified.", file.path);
+            return Err(MCManageError::InvalidFile);
+        };
+
+        let mut last_error = MCManageError::NotFound;
+        for url in &file.downloads {
+            let request = DownloadRequest::new(url).with_checksum(Checksum::Sha1(sha1.clone()));
+            match DownloadManager::download(request, &destination).finish().await {
+                Ok(_) => return Ok(()),
+                Err(error) => {
+                    warn!("Failed to download the file {} from {url}. Error: {error}", file.path);
+                    last_error = error;
+                }
+            }
+        }
+        error!("The file {} could not be downloaded from any of its urls.", file.path);
+        Err(last_error)
+    }
+    /// Copy the content of every directory in the [`OVERRIDE_DIRS`] to the given server directory.
+    fn extract_overrides(mrpack_path: &Path, server_path: &Path) -> Result<(), MCManageError> {
+        let mut archive = ZipArchive::new(File::open(mrpack_path)?)?;
+
+        for override_dir in OVERRIDE_DIRS {
+            for i in 0..archive.len() {
+                let mut entry = archive.by_index(i)?;
+                let Some(relative_path) = entry
+                    .enclosed_name()
+                    .and_then(|name| name.strip_prefix(override_dir).ok())
+                    .map(Path::to_path_buf)
+                else {
+                    continue;
+                };
+                let destination = Self::destination(server_path, &relative_path)?;
+
+                if entry.is_dir() {
+                    create_dir_all(destination)?;
+                    continue;
+                }
+                if let Some(parent) = destination.parent() {
+                    create_dir_all(parent)?;
+                }
+                io::copy(&mut entry, &mut File::create(destination)?)?;
+            }
+        }
+        Ok(())
+    }
+    /// Join the given relative path to the server directory. Paths leaving the server directory will be rejected with an [`MCManageError::InvalidFile`].
+    fn destination(server_path: &Path, relative_path: &Path) -> Result<PathBuf, MCManageError> {
+        if relative_path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+        {
+            Ok(server_path.join(relative_path))
+        } else {
+            error!("The path {} points outside of the server directory.", relative_path.display());
+            Err(MCManageError::InvalidFile)
+        }
+    }
+}
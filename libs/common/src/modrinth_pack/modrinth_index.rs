@@ -0,0 +1,62 @@
+//! This module provides the [`ModrinthIndex`] struct, which represents the `modrinth.index.json` file of a Modrinth modpack.
+
+use std::collections::HashMap;
+
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// The name of the index file inside of a `.mrpack` archive.
+pub const MODRINTH_INDEX_JSON: &str = "modrinth.index.json";
+
+/// This struct represents the `modrinth.index.json` file of a Modrinth modpack.
+#[add_convert]
+#[serde(rename_all = "camelCase")]
+pub struct ModrinthIndex {
+    /// The version of the format. Only the version `1` is supported.
+    pub format_version: u64,
+    /// The game of the modpack. Only `minecraft` is supported.
+    pub game: String,
+    /// The version of the modpack
+    pub version_id: String,
+    /// The name of the modpack
+    pub name: String,
+    /// Every file which has to be downloaded
+    pub files: Vec<ModrinthFile>,
+    /// The Minecraft version and the mod loader of the modpack. (For example: `minecraft = "1.20.4"` and `fabric-loader = "0.15.6"`)
+    pub dependencies: HashMap<String, String>,
+}
+
+/// This struct represents one file listed in the [`ModrinthIndex`].
+#[add_convert]
+#[serde(rename_all = "camelCase")]
+pub struct ModrinthFile {
+    /// The path the file should be saved to, relative to the server directory
+    pub path: String,
+    /// The hashes of the file. Modrinth always provides a `sha1` and `sha512` hash.
+    pub hashes: HashMap<String, String>,
+    /// The sides the file is needed on. If not set, the file is needed on both sides.
+    pub env: Option<ModrinthEnvironment>,
+    /// The urls the file can be downloaded from, in order of preference
+    pub downloads: Vec<String>,
+    /// The size of the file in bytes
+    #[serde(default)]
+    pub file_size: u64,
+}
+impl ModrinthFile {
+    /// Return whether this file is needed on a server.
+    pub fn server_side(&self) -> bool {
+        self.env
+            .as_ref()
+            .map_or(true, |env| env.server != "unsupported")
+    }
+}
+
+/// This struct describes on which sides a [`ModrinthFile`] is needed.
+#[add_convert]
+pub struct ModrinthEnvironment {
+    /// Either `required`, `optional` or `unsupported`
+    pub client: String,
+    /// Either `required`, `optional` or `unsupported`
+    pub server: String,
+}
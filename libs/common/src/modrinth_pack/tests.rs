@@ -0,0 +1,212 @@
+#![cfg(test)]
+
+use sha1::{
+    Digest,
+    Sha1,
+};
+
+use super::*;
+use crate::{
+    generated_files::paths::ROOT_DIR,
+    test_functions::*,
+    version_provider::{
+        get_provider,
+        quilt::QUILT_LAUNCHER_JAR,
+    },
+};
+
+/// The content of the mod served by the mock http server.
+const MOD_CONTENT: &str = "This is not really a mod, but it will do for these tests.";
+
+/// Create a `modrinth.index.json` file listing a server-side and a client-only mod. \
+/// The server-side mod will be verified using the given sha1 hash.
+fn index(base_url: &str, loader: &str, sha1: &str) -> String {
+    format!(
+        r#"{{
+            "formatVersion": 1,
+            "game": "minecraft",
+            "versionId": "1.0.0",
+            "name": "My Modpack",
+            "files": [
+                {{
+                    "path": "mods/lithium.jar",
+                    "hashes": {{ "sha1": "{sha1}", "sha512": "" }},
+                    "env": {{ "client": "optional", "server": "required" }},
+                    "downloads": ["{base_url}/missing.jar", "{base_url}/lithium.jar"],
+                    "fileSize": {}
+                }},
+                {{
+                    "path": "mods/sodium.jar",
+                    "hashes": {{ "sha1": "", "sha512": "" }},
+                    "env": {{ "client": "required", "server": "unsupported" }},
+                    "downloads": ["{base_url}/sodium.jar"],
+                    "fileSize": 0
+                }}
+            ],
+            "dependencies": {{ "minecraft": "1.20.4", "{loader}": "0.15.6" }}
+        }}"#,
+        MOD_CONTENT.len()
+    )
+}
+/// Save a `.mrpack` archive with the given index and some overrides and return its path.
+async fn mrpack(index: &str) -> PathBuf {
+    let path = ROOT_DIR.join("my_modpack.mrpack");
+    fs::write(
+        &path,
+        jar(&[
+            (MODRINTH_INDEX_JSON, index),
+            ("overrides/config/lithium.properties", "mixin.ai=true"),
+            ("overrides/server.properties", "motd=client"),
+            ("server-overrides/server.properties", "motd=server"),
+        ]),
+    )
+    .await
+    .unwrap();
+    path
+}
+/// Serve the server-side mod and return the base url of the mock http server.
+async fn serve() -> String {
+    mock_http_server(vec![("/lithium.jar".to_string(), MOD_CONTENT.as_bytes().to_vec())]).await
+}
+
+#[tokio::test]
+async fn import() {
+    start_test();
+    init_server_list().await;
+    let base_url = serve().await;
+    let sha1 = hex::encode(Sha1::digest(MOD_CONTENT));
+    let path = mrpack(&index(&base_url, "fabric-loader", &sha1)).await;
+
+    let server_data = ModrinthPack::import(&path, "myModpack").await.unwrap();
+
+    assert!(server_data.id.is_some());
+    assert_eq!(server_data.server_type.as_deref(), Some("fabric"));
+    assert_eq!(server_data.args.as_deref(), Some("-jar fabric-server-launch.jar nogui"));
+    assert_eq!(server_data.version.as_deref(), Some("1.20.4"));
+    assert_eq!(server_data.build.as_deref(), Some("0.15.6"));
+
+    let server_path = SERVERS_DIR.join("myModpack");
+    assert_eq!(
+        fs::read_to_string(server_path.join("mods/lithium.jar")).await.unwrap(),
+        MOD_CONTENT
+    );
+    assert!(!server_path.join("mods/sodium.jar").exists());
+    assert_eq!(
+        fs::read_to_string(server_path.join("config/lithium.properties")).await.unwrap(),
+        "mixin.ai=true"
+    );
+    assert_eq!(
+        fs::read_to_string(server_path.join("server.properties")).await.unwrap(),
+        "motd=server"
+    );
+    assert!(ServerList::get("myModpack").await.is_ok());
+
+    assert!(matches!(
+        ModrinthPack::import(&path, "myModpack").await,
+        Err(MCManageError::InvalidRequest(_))
+    ));
+
+    cleanup();
+}
+#[tokio::test]
+async fn hash_mismatch() {
+    start_test();
+    init_server_list().await;
+    let base_url = serve().await;
+    let path = mrpack(&index(&base_url, "fabric-loader", "0000000000000000000000000000000000000000")).await;
+
+    assert!(ModrinthPack::import(&path, "myBrokenModpack").await.is_err());
+    assert!(!SERVERS_DIR.join("myBrokenModpack").exists());
+    assert!(!SERVERS_DIR.join("myBrokenModpack.import").exists());
+    assert!(matches!(
+        ServerList::get("myBrokenModpack").await,
+        Err(MCManageError::NotFound)
+    ));
+
+    cleanup();
+}
+#[tokio::test]
+async fn import_over_existing_server() {
+    start_test();
+    init_server_list().await;
+    let base_url = serve().await;
+    let sha1 = hex::encode(Sha1::digest(MOD_CONTENT));
+    let path = mrpack(&index(&base_url, "fabric-loader", &sha1)).await;
+
+    // a server directory not known to the server list
+    let server_path = SERVERS_DIR.join("myExistingServer");
+    fs::create_dir_all(server_path.join("world")).await.unwrap();
+    fs::write(server_path.join("world/level.dat"), "myWorld").await.unwrap();
+    fs::write(server_path.join("server.properties"), "motd=existing").await.unwrap();
+
+    assert!(matches!(
+        ModrinthPack::import(&path, "myExistingServer").await,
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    assert_eq!(fs::read_to_string(server_path.join("world/level.dat")).await.unwrap(), "myWorld");
+    assert_eq!(
+        fs::read_to_string(server_path.join("server.properties")).await.unwrap(),
+        "motd=existing"
+    );
+    assert!(!server_path.join("mods").exists());
+
+    // a server of the server list which got no directory yet
+    let server_item = ModrinthPack::open(&path).await.unwrap().server_item().unwrap();
+    ServerList::add(ServerData::from_server_item("myListedServer", server_item))
+        .await
+        .unwrap();
+    assert!(matches!(
+        ModrinthPack::import(&path, "myListedServer").await,
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    assert!(!SERVERS_DIR.join("myListedServer").exists());
+    assert!(!SERVERS_DIR.join("myListedServer.import").exists());
+
+    cleanup();
+}
+#[tokio::test]
+async fn quilt_loader() {
+    start_test();
+    init_config().await;
+    let path = mrpack(&index("http://127.0.0.1:0", "quilt-loader", "")).await;
+    let modpack = ModrinthPack::open(&path).await.unwrap();
+
+    assert_eq!(modpack.loader().unwrap(), ("quilt", "0.15.6"));
+    let server_item = modpack.server_item().unwrap();
+    assert_eq!(server_item.server_type, "quilt");
+    assert_eq!(server_item.args, format!("-jar {QUILT_LAUNCHER_JAR} nogui"));
+    assert_eq!(server_item.build.as_deref(), Some("0.15.6"));
+    // the launcher gets installed by the Quilt installer on the first start
+    assert!(get_provider(&server_item.server_type).await.is_some());
+
+    cleanup();
+}
+#[tokio::test]
+async fn unsupported_loader() {
+    start_test();
+    let path = mrpack(&index("http://127.0.0.1:0", "forge", "")).await;
+
+    let modpack = ModrinthPack::open(&path).await.unwrap();
+    assert_eq!(modpack.minecraft_version().unwrap(), "1.20.4");
+    assert!(matches!(modpack.server_item(), Err(MCManageError::InvalidRequest(_))));
+
+    assert!(matches!(
+        ModrinthPack::import(&path, "../myModpack").await,
+        Err(MCManageError::InvalidRequest(_))
+    ));
+
+    cleanup();
+}
+#[tokio::test]
+async fn unsafe_path() {
+    start_test();
+    let modpack = mrpack(&index("http://127.0.0.1:0", "fabric-loader", "").replace("mods/lithium.jar", "../lithium.jar")).await;
+    let modpack = ModrinthPack::open(&modpack).await.unwrap();
+
+    assert!(matches!(
+        modpack.install(&SERVERS_DIR.join("myModpack")).await,
+        Err(MCManageError::InvalidFile)
+    ));
+
+    cleanup();
+}
@@ -0,0 +1,114 @@
+//! This module provides the [`PlayerIdentities`] struct, which keeps track of the uuid of every player and the names the player used.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use goolog::*;
+use md5::{
+    Digest,
+    Md5,
+};
+use tokio::sync::OnceCell;
+
+use self::player_identity::PlayerIdentity;
+use crate::{
+    mcmanage_error::MCManageError,
+    storage::HistoryStorage,
+};
+
+pub mod player_identity;
+mod tests;
+
+const GOOLOG_CALLER: &str = "PlayerIdentities";
+/// The storage the identities get saved to. It gets set by the [`init`](PlayerIdentities::init) method.
+static PLAYER_IDENTITIES_STORAGE: OnceCell<Arc<dyn HistoryStorage>> = OnceCell::const_new();
+
+/// This struct provides access to the `player_identities` and `player_names` tables. \
+/// Players get identified by their uuid, since their names can change. Every feature storing data about a player should therefore use the uuid.
+pub struct PlayerIdentities;
+// actions
+impl PlayerIdentities {
+    /// Start saving the identities to the given storage. \
+    /// This method can only be called once. Every other method will return an [`MCManageError::NotReady`] until then.
+    pub async fn init(storage: Arc<dyn HistoryStorage>) -> Result<(), MCManageError> {
+        if PLAYER_IDENTITIES_STORAGE.set(storage).is_err() {
+            fatal!("The player identities have already been initialized.")
+        }
+        Ok(())
+    }
+    /// Record that the player with the given uuid joined using the given name. \
+    /// If the player used another name before, the name change will be logged. The ip will only replace the saved one if it is set.
+    pub async fn seen(uuid: &str, name: &str, ip: Option<&str>) -> Result<(), MCManageError> {
+        let previous_name = Self::storage()?.seen_player(uuid, name, ip, Utc::now()).await?;
+
+        if let Some(previous_name) = previous_name {
+            if previous_name != name {
+                info!("The player {previous_name} ( {uuid} ) is now known as {name}.");
+            }
+        }
+        Ok(())
+    }
+}
+// info
+impl PlayerIdentities {
+    /// Return the [`PlayerIdentity`] of the given player. The player can either be given by its uuid or by its current name. \
+    /// Names are compared case-insensitively, just like Minecraft does.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                                        |
+    /// |-----------------------------|----------------------------------------------------|
+    /// | `Ok(PlayerIdentity)`        | The player got found.                              |
+    /// | [`MCManageError::NotFound`] | No player with the given uuid or name ever joined. |
+    /// | `Err(...)`                  | The database could not be queried.                 |
+    pub async fn get(player: &str) -> Result<PlayerIdentity, MCManageError> {
+        Self::storage()?.player_identity(player).await
+    }
+    /// Return the uuid an offline mode server assigns to the player with the given name. \
+    /// This uuid will be used if a server did not announce the uuid of a joining player.
+    pub fn offline_uuid(name: &str) -> String {
+        let mut hash = [0; 16];
+        hash.copy_from_slice(&Md5::digest(format!("OfflinePlayer:{name}").as_bytes()));
+        // mark the hash as a name based ( version 3 ) uuid
+        hash[6] = (hash[6] & 0x0f) | 0x30;
+        hash[8] = (hash[8] & 0x3f) | 0x80;
+
+        let hash = hex::encode(hash);
+        format!(
+            "{}-{}-{}-{}-{}",
+            &hash[0..8],
+            &hash[8..12],
+            &hash[12..16],
+            &hash[16..20],
+            &hash[20..32]
+        )
+    }
+    /// Return the uuid of the Bedrock Edition player with the given xuid. Bedrock players have no Java uuid, so it gets derived from the xuid the
+    /// same way Floodgate does it. \
+    /// If the xuid is no number, for example because an offline mode Bedrock server printed an empty one, `None` will be returned.
+    pub fn xuid_uuid(xuid: &str) -> Option<String> {
+        let xuid: u64 = xuid.trim().parse().ok()?;
+        Some(format!(
+            "00000000-0000-0000-{:04x}-{:012x}",
+            xuid >> 48,
+            xuid & 0xffff_ffff_ffff
+        ))
+    }
+}
+// internal
+impl PlayerIdentities {
+    /// Return the storage the identities get saved to.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                                              |
+    /// |-----------------------------|----------------------------------------------------------|
+    /// | `Ok(&dyn HistoryStorage)`   | The player identities got initialized.                   |
+    /// | [`MCManageError::NotReady`] | The [`init`](Self::init) method has not been called yet. |
+    fn storage() -> Result<&'static dyn HistoryStorage, MCManageError> {
+        PLAYER_IDENTITIES_STORAGE
+            .get()
+            .map(|storage| storage.as_ref())
+            .ok_or(MCManageError::NotReady)
+    }
+}
@@ -0,0 +1,38 @@
+//! This module provides the [`PlayerIdentity`] and [`PlayerName`] structs.
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This struct describes a player identified by its uuid, together with every name the player used.
+#[add_convert]
+pub struct PlayerIdentity {
+    /// The uuid of the player
+    pub uuid: String,
+    /// The name the player used the last time it joined any server
+    pub name: String,
+    /// The time the player joined any server for the first time
+    pub first_seen: DateTime<Utc>,
+    /// The time the player joined any server for the last time
+    pub last_seen: DateTime<Utc>,
+    /// The ip the player joined from the last time. This will only be saved if the `store_player_ips` option of the config is enabled.
+    pub last_ip: Option<String>,
+    /// Every name the player used, starting with the first one
+    pub names: Vec<PlayerName>,
+}
+
+/// This struct describes a name used by a player.
+#[add_convert]
+#[derive(sqlx::FromRow)]
+pub struct PlayerName {
+    /// The name of the player
+    pub name: String,
+    /// The time the player joined with this name for the first time
+    pub first_seen: DateTime<Utc>,
+    /// The time the player joined with this name for the last time
+    pub last_seen: DateTime<Utc>,
+}
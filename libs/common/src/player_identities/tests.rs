@@ -0,0 +1,134 @@
+#![cfg(test)]
+
+use chrono::{
+    DateTime,
+    TimeZone,
+};
+
+use super::{
+    player_identity::PlayerName,
+    *,
+};
+use crate::{
+    storage::{
+        postgres_storage::PostgresStorage,
+        sqlite_storage::SqliteStorage,
+    },
+    test_functions::postgres_pool,
+};
+
+/// The uuid of the player `Notch`
+const NOTCH: &str = "069a79f4-44e9-4726-a5be-fca90e38aaf5";
+/// The uuid of the player `jeb_`
+const JEB: &str = "853c80ef-3c37-49fd-aa49-938b674adae6";
+/// The uuid of the player `Dinnerbone`
+const DINNERBONE: &str = "61699b2e-d327-4a01-9f1e-0ea8c3f06bc6";
+
+/// Return the given number of minutes after the start of 2024. Whole seconds are used, since PostgreSQL only saves microseconds.
+fn at(minutes: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap() + chrono::Duration::minutes(minutes)
+}
+/// Return the name, the time it got used first and the time it got used last of every name of the given player.
+async fn names(storage: &dyn HistoryStorage, player: &str) -> Vec<(String, DateTime<Utc>, DateTime<Utc>)> {
+    storage
+        .player_identity(player)
+        .await
+        .unwrap()
+        .names
+        .into_iter()
+        .map(|name| (name.name, name.first_seen, name.last_seen))
+        .collect()
+}
+
+/// Save and read identities using the given [`HistoryStorage`]. The storage has to be empty.
+async fn identities_suite(storage: &dyn HistoryStorage) {
+    assert!(matches!(storage.player_identity("Notch").await, Err(MCManageError::NotFound)));
+
+    // the first join creates the identity
+    assert_eq!(storage.seen_player(NOTCH, "Notch", Some("203.0.113.1"), at(0)).await.unwrap(), None);
+    let identity = storage.player_identity(NOTCH).await.unwrap();
+    assert_eq!(
+        identity,
+        PlayerIdentity {
+            uuid: NOTCH.to_string(),
+            name: "Notch".to_string(),
+            first_seen: at(0),
+            last_seen: at(0),
+            last_ip: Some("203.0.113.1".to_string()),
+            names: vec![PlayerName {
+                name: "Notch".to_string(),
+                first_seen: at(0),
+                last_seen: at(0),
+            }],
+        }
+    );
+    // the player can be found by its uuid in any case or by its current name ignoring the case
+    assert_eq!(storage.player_identity(&NOTCH.to_uppercase()).await.unwrap(), identity);
+    assert_eq!(storage.player_identity("nOTCH").await.unwrap(), identity);
+
+    // joining again updates the identity, but a missing ip keeps the saved one
+    assert_eq!(
+        storage.seen_player(NOTCH, "Notch", None, at(10)).await.unwrap(),
+        Some("Notch".to_string())
+    );
+    let identity = storage.player_identity(NOTCH).await.unwrap();
+    assert_eq!((identity.first_seen, identity.last_seen), (at(0), at(10)));
+    assert_eq!(identity.last_ip, Some("203.0.113.1".to_string()));
+    assert_eq!(names(storage, NOTCH).await, vec![("Notch".to_string(), at(0), at(10))]);
+
+    // a new name and a new ip replace the saved ones, while the old name is kept in the history
+    assert_eq!(
+        storage.seen_player(NOTCH, "Notch_", Some("203.0.113.2"), at(20)).await.unwrap(),
+        Some("Notch".to_string())
+    );
+    let identity = storage.player_identity("notch_").await.unwrap();
+    assert_eq!((identity.uuid.as_str(), identity.name.as_str()), (NOTCH, "Notch_"));
+    assert_eq!(identity.last_ip, Some("203.0.113.2".to_string()));
+    assert!(matches!(storage.player_identity("Notch").await, Err(MCManageError::NotFound)));
+
+    // switching back to an old name updates its entry instead of adding a new one
+    storage.seen_player(NOTCH, "Notch", None, at(30)).await.unwrap();
+    assert_eq!(
+        names(storage, NOTCH).await,
+        vec![
+            ("Notch".to_string(), at(0), at(30)),
+            ("Notch_".to_string(), at(20), at(20)),
+        ]
+    );
+    assert_eq!(storage.player_identity(NOTCH).await.unwrap().last_ip, Some("203.0.113.2".to_string()));
+
+    // if two players were last seen with the same name, the one seen last is returned
+    storage.seen_player(DINNERBONE, "Grumm", None, at(40)).await.unwrap();
+    storage.seen_player(JEB, "Grumm", None, at(50)).await.unwrap();
+    assert_eq!(storage.player_identity("grumm").await.unwrap().uuid, JEB);
+    // the uuid of a player wins over the name of another one
+    storage.seen_player(DINNERBONE, JEB, None, at(60)).await.unwrap();
+    assert_eq!(storage.player_identity(JEB).await.unwrap().uuid, JEB);
+}
+
+#[tokio::test]
+async fn sqlite_identities() {
+    identities_suite(&SqliteStorage::in_memory().await.unwrap()).await;
+}
+#[tokio::test]
+#[ignore = "requires a PostgreSQL database set in MCMANAGE_TEST_POSTGRES_URL"]
+async fn postgres_identities() {
+    let pool = postgres_pool("postgres_identities").await;
+    identities_suite(&PostgresStorage::new(pool).await.unwrap()).await;
+}
+
+#[test]
+fn offline_uuid() {
+    assert_eq!(
+        PlayerIdentities::offline_uuid("Notch"),
+        "b50ad385-829d-3141-a216-7e7d7539ba7f"
+    );
+}
+#[test]
+fn xuid_uuid() {
+    assert_eq!(
+        PlayerIdentities::xuid_uuid("2535416409208343"),
+        Some("00000000-0000-0000-0009-01f2a26db617".to_string())
+    );
+    assert_eq!(PlayerIdentities::xuid_uuid(""), None);
+}
@@ -0,0 +1,161 @@
+//! This module provides the [`PlayerSessions`] struct, which records every session of a player on a Minecraft server in the database.
+
+use std::{
+    collections::BTreeMap,
+    sync::Arc,
+    time::Duration,
+};
+
+use chrono::Utc;
+use goolog::*;
+use tokio::sync::OnceCell;
+
+use self::{
+    player_session::PlayerSession,
+    player_statistics::PlayerStatistics,
+    session_close_reason::SessionCloseReason,
+};
+use crate::{
+    mcmanage_error::MCManageError,
+    player_identities::PlayerIdentities,
+    storage::HistoryStorage,
+};
+
+pub mod player_session;
+pub mod player_statistics;
+pub mod session_close_reason;
+mod tests;
+
+const GOOLOG_CALLER: &str = "PlayerSessions";
+/// The storage the sessions get saved to. It gets set by the [`init`](PlayerSessions::init) method.
+static PLAYER_SESSIONS_STORAGE: OnceCell<Arc<dyn HistoryStorage>> = OnceCell::const_new();
+
+/// This struct provides access to the `player_sessions` table. \
+/// Every time a player joins a [`Server`](crate::server_manager::server::Server), a session gets opened, which will be closed once the player leaves or
+/// the server stops. Since the sessions are saved to the database, they survive restarts of MCManage.
+pub struct PlayerSessions;
+// actions
+impl PlayerSessions {
+    /// Start saving the sessions to the given storage and close every session still open from the last run of MCManage with the reason
+    /// [`SessionCloseReason::Interrupted`]. \
+    /// This method can only be called once. Every other method will return an [`MCManageError::NotReady`] until then.
+    pub async fn init(storage: Arc<dyn HistoryStorage>) -> Result<(), MCManageError> {
+        let interrupted = storage
+            .close_sessions(None, None, SessionCloseReason::Interrupted, Utc::now())
+            .await?;
+        if interrupted > 0 {
+            warn!("Closed {interrupted} sessions which were still open when MCManage stopped.");
+        }
+
+        if PLAYER_SESSIONS_STORAGE.set(storage).is_err() {
+            fatal!("The player sessions have already been initialized.")
+        }
+        Ok(())
+    }
+    /// Open a new session for the player with the given uuid and name on the given server. \
+    /// Sessions of this player on this server which are still open will be closed first, since a player can only be online once.
+    pub async fn open(server: &str, uuid: &str, player: &str) -> Result<(), MCManageError> {
+        let storage = Self::storage()?;
+        let now = Utc::now();
+        storage
+            .close_sessions(Some(server), Some(uuid), SessionCloseReason::Left, now)
+            .await?;
+        storage.open_session(server, uuid, player, now).await
+    }
+    /// Close the open session of the player with the given uuid on the given server.
+    pub async fn close(server: &str, uuid: &str, reason: SessionCloseReason) -> Result<(), MCManageError> {
+        Self::storage()?
+            .close_sessions(Some(server), Some(uuid), reason, Utc::now())
+            .await?;
+        Ok(())
+    }
+    /// Close every open session on the given server and return how many got closed.
+    pub async fn close_all(server: &str, reason: SessionCloseReason) -> Result<u64, MCManageError> {
+        Self::storage()?
+            .close_sessions(Some(server), None, reason, Utc::now())
+            .await
+    }
+    /// Move every session on the server with the given old name to its new name. This has to be done every time a server gets renamed, since
+    /// the sessions are saved by the name of their server.
+    pub async fn rename_server(old_name: &str, new_name: &str) -> Result<(), MCManageError> {
+        let moved = Self::storage()?.rename_sessions(old_name, new_name).await?;
+        if moved > 0 {
+            info!("Moved {moved} sessions of the server {old_name} to its new name {new_name}.");
+        }
+        Ok(())
+    }
+}
+// info
+impl PlayerSessions {
+    /// Return the sessions of every player currently online on any server, sorted by the time they joined.
+    pub async fn online() -> Result<Vec<PlayerSession>, MCManageError> {
+        Self::storage()?.online_sessions().await
+    }
+    /// Return every session on the given server, starting with the latest one.
+    pub async fn sessions(server: &str) -> Result<Vec<PlayerSession>, MCManageError> {
+        Self::storage()?.sessions(server).await
+    }
+    /// Return the [`PlayerStatistics`] of the given player. The player can either be given by its uuid or by its current name.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                         |
+    /// |-----------------------------|-------------------------------------|
+    /// | `Ok(PlayerStatistics)`      | The statistics got calculated.      |
+    /// | [`MCManageError::NotFound`] | The player never joined any server. |
+    /// | `Err(...)`                  | The database could not be queried.  |
+    pub async fn statistics(player: &str) -> Result<PlayerStatistics, MCManageError> {
+        let uuid = PlayerIdentities::get(player).await?.uuid;
+        Self::query_statistics(Some(&uuid))
+            .await?
+            .pop()
+            .ok_or(MCManageError::NotFound)
+    }
+    /// Return the [`PlayerStatistics`] of every player who ever joined any server, sorted by their current names.
+    pub async fn all_statistics() -> Result<Vec<PlayerStatistics>, MCManageError> {
+        Self::query_statistics(None).await
+    }
+}
+// internal
+impl PlayerSessions {
+    /// Return the storage the sessions get saved to.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                                              |
+    /// |-----------------------------|----------------------------------------------------------|
+    /// | `Ok(&dyn HistoryStorage)`   | The player sessions got initialized.                     |
+    /// | [`MCManageError::NotReady`] | The [`init`](Self::init) method has not been called yet. |
+    fn storage() -> Result<&'static dyn HistoryStorage, MCManageError> {
+        PLAYER_SESSIONS_STORAGE
+            .get()
+            .map(|storage| storage.as_ref())
+            .ok_or(MCManageError::NotReady)
+    }
+    /// Calculate the statistics of the player with the given uuid, or of every player if `None` got provided. \
+    /// Sessions which are still open count up to the current time.
+    async fn query_statistics(uuid: Option<&str>) -> Result<Vec<PlayerStatistics>, MCManageError> {
+        let rows = Self::storage()?.server_statistics(uuid, Utc::now()).await?;
+
+        let mut statistics: BTreeMap<String, PlayerStatistics> = BTreeMap::new();
+        for (uuid, player, server_statistics) in rows {
+            let player_statistics = statistics
+                .entry(uuid.clone())
+                .or_insert_with(|| PlayerStatistics {
+                    uuid,
+                    player,
+                    first_seen: server_statistics.first_seen,
+                    last_seen: server_statistics.last_seen,
+                    playtime: Duration::ZERO,
+                    servers: vec![],
+                });
+            player_statistics.first_seen = player_statistics.first_seen.min(server_statistics.first_seen);
+            player_statistics.last_seen = player_statistics.last_seen.max(server_statistics.last_seen);
+            player_statistics.playtime += server_statistics.playtime;
+            player_statistics.servers.push(server_statistics);
+        }
+        let mut statistics: Vec<PlayerStatistics> = statistics.into_values().collect();
+        statistics.sort_by(|a, b| a.player.to_lowercase().cmp(&b.player.to_lowercase()));
+        Ok(statistics)
+    }
+}
@@ -0,0 +1,35 @@
+//! This module provides the [`PlayerSession`] struct.
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use proc_macros::add_convert;
+
+use super::session_close_reason::SessionCloseReason;
+use crate::mcmanage_error::MCManageError;
+
+/// This struct represents the time a player spent on a Minecraft server, from joining it until leaving it.
+#[add_convert]
+pub struct PlayerSession {
+    /// The id of this session in the database
+    pub id: i64,
+    /// The name of the server the player played on
+    pub server: String,
+    /// The uuid of the player
+    pub uuid: String,
+    /// The name the player used during this session
+    pub player: String,
+    /// The time the player joined the server at
+    pub joined_at: DateTime<Utc>,
+    /// The time the player left the server at. This will be `None` as long as the player is online.
+    pub left_at: Option<DateTime<Utc>>,
+    /// The reason this session ended. This will be `None` as long as the player is online.
+    pub close_reason: Option<SessionCloseReason>,
+}
+impl PlayerSession {
+    /// Return whether or not the player of this session is still online.
+    pub fn is_online(&self) -> bool {
+        self.left_at.is_none()
+    }
+}
@@ -0,0 +1,51 @@
+//! This module provides the [`PlayerStatistics`] and [`ServerStatistics`] structs.
+
+use std::time::Duration;
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This struct summarizes every session of a player across all Minecraft servers.
+#[add_convert]
+pub struct PlayerStatistics {
+    /// The uuid of the player
+    pub uuid: String,
+    /// The current name of the player
+    pub player: String,
+    /// The time the player joined any server for the first time
+    pub first_seen: DateTime<Utc>,
+    /// The time the player was last seen on any server. If the player is online, this is the current time.
+    pub last_seen: DateTime<Utc>,
+    /// The time the player spent on all servers combined
+    pub playtime: Duration,
+    /// The statistics of every server the player played on
+    pub servers: Vec<ServerStatistics>,
+}
+impl PlayerStatistics {
+    /// Return whether or not the player is online on any server.
+    pub fn is_online(&self) -> bool {
+        self.servers.iter().any(|server| server.online)
+    }
+}
+
+/// This struct summarizes every session of a player on one Minecraft server.
+#[add_convert]
+pub struct ServerStatistics {
+    /// The name of the server
+    pub server: String,
+    /// The number of sessions the player had on this server
+    pub sessions: u64,
+    /// The time the player joined this server for the first time
+    pub first_seen: DateTime<Utc>,
+    /// The time the player was last seen on this server. If the player is online, this is the current time.
+    pub last_seen: DateTime<Utc>,
+    /// The time the player spent on this server
+    pub playtime: Duration,
+    /// Whether or not the player is currently online on this server
+    pub online: bool,
+}
@@ -0,0 +1,41 @@
+//! This module provides the [`SessionCloseReason`] enum.
+
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This enum describes why a [`PlayerSession`](super::player_session::PlayerSession) ended.
+#[add_convert]
+#[derive(Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionCloseReason {
+    /// The player left the Minecraft server.
+    Left,
+    /// The Minecraft server got stopped or restarted while the player was online.
+    Stopped,
+    /// The Minecraft server stopped unexpectedly while the player was online.
+    Crashed,
+    /// MCManage stopped while the player was online. These sessions get closed the next time MCManage starts.
+    Interrupted,
+}
+impl SessionCloseReason {
+    /// Return the name this reason gets saved to the database with.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Stopped => "stopped",
+            Self::Crashed => "crashed",
+            Self::Interrupted => "interrupted",
+        }
+    }
+    /// Return the reason saved to the database with the given name. Unknown names will return `None`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "left" => Some(Self::Left),
+            "stopped" => Some(Self::Stopped),
+            "crashed" => Some(Self::Crashed),
+            "interrupted" => Some(Self::Interrupted),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,230 @@
+#![cfg(test)]
+
+use chrono::{
+    DateTime,
+    TimeZone,
+};
+
+use super::{
+    player_statistics::ServerStatistics,
+    *,
+};
+use crate::{
+    server_manager::server_list::{
+        server_data::ServerData,
+        ServerList,
+    },
+    storage::sqlite_storage::SqliteStorage,
+    test_functions::init_server_list,
+};
+
+/// The uuid of the player `Notch`
+const NOTCH: &str = "069a79f4-44e9-4726-a5be-fca90e38aaf5";
+/// The uuid of the player `jeb_`
+const JEB: &str = "853c80ef-3c37-49fd-aa49-938b674adae6";
+/// The uuid of the player `Dinnerbone`
+const DINNERBONE: &str = "61699b2e-d327-4a01-9f1e-0ea8c3f06bc6";
+
+/// Save a session of the given player on the given server. It will only be closed if a time got provided.
+async fn insert_session(
+    storage: &dyn HistoryStorage,
+    server: &str,
+    uuid: &str,
+    player: &str,
+    joined_at: DateTime<Utc>,
+    left_at: Option<DateTime<Utc>>,
+) {
+    storage.open_session(server, uuid, player, joined_at).await.unwrap();
+    if let Some(left_at) = left_at {
+        storage
+            .close_sessions(Some(server), Some(uuid), SessionCloseReason::Left, left_at)
+            .await
+            .unwrap();
+    }
+}
+/// Return the sessions of every player currently online on the servers of this test. Other tests might open sessions on their own servers.
+async fn online_sessions() -> Vec<PlayerSession> {
+    PlayerSessions::online()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|session| session.server.starts_with("mySessions"))
+        .collect()
+}
+
+/// The [`PlayerSessions`] and [`PlayerIdentities`] can only be initialized once per process, so every part of them gets tested in this one test.
+#[tokio::test]
+async fn player_sessions() {
+    let storage = Arc::new(SqliteStorage::in_memory().await.unwrap());
+    assert!(matches!(PlayerSessions::online().await, Err(MCManageError::NotReady)));
+
+    // a session left open by the last run of MCManage
+    let joined_at = Utc::now() - chrono::Duration::hours(1);
+    insert_session(storage.as_ref(), "mySessionsLobby", NOTCH, "Notch", joined_at, None).await;
+
+    PlayerIdentities::init(storage.clone()).await.unwrap();
+    PlayerSessions::init(storage.clone()).await.unwrap();
+
+    let sessions = PlayerSessions::sessions("mySessionsLobby").await.unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert!(!sessions[0].is_online());
+    assert_eq!(sessions[0].close_reason, Some(SessionCloseReason::Interrupted));
+    assert!(online_sessions().await.is_empty());
+
+    open_and_close().await;
+    close_after_crash().await;
+    rename_server().await;
+    statistics(storage.as_ref()).await;
+}
+/// Open and close the sessions of a player.
+async fn open_and_close() {
+    PlayerSessions::open("mySessionsLobby", NOTCH, "Notch").await.unwrap();
+    let online = online_sessions().await;
+    assert_eq!(online.len(), 1);
+    assert_eq!((online[0].server.as_str(), online[0].player.as_str()), ("mySessionsLobby", "Notch"));
+
+    // a player can only be online once per server
+    PlayerSessions::open("mySessionsLobby", NOTCH, "Notch").await.unwrap();
+    assert_eq!(online_sessions().await.len(), 1);
+    let sessions = PlayerSessions::sessions("mySessionsLobby").await.unwrap();
+    assert_eq!(sessions.len(), 3);
+    assert!(sessions[0].is_online());
+    assert_eq!(sessions[1].close_reason, Some(SessionCloseReason::Left));
+
+    PlayerSessions::close("mySessionsLobby", NOTCH, SessionCloseReason::Left)
+        .await
+        .unwrap();
+    assert!(online_sessions().await.is_empty());
+    let session = &PlayerSessions::sessions("mySessionsLobby").await.unwrap()[0];
+    assert_eq!(session.close_reason, Some(SessionCloseReason::Left));
+    assert!(session.left_at.unwrap() >= session.joined_at);
+}
+/// Rename a server through the [`ServerList`], which moves its sessions to the new name.
+async fn rename_server() {
+    init_server_list().await;
+    let id = ServerList::add(ServerData {
+        name: Some("mySessionsCreative".to_string()),
+        ..Default::default()
+    })
+    .await
+    .unwrap()
+    .id
+    .unwrap();
+    PlayerSessions::open("mySessionsCreative", JEB, "jeb_").await.unwrap();
+
+    ServerList::change(
+        id,
+        ServerData {
+            name: Some("mySessionsRenamed".to_string()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    assert!(PlayerSessions::sessions("mySessionsCreative").await.unwrap().is_empty());
+    let online = online_sessions().await;
+    assert_eq!(online.len(), 1);
+    assert_eq!((online[0].server.as_str(), online[0].uuid.as_str()), ("mySessionsRenamed", JEB));
+
+    PlayerSessions::close_all("mySessionsRenamed", SessionCloseReason::Stopped)
+        .await
+        .unwrap();
+    assert!(online_sessions().await.is_empty());
+}
+/// Close every session of a server, as it happens once it crashed or got stopped.
+async fn close_after_crash() {
+    PlayerSessions::open("mySessionsLobby", NOTCH, "Notch").await.unwrap();
+    PlayerSessions::open("mySessionsLobby", JEB, "jeb_").await.unwrap();
+    PlayerSessions::open("mySessionsSurvival", JEB, "jeb_").await.unwrap();
+
+    assert_eq!(
+        PlayerSessions::close_all("mySessionsLobby", SessionCloseReason::Crashed)
+            .await
+            .unwrap(),
+        2
+    );
+    // other servers are not affected
+    let online = online_sessions().await;
+    assert_eq!(online.len(), 1);
+    assert_eq!((online[0].server.as_str(), online[0].uuid.as_str()), ("mySessionsSurvival", JEB));
+    assert!(PlayerSessions::sessions("mySessionsLobby").await.unwrap()[..2]
+        .iter()
+        .all(|session| session.close_reason == Some(SessionCloseReason::Crashed)));
+
+    assert_eq!(
+        PlayerSessions::close_all("mySessionsSurvival", SessionCloseReason::Stopped)
+            .await
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        PlayerSessions::close_all("mySessionsSurvival", SessionCloseReason::Stopped)
+            .await
+            .unwrap(),
+        0
+    );
+}
+/// Calculate the statistics of a player who played on two servers using an old name.
+async fn statistics(storage: &dyn HistoryStorage) {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    let at = |minutes| start + chrono::Duration::minutes(minutes);
+    insert_session(storage, "mySessionsLobby", DINNERBONE, "Dinnerbone_", at(0), Some(at(60))).await;
+    insert_session(storage, "mySessionsSurvival", DINNERBONE, "Dinnerbone_", at(120), Some(at(150))).await;
+    insert_session(storage, "mySessionsLobby", DINNERBONE, "Dinnerbone_", at(180), Some(at(190))).await;
+    PlayerIdentities::seen(DINNERBONE, "Dinnerbone", None).await.unwrap();
+
+    // the player can be found by its current name or by its uuid
+    let statistics = PlayerSessions::statistics("dinnerbone").await.unwrap();
+    assert_eq!(PlayerSessions::statistics(DINNERBONE).await.unwrap(), statistics);
+    assert_eq!(statistics.uuid, DINNERBONE);
+    assert_eq!(statistics.player, "Dinnerbone");
+    assert_eq!(statistics.first_seen, at(0));
+    assert_eq!(statistics.last_seen, at(190));
+    assert_eq!(statistics.playtime, Duration::from_secs(100 * 60));
+    assert!(!statistics.is_online());
+    assert_eq!(
+        statistics.servers,
+        vec![
+            ServerStatistics {
+                server: "mySessionsLobby".to_string(),
+                sessions: 2,
+                first_seen: at(0),
+                last_seen: at(190),
+                playtime: Duration::from_secs(70 * 60),
+                online: false,
+            },
+            ServerStatistics {
+                server: "mySessionsSurvival".to_string(),
+                sessions: 1,
+                first_seen: at(120),
+                last_seen: at(150),
+                playtime: Duration::from_secs(30 * 60),
+                online: false,
+            },
+        ]
+    );
+
+    // open sessions count up to the current time
+    PlayerSessions::open("mySessionsSurvival", DINNERBONE, "Dinnerbone").await.unwrap();
+    let statistics = PlayerSessions::statistics("Dinnerbone").await.unwrap();
+    assert!(statistics.is_online());
+    assert!(statistics.last_seen > at(190));
+    assert!(statistics.playtime > Duration::from_secs(100 * 60));
+    PlayerSessions::close("mySessionsSurvival", DINNERBONE, SessionCloseReason::Left)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        PlayerSessions::statistics("Grumm").await,
+        Err(MCManageError::NotFound)
+    ));
+    // Notch and jeb_ never got recorded by the player identities, so the name of their last session is used
+    let players: Vec<String> = PlayerSessions::all_statistics()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|statistics| [NOTCH, JEB, DINNERBONE].contains(&statistics.uuid.as_str()))
+        .map(|statistics| statistics.player)
+        .collect();
+    assert_eq!(players, vec!["Dinnerbone", "jeb_", "Notch"]);
+}
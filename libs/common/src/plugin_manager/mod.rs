@@ -0,0 +1,181 @@
+//! This module provides the [`PluginManager`], which manages the plugins of Bukkit-family servers like Spigot, Paper or Purpur. \
+//! Plugins get read from the `plugins` directory of a server. Disabled plugins get moved to the `plugins/.disabled` directory, so they will not be loaded.
+
+use std::path::Path;
+
+use tokio::fs;
+
+use self::{
+    plugin::Plugin,
+    plugin_descriptor::{
+        PluginDescriptor,
+        PAPER_PLUGIN_YML,
+        PLUGIN_YML,
+    },
+    plugin_warning::PluginWarning,
+};
+use crate::{
+    jar_directory::JarDirectory,
+    jar_file::read_entry,
+    mcmanage_error::MCManageError,
+};
+
+pub mod plugin;
+pub mod plugin_descriptor;
+pub mod plugin_warning;
+mod tests;
+
+/// This struct manages the plugins installed on one Minecraft server.
+pub struct PluginManager {
+    /// The `plugins` directory of the server
+    plugins_dir: JarDirectory,
+}
+// actions
+impl PluginManager {
+    /// Create a new [`PluginManager`] for the server in the given directory.
+    pub fn new(server_path: &Path) -> Self {
+        Self {
+            plugins_dir: JarDirectory::new(&server_path.join("plugins")),
+        }
+    }
+    /// Enable the given plugin by moving it from the `plugins/.disabled` directory back to the `plugins` directory. \
+    /// See the [`JarDirectory::enable`] method for the possible errors.
+    pub async fn enable(&self, file_name: &str) -> Result<(), MCManageError> {
+        self.plugins_dir.enable(file_name).await
+    }
+    /// Disable the given plugin by moving it to the `plugins/.disabled` directory. \
+    /// See the [`JarDirectory::disable`] method for the possible errors.
+    pub async fn disable(&self, file_name: &str) -> Result<(), MCManageError> {
+        self.plugins_dir.disable(file_name).await
+    }
+    /// Save the given jar as a new, enabled plugin. An existing plugin with the same file name will be replaced, no matter if it is enabled or not.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                                          |
+    /// |-----------------------------------|----------------------------------------------------------------------|
+    /// | `Ok(Plugin)`                      | The plugin got saved. Its warnings are not checked yet.              |
+    /// | [`MCManageError::InvalidRequest`] | The file name is not the name of a jar file or the jar is no plugin. |
+    pub async fn upload(&self, file_name: &str, content: &[u8]) -> Result<Plugin, MCManageError> {
+        let staged_path = self.plugins_dir.stage(file_name, content).await?;
+
+        let plugin = Self::read_plugin(&staged_path, file_name, true).await;
+        if plugin.descriptor.is_none() {
+            fs::remove_file(&staged_path).await?;
+            return Err(MCManageError::InvalidRequest(format!(
+                "`{file_name}` is no plugin. It contains neither a `{PLUGIN_YML}` nor a `{PAPER_PLUGIN_YML}` file."
+            )));
+        }
+
+        self.plugins_dir.install(&staged_path, file_name).await?;
+        Ok(plugin)
+    }
+    /// Delete the given plugin, no matter if it is enabled or not. Files created by the plugin in its data folder will be kept. \
+    /// See the [`JarDirectory::delete`] method for the possible errors.
+    pub async fn delete(&self, file_name: &str) -> Result<(), MCManageError> {
+        self.plugins_dir.delete(file_name).await
+    }
+}
+// info
+impl PluginManager {
+    /// Return every plugin installed, enabled or disabled, sorted by their file name. \
+    /// If the Minecraft version of the server is given, every enabled plugin written for a newer version will get a
+    /// [`PluginWarning::ApiVersionMismatch`].
+    pub async fn plugins(&self, minecraft_version: Option<&str>) -> Result<Vec<Plugin>, MCManageError> {
+        let mut plugins = vec![];
+        for (path, file_name, enabled) in self.plugins_dir.jars().await? {
+            plugins.push(Self::read_plugin(&path, &file_name, enabled).await);
+        }
+
+        // every name an enabled plugin can be depended on with
+        let available: Vec<String> = plugins
+            .iter()
+            .filter(|plugin| plugin.enabled)
+            .filter_map(|plugin| plugin.descriptor.as_ref())
+            .flat_map(|descriptor| {
+                [descriptor.name.clone()]
+                    .into_iter()
+                    .chain(descriptor.provides.clone())
+            })
+            .collect();
+
+        for plugin in plugins.iter_mut().filter(|plugin| plugin.enabled) {
+            let Some(descriptor) = &plugin.descriptor else {
+                continue;
+            };
+
+            for dependency in &descriptor.dependencies {
+                if !available.contains(dependency) {
+                    plugin.warnings.push(PluginWarning::MissingDependency {
+                        dependency: dependency.clone(),
+                    });
+                }
+            }
+
+            if let (Some(api_version), Some(minecraft_version)) = (&descriptor.api_version, minecraft_version) {
+                if !supports_api_version(minecraft_version, api_version) {
+                    plugin.warnings.push(PluginWarning::ApiVersionMismatch {
+                        api_version: api_version.clone(),
+                        minecraft_version: minecraft_version.to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(plugins)
+    }
+}
+// internal
+impl PluginManager {
+    /// Read the descriptor of the plugin jar at the given path. If it can not be read, the plugin will get a [`PluginWarning::InvalidDescriptor`].
+    async fn read_plugin(path: &Path, file_name: &str, enabled: bool) -> Plugin {
+        let descriptor = match read_entry(path, &[PAPER_PLUGIN_YML, PLUGIN_YML]).await {
+            Ok(Some((entry, content))) => PluginDescriptor::parse(&content, entry == PAPER_PLUGIN_YML),
+            Ok(None) => Err(MCManageError::NotFound),
+            Err(error) => Err(error),
+        };
+
+        match descriptor {
+            Ok(descriptor) => Plugin {
+                file_name: file_name.to_string(),
+                enabled,
+                descriptor: Some(descriptor),
+                warnings: vec![],
+            },
+            Err(error) => Plugin {
+                file_name: file_name.to_string(),
+                enabled,
+                descriptor: None,
+                warnings: vec![PluginWarning::InvalidDescriptor {
+                    error: if let MCManageError::NotFound = error {
+                        format!("Neither a `{PLUGIN_YML}` nor a `{PAPER_PLUGIN_YML}` file could be found.")
+                    } else {
+                        error.to_string()
+                    },
+                }],
+            },
+        }
+    }
+}
+
+/// Return whether a server running the given Minecraft version can load a plugin with the given api-version. \
+/// This is the case if the api-version is not newer than the Minecraft version. (For example: The api-version `1.20` is supported by `1.20.4`, but not by `1.19.4`.)
+pub fn supports_api_version(minecraft_version: &str, api_version: &str) -> bool {
+    /// Parse the numeric parts of a version. Parsing stops at the first part which is not a number, like a `-pre1` suffix.
+    fn parse(version: &str) -> Vec<u64> {
+        version
+            .split('.')
+            .map_while(|part| part.parse().ok())
+            .collect()
+    }
+
+    let api_version = parse(api_version);
+    let mut minecraft_version = parse(minecraft_version);
+    if api_version.is_empty() || minecraft_version.is_empty() {
+        // snapshots and unusual versions can not be compared
+        return true;
+    }
+    minecraft_version.resize(api_version.len().max(minecraft_version.len()), 0);
+
+    minecraft_version[..api_version.len()] >= api_version[..]
+}
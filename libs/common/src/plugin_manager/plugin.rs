@@ -0,0 +1,22 @@
+//! This module provides the [`Plugin`] struct.
+
+use proc_macros::add_convert;
+
+use super::{
+    plugin_descriptor::PluginDescriptor,
+    plugin_warning::PluginWarning,
+};
+use crate::mcmanage_error::MCManageError;
+
+/// This struct represents one plugin jar found in a server's `plugins` directory.
+#[add_convert]
+pub struct Plugin {
+    /// The name of the jar file.
+    pub file_name: String,
+    /// Whether the plugin will be loaded by the server. Disabled plugins lie in the `plugins/.disabled` directory.
+    pub enabled: bool,
+    /// The information the plugin provides about itself. It is `None` if the jar contains no valid descriptor.
+    pub descriptor: Option<PluginDescriptor>,
+    /// Every problem detected with this plugin. Dependencies and api-versions are only checked for enabled plugins.
+    pub warnings: Vec<PluginWarning>,
+}
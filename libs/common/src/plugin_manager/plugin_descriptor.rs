@@ -0,0 +1,144 @@
+//! This module provides the [`PluginDescriptor`] struct.
+
+use proc_macros::add_convert;
+use serde_yaml::{
+    Mapping,
+    Value,
+};
+
+use crate::mcmanage_error::MCManageError;
+
+/// The name of the descriptor file of Bukkit plugins.
+pub const PLUGIN_YML: &str = "plugin.yml";
+/// The name of the descriptor file of Paper plugins. Paper prefers this file over the [`PLUGIN_YML`].
+pub const PAPER_PLUGIN_YML: &str = "paper-plugin.yml";
+
+/// This struct represents the information a plugin provides about itself in its `plugin.yml` or `paper-plugin.yml` file.
+#[add_convert]
+pub struct PluginDescriptor {
+    /// The name of the plugin.
+    pub name: String,
+    /// The version of the plugin.
+    pub version: String,
+    /// Everyone who developed the plugin.
+    pub authors: Vec<String>,
+    /// Every plugin required by this plugin.
+    pub dependencies: Vec<String>,
+    /// Every plugin this plugin can use, but does not require.
+    pub soft_dependencies: Vec<String>,
+    /// Other names this plugin can be depended on with.
+    pub provides: Vec<String>,
+    /// The oldest Minecraft version this plugin got written for. Plugins without one are loaded in legacy mode.
+    pub api_version: Option<String>,
+    /// Whether this descriptor got read from a `paper-plugin.yml` file.
+    pub paper_plugin: bool,
+}
+impl PluginDescriptor {
+    /// Parse the content of a `plugin.yml` or `paper-plugin.yml` file.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                             |
+    /// |-----------------------------------|-----------------------------------------|
+    /// | `Ok(PluginDescriptor)`            | The descriptor got parsed.              |
+    /// | [`MCManageError::InvalidFile`]    | The descriptor does not contain a name. |
+    /// | [`MCManageError::SerdeYamlError`] | The descriptor is no valid yaml.        |
+    pub fn parse(content: &[u8], paper_plugin: bool) -> Result<Self, MCManageError> {
+        let descriptor: Mapping = serde_yaml::from_slice(content)?;
+
+        let name = descriptor
+            .get("name")
+            .and_then(to_string)
+            .ok_or(MCManageError::InvalidFile)?;
+        let version = descriptor
+            .get("version")
+            .and_then(to_string)
+            .unwrap_or_default();
+
+        let mut authors = string_list(descriptor.get("authors"));
+        if let Some(author) = descriptor.get("author").and_then(to_string) {
+            authors.insert(0, author);
+        }
+
+        let (dependencies, soft_dependencies) = if paper_plugin {
+            paper_dependencies(descriptor.get("dependencies"))
+        } else {
+            (
+                string_list(descriptor.get("depend")),
+                string_list(descriptor.get("softdepend")),
+            )
+        };
+
+        Ok(Self {
+            name,
+            version,
+            authors,
+            dependencies,
+            soft_dependencies,
+            provides: string_list(descriptor.get("provides")),
+            api_version: descriptor.get("api-version").and_then(to_string),
+            paper_plugin,
+        })
+    }
+}
+
+/// Convert a yaml scalar to a string. Versions like `1.20` are often written without quotes, so numbers are accepted too.
+fn to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(value) => Some(value.clone()),
+        Value::Number(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+/// Convert a yaml list of strings to a vector. A single string will be treated as a list with one element.
+fn string_list(value: Option<&Value>) -> Vec<String> {
+    match value {
+        Some(Value::Sequence(values)) => values.iter().filter_map(to_string).collect(),
+        Some(value) => to_string(value).into_iter().collect(),
+        None => vec![],
+    }
+}
+/// Read the required and optional dependencies of a `paper-plugin.yml` file. \
+/// Both the current format ( `dependencies.server.<name>.required` ) and the old list format ( `dependencies[].name` ) are supported.
+fn paper_dependencies(value: Option<&Value>) -> (Vec<String>, Vec<String>) {
+    /// Read whether a dependency is required. Paper treats dependencies as required by default.
+    fn is_required(dependency: &Value) -> bool {
+        dependency
+            .get("required")
+            .and_then(Value::as_bool)
+            .unwrap_or(true)
+    }
+
+    let mut dependencies = vec![];
+    let mut soft_dependencies = vec![];
+
+    match value {
+        Some(Value::Mapping(sections)) => {
+            if let Some(Value::Mapping(server_dependencies)) = sections.get("server") {
+                for (name, dependency) in server_dependencies {
+                    if let Some(name) = to_string(name) {
+                        if is_required(dependency) {
+                            dependencies.push(name);
+                        } else {
+                            soft_dependencies.push(name);
+                        }
+                    }
+                }
+            }
+        }
+        Some(Value::Sequence(list)) => {
+            for dependency in list {
+                if let Some(name) = dependency.get("name").and_then(to_string) {
+                    if is_required(dependency) {
+                        dependencies.push(name);
+                    } else {
+                        soft_dependencies.push(name);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+
+    (dependencies, soft_dependencies)
+}
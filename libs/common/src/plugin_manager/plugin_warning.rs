@@ -0,0 +1,28 @@
+//! This module provides the [`PluginWarning`] enum.
+
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This enum represents a problem detected with an installed plugin.
+#[add_convert]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PluginWarning {
+    /// The jar does not contain a valid `plugin.yml` or `paper-plugin.yml` file.
+    InvalidDescriptor {
+        /// Why the descriptor could not be read
+        error: String,
+    },
+    /// A plugin required by this plugin is not installed or disabled.
+    MissingDependency {
+        /// The name of the missing plugin
+        dependency: String,
+    },
+    /// The plugin got written for a newer Minecraft version than the one the server is running.
+    ApiVersionMismatch {
+        /// The api-version of the plugin
+        api_version: String,
+        /// The Minecraft version of the server
+        minecraft_version: String,
+    },
+}
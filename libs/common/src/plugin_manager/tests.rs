@@ -0,0 +1,175 @@
+#![cfg(test)]
+
+use super::*;
+use crate::{
+    generated_files::paths::SERVERS_DIR,
+    test_functions::*,
+};
+
+/// Create a [`PluginManager`] for the server `myServer` with a few plugins installed.
+async fn setup() -> PluginManager {
+    start_test();
+    let plugin_manager = PluginManager::new(&SERVERS_DIR.join("myServer"));
+
+    plugin_manager
+        .upload(
+            "Essentials.jar",
+            &jar(&[("plugin.yml", "name: Essentials\nversion: 2.20.1\nauthors: [zenexer, snowleo]\nsoftdepend: [Vault]\napi-version: '1.13'")]),
+        )
+        .await
+        .unwrap();
+    plugin_manager
+        .upload(
+            "EssentialsChat.jar",
+            &jar(&[("plugin.yml", "name: EssentialsChat\nversion: 2.20.1\nauthor: mdcfe\ndepend: [Essentials]\napi-version: 1.13")]),
+        )
+        .await
+        .unwrap();
+    plugin_manager
+        .upload(
+            "NewPlugin.jar",
+            &jar(&[("paper-plugin.yml", "name: NewPlugin\nversion: '1.0'\napi-version: '1.20'\ndependencies:\n  server:\n    LuckPerms:\n      load: BEFORE\n    Vault:\n      required: false")]),
+        )
+        .await
+        .unwrap();
+
+    plugin_manager
+}
+
+#[tokio::test]
+async fn parse_plugin_yml() {
+    let descriptor = PluginDescriptor::parse(
+        b"name: EssentialsChat\nversion: 2.20.1\nauthor: mdcfe\nauthors: [snowleo]\ndepend: [Essentials]\nsoftdepend: Vault\napi-version: 1.13",
+        false,
+    )
+    .unwrap();
+
+    assert_eq!(
+        descriptor,
+        PluginDescriptor {
+            name: "EssentialsChat".to_string(),
+            version: "2.20.1".to_string(),
+            authors: vec!["mdcfe".to_string(), "snowleo".to_string()],
+            dependencies: vec!["Essentials".to_string()],
+            soft_dependencies: vec!["Vault".to_string()],
+            provides: vec![],
+            api_version: Some("1.13".to_string()),
+            paper_plugin: false,
+        }
+    );
+}
+#[tokio::test]
+async fn parse_paper_plugin_yml() {
+    let descriptor = PluginDescriptor::parse(
+        b"name: NewPlugin\nversion: '1.0'\ndependencies:\n  - name: LuckPerms\n  - name: Vault\n    required: false",
+        true,
+    )
+    .unwrap();
+
+    assert_eq!(descriptor.dependencies, vec!["LuckPerms"]);
+    assert_eq!(descriptor.soft_dependencies, vec!["Vault"]);
+    assert!(descriptor.paper_plugin);
+}
+#[tokio::test]
+async fn parse_without_name() {
+    assert!(matches!(
+        PluginDescriptor::parse(b"version: 1.0", false),
+        Err(MCManageError::InvalidFile)
+    ));
+}
+#[tokio::test]
+async fn plugins_with_warnings() {
+    let plugin_manager = setup().await;
+
+    let plugins = plugin_manager.plugins(Some("1.19.4")).await.unwrap();
+
+    assert_eq!(
+        plugins
+            .iter()
+            .map(|plugin| plugin.file_name.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["Essentials.jar", "EssentialsChat.jar", "NewPlugin.jar"]
+    );
+    assert!(plugins[0].warnings.is_empty());
+    assert!(plugins[1].warnings.is_empty());
+    assert_eq!(
+        plugins[2].warnings,
+        vec![
+            PluginWarning::MissingDependency {
+                dependency: "LuckPerms".to_string()
+            },
+            PluginWarning::ApiVersionMismatch {
+                api_version: "1.20".to_string(),
+                minecraft_version: "1.19.4".to_string()
+            }
+        ]
+    );
+
+    cleanup();
+}
+#[tokio::test]
+async fn disable_and_enable() {
+    let plugin_manager = setup().await;
+
+    plugin_manager.disable("Essentials.jar").await.unwrap();
+    assert!(SERVERS_DIR.join("myServer/plugins/.disabled/Essentials.jar").exists());
+
+    let plugins = plugin_manager.plugins(None).await.unwrap();
+    assert!(!plugins[0].enabled);
+    assert_eq!(
+        plugins[1].warnings,
+        vec![PluginWarning::MissingDependency {
+            dependency: "Essentials".to_string()
+        }]
+    );
+
+    plugin_manager.enable("Essentials.jar").await.unwrap();
+    assert!(SERVERS_DIR.join("myServer/plugins/Essentials.jar").exists());
+    assert!(matches!(
+        plugin_manager.enable("Essentials.jar").await,
+        Err(MCManageError::NotFound)
+    ));
+
+    cleanup();
+}
+#[tokio::test]
+async fn upload_invalid() {
+    let plugin_manager = setup().await;
+
+    assert!(matches!(
+        plugin_manager.upload("../evil.jar", &jar(&[("plugin.yml", "name: Evil")])).await,
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    assert!(matches!(
+        plugin_manager.upload("NoPlugin.jar", &jar(&[("fabric.mod.json", "{}")])).await,
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    assert_eq!(plugin_manager.plugins(None).await.unwrap().len(), 3);
+
+    cleanup();
+}
+#[tokio::test]
+async fn delete() {
+    let plugin_manager = setup().await;
+
+    plugin_manager.disable("NewPlugin.jar").await.unwrap();
+    plugin_manager.delete("NewPlugin.jar").await.unwrap();
+    plugin_manager.delete("Essentials.jar").await.unwrap();
+
+    assert_eq!(plugin_manager.plugins(None).await.unwrap().len(), 1);
+    assert!(matches!(
+        plugin_manager.delete("Essentials.jar").await,
+        Err(MCManageError::NotFound)
+    ));
+
+    cleanup();
+}
+#[test]
+fn api_versions() {
+    assert!(supports_api_version("1.20.4", "1.13"));
+    assert!(supports_api_version("1.20.4", "1.20"));
+    assert!(supports_api_version("1.20", "1.20"));
+    assert!(!supports_api_version("1.19.4", "1.20"));
+    assert!(!supports_api_version("1.20.4", "1.20.5"));
+    assert!(supports_api_version("24w03a", "1.20"));
+}
@@ -0,0 +1,518 @@
+//! This module provides the [`ProxyNetwork`] struct, which connects a Velocity or BungeeCord proxy with the Minecraft servers behind it.
+
+use std::{
+    io::ErrorKind,
+    path::Path,
+    sync::Arc,
+};
+
+use goolog::*;
+use rand::{
+    distributions::Alphanumeric,
+    Rng,
+};
+use serde_yaml::{
+    Mapping,
+    Value as YamlValue,
+};
+use tokio::fs;
+use toml::{
+    Table,
+    Value as TomlValue,
+};
+
+use self::{
+    network_members::NetworkMembers,
+    proxy_software::ProxySoftware,
+};
+use crate::{
+    existing_server::property,
+    mcmanage_error::MCManageError,
+    mod_manager::mod_descriptor::ModLoader,
+    server_manager::{
+        server::{
+            network_role::NetworkRole,
+            server_kind::ServerKind,
+            Server,
+        },
+        server_list::ServerList,
+    },
+};
+
+pub mod network_members;
+pub mod proxy_software;
+mod tests;
+
+const GOOLOG_CALLER: &str = "ProxyNetwork";
+/// The file the forwarding secret of a Velocity proxy gets saved to, relative to the directory of the proxy
+const FORWARDING_SECRET_FILE: &str = "forwarding.secret";
+/// The length of a generated forwarding secret
+const FORWARDING_SECRET_LENGTH: usize = 32;
+/// The address the proxy reaches its backends at. The backends run on the same machine as the proxy.
+const BACKEND_HOST: &str = "127.0.0.1";
+/// The port a Minecraft server uses if none is set in its `server.properties` file. It usually belongs to the proxy of a network.
+const DEFAULT_PORT: u16 = 25565;
+/// The first port given to a backend using the [`DEFAULT_PORT`]. Velocity uses the same ports for the servers of its default config.
+const FIRST_BACKEND_PORT: u16 = 30066;
+/// The server types able to receive players forwarded by Velocity using its modern forwarding
+const VELOCITY_FORWARDING_TYPES: [&str; 2] = ["paper", "purpur"];
+/// The server types able to receive players forwarded by BungeeCord
+const BUNGEECORD_FORWARDING_TYPES: [&str; 3] = ["paper", "purpur", "spigot"];
+
+/// This struct represents a network of [`Servers`](Server) behind a Velocity or BungeeCord proxy. \
+/// The servers of a network are marked by their `network` and `network_role` fields, which get set using the server list api. Every network has exactly
+/// one proxy, which players connect to, and at least one backend. Servers without a `network_role` are backends.
+///
+/// Before any server of a network starts, the whole network gets [`configured`](Self::configure): The backends get written to the config of the
+/// proxy, their online-mode gets disabled and the forwarding of the players gets set up.
+pub struct ProxyNetwork {
+    /// The name of this network
+    name: String,
+    /// The software of the proxy
+    software: ProxySoftware,
+    /// The proxy players connect to
+    proxy: Arc<Server>,
+    /// The backends, in the order players get sent to them
+    backends: Vec<Arc<Server>>,
+}
+// actions
+impl ProxyNetwork {
+    /// Get the network with the given name from the [`ServerList`]. See the [`from_servers method`](Self::from_servers).
+    pub async fn get(name: &str) -> Result<Self, MCManageError> {
+        Self::from_servers(name, &ServerList::servers().await)
+    }
+    /// Collect the network with the given name from the given servers.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                                                               |
+    /// |-----------------------------------|-------------------------------------------------------------------------------------------|
+    /// | `Ok(ProxyNetwork)`                | The network got found.                                                                    |
+    /// | [`MCManageError::NotFound`]       | No server belongs to the network.                                                         |
+    /// | [`MCManageError::InvalidRequest`] | The network has no proxy, more than one proxy, a proxy of an unknown type or no backends. |
+    pub fn from_servers(name: &str, servers: &[Arc<Server>]) -> Result<Self, MCManageError> {
+        let members: Vec<&Arc<Server>> = servers
+            .iter()
+            .filter(|server| server.network().as_deref() == Some(name))
+            .collect();
+        if members.is_empty() {
+            return Err(MCManageError::NotFound);
+        }
+
+        let (proxies, backends): (Vec<&Arc<Server>>, Vec<&Arc<Server>>) = members
+            .into_iter()
+            .partition(|server| matches!(server.network_role(), Some(NetworkRole::Proxy)));
+        let proxy = match proxies[..] {
+            [proxy] => proxy.clone(),
+            [] => {
+                return Err(MCManageError::InvalidRequest(format!(
+                    "The network `{name}` has no proxy. Set the network_role of its Velocity or BungeeCord server to \"proxy\"."
+                )))
+            }
+            _ => {
+                return Err(MCManageError::InvalidRequest(format!(
+                    "The network `{name}` has more than one proxy."
+                )))
+            }
+        };
+        let Some(software) = ProxySoftware::from_server_type(&proxy.server_type()) else {
+            return Err(MCManageError::InvalidRequest(format!(
+                "The proxy {} of the network `{name}` is of the server_type {}. Only velocity and bungeecord proxies are supported.",
+                proxy.name(),
+                proxy.server_type()
+            )));
+        };
+        if backends.is_empty() {
+            return Err(MCManageError::InvalidRequest(format!(
+                "The network `{name}` has no backends."
+            )));
+        }
+        if let Some(bedrock_server) = backends
+            .iter()
+            .find(|server| matches!(server.kind(), ServerKind::Bedrock))
+        {
+            return Err(MCManageError::InvalidRequest(format!(
+                "The server {} of the network `{name}` is a Bedrock server. Velocity and BungeeCord only support Java Edition backends.",
+                bedrock_server.name()
+            )));
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            software,
+            proxy,
+            backends: backends.into_iter().cloned().collect(),
+        })
+    }
+    /// Write the backends of this network to the config of its proxy and set up the backends to accept the players forwarded by it. \
+    /// Backends using the port 25565 or no port at all will get a free port starting from 30066, since the default port usually belongs to the proxy.
+    ///
+    /// For Velocity, a forwarding secret will be generated and shared with Paper and Purpur backends in their `config/paper-global.yml` file and with
+    /// Fabric backends in the `config/FabricProxy-Lite.toml` file of the FabricProxy-Lite mod. For BungeeCord, the forwarding will be enabled in the
+    /// `spigot.yml` file of the backends. Backends of other types have to be set up manually.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                         |
+    /// |-----------------------------------|-----------------------------------------------------|
+    /// | `Ok(())`                          | The network got configured.                         |
+    /// | [`MCManageError::InvalidRequest`] | Two backends use the same port.                     |
+    /// | `Err(...)`                        | A config file could not be read, parsed or written. |
+    pub async fn configure(&self) -> Result<(), MCManageError> {
+        let backend_ports = self.backend_ports().await?;
+        match self.software {
+            ProxySoftware::Velocity => {
+                let secret = forwarding_secret(self.proxy.path()).await?;
+                write_velocity_config(self.proxy.path(), &backend_ports).await?;
+                for backend in &self.backends {
+                    self.configure_backend(backend, Some(&secret)).await?;
+                }
+            }
+            ProxySoftware::BungeeCord => {
+                write_bungeecord_config(self.proxy.path(), &backend_ports).await?;
+                for backend in &self.backends {
+                    self.configure_backend(backend, None).await?;
+                }
+            }
+        }
+
+        info!("Configured the network {} with {} backends.", self.name, self.backends.len());
+        Ok(())
+    }
+    /// Start every server of this network. The backends get started one after another before the proxy, so players joining the proxy can be
+    /// forwarded right away. Servers already running will be skipped. \
+    /// This method returns once the proxy started.
+    ///
+    /// # Returns
+    ///
+    /// | Return     | Description                                                                                                |
+    /// |------------|------------------------------------------------------------------------------------------------------------|
+    /// | `Ok(())`   | Every server of this network is running.                                                                   |
+    /// | `Err(...)` | A server failed to start. See the [`Server::impl_start`] method. The servers after it will not be started. |
+    pub async fn start(&self) -> Result<(), MCManageError> {
+        info!("Starting the network {}...", self.name);
+        for server in self.backends.iter().chain([&self.proxy]) {
+            match server.clone().impl_start(false).await {
+                Ok(()) | Err(MCManageError::AlreadyExecuted) => {}
+                Err(error) => {
+                    error!("The network {} could not be started, since {} failed to start. Error: {error}", self.name, server.name());
+                    return Err(error);
+                }
+            }
+        }
+        info!("Started the network {}!", self.name);
+        Ok(())
+    }
+    /// Stop every server of this network. The proxy gets stopped first, so no player gets sent to a stopping backend. Servers already stopped will be
+    /// skipped.
+    ///
+    /// # Returns
+    ///
+    /// | Return     | Description                                                                                              |
+    /// |------------|----------------------------------------------------------------------------------------------------------|
+    /// | `Ok(())`   | Every server of this network is stopped.                                                                 |
+    /// | `Err(...)` | A server failed to stop. See the [`Server::impl_stop`] method. The servers after it will not be stopped. |
+    pub async fn stop(&self) -> Result<(), MCManageError> {
+        info!("Stopping the network {}...", self.name);
+        for server in [&self.proxy].into_iter().chain(self.backends.iter()) {
+            match server.clone().impl_stop(false, false).await {
+                Ok(()) | Err(MCManageError::AlreadyExecuted) => {}
+                Err(error) => {
+                    error!("The network {} could not be stopped, since {} failed to stop. Error: {error}", self.name, server.name());
+                    return Err(error);
+                }
+            }
+        }
+        info!("Stopped the network {}!", self.name);
+        Ok(())
+    }
+}
+// info
+impl ProxyNetwork {
+    /// Return the name of this network.
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+    /// Return which servers belong to this network.
+    pub fn members(&self) -> NetworkMembers {
+        NetworkMembers {
+            name: self.name.clone(),
+            software: self.software,
+            proxy: self.proxy.name(),
+            backends: self.backends.iter().map(|backend| backend.name()).collect(),
+        }
+    }
+}
+// internal
+impl ProxyNetwork {
+    /// Return the name and port of every backend, in the order of the backends. Backends using the [`DEFAULT_PORT`] or no port at all get a free
+    /// port starting from the [`FIRST_BACKEND_PORT`], which will be saved to their `server.properties` file.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                   |
+    /// |-----------------------------------|-----------------------------------------------|
+    /// | `Ok(Vec<(String, u16)>)`          | The name and port of every backend.           |
+    /// | [`MCManageError::InvalidRequest`] | Two backends use the same port.               |
+    /// | `Err(...)`                        | A `server.properties` file could not be read. |
+    async fn backend_ports(&self) -> Result<Vec<(String, u16)>, MCManageError> {
+        let mut ports: Vec<Option<u16>> = vec![];
+        for backend in &self.backends {
+            let port = read_property(backend.path(), "server-port")
+                .await?
+                .and_then(|port| port.parse().ok())
+                .filter(|port| *port != DEFAULT_PORT);
+            if let Some(port) = port {
+                if let Some(other_backend) = ports.iter().position(|other_port| *other_port == Some(port)) {
+                    return Err(MCManageError::InvalidRequest(format!(
+                        "The backends {} and {} of the network `{}` both use the port {port}. Change the server-port of one of them.",
+                        self.backends[other_backend].name(),
+                        backend.name(),
+                        self.name
+                    )));
+                }
+            }
+            ports.push(port);
+        }
+
+        let mut next_port = FIRST_BACKEND_PORT;
+        let mut backend_ports = vec![];
+        for (backend, port) in self.backends.iter().zip(ports.clone()) {
+            let port = match port {
+                Some(port) => port,
+                None => {
+                    while ports.contains(&Some(next_port)) {
+                        next_port += 1;
+                    }
+                    ports.push(Some(next_port));
+                    set_properties(backend.path(), &[("server-port", &next_port.to_string())]).await?;
+                    info!("The backend {} of the network {} will use the port {next_port}.", backend.name(), self.name);
+                    next_port
+                }
+            };
+            backend_ports.push((backend.name(), port));
+        }
+        Ok(backend_ports)
+    }
+    /// Disable the online-mode of the given backend and enable the forwarding of the players sent by the proxy. The `secret` is the forwarding
+    /// secret of a Velocity proxy.
+    async fn configure_backend(&self, backend: &Arc<Server>, secret: Option<&str>) -> Result<(), MCManageError> {
+        // the proxy authenticates the players, the backends only receive them from the proxy
+        set_properties(backend.path(), &[("online-mode", "false")]).await?;
+
+        let server_type = backend.server_type();
+        match (self.software, secret) {
+            (ProxySoftware::Velocity, Some(secret)) if VELOCITY_FORWARDING_TYPES.contains(&server_type.as_str()) => {
+                set_yaml_values(
+                    &backend.path().join("config/paper-global.yml"),
+                    &[
+                        ("proxies.velocity.enabled", YamlValue::Bool(true)),
+                        ("proxies.velocity.online-mode", YamlValue::Bool(true)),
+                        ("proxies.velocity.secret", YamlValue::String(secret.to_string())),
+                    ],
+                )
+                .await
+            }
+            (ProxySoftware::Velocity, Some(secret)) if backend.mod_loader() == Some(ModLoader::Fabric) => {
+                let config_path = backend.path().join("config/FabricProxy-Lite.toml");
+                let mut config = read_toml(&config_path).await?;
+                config.insert("secret".to_string(), secret.into());
+                write_file(&config_path, &toml::to_string(&config)?).await
+            }
+            (ProxySoftware::BungeeCord, _) if BUNGEECORD_FORWARDING_TYPES.contains(&server_type.as_str()) => {
+                set_yaml_values(&backend.path().join("spigot.yml"), &[("settings.bungeecord", YamlValue::Bool(true))]).await
+            }
+            _ => {
+                warn!(
+                    "The backend {} of the network {} is of the server_type {server_type}, which can not be set up to receive the players forwarded by the proxy. Install a mod or plugin supporting the forwarding of {} and set it up manually.",
+                    backend.name(),
+                    self.name,
+                    self.proxy.server_type()
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Return the forwarding secret of the Velocity proxy in the given directory. If it has none yet, a new one will be generated and saved to its
+/// [`FORWARDING_SECRET_FILE`].
+async fn forwarding_secret(proxy_path: &Path) -> Result<String, MCManageError> {
+    let secret_path = proxy_path.join(FORWARDING_SECRET_FILE);
+    if let Ok(secret) = fs::read_to_string(&secret_path).await {
+        if !secret.trim().is_empty() {
+            return Ok(secret.trim().to_string());
+        }
+    }
+
+    let secret: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(FORWARDING_SECRET_LENGTH)
+        .map(char::from)
+        .collect();
+    write_file(&secret_path, &secret).await?;
+    Ok(secret)
+}
+/// Write the given backends to the `velocity.toml` file of the proxy in the given directory and enable the modern forwarding of the players. \
+/// Forced hosts pointing to servers outside of the network get removed, since Velocity refuses to start with them.
+async fn write_velocity_config(proxy_path: &Path, backends: &[(String, u16)]) -> Result<(), MCManageError> {
+    let config_path = proxy_path.join("velocity.toml");
+    let mut config = read_toml(&config_path).await?;
+    let names: Vec<&str> = backends.iter().map(|(name, _)| name.as_str()).collect();
+
+    config.insert("player-info-forwarding-mode".to_string(), "modern".into());
+    config.insert("forwarding-secret-file".to_string(), FORWARDING_SECRET_FILE.into());
+
+    let mut servers = Table::new();
+    for (name, port) in backends {
+        servers.insert(name.clone(), format!("{BACKEND_HOST}:{port}").into());
+    }
+    servers.insert("try".to_string(), names.clone().into());
+    config.insert("servers".to_string(), servers.into());
+
+    if let Some(TomlValue::Table(forced_hosts)) = config.get_mut("forced-hosts") {
+        *forced_hosts = forced_hosts
+            .clone()
+            .into_iter()
+            .filter_map(|(host, targets)| {
+                let targets: Vec<TomlValue> = targets
+                    .as_array()?
+                    .iter()
+                    .filter(|target| target.as_str().map_or(false, |target| names.contains(&target)))
+                    .cloned()
+                    .collect();
+                (!targets.is_empty()).then(|| (host, TomlValue::Array(targets)))
+            })
+            .collect();
+    }
+
+    write_file(&config_path, &toml::to_string(&config)?).await
+}
+/// Write the given backends to the `config.yml` file of the proxy in the given directory and enable the forwarding of the players. \
+/// Every listener of the proxy will send players to the backends in the given order. Forced hosts pointing to servers outside of the network get
+/// removed.
+async fn write_bungeecord_config(proxy_path: &Path, backends: &[(String, u16)]) -> Result<(), MCManageError> {
+    let config_path = proxy_path.join("config.yml");
+    let mut config = read_yaml(&config_path).await?;
+    let names: Vec<&str> = backends.iter().map(|(name, _)| name.as_str()).collect();
+
+    config.insert("ip_forward".into(), true.into());
+
+    let mut servers = Mapping::new();
+    for (name, port) in backends {
+        let mut server = Mapping::new();
+        server.insert("motd".into(), name.as_str().into());
+        server.insert("address".into(), format!("{BACKEND_HOST}:{port}").into());
+        server.insert("restricted".into(), false.into());
+        servers.insert(name.as_str().into(), server.into());
+    }
+    config.insert("servers".into(), servers.into());
+
+    let listeners = config
+        .entry("listeners".into())
+        .or_insert_with(|| YamlValue::Sequence(vec![]));
+    if !listeners.is_sequence() {
+        *listeners = YamlValue::Sequence(vec![]);
+    }
+    if let YamlValue::Sequence(listeners) = listeners {
+        if listeners.is_empty() {
+            let mut listener = Mapping::new();
+            listener.insert("host".into(), "0.0.0.0:25577".into());
+            listeners.push(listener.into());
+        }
+        for listener in listeners.iter_mut().filter_map(YamlValue::as_mapping_mut) {
+            listener.insert("priorities".into(), names.clone().into());
+            if let Some(YamlValue::Mapping(forced_hosts)) = listener.get_mut("forced_hosts") {
+                *forced_hosts = forced_hosts
+                    .clone()
+                    .into_iter()
+                    .filter(|(_, target)| target.as_str().map_or(false, |target| names.contains(&target)))
+                    .collect();
+            }
+        }
+    }
+
+    write_file(&config_path, &serde_yaml::to_string(&config)?).await
+}
+
+/// Read the value of the given key from the `server.properties` file in the given server directory. A missing file has no values.
+async fn read_property(server_path: &Path, key: &str) -> Result<Option<String>, MCManageError> {
+    match fs::read_to_string(server_path.join("server.properties")).await {
+        Ok(properties) => Ok(property(&properties, key)),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error.into()),
+    }
+}
+/// Set the given keys of the `server.properties` file in the given server directory. Every other line, including comments, will be kept. If the
+/// file does not exist yet, it will be created.
+async fn set_properties(server_path: &Path, values: &[(&str, &str)]) -> Result<(), MCManageError> {
+    let properties_path = server_path.join("server.properties");
+    let properties = fs::read_to_string(&properties_path).await.unwrap_or_default();
+
+    let mut lines: Vec<String> = properties.lines().map(str::to_string).collect();
+    for (key, value) in values {
+        let existing_line = lines.iter_mut().find(|line| {
+            !line.trim_start().starts_with('#') && line.split_once('=').map_or(false, |(line_key, _)| line_key.trim() == *key)
+        });
+        match existing_line {
+            Some(line) => *line = format!("{key}={value}"),
+            None => lines.push(format!("{key}={value}")),
+        }
+    }
+
+    write_file(&properties_path, &(lines.join("\n") + "\n")).await
+}
+/// Read the toml file at the given path. A missing file is an empty table.
+async fn read_toml(path: &Path) -> Result<Table, MCManageError> {
+    match fs::read_to_string(path).await {
+        Ok(content) => Ok(toml::from_str(&content)?),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(Table::new()),
+        Err(error) => Err(error.into()),
+    }
+}
+/// Read the yaml file at the given path. A missing or empty file is an empty mapping.
+async fn read_yaml(path: &Path) -> Result<Mapping, MCManageError> {
+    match fs::read_to_string(path).await {
+        Ok(content) if content.trim().is_empty() => Ok(Mapping::new()),
+        Ok(content) => Ok(serde_yaml::from_str(&content)?),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(Mapping::new()),
+        Err(error) => Err(error.into()),
+    }
+}
+/// Set the values of the yaml file at the given path. Every value is saved under a path of keys separated by dots, like `proxies.velocity.secret`.
+/// Missing mappings on the way will be created. Every other value of the file will be kept.
+async fn set_yaml_values(path: &Path, values: &[(&str, YamlValue)]) -> Result<(), MCManageError> {
+    /// Insert the value under the given path of keys into the given mapping.
+    fn insert(mapping: &mut Mapping, keys: &[&str], value: YamlValue) {
+        let Some((key, remaining_keys)) = keys.split_first() else {
+            return;
+        };
+        if remaining_keys.is_empty() {
+            mapping.insert((*key).into(), value);
+            return;
+        }
+        let child = mapping
+            .entry((*key).into())
+            .or_insert_with(|| Mapping::new().into());
+        if !child.is_mapping() {
+            *child = Mapping::new().into();
+        }
+        if let YamlValue::Mapping(child) = child {
+            insert(child, remaining_keys, value);
+        }
+    }
+
+    let mut config = read_yaml(path).await?;
+    for (keys, value) in values {
+        insert(&mut config, &keys.split('.').collect::<Vec<&str>>(), value.clone());
+    }
+    write_file(path, &serde_yaml::to_string(&config)?).await
+}
+/// Write the given content to the file at the given path. Missing directories will be created.
+async fn write_file(path: &Path, content: &str) -> Result<(), MCManageError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    fs::write(path, content).await?;
+    Ok(())
+}
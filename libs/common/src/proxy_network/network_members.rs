@@ -0,0 +1,19 @@
+//! This module provides the [`NetworkMembers`] struct.
+
+use proc_macros::add_convert;
+
+use super::proxy_software::ProxySoftware;
+use crate::mcmanage_error::MCManageError;
+
+/// This struct describes which [`Servers`](crate::server_manager::server::Server) belong to a [`ProxyNetwork`](super::ProxyNetwork).
+#[add_convert]
+pub struct NetworkMembers {
+    /// The name of the network
+    pub name: String,
+    /// The software of the proxy
+    pub software: ProxySoftware,
+    /// The name of the proxy
+    pub proxy: String,
+    /// The names of the backends, in the order players get sent to them
+    pub backends: Vec<String>,
+}
@@ -0,0 +1,26 @@
+//! This module provides the [`ProxySoftware`] enum.
+
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This enum represents the proxies a [`ProxyNetwork`](super::ProxyNetwork) can be built around.
+#[add_convert]
+#[derive(Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ProxySoftware {
+    /// A Velocity proxy. It gets configured using its `velocity.toml` file and forwards players using a secret shared with its backends.
+    Velocity,
+    /// A BungeeCord or Waterfall proxy. It gets configured using its `config.yml` file.
+    BungeeCord,
+}
+impl ProxySoftware {
+    /// Return the proxy software of the given server type. If the server type is no proxy, `None` will be returned.
+    pub fn from_server_type(server_type: &str) -> Option<Self> {
+        match server_type.to_lowercase().as_str() {
+            "velocity" => Some(Self::Velocity),
+            "bungeecord" | "waterfall" => Some(Self::BungeeCord),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,188 @@
+#![cfg(test)]
+
+use std::time::Duration;
+
+use super::*;
+use crate::{
+    generated_files::paths::SERVERS_DIR,
+    server_manager::{
+        server::{
+            server_kind::ServerKind,
+            update_policy::UpdatePolicy,
+        },
+        server_item::ServerItem,
+    },
+    test_functions::*,
+};
+
+/// Create a [`Server`] of the given type belonging to the network `myNetwork`.
+async fn server(name: &str, server_type: &str, network_role: Option<NetworkRole>) -> Arc<Server> {
+    Server::new(
+        name,
+        ServerItem {
+            args: format!("-jar {server_type}.jar nogui"),
+            download_from: "".to_string(),
+            version: None,
+            build: None,
+            update_policy: UpdatePolicy::Disabled,
+            server_type: server_type.to_string(),
+            restart_time: Duration::ZERO,
+            eula_accepted_by: None,
+            network: Some("myNetwork".to_string()),
+            network_role,
+            kind: if server_type == "bedrock" { ServerKind::Bedrock } else { ServerKind::Java },
+            depends_on: vec![],
+            start_delay: Duration::ZERO,
+        },
+    )
+    .await
+}
+/// Write the given file of the given server.
+async fn write(server: &str, file: &str, content: &str) {
+    let path = SERVERS_DIR.join(server).join(file);
+    fs::create_dir_all(path.parent().unwrap()).await.unwrap();
+    fs::write(path, content).await.unwrap();
+}
+/// Read the given file of the given server.
+async fn read(server: &str, file: &str) -> String {
+    fs::read_to_string(SERVERS_DIR.join(server).join(file)).await.unwrap()
+}
+
+#[tokio::test]
+async fn velocity_network() {
+    start_test();
+    let servers = vec![
+        server("myProxy", "velocity", Some(NetworkRole::Proxy)).await,
+        server("lobby", "paper", Some(NetworkRole::Backend)).await,
+        server("survival", "fabric", None).await,
+    ];
+    write("myProxy", "velocity.toml", "bind = \"0.0.0.0:25565\"\n\n[forced-hosts]\n\"lobby.example.com\" = [\"lobby\"]\n\"factions.example.com\" = [\"factions\"]\n").await;
+    write("lobby", "server.properties", "#Minecraft server properties\nonline-mode=true\nserver-port=25565\nmotd=Lobby\n").await;
+    write("lobby", "config/paper-global.yml", "proxies:\n  bungee-cord:\n    online-mode: true\n").await;
+
+    let network = ProxyNetwork::from_servers("myNetwork", &servers).unwrap();
+    assert_eq!(
+        network.members(),
+        NetworkMembers {
+            name: "myNetwork".to_string(),
+            software: ProxySoftware::Velocity,
+            proxy: "myProxy".to_string(),
+            backends: vec!["lobby".to_string(), "survival".to_string()],
+        }
+    );
+    network.configure().await.unwrap();
+
+    let secret = read("myProxy", FORWARDING_SECRET_FILE).await;
+    assert_eq!(secret.len(), FORWARDING_SECRET_LENGTH);
+
+    let velocity_config: Table = toml::from_str(&read("myProxy", "velocity.toml").await).unwrap();
+    assert_eq!(velocity_config["bind"].as_str(), Some("0.0.0.0:25565"));
+    assert_eq!(velocity_config["player-info-forwarding-mode"].as_str(), Some("modern"));
+    assert_eq!(velocity_config["forwarding-secret-file"].as_str(), Some(FORWARDING_SECRET_FILE));
+    assert_eq!(velocity_config["servers"]["lobby"].as_str(), Some("127.0.0.1:30066"));
+    assert_eq!(velocity_config["servers"]["survival"].as_str(), Some("127.0.0.1:30067"));
+    assert_eq!(velocity_config["servers"]["try"], TomlValue::from(vec!["lobby", "survival"]));
+    // forced hosts pointing to servers outside of the network would stop Velocity from starting
+    let forced_hosts = velocity_config["forced-hosts"].as_table().unwrap();
+    assert_eq!(forced_hosts.len(), 1);
+    assert_eq!(forced_hosts["lobby.example.com"], TomlValue::from(vec!["lobby"]));
+
+    assert_eq!(
+        read("lobby", "server.properties").await,
+        "#Minecraft server properties\nonline-mode=false\nserver-port=30066\nmotd=Lobby\n"
+    );
+    assert_eq!(read("survival", "server.properties").await, "server-port=30067\nonline-mode=false\n");
+
+    let paper_global: YamlValue = serde_yaml::from_str(&read("lobby", "config/paper-global.yml").await).unwrap();
+    assert_eq!(paper_global["proxies"]["bungee-cord"]["online-mode"], YamlValue::Bool(true));
+    assert_eq!(paper_global["proxies"]["velocity"]["enabled"], YamlValue::Bool(true));
+    assert_eq!(paper_global["proxies"]["velocity"]["secret"].as_str(), Some(secret.as_str()));
+
+    let fabric_proxy: Table = toml::from_str(&read("survival", "config/FabricProxy-Lite.toml").await).unwrap();
+    assert_eq!(fabric_proxy["secret"].as_str(), Some(secret.as_str()));
+
+    // configuring the network again must not change anything
+    network.configure().await.unwrap();
+    assert_eq!(read("myProxy", FORWARDING_SECRET_FILE).await, secret);
+    assert_eq!(read("survival", "server.properties").await, "server-port=30067\nonline-mode=false\n");
+
+    cleanup();
+}
+#[tokio::test]
+async fn bungeecord_network() {
+    start_test();
+    let servers = vec![
+        server("lobby", "purpur", None).await,
+        server("myProxy", "bungeecord", Some(NetworkRole::Proxy)).await,
+    ];
+    write(
+        "myProxy",
+        "config.yml",
+        "online_mode: true\nlisteners:\n- host: 0.0.0.0:25565\n  priorities:\n  - lobby\n  - factions\n  forced_hosts:\n    pvp.md-5.net: pvp\n    lobby.example.com: lobby\n",
+    )
+    .await;
+    write("lobby", "server.properties", "server-port=25570\n").await;
+
+    let network = ProxyNetwork::from_servers("myNetwork", &servers).unwrap();
+    network.configure().await.unwrap();
+
+    let bungeecord_config: YamlValue = serde_yaml::from_str(&read("myProxy", "config.yml").await).unwrap();
+    assert_eq!(bungeecord_config["online_mode"], YamlValue::Bool(true));
+    assert_eq!(bungeecord_config["ip_forward"], YamlValue::Bool(true));
+    assert_eq!(bungeecord_config["servers"]["lobby"]["address"].as_str(), Some("127.0.0.1:25570"));
+    let listener = &bungeecord_config["listeners"][0];
+    assert_eq!(listener["host"].as_str(), Some("0.0.0.0:25565"));
+    assert_eq!(listener["priorities"], YamlValue::from(vec!["lobby"]));
+    assert_eq!(listener["forced_hosts"].as_mapping().unwrap().len(), 1);
+    assert_eq!(listener["forced_hosts"]["lobby.example.com"].as_str(), Some("lobby"));
+
+    assert_eq!(read("lobby", "server.properties").await, "server-port=25570\nonline-mode=false\n");
+    let spigot_config: YamlValue = serde_yaml::from_str(&read("lobby", "spigot.yml").await).unwrap();
+    assert_eq!(spigot_config["settings"]["bungeecord"], YamlValue::Bool(true));
+    assert!(!SERVERS_DIR.join("myProxy").join(FORWARDING_SECRET_FILE).exists());
+
+    cleanup();
+}
+#[tokio::test]
+async fn invalid_networks() {
+    start_test();
+    let proxy = server("myProxy", "velocity", Some(NetworkRole::Proxy)).await;
+    let second_proxy = server("mySecondProxy", "bungeecord", Some(NetworkRole::Proxy)).await;
+    let no_proxy = server("myPurpurProxy", "purpur", Some(NetworkRole::Proxy)).await;
+    let lobby = server("lobby", "paper", None).await;
+    let survival = server("survival", "paper", None).await;
+    let bedrock = server("bedrock", "bedrock", None).await;
+
+    assert!(matches!(
+        ProxyNetwork::from_servers("myOtherNetwork", &[proxy.clone(), lobby.clone()]),
+        Err(MCManageError::NotFound)
+    ));
+    assert!(matches!(
+        ProxyNetwork::from_servers("myNetwork", &[lobby.clone()]),
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    assert!(matches!(
+        ProxyNetwork::from_servers("myNetwork", &[proxy.clone(), second_proxy, lobby.clone()]),
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    assert!(matches!(
+        ProxyNetwork::from_servers("myNetwork", &[no_proxy, lobby.clone()]),
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    assert!(matches!(
+        ProxyNetwork::from_servers("myNetwork", &[proxy.clone()]),
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    assert!(matches!(
+        ProxyNetwork::from_servers("myNetwork", &[proxy.clone(), lobby.clone(), bedrock]),
+        Err(MCManageError::InvalidRequest(_))
+    ));
+
+    // two backends can not share a port
+    write("lobby", "server.properties", "server-port=25570\n").await;
+    write("survival", "server.properties", "server-port=25570\n").await;
+    let network = ProxyNetwork::from_servers("myNetwork", &[proxy, lobby, survival]).unwrap();
+    assert!(matches!(network.configure().await, Err(MCManageError::InvalidRequest(_))));
+
+    cleanup();
+}
@@ -25,41 +25,50 @@ use self::{
 };
 use crate::{
     config::Config,
+    database_server::{
+        init_db_server,
+        stop_db_server,
+    },
     generated_files::{
         load_toml_file::load_toml,
         paths::SERVER_LIST_FILE,
     },
     mcmanage_error::MCManageError,
     status::Status,
+    storage::database_backend::DatabaseBackend,
     types::ThreadJoinHandle, concurrent_class::check_allowed::check_allowed_start, server_manager::server_list::ServerList,
 };
 use chrono::prelude::*;
 
 pub mod server;
-mod server_item;
+pub mod server_item;
 mod tests;
-mod server_list;
+pub mod server_list;
+pub mod start_order;
 
 const GOOLOG_CALLER: &str = "ServerManager";
 static SERVER_MANAGER: OnceCell<ServerManager> = OnceCell::const_new();
 
 
-// FIXME When specifying a ram limit like `-Xmx=4G` in the `servers/server_list.toml` file, the Minecraft server can fail to start. (only when starting them via the ServerManager)
+// FIXME When specifying a ram limit like `-Xmx=4G` in the args of a server, the Minecraft server can fail to start. (only when starting them via the ServerManager)
 // TODO constantly update Server list
 // FIXME Register errors, like "java.net.BindException: Address already in use: bind" from the Minecraft server and print them to the console (do not crash the application -> instead, stop Minecraft server)
 // TODO Make the server.properties file editable
 // TODO Make tests able to run concurrently
 
 /// This struct is responsible for managing all [`Servers`](Server). ( starting, stopping, ... ) \
-/// In more detail, it creates [`Server`] structs accordingly to the `servers/server_list.toml` file. Additionally it will also start a thread which:
+/// In more detail, it starts the [`Servers`](Server) of the [`ServerList`] in their start order. Additionally it will also start a thread which:
 ///     - If set, will shut down the computer that is running this application.
 ///     - If enabled, will restart Minecraft servers automatically.
 ///
+/// A second thread will regularly check every [`Server`] for newer builds of its Minecraft version. See the [`Server::check_for_update`] method.
+///
 /// # Warning
-/// When specifying a ram limit like `-Xmx=4G` in the `servers/server_list.toml` file, the Minecraft server can fail to start.
+/// When specifying a ram limit like `-Xmx=4G` in the args of a server, the Minecraft server can fail to start.
 // #[derive(ConcurrentClass)]
 pub struct ServerManager {
     main_thread: JoinHandle<Result<(), MCManageError>>,
+    updater_thread: JoinHandle<()>,
 }
 impl ServerManager {
     /// Get the [`ServerManager`].
@@ -78,12 +87,17 @@ impl ServerManager {
     // TODO doc
     // TODO move method used only in here to here
     pub async fn init() {
-        ServerList::init();
+        if let DatabaseBackend::Postgres = Config::database_backend().await {
+            init_db_server().await;
+        }
+        ServerList::init().await;
 
         let main_thread = spawn(Self::main());
+        let updater_thread = spawn(Self::updater());
 
         if SERVER_MANAGER.set(Self {
             main_thread,
+            updater_thread,
         }).is_err() {
             fatal!("Already initialized.")
         }
@@ -100,7 +114,11 @@ impl ServerManager {
         info!("Shutting down...");
         let stop_time = Instant::now();
 
+        Self::stop_all().await?;
         ServerList::stop().await;
+        if let DatabaseBackend::Postgres = Config::database_backend().await {
+            stop_db_server().await;
+        }
 
         server_manager
             .main_thread
@@ -111,6 +129,7 @@ impl ServerManager {
             //     fatal!("The main thread should have been set by now.")
             // })
             .abort();
+        server_manager.updater_thread.abort();
 
         info!(
             "Stopped in {:.3} secs!",
@@ -207,8 +226,135 @@ impl ServerManager {
     //     }
     // }
 
+    /// Start every [`Server`] in the [`StartOrder`](start_order::StartOrder) of the [`ServerList`]. See the [`start_servers`](Self::start_servers)
+    /// method for more information.
+    pub async fn start_all() -> Result<(), MCManageError> {
+        Self::start_servers(&ServerList::start_order().await).await
+    }
+    /// Stop every [`Server`] in the reverse [`StartOrder`](start_order::StartOrder) of the [`ServerList`]. See the [`stop_servers`](Self::stop_servers)
+    /// method for more information.
+    pub async fn stop_all() -> Result<(), MCManageError> {
+        Self::stop_servers(&ServerList::start_order().await).await
+    }
+    /// Start every [`Server`] of the given [`StartOrder`](start_order::StartOrder) in its order. \
+    /// Every server waits for the servers it depends on to be started and then for its start delay. Servers which are already running will be
+    /// left untouched. If a server fails to start, only the servers depending on it, directly or not, will not be started.
+    ///
+    /// # Returns
+    ///
+    /// | Return                           | Description                                                                        |
+    /// |----------------------------------|------------------------------------------------------------------------------------|
+    /// | `Ok(())`                         | Every server got started.                                                          |
+    /// | [`MCManageError::ServersFailed`] | Some servers failed to start or got skipped, since a server they depend on failed. |
+    async fn start_servers(start_order: &start_order::StartOrder) -> Result<(), MCManageError> {
+        let mut failed: Vec<(String, MCManageError)> = vec![];
+        for name in start_order.start_order() {
+            let failed_dependency = start_order
+                .dependencies(name)
+                .iter()
+                .find(|dependency| failed.iter().any(|(failed_name, _)| failed_name == *dependency));
+            if let Some(dependency) = failed_dependency {
+                warn!("{name} will not be started, since the server {dependency} it depends on could not be started.");
+                failed.push((name.clone(), MCManageError::NotReady));
+                continue;
+            }
+
+            if let Err(error) = Self::start_server(name, start_order.dependencies(name)).await {
+                error!("{name} failed to start. The servers depending on it will not be started. Error: {error}");
+                failed.push((name.clone(), error));
+            }
+        }
+
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(MCManageError::ServersFailed(failed))
+        }
+    }
+    /// Stop every [`Server`] of the given [`StartOrder`](start_order::StartOrder) in its reverse order, so no server loses a dependency while it is
+    /// still running. \
+    /// Servers which are already stopped will be left untouched.
+    ///
+    /// # Returns
+    ///
+    /// | Return     | Description                                                                      |
+    /// |------------|----------------------------------------------------------------------------------|
+    /// | `Ok(())`   | Every server got stopped.                                                        |
+    /// | `Err(...)` | A server failed to stop. The servers preceding it in the order were not stopped. |
+    async fn stop_servers(start_order: &start_order::StartOrder) -> Result<(), MCManageError> {
+        for name in start_order.stop_order() {
+            let server = ServerList::get(&name).await?;
+            match server.clone().impl_stop(false, false).await {
+                Ok(()) | Err(MCManageError::AlreadyExecuted) => {}
+                Err(error) => {
+                    error!("{name} failed to stop. The servers it depends on will not be stopped. Error: {error}");
+                    return Err(error);
+                }
+            }
+        }
+        Ok(())
+    }
+    /// Start the [`Server`] with the given name once the given servers it depends on got started and its start delay passed. \
+    /// If the server is already running, it will be left untouched. If it is currently starting, this method waits until it got started.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                                                   |
+    /// |-----------------------------|---------------------------------------------------------------|
+    /// | `Ok(())`                    | The server got started.                                       |
+    /// | [`MCManageError::NotFound`] | The server or one of its dependencies does not exist.         |
+    /// | [`MCManageError::NotReady`] | A dependency got stopped while the server was waiting for it. |
+    /// | `Err(...)`                  | See the [`Server::impl_start`] method.                        |
+    async fn start_server(name: &str, dependencies: &[String]) -> Result<(), MCManageError> {
+        let server = ServerList::get(name).await?;
+        for dependency in dependencies {
+            Self::wait_until_started(&ServerList::get(dependency).await?).await?;
+        }
+        if let Status::Stopped = server.status().await {
+            if !server.start_delay().is_zero() {
+                info!("Waiting {:?} before starting {name}.", server.start_delay());
+                sleep(server.start_delay()).await;
+            }
+        }
+
+        match server.clone().impl_start(false).await {
+            Ok(()) | Err(MCManageError::AlreadyExecuted) => Ok(()),
+            Err(MCManageError::CurrentlyExecuting) => Self::wait_until_started(&server).await,
+            Err(error) => Err(error),
+        }
+    }
+    /// Wait until the given [`Server`] got started. \
+    /// If it is stopped or stopping, it will not be started anytime soon, so an [`MCManageError::NotReady`] will be returned.
+    async fn wait_until_started(server: &Arc<Server>) -> Result<(), MCManageError> {
+        loop {
+            match server.status().await {
+                Status::Started => return Ok(()),
+                Status::Stopped | Status::Stopping => {
+                    error!("The server {} is not running.", server.name());
+                    return Err(MCManageError::NotReady);
+                }
+                Status::Starting | Status::Restarting => sleep(Config::cooldown().await).await,
+            }
+        }
+    }
+    /// Check every [`Server`] for a newer build of its Minecraft version every [`Config::update_check_interval`].
+    async fn updater() {
+        loop {
+            sleep(Config::update_check_interval().await).await;
+
+            for server in ServerList::servers().await.iter() {
+                if let Err(error) = server.check_for_update().await {
+                    warn!("Failed to check {} for updates. Error: {error}", server.name());
+                }
+            }
+        }
+    }
     /// This represents the main loop of a given struct.
     async fn main() -> Result<(), MCManageError> {
+        if let Err(error) = Self::start_all().await {
+            error!("Not every server could be started. Error: {error}");
+        }
+
         // let server_manager = Self::server_manager();
 
         // let mut offline_counter: Option<Instant> = None;
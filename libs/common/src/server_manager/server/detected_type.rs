@@ -0,0 +1,50 @@
+//! This module provides the [`DetectedType`] struct.
+
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use proc_macros::add_convert;
+use tokio::fs;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This struct records which [`ServerType`](super::mcserver_type::ServerType) got detected for a [`Server`](super::Server) without a configured
+/// server_type. \
+/// It gets saved in the server's directory, so the detection does not have to be repeated at every start.
+#[add_convert]
+pub struct DetectedType {
+    /// The name of the detected server type
+    pub server_type: String,
+    /// Whether the server type got detected from the output of the Minecraft server or only from its jar. A detection from the output is more
+    /// reliable and will not be replaced by one from the jar.
+    pub from_output: bool,
+    /// The time the server type got detected at
+    pub detected_at: DateTime<Utc>,
+}
+impl DetectedType {
+    /// Return the path to the file this struct gets saved to for the given server directory.
+    fn file_path(server_path: &Path) -> PathBuf {
+        server_path.join(".mcmanage").join("detected_type.json")
+    }
+    /// Load the [`DetectedType`] of the given server directory. \
+    /// If none was saved, or the saved one is invalid, `None` will be returned.
+    pub async fn load(server_path: &Path) -> Option<Self> {
+        let content = fs::read(Self::file_path(server_path)).await.ok()?;
+        Self::try_from(content).ok()
+    }
+    /// Save this [`DetectedType`] for the given server directory.
+    pub async fn save(&self, server_path: &Path) -> Result<(), MCManageError> {
+        let file_path = Self::file_path(server_path);
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::write(file_path, serde_json::to_vec_pretty(self)?).await?;
+        Ok(())
+    }
+}
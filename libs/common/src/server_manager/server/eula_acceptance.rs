@@ -0,0 +1,64 @@
+//! This module provides the [`EulaAcceptance`] struct.
+
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use proc_macros::add_convert;
+use tokio::fs;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This struct records who accepted the [Minecraft EULA](https://aka.ms/MinecraftEULA) for a [`Server`](super::Server), and when. \
+/// A [`Server`](super::Server) will only write its `eula.txt` file and start once this record exists.
+#[add_convert]
+pub struct EulaAcceptance {
+    /// The name of the server the EULA got accepted for.
+    pub server: String,
+    /// The person who accepted the EULA.
+    pub accepted_by: String,
+    /// The time the EULA got accepted at.
+    pub accepted_at: DateTime<Utc>,
+}
+impl EulaAcceptance {
+    /// Return the path to the file this struct gets saved to for the given server directory.
+    fn file_path(server_path: &Path) -> PathBuf {
+        server_path.join(".mcmanage").join("eula_acceptance.json")
+    }
+    /// Load the [`EulaAcceptance`] of the given server directory. \
+    /// If none was saved, or the saved one is invalid, `None` will be returned.
+    pub async fn load(server_path: &Path) -> Option<Self> {
+        let content = fs::read(Self::file_path(server_path)).await.ok()?;
+        Self::try_from(content).ok()
+    }
+    /// Save this [`EulaAcceptance`] for the given server directory.
+    pub async fn save(&self, server_path: &Path) -> Result<(), MCManageError> {
+        let file_path = Self::file_path(server_path);
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::write(file_path, serde_json::to_vec_pretty(self)?).await?;
+        Ok(())
+    }
+    /// Write the `eula.txt` file of the given server directory. The accepting person and time will be noted as comments above the `eula=true` line.
+    pub async fn write_eula_txt(&self, server_path: &Path) -> Result<(), MCManageError> {
+        fs::create_dir_all(server_path).await?;
+        fs::write(
+            server_path.join("eula.txt"),
+            format!(
+                "#By changing the setting below to TRUE you are indicating your agreement to our EULA (https://aka.ms/MinecraftEULA).\n\
+                 #Accepted by {} at {} using MCManage.\n\
+                 eula=true\n",
+                self.accepted_by,
+                self.accepted_at.to_rfc3339()
+            ),
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+//! This module provides the [`InstalledBuild`] struct.
+
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use proc_macros::add_convert;
+use tokio::fs;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This struct records which build of a Minecraft server software is installed in a [`Server's`](super::Server) directory. \
+/// It only exists for jars resolved by a [`VersionProvider`](crate::version_provider::VersionProvider), since the build of any other jar is unknown.
+#[add_convert]
+pub struct InstalledBuild {
+    /// The Minecraft version of the installed jar.
+    pub version: String,
+    /// The build of the installed jar.
+    pub build: String,
+    /// Every build which failed to start after an update. These will not be installed again.
+    #[serde(default)]
+    pub failed_builds: Vec<String>,
+}
+impl InstalledBuild {
+    /// Return the path to the file this struct gets saved to for the given server directory.
+    fn file_path(server_path: &Path) -> PathBuf {
+        server_path.join(".mcmanage").join("installed_build.json")
+    }
+    /// Load the [`InstalledBuild`] of the given server directory. \
+    /// If none was saved, or the saved one is invalid, `None` will be returned.
+    pub async fn load(server_path: &Path) -> Option<Self> {
+        let content = fs::read(Self::file_path(server_path)).await.ok()?;
+        Self::try_from(content).ok()
+    }
+    /// Save this [`InstalledBuild`] for the given server directory.
+    pub async fn save(&self, server_path: &Path) -> Result<(), MCManageError> {
+        let file_path = Self::file_path(server_path);
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::write(file_path, serde_json::to_vec_pretty(self)?).await?;
+        Ok(())
+    }
+}
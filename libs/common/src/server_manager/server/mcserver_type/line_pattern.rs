@@ -0,0 +1,112 @@
+//! This module provides the [`LinePattern`] enum, which describes how a message of a Minecraft server can be recognized.
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use goolog::*;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use toml::Value;
+
+use crate::mcmanage_error::MCManageError;
+
+const GOOLOG_CALLER: &str = "LinePattern";
+/// Every regex compiled so far, saved under its pattern. Patterns get used for every line a Minecraft server prints, so they only get compiled once.
+static COMPILED_PATTERNS: Lazy<Mutex<HashMap<String, Arc<Regex>>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+/// The ANSI escape sequences and legacy `§` color codes Minecraft servers use to color their output
+static COLOR_CODES: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\x1b\[[0-9;]*[A-Za-z]|§[0-9a-fk-orA-FK-OR]")
+        .unwrap_or_else(|error| fatal!("The color code pattern is invalid. Error: {error}"))
+});
+
+/// This enum describes a pattern of the `config/server_types.toml` file, which a line printed by a Minecraft server has to match.
+#[derive(Clone, Debug)]
+pub enum LinePattern {
+    /// A list of strings the line has to contain. The values inside of the line, like the name of a player, are read by their position. \
+    /// In the `config/server_types.toml` file, this pattern is written as a list: `player_joined = [" joined the game"]`
+    Substrings(Vec<String>),
+    /// A regex the line has to match. The values inside of the line are read from the named groups of the regex, like `(?P<player>\w+)`. \
+    /// In the `config/server_types.toml` file, this pattern is written as a single string: `player_joined = '(?P<player>\w+) joined the game$'`
+    Regex(Arc<Regex>),
+}
+impl LinePattern {
+    /// Read a pattern from a value of the `config/server_types.toml` file. A list of strings will be read as [`Substrings`](Self::Substrings) and a
+    /// single string as a [`Regex`](Self::Regex).
+    ///
+    /// # Returns
+    ///
+    /// | Return                         | Description                                |
+    /// |--------------------------------|--------------------------------------------|
+    /// | `Ok(LinePattern)`              | The value is a valid pattern.              |
+    /// | [`MCManageError::InvalidFile`] | The value is neither a list nor a string.  |
+    /// | [`MCManageError::RegexError`]  | The value is a string, but no valid regex. |
+    pub fn from_value(value: &Value) -> Result<Self, MCManageError> {
+        match value {
+            Value::String(pattern) => Ok(Self::Regex(Self::compile(pattern)?)),
+            Value::Array(items) => items
+                .iter()
+                .map(|item| item.as_str().map(str::to_string).ok_or(MCManageError::InvalidFile))
+                .collect::<Result<Vec<String>, MCManageError>>()
+                .map(Self::Substrings),
+            _ => Err(MCManageError::InvalidFile),
+        }
+    }
+    /// Compile the given regex. Every pattern will only be compiled once, afterwards the compiled regex will be taken from a cache.
+    pub fn compile(pattern: &str) -> Result<Arc<Regex>, MCManageError> {
+        let mut compiled_patterns = COMPILED_PATTERNS
+            .lock()
+            .unwrap_or_else(|error| fatal!("The cache of compiled patterns got poisoned. Error: {error}"));
+        if let Some(regex) = compiled_patterns.get(pattern) {
+            return Ok(regex.clone());
+        }
+
+        let regex = Arc::new(Regex::new(pattern)?);
+        compiled_patterns.insert(pattern.to_string(), regex.clone());
+        Ok(regex)
+    }
+
+    /// Check whether or not the given line matches this pattern. Color codes inside of the line will be ignored.
+    pub fn matches(&self, line: &str) -> bool {
+        let line = strip_colors(line);
+        match self {
+            Self::Substrings(items) => items.iter().all(|item| line.contains(item)),
+            Self::Regex(regex) => regex.is_match(&line),
+        }
+    }
+    /// Return the named groups of this pattern found in the given line, saved under their names. Color codes inside of the line will be ignored. \
+    /// If the line does not match this pattern or this pattern is no [`Regex`](Self::Regex), `None` will be returned.
+    pub fn captures(&self, line: &str) -> Option<HashMap<String, String>> {
+        let Self::Regex(regex) = self else {
+            return None;
+        };
+        let line = strip_colors(line);
+        let captures = regex.captures(&line)?;
+        Some(
+            regex
+                .capture_names()
+                .flatten()
+                .filter_map(|name| Some((name.to_string(), captures.name(name)?.as_str().to_string())))
+                .collect(),
+        )
+    }
+}
+impl PartialEq for LinePattern {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Substrings(items), Self::Substrings(other_items)) => items == other_items,
+            (Self::Regex(regex), Self::Regex(other_regex)) => regex.as_str() == other_regex.as_str(),
+            _ => false,
+        }
+    }
+}
+
+/// Remove every color code from the given line.
+pub fn strip_colors(line: &str) -> Cow<str> {
+    COLOR_CODES.replace_all(line, "")
+}
@@ -1,14 +1,25 @@
 //! This module provides the [`ServerType struct`](ServerType), which is used to read the `config/server_types.toml` file and provide the [`Server`](super::Server) with strings
 //! corresponding to different situations, like a player joining or leaving.
 
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        RwLock,
+    },
+    time::SystemTime,
+};
 
 use async_recursion::async_recursion;
 use tokio::{
+    fs,
     sync::Mutex,
     time::sleep,
 };
-use toml::Value;
+use toml::{
+    Table,
+    Value,
+};
 
 use goolog::*;
 
@@ -22,29 +33,70 @@ use crate::{
         paths::MCSERVER_TYPES_FILE,
     },
     mcmanage_error::MCManageError,
+    player_identities::PlayerIdentities,
 };
 
+use self::line_pattern::{
+    strip_colors,
+    LinePattern,
+};
+
+pub mod line_pattern;
 mod tests;
 
+/// The command used to stop a Minecraft server, if its type does not define another one
+const DEFAULT_STOP_COMMAND: &str = "stop";
+
+/// The parsed `config/server_types.toml` file together with the modification time and length the file had when it got read. See the
+/// [`server_types function`](server_types).
+static SERVER_TYPES: Mutex<Option<((SystemTime, u64), Arc<Table>)>> = Mutex::const_new(None);
+
 /// With this struct, the [`Server`](super::Server) is able to interpret messages sent by a Minecraft server. \
 /// To be exact, this struct is responsible for reading the `config/server_types.toml` file and providing the [`Server`](super::Server) with strings corresponding to
-/// different situations, like a player joining or leaving.
+/// different situations, like a player joining or leaving. \
+/// Every message is described by a [`LinePattern`]. It is either a list of strings the line has to contain, whose values get read by their position, or
+/// a regex, whose values get read from its named groups.
 ///
 /// # Methods
 ///
-/// | Method                                                                               | Description                                                  |
-/// |--------------------------------------------------------------------------------------|--------------------------------------------------------------|
-/// | [`new(...) -> Self`](ServerType::new)                                              | Create a new [`ServerType`](ServerType).                 |
-/// |                                                                                      |                                                              |
-/// | [`get_started(...) -> Result<...>`](ServerType::get_started)                       | Get this Minecraft server types started message.             |
-/// | [`get_player_joined(...) -> Result<...>`](ServerType::get_player_joined)           | Get this Minecraft server types player joined message.       |
-/// | [`get_player_left(...) -> Result<...>`](ServerType::get_player_left)               | Get this Minecraft server types player left message.         |
-/// | [`get_player_name_joined(...) -> Result<...>`](ServerType::get_player_name_joined) | Get the name of the player that joined in the line provided. |
-/// | [`get_player_name_left(...) -> Result<...>`](ServerType::get_player_name_left)     | Get the name of the player that left in the line provided.   |
-#[derive(Clone)]
+/// | Method                                                                             | Description                                                   |
+/// |------------------------------------------------------------------------------------|---------------------------------------------------------------|
+/// | [`new(...) -> Self`](ServerType::new)                                              | Create a new [`ServerType`](ServerType).                      |
+/// | [`set(...)`](ServerType::set)                                                      | Change the type of the server.                                |
+/// | [`exists(...) -> bool`](ServerType::exists)                                        | Check whether or not a server type is defined.                |
+/// | [`detect(...) -> Option<...>`](ServerType::detect)                                 | Get the server type whose branding matches the line provided. |
+/// |                                                                                    |                                                               |
+/// | [`get_started(...) -> Result<...>`](ServerType::get_started)                       | Get this Minecraft server types started message.              |
+/// | [`get_player_joined(...) -> Result<...>`](ServerType::get_player_joined)           | Get this Minecraft server types player joined message.        |
+/// | [`get_player_left(...) -> Result<...>`](ServerType::get_player_left)               | Get this Minecraft server types player left message.          |
+/// | [`get_player_name_joined(...) -> Result<...>`](ServerType::get_player_name_joined) | Get the name of the player that joined in the line provided.  |
+/// | [`get_player_name_left(...) -> Result<...>`](ServerType::get_player_name_left)     | Get the name of the player that left in the line provided.    |
+/// | [`get_player_uuid(...) -> Result<...>`](ServerType::get_player_uuid)               | Get this Minecraft server types player uuid message.          |
+/// | [`get_player_logged_in(...) -> Result<...>`](ServerType::get_player_logged_in)     | Get this Minecraft server types player logged in message.     |
+/// | [`get_player_name_uuid(...) -> Result<...>`](ServerType::get_player_name_uuid)     | Get the name and uuid of the player in the line provided.     |
+/// | [`get_player_name_ip(...) -> Result<...>`](ServerType::get_player_name_ip)         | Get the name and ip of the player in the line provided.       |
+/// | [`get_chat(...) -> Result<...>`](ServerType::get_chat)                             | Get this Minecraft server types chat message.                 |
+/// | [`get_chat_message(...) -> Result<...>`](ServerType::get_chat_message)             | Get the sender and the message of the chat line provided.     |
+/// |                                                                                    |                                                               |
+/// | [`get_stopping(...) -> Option<...>`](ServerType::get_stopping)                     | Get this Minecraft server types stopping message.             |
+/// | [`get_save_complete(...) -> Option<...>`](ServerType::get_save_complete)           | Get this Minecraft server types save complete message.        |
+/// | [`get_player_death(...) -> Option<...>`](ServerType::get_player_death)             | Get this Minecraft server types player death message.         |
+/// | [`get_advancement(...) -> Option<...>`](ServerType::get_advancement)               | Get this Minecraft server types advancement message.          |
+/// | [`get_overloaded(...) -> Option<...>`](ServerType::get_overloaded)                 | Get this Minecraft server types overloaded message.           |
+/// | [`get_exception(...) -> Option<...>`](ServerType::get_exception)                   | Get this Minecraft server types exception message.            |
+/// | [`get_stack_trace(...) -> Option<...>`](ServerType::get_stack_trace)               | Get this Minecraft server types stack trace line.             |
+/// | [`get_plugin_load_failed(...) -> Option<...>`](ServerType::get_plugin_load_failed) | Get this Minecraft server types plugin load failure message.  |
+/// | [`get_death_message(...) -> Result<...>`](ServerType::get_death_message)           | Get the player and the death message of the line provided.    |
+/// | [`get_advancement_name(...) -> Result<...>`](ServerType::get_advancement_name)     | Get the player and the advancement of the line provided.      |
+/// | [`get_overload(...) -> Result<...>`](ServerType::get_overload)                     | Get how far the server is behind in the line provided.        |
+/// | [`get_exception_message(...) -> Result<...>`](ServerType::get_exception_message)   | Get the message of the exception in the line provided.        |
+/// | [`get_failed_plugin(...) -> Result<...>`](ServerType::get_failed_plugin)           | Get the plugin which failed to load in the line provided.     |
+/// | [`get_player_switched(...) -> Option<...>`](ServerType::get_player_switched)       | Get this proxy types player switched message.                 |
+/// | [`get_switched_server(...) -> Result<...>`](ServerType::get_switched_server)       | Get the player and the backend of the line provided.          |
+/// | [`get_stop_command(...) -> String`](ServerType::get_stop_command)                  | Get the command used to stop this Minecraft server type.      |
 pub struct ServerType {
-    /// The type of the [`Server`](super::Server) holding this struct
-    server_type: String,
+    /// The type of the [`Server`](super::Server) holding this struct. It can change, once the type of the Minecraft server got detected.
+    server_type: RwLock<String>,
     /// The name of the [`Server`](super::Server) holding this struct
     parent: String,
 }
@@ -53,28 +105,49 @@ impl ServerType {
     ///
     /// # Parameters
     ///
-    /// | Parameter           | Description                                                                        |
-    /// |---------------------|------------------------------------------------------------------------------------|
-    /// | `server_type: &str` | To see all available options see the `config/server_types.toml` file.            |
+    /// | Parameter           | Description                                                                  |
+    /// |---------------------|------------------------------------------------------------------------------|
+    /// | `server_type: &str` | To see all available options see the `config/server_types.toml` file.        |
     /// | `parent: &str`      | The name of the [`Server`](super::Server) this [`ServerType`] was meant for. |
     pub fn new(server_type: &str, parent: &str) -> Self {
         Self {
-            server_type: server_type.to_string(),
+            server_type: server_type.to_string().into(),
             parent: parent.to_string(),
         }
     }
 
+    /// Change the type of the [`Server`](super::Server) holding this struct. Every message requested afterwards will be read from the new type.
+    pub fn set(&self, server_type: &str) {
+        *self.server_type.write().unwrap_or_else(|error| error.into_inner()) = server_type.to_string();
+    }
+    /// Check whether or not the given server type is defined in the `config/server_types.toml` file.
+    pub async fn exists(&self, server_type: &str) -> bool {
+        server_types(&self.parent)
+            .await
+            .contains_key(server_type)
+    }
+    /// Return the name of the server type whose optional `branding` message matches the line provided. Minecraft servers print these messages while
+    /// starting, like `This server is running Paper version ...`. \
+    /// If no server type matches the line, `None` will be returned.
+    pub async fn detect(&self, line: &str) -> Option<String> {
+        let server_type_toml = server_types(&self.parent).await;
+        server_type_toml.iter().find_map(|(server_type, messages)| {
+            let pattern = LinePattern::from_value(messages.get("branding")?).ok()?;
+            pattern.matches(line).then(|| server_type.clone())
+        })
+    }
+
     /// Get a message from the `config/server_types.toml` file, which can be found under this Server's type ( vanilla, purpur, etc. ) and its
     /// identifier ( started, player_joined, etc. ). \
     /// \
-    /// This method only works if the message to get is a single string. For messages containing multiple strings, use the
-    /// [`get_message_vector method`](Self::get_message_vector).
+    /// This method returns the raw value of the message. For patterns, use the [`get_pattern method`](Self::get_pattern).
     #[async_recursion]
     async fn get_message(&self, identifier: &str) -> Value {
-        let server_type_toml = load_toml_replace(&MCSERVER_TYPES_FILE, &self.parent, true).await;
+        let server_type_toml = server_types(&self.parent).await;
 
         // get the toml of a provided server type
-        if let Some(server) = server_type_toml.get(&self.server_type) {
+        let server_type = self.to_string();
+        if let Some(server) = server_type_toml.get(&server_type) {
             if let Some(message) = server.get(identifier) {
                 message.to_owned()
             } else {
@@ -84,13 +157,11 @@ impl ServerType {
         } else {
             error!(
                 self.parent,
-                "Could not find the server_type {} in the config/server_types.toml file.",
-                self.server_type
+                "Could not find the server_type {server_type} in the config/server_types.toml file."
             );
             error!(
                 self.parent,
-                "This Server will now be blocked until the server_type {} got added.",
-                self.server_type
+                "This Server will now be blocked until the server_type {server_type} got added."
             );
             error!(
                 self.parent,
@@ -99,120 +170,317 @@ impl ServerType {
             );
 
             loop {
-                let server_type_toml =
-                    load_toml_replace(&MCSERVER_TYPES_FILE, &self.parent, true).await;
+                let server_type_toml = server_types(&self.parent).await;
 
-                if server_type_toml.get(&self.server_type).is_some() {
+                if server_type_toml.get(&server_type).is_some() || self.to_string() != server_type {
                     return self.get_message(identifier).await;
                 }
 
-                // TODO check if the server's type got changed
-
                 sleep(Config::cooldown().await).await;
             }
         }
     }
-    /// Get a message from the `config/server_types.toml` file, which can be found under this Server's type ( vanilla, purpur, etc. ) and its
+    /// Get the [`LinePattern`] from the `config/server_types.toml` file, which can be found under this Server's type ( vanilla, purpur, etc. ) and its
     /// identifier ( started, player_joined, etc. ). \
-    /// \
-    /// This method is only useful if the message to be retrieved contains multiple strings. For messages containing a single string, use the
-    /// [`get_message method`](Self::get_message).
+    /// Regex patterns only get compiled once. See the [`LinePattern::compile`] method.
     #[async_recursion]
-    async fn get_message_vector(&self, identifier: &str) -> Vec<String> {
-        // convert the message got into a vector of strings and return it
-        if let Some(vec) = self.get_message(identifier).await.as_array() {
-            let mut final_vec: Vec<String> = vec![];
-            for item in vec {
-                if let Some(string) = item.as_str() {
-                    final_vec.push(string.to_string());
-                } else {
-                    replace_with_valid_file(&MCSERVER_TYPES_FILE).await;
-                    return self.get_message_vector(identifier).await;
-                }
+    async fn get_pattern(&self, identifier: &str) -> LinePattern {
+        match LinePattern::from_value(&self.get_message(identifier).await) {
+            Ok(pattern) => pattern,
+            Err(error) => {
+                warn!(self.parent, "The parameter {identifier} in the config/server_types.toml file is invalid. A valid file will be generated. Error: {error}");
+                replace_with_valid_file(&MCSERVER_TYPES_FILE).await;
+                self.get_pattern(identifier).await
             }
-            final_vec
+        }
+    }
+
+    /// Get a message from the `config/server_types.toml` file, which can be found under this Server's type ( vanilla, purpur, etc. ) and its
+    /// identifier ( chat_sender_start, etc. ). \
+    /// \
+    /// This method only works if the message to get is a single string.
+    #[async_recursion]
+    async fn get_message_string(&self, identifier: &str) -> String {
+        if let Some(string) = self.get_message(identifier).await.as_str() {
+            string.to_string()
         } else {
-            warn!(self.parent, "Could not find the parameter {identifier} in the config/server_list.toml file. A valid file will be generated.");
             replace_with_valid_file(&MCSERVER_TYPES_FILE).await;
-            self.get_message_vector(identifier).await
+            self.get_message_string(identifier).await
         }
     }
 
     /// Get this Minecraft server types started message.
-    pub async fn get_started(&self) -> Vec<String> {
-        self.get_message_vector("started").await
+    pub async fn get_started(&self) -> LinePattern {
+        self.get_pattern("started").await
     }
     /// Get this Minecraft server types player joined message.
-    pub async fn get_player_joined(&self) -> Vec<String> {
-        self.get_message_vector("player_joined").await
+    pub async fn get_player_joined(&self) -> LinePattern {
+        self.get_pattern("player_joined").await
     }
     /// Get this Minecraft server types player left message.
-    pub async fn get_player_left(&self) -> Vec<String> {
-        self.get_message_vector("player_left").await
+    pub async fn get_player_left(&self) -> LinePattern {
+        self.get_pattern("player_left").await
+    }
+    /// Get this Minecraft server types message announcing the uuid of a player who is about to join.
+    pub async fn get_player_uuid(&self) -> LinePattern {
+        self.get_pattern("player_uuid").await
+    }
+    /// Get this Minecraft server types message announcing the address a player logged in from.
+    pub async fn get_player_logged_in(&self) -> LinePattern {
+        self.get_pattern("player_logged_in").await
+    }
+    /// Get this Minecraft server types chat message.
+    pub async fn get_chat(&self) -> LinePattern {
+        self.get_pattern("chat").await
     }
 
-    /// Get the name of the player that joined in the line provided.
-    #[async_recursion]
+    /// Get the name of the player that joined in the line provided. A regex pattern has to capture the name in the group `player`.
     pub async fn get_player_name_joined(&self, line: &str) -> Result<String, MCManageError> {
-        let player_name_pos: u64;
-        if let Some(pos) = self
-            .get_message("player_name_joined_pos")
-            .await
-            .as_integer()
-        {
-            if let Ok(pos) = pos.try_into() {
-                player_name_pos = pos;
-            } else {
-                replace_with_valid_file(&MCSERVER_TYPES_FILE).await;
-                return self.get_player_name_joined(line).await;
-            }
-        } else {
-            replace_with_valid_file(&MCSERVER_TYPES_FILE).await;
-            return self.get_player_name_joined(line).await;
+        let pattern = self.get_player_joined().await;
+        if let LinePattern::Regex(_) = pattern {
+            let captures = pattern.captures(line).ok_or(MCManageError::NotFound)?;
+            return group(&captures, "player");
         }
+        self.get_word(line, "player_name_joined_pos").await
+    }
+    /// Get the name of the player that left in the line provided. A regex pattern has to capture the name in the group `player`.
+    pub async fn get_player_name_left(&self, line: &str) -> Result<String, MCManageError> {
+        let pattern = self.get_player_left().await;
+        if let LinePattern::Regex(_) = pattern {
+            let captures = pattern.captures(line).ok_or(MCManageError::NotFound)?;
+            return group(&captures, "player");
+        }
+        self.get_word(line, "player_name_left_pos").await
+    }
+    /// Get the name and the uuid of the player in the line provided. The line has to be a [`player uuid message`](Self::get_player_uuid). \
+    /// A regex pattern has to capture them in the groups `player` and `uuid`. Bedrock Edition servers only announce the xuid of a player, so a
+    /// pattern without a `uuid` group can capture the xuid in the group `xuid` instead. It will be converted using the
+    /// [`PlayerIdentities::xuid_uuid`] method.
+    pub async fn get_player_name_uuid(&self, line: &str) -> Result<(String, String), MCManageError> {
+        let pattern = self.get_player_uuid().await;
+        if let LinePattern::Regex(_) = pattern {
+            let captures = pattern.captures(line).ok_or(MCManageError::NotFound)?;
+            let uuid = match captures.get("uuid") {
+                Some(uuid) => uuid.clone(),
+                None => PlayerIdentities::xuid_uuid(&group(&captures, "xuid")?).ok_or(MCManageError::NotFound)?,
+            };
+            return Ok((group(&captures, "player")?, uuid));
+        }
+        let player_name = self.get_word(line, "player_uuid_name_pos").await?;
+        let uuid = self.get_word(line, "player_uuid_pos").await?;
+        Ok((player_name, uuid))
+    }
+    /// Get the name and the ip of the player in the line provided. The line has to be a [`player logged in message`](Self::get_player_logged_in). \
+    /// A regex pattern has to capture them in the groups `player` and `ip`. Using the legacy format, the player and its address are written as one
+    /// word, like `Gooxey[/127.0.0.1:51234]`. The port will be removed from the ip.
+    pub async fn get_player_name_ip(&self, line: &str) -> Result<(String, String), MCManageError> {
+        let pattern = self.get_player_logged_in().await;
+        if let LinePattern::Regex(_) = pattern {
+            let captures = pattern.captures(line).ok_or(MCManageError::NotFound)?;
+            return Ok((group(&captures, "player")?, group(&captures, "ip")?));
+        }
+        let word = self.get_word(line, "player_logged_in_pos").await?;
+        let Some((player_name, address)) = word.split_once("[/") else {
+            return Err(MCManageError::NotFound);
+        };
+        let address = address.trim_end_matches(']');
+        let ip = address.rsplit_once(':').map_or(address, |(ip, _)| ip);
+        Ok((player_name.to_string(), ip.to_string()))
+    }
 
-        let mut line_iter = line.split(' ').map(String::from);
-        for i in 0.. {
-            if i >= player_name_pos {
-                break;
-            }
+    /// Get the sender and the message of the chat line provided. The line has to be a [`chat message`](Self::get_chat). \
+    /// A regex pattern has to capture them in the groups `sender` and `message`. Using the legacy format, the sender is enclosed by the
+    /// `chat_sender_start` and `chat_sender_end` strings, like `<Gooxey> hello`. Tags in front of the sender, like the `[Not Secure]` tag Paper adds
+    /// to unsigned messages, will be ignored.
+    pub async fn get_chat_message(&self, line: &str) -> Result<(String, String), MCManageError> {
+        let pattern = self.get_chat().await;
+        if let LinePattern::Regex(_) = pattern {
+            let captures = pattern.captures(line).ok_or(MCManageError::NotFound)?;
+            return Ok((group(&captures, "sender")?, group(&captures, "message")?));
+        }
+        let line = strip_colors(line);
+        let sender_start = self.get_message_string("chat_sender_start").await;
+        let sender_end = self.get_message_string("chat_sender_end").await;
 
-            line_iter.next();
+        // every log line starts with a prefix like `[13:53:51 INFO]: `
+        let Some((_, content)) = line.split_once("]: ") else {
+            return Err(MCManageError::NotFound);
+        };
+        let Some((tags, content)) = content.split_once(&sender_start) else {
+            return Err(MCManageError::NotFound);
+        };
+        let tags = tags.trim();
+        if !tags.is_empty() && !(tags.starts_with('[') && tags.ends_with(']')) {
+            return Err(MCManageError::NotFound);
+        }
+        let Some((sender, message)) = content.split_once(&sender_end) else {
+            return Err(MCManageError::NotFound);
+        };
+        if sender.is_empty() || sender.contains(' ') {
+            return Err(MCManageError::NotFound);
         }
+        Ok((sender.to_string(), message.to_string()))
+    }
 
-        if let Some(player_name) = line_iter.next() {
-            Ok(player_name)
-        } else {
-            Err(MCManageError::NotFound)
+    /// Get this Minecraft server types message announcing that it is stopping. This message is optional.
+    pub async fn get_stopping(&self) -> Option<LinePattern> {
+        self.get_optional_pattern("stopping").await
+    }
+    /// Get this Minecraft server types message announcing that its worlds got saved. This message is optional.
+    pub async fn get_save_complete(&self) -> Option<LinePattern> {
+        self.get_optional_pattern("save_complete").await
+    }
+    /// Get this Minecraft server types player death message. This message is optional.
+    pub async fn get_player_death(&self) -> Option<LinePattern> {
+        self.get_optional_pattern("player_death").await
+    }
+    /// Get this Minecraft server types message announcing an advancement, challenge or goal a player made. This message is optional.
+    pub async fn get_advancement(&self) -> Option<LinePattern> {
+        self.get_optional_pattern("advancement").await
+    }
+    /// Get this Minecraft server types message announcing that it can not keep up. This message is optional.
+    pub async fn get_overloaded(&self) -> Option<LinePattern> {
+        self.get_optional_pattern("overloaded").await
+    }
+    /// Get this Minecraft server types message starting an exception. This message is optional.
+    pub async fn get_exception(&self) -> Option<LinePattern> {
+        self.get_optional_pattern("exception").await
+    }
+    /// Get the pattern every line of a stack trace following an [`exception`](Self::get_exception) matches. This pattern is optional.
+    pub async fn get_stack_trace(&self) -> Option<LinePattern> {
+        self.get_optional_pattern("stack_trace").await
+    }
+    /// Get this Minecraft server types message announcing that a plugin could not be loaded or enabled. This message is optional.
+    pub async fn get_plugin_load_failed(&self) -> Option<LinePattern> {
+        self.get_optional_pattern("plugin_load_failed").await
+    }
+
+    /// Get the name of the player who died and the death message of the line provided. The line has to be a
+    /// [`player death message`](Self::get_player_death). \
+    /// A regex pattern has to capture the player in the group `player` and can capture the death message in the group `message`. Otherwise, the
+    /// content of the line will be the death message and its first word the player.
+    pub async fn get_death_message(&self, line: &str) -> Result<(String, String), MCManageError> {
+        let groups = self.get_optional_groups("player_death", line).await?;
+        let message = groups.get("message").cloned().unwrap_or_else(|| content(line));
+        let player = match groups.get("player") {
+            Some(player) => player.clone(),
+            None => message.split(' ').next().unwrap_or_default().to_string(),
+        };
+        Ok((player, message))
+    }
+    /// Get the name of the player and the name of the advancement of the line provided. The line has to be an
+    /// [`advancement message`](Self::get_advancement). \
+    /// A regex pattern has to capture them in the groups `player` and `advancement`. Otherwise, the first word of the content of the line will be
+    /// the player and the text enclosed by the last brackets the advancement.
+    pub async fn get_advancement_name(&self, line: &str) -> Result<(String, String), MCManageError> {
+        let groups = self.get_optional_groups("advancement", line).await?;
+        if let (Some(player), Some(advancement)) = (groups.get("player"), groups.get("advancement")) {
+            return Ok((player.clone(), advancement.clone()));
+        }
+
+        let content = content(line);
+        let player = content.split(' ').next().unwrap_or_default().to_string();
+        let Some((_, advancement)) = content.rsplit_once('[') else {
+            return Err(MCManageError::NotFound);
+        };
+        Ok((player, advancement.trim_end_matches(']').to_string()))
+    }
+    /// Get how many milliseconds and ticks the Minecraft server is behind in the line provided. The line has to be an
+    /// [`overloaded message`](Self::get_overloaded). \
+    /// A regex pattern can capture them in the groups `milliseconds` and `ticks`.
+    pub async fn get_overload(&self, line: &str) -> Result<(Option<u64>, Option<u64>), MCManageError> {
+        let groups = self.get_optional_groups("overloaded", line).await?;
+        let number = |name: &str| groups.get(name).and_then(|number| number.parse().ok());
+        Ok((number("milliseconds"), number("ticks")))
+    }
+    /// Get the message of the exception in the line provided. The line has to be an [`exception message`](Self::get_exception). \
+    /// A regex pattern can capture it in the group `message`. Otherwise, the content of the line will be the message.
+    pub async fn get_exception_message(&self, line: &str) -> Result<String, MCManageError> {
+        let groups = self.get_optional_groups("exception", line).await?;
+        Ok(groups.get("message").cloned().unwrap_or_else(|| content(line)))
+    }
+    /// Get the name of the plugin which failed to load in the line provided. The line has to be a
+    /// [`plugin load failure message`](Self::get_plugin_load_failed). \
+    /// A regex pattern can capture it in the group `plugin`. Otherwise, the content of the line will be returned.
+    pub async fn get_failed_plugin(&self, line: &str) -> Result<String, MCManageError> {
+        let groups = self.get_optional_groups("plugin_load_failed", line).await?;
+        Ok(groups.get("plugin").cloned().unwrap_or_else(|| content(line)))
+    }
+
+    /// Get the message of a proxy announcing that a player connected to one of its backends. Players switching between the backends of a network
+    /// will cause this message too. This message is optional.
+    pub async fn get_player_switched(&self) -> Option<LinePattern> {
+        self.get_optional_pattern("player_switched").await
+    }
+    /// Get the name of the player and the name of the backend the player connected to in the line provided. The line has to be a
+    /// [`player switched message`](Self::get_player_switched). \
+    /// The pattern has to be a regex capturing them in the groups `player` and `server`.
+    pub async fn get_switched_server(&self, line: &str) -> Result<(String, String), MCManageError> {
+        let groups = self.get_optional_groups("player_switched", line).await?;
+        Ok((group(&groups, "player")?, group(&groups, "server")?))
+    }
+    /// Get the command used to stop this Minecraft server type. Proxies like BungeeCord use `end` instead of `stop`. \
+    /// If this server type defines no `stop_command`, `stop` will be returned.
+    pub async fn get_stop_command(&self) -> String {
+        server_types(&self.parent)
+            .await
+            .get(&self.to_string())
+            .and_then(|messages| messages.get("stop_command"))
+            .and_then(Value::as_str)
+            .unwrap_or(DEFAULT_STOP_COMMAND)
+            .to_string()
+    }
+
+    /// Get the [`LinePattern`] saved under this Server's type and the given identifier, if one is defined. \
+    /// Unlike the [`get_pattern method`](Self::get_pattern), missing or invalid patterns will not cause the `config/server_types.toml` file to be
+    /// replaced, since these patterns are optional.
+    async fn get_optional_pattern(&self, identifier: &str) -> Option<LinePattern> {
+        let server_type_toml = server_types(&self.parent).await;
+        let value = server_type_toml.get(&self.to_string())?.get(identifier)?;
+        match LinePattern::from_value(value) {
+            Ok(pattern) => Some(pattern),
+            Err(error) => {
+                warn!(self.parent, "The parameter {identifier} in the config/server_types.toml file is invalid. It will be ignored. Error: {error}");
+                None
+            }
         }
     }
-    /// Get the name of the player that left in the line provided.
+    /// Get the named groups of the optional pattern saved under the given identifier found in the line provided. Patterns using the legacy format
+    /// have no groups, so an empty map will be returned for them.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                                               |
+    /// |-----------------------------|-----------------------------------------------------------|
+    /// | `Ok(HashMap)`               | The line matches the pattern.                             |
+    /// | [`MCManageError::NotFound`] | The pattern is not defined or the line does not match it. |
+    async fn get_optional_groups(&self, identifier: &str, line: &str) -> Result<HashMap<String, String>, MCManageError> {
+        match self.get_optional_pattern(identifier).await {
+            Some(pattern @ LinePattern::Regex(_)) => pattern.captures(line).ok_or(MCManageError::NotFound),
+            Some(pattern) if pattern.matches(line) => Ok(HashMap::new()),
+            _ => Err(MCManageError::NotFound),
+        }
+    }
+
+    /// Get the word of the line provided, whose position is saved under the given identifier in the `config/server_types.toml` file. \
+    /// Words are separated by spaces and counted starting from 0. Color codes will be ignored.
     #[async_recursion]
-    pub async fn get_player_name_left(&self, line: &str) -> Result<String, MCManageError> {
-        let player_name_pos;
-        if let Some(pos) = self.get_message("player_name_left_pos").await.as_integer() {
+    async fn get_word(&self, line: &str, identifier: &str) -> Result<String, MCManageError> {
+        let position: usize;
+        if let Some(pos) = self.get_message(identifier).await.as_integer() {
             if let Ok(pos) = pos.try_into() {
-                player_name_pos = pos;
+                position = pos;
             } else {
                 replace_with_valid_file(&MCSERVER_TYPES_FILE).await;
-                return self.get_player_name_left(line).await;
+                return self.get_word(line, identifier).await;
             }
         } else {
             replace_with_valid_file(&MCSERVER_TYPES_FILE).await;
-            return self.get_player_name_left(line).await;
-        }
-
-        let mut line_iter = line.split(' ').map(String::from);
-        for i in 0.. {
-            if i >= player_name_pos {
-                break;
-            }
-
-            line_iter.next();
+            return self.get_word(line, identifier).await;
         }
 
-        if let Some(player_name) = line_iter.next() {
-            Ok(player_name)
+        if let Some(word) = strip_colors(line).split(' ').nth(position) {
+            Ok(word.to_string())
         } else {
             Err(MCManageError::NotFound)
         }
@@ -220,6 +488,45 @@ impl ServerType {
 }
 impl ToString for ServerType {
     fn to_string(&self) -> String {
-        self.server_type.clone()
+        self.server_type
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .clone()
     }
 }
+
+/// Return the parsed `config/server_types.toml` file. \
+/// The file only gets read and parsed again once its modification time or length changed since the last call, so looking up a message only
+/// costs reading the file's metadata. Changes made to the file, for example by the user or by the [`replace_with_valid_file function`](replace_with_valid_file),
+/// will therefore still be picked up.
+async fn server_types(caller_name: &str) -> Arc<Table> {
+    let mut cache = SERVER_TYPES.lock().await;
+
+    let version = fs::metadata(MCSERVER_TYPES_FILE.as_path())
+        .await
+        .ok()
+        .and_then(|metadata| Some((metadata.modified().ok()?, metadata.len())));
+    if let (Some(version), Some((cached_version, table))) = (version, cache.as_ref()) {
+        if version == *cached_version {
+            return table.clone();
+        }
+    }
+
+    // if the file gets replaced while loading it, its version will not match on the next call and it will simply be parsed again
+    let table = Arc::new(load_toml_replace(&MCSERVER_TYPES_FILE, caller_name, true).await);
+    *cache = version.map(|version| (version, table.clone()));
+    table
+}
+/// Return the content of the line provided, which is everything after its prefix like `[13:53:51 INFO]: `. Color codes will be removed.
+fn content(line: &str) -> String {
+    let line = strip_colors(line);
+    line.split_once("]: ")
+        .map_or(line.as_ref(), |(_, content)| content)
+        .trim()
+        .to_string()
+}
+/// Return the named group of a regex pattern with the given name. If the pattern has no such group or it did not match anything, an
+/// [`MCManageError::NotFound`] will be returned.
+fn group(captures: &HashMap<String, String>, name: &str) -> Result<String, MCManageError> {
+    captures.get(name).cloned().ok_or(MCManageError::NotFound)
+}
@@ -6,6 +6,17 @@ use crate::test_functions::{
     cleanup,
 };
 
+/// A server type using regex patterns instead of the legacy format
+const REGEX_SERVER_TYPE: &str = r#"
+[myRegexType]
+started = 'INFO\]: Done \([0-9.]+s\)!'
+player_joined = '\]: (?:\[[^\]]*\] )*(?P<player>\w+) joined the game$'
+player_left = '\]: (?:\[[^\]]*\] )*(?P<player>\w+) left the game$'
+player_uuid = 'UUID of player (?P<player>\w+) is (?P<uuid>[0-9a-f-]{36})'
+player_logged_in = '\]: (?P<player>\w+)\[/(?P<ip>[^:\]]+)(?::\d+)?\] logged in'
+chat = '\]: (?:\[Not Secure\] )?(?:\[[^\]]*\] )?<(?P<sender>\w+)> (?P<message>.*)$'
+"#;
+
 async fn start_test() -> ServerType {
     test_functions::start_test();
     ServerType::new("purpur", "MyServer")
@@ -15,7 +26,7 @@ async fn start_test() -> ServerType {
 async fn new() {
     let my_server_type = start_test().await;
 
-    assert_eq!(my_server_type.server_type, "purpur".to_string());
+    assert_eq!(my_server_type.to_string(), "purpur".to_string());
 
     cleanup();
 }
@@ -28,7 +39,10 @@ async fn get_started() {
 
     assert_eq!(
         my_server_type.get_started().await,
-        [" INFO]: Done (", ")! For help, type \"help\""]
+        LinePattern::Substrings(vec![
+            " INFO]: Done (".to_string(),
+            ")! For help, type \"help\"".to_string()
+        ])
     );
 
     cleanup();
@@ -38,8 +52,8 @@ async fn get_player_joined() {
     let my_server_type = start_test().await;
 
     assert_eq!(
-        my_server_type.get_player_joined().await[0],
-        " joined the game"
+        my_server_type.get_player_joined().await,
+        LinePattern::Substrings(vec![" joined the game".to_string()])
     );
 
     cleanup();
@@ -48,7 +62,10 @@ async fn get_player_joined() {
 async fn get_player_left() {
     let my_server_type = start_test().await;
 
-    assert_eq!(my_server_type.get_player_left().await[0], "left the game");
+    assert_eq!(
+        my_server_type.get_player_left().await,
+        LinePattern::Substrings(vec!["left the game".to_string()])
+    );
 
     cleanup();
 }
@@ -79,3 +96,513 @@ async fn get_player_name_left() {
 
     cleanup();
 }
+#[tokio::test]
+async fn get_player_name_uuid() {
+    let my_server_type = start_test().await;
+
+    let (name, uuid) = my_server_type
+        .get_player_name_uuid("[13:53:50 INFO]: UUID of player Gooxey is 069a79f4-44e9-4726-a5be-fca90e38aaf5")
+        .await
+        .unwrap();
+
+    assert_eq!(name, "Gooxey");
+    assert_eq!(uuid, "069a79f4-44e9-4726-a5be-fca90e38aaf5");
+
+    cleanup();
+}
+#[tokio::test]
+async fn get_player_name_ip() {
+    let my_server_type = start_test().await;
+
+    let (name, ip) = my_server_type
+        .get_player_name_ip("[13:53:51 INFO]: Gooxey[/127.0.0.1:51234] logged in with entity id 42 at ([world]0.5, 64.0, 0.5)")
+        .await
+        .unwrap();
+
+    assert_eq!(name, "Gooxey");
+    assert_eq!(ip, "127.0.0.1");
+
+    cleanup();
+}
+#[tokio::test]
+async fn get_chat_message() {
+    let my_server_type = start_test().await;
+
+    assert_eq!(
+        my_server_type
+            .get_chat_message("[13:53:52 INFO]: <Gooxey> hello <everyone> out there")
+            .await
+            .unwrap(),
+        ("Gooxey".to_string(), "hello <everyone> out there".to_string())
+    );
+    assert_eq!(
+        my_server_type
+            .get_chat_message("[13:53:52 INFO]: [Not Secure] <Gooxey> hi")
+            .await
+            .unwrap(),
+        ("Gooxey".to_string(), "hi".to_string())
+    );
+    assert!(my_server_type
+        .get_chat_message("[13:53:52 WARN]: Plugin <Example> failed: a > b")
+        .await
+        .is_err());
+
+    cleanup();
+}
+
+/// Add the [`REGEX_SERVER_TYPE`] to the valid `config/server_types.toml` file and return a [`ServerType`] using it.
+async fn start_regex_test() -> ServerType {
+    test_functions::start_test();
+    tokio::fs::create_dir_all(MCSERVER_TYPES_FILE.parent().unwrap())
+        .await
+        .unwrap();
+    tokio::fs::write(
+        MCSERVER_TYPES_FILE.as_path(),
+        format!(
+            "{}\n{REGEX_SERVER_TYPE}",
+            crate::generated_files::default_files::valid_server_types::VALID_MCSERVER_TYPES
+        ),
+    )
+    .await
+    .unwrap();
+    ServerType::new("myRegexType", "MyServer")
+}
+
+#[tokio::test]
+async fn regex_player_activity() {
+    let my_server_type = start_regex_test().await;
+
+    assert!(my_server_type
+        .get_started()
+        .await
+        .matches("[13:53:40 INFO]: Done (4.216s)! For help, type \"help\""));
+    let joined = "[13:53:51 INFO]: [Admin] Gooxey joined the game";
+    assert!(my_server_type.get_player_joined().await.matches(joined));
+    assert_eq!(my_server_type.get_player_name_joined(joined).await.unwrap(), "Gooxey");
+    let left = "\x1b[33m[13:53:59 INFO]: Gooxey left the game\x1b[0m";
+    assert!(!my_server_type.get_player_joined().await.matches(left));
+    assert_eq!(my_server_type.get_player_name_left(left).await.unwrap(), "Gooxey");
+
+    cleanup();
+}
+#[tokio::test]
+async fn regex_player_identity() {
+    let my_server_type = start_regex_test().await;
+
+    assert_eq!(
+        my_server_type
+            .get_player_name_uuid("[13:53:50 INFO]: UUID of player Gooxey is 069a79f4-44e9-4726-a5be-fca90e38aaf5")
+            .await
+            .unwrap(),
+        ("Gooxey".to_string(), "069a79f4-44e9-4726-a5be-fca90e38aaf5".to_string())
+    );
+    assert_eq!(
+        my_server_type
+            .get_player_name_ip("[13:53:51 INFO]: Gooxey[/127.0.0.1:51234] logged in with entity id 42 at ([world]0.5, 64.0, 0.5)")
+            .await
+            .unwrap(),
+        ("Gooxey".to_string(), "127.0.0.1".to_string())
+    );
+
+    cleanup();
+}
+#[tokio::test]
+async fn regex_chat_message() {
+    let my_server_type = start_regex_test().await;
+
+    assert_eq!(
+        my_server_type
+            .get_chat_message("[13:53:52 INFO]: [Not Secure] [§6VIP§r] <Gooxey> hello there")
+            .await
+            .unwrap(),
+        ("Gooxey".to_string(), "hello there".to_string())
+    );
+    assert!(my_server_type
+        .get_chat_message("[13:53:52 WARN]: Plugin <Example> failed: a > b")
+        .await
+        .is_err());
+
+    cleanup();
+}
+#[test]
+fn compile_pattern() {
+    let regex = LinePattern::compile(r"(?P<player>\w+) joined").unwrap();
+
+    assert!(std::sync::Arc::ptr_eq(
+        &regex,
+        &LinePattern::compile(r"(?P<player>\w+) joined").unwrap()
+    ));
+    assert!(matches!(
+        LinePattern::compile(r"(?P<player>\w+"),
+        Err(MCManageError::RegexError(_))
+    ));
+}
+#[tokio::test]
+async fn get_death_message() {
+    let my_server_type = start_test().await;
+    let line = "[13:54:10 INFO]: Gooxey was slain by Zombie";
+
+    assert!(my_server_type.get_player_death().await.unwrap().matches(line));
+    assert_eq!(
+        my_server_type.get_death_message(line).await.unwrap(),
+        ("Gooxey".to_string(), "Gooxey was slain by Zombie".to_string())
+    );
+    assert!(!my_server_type
+        .get_player_death()
+        .await
+        .unwrap()
+        .matches("[13:54:10 INFO]: <Gooxey> I was slain"));
+
+    cleanup();
+}
+#[tokio::test]
+async fn get_advancement_name() {
+    let my_server_type = start_test().await;
+
+    assert_eq!(
+        my_server_type
+            .get_advancement_name("[13:54:20 INFO]: Gooxey has made the advancement [Stone Age]")
+            .await
+            .unwrap(),
+        ("Gooxey".to_string(), "Stone Age".to_string())
+    );
+
+    cleanup();
+}
+#[tokio::test]
+async fn get_overload() {
+    let my_server_type = start_test().await;
+
+    assert_eq!(
+        my_server_type
+            .get_overload("[13:54:30 WARN]: Can't keep up! Is the server overloaded? Running 2034ms or 40 ticks behind")
+            .await
+            .unwrap(),
+        (Some(2034), Some(40))
+    );
+
+    cleanup();
+}
+#[tokio::test]
+async fn get_exception_message() {
+    let my_server_type = start_test().await;
+
+    assert_eq!(
+        my_server_type
+            .get_exception_message("[13:54:40 WARN]: java.lang.IllegalStateException: Asynchronous chunk load!")
+            .await
+            .unwrap(),
+        "java.lang.IllegalStateException: Asynchronous chunk load!"
+    );
+    let stack_trace = my_server_type.get_stack_trace().await.unwrap();
+    assert!(stack_trace.matches("\tat org.bukkit.craftbukkit.CraftWorld.getChunkAt(CraftWorld.java:42)"));
+    assert!(stack_trace.matches("Caused by: java.lang.NullPointerException"));
+    assert!(!stack_trace.matches("[13:54:41 INFO]: Gooxey joined the game"));
+
+    cleanup();
+}
+#[tokio::test]
+async fn get_failed_plugin() {
+    let my_server_type = start_test().await;
+
+    assert_eq!(
+        my_server_type
+            .get_failed_plugin("[13:54:50 ERROR]: Could not load 'plugins/Example.jar' in folder 'plugins'")
+            .await
+            .unwrap(),
+        "Example.jar"
+    );
+    assert_eq!(
+        my_server_type
+            .get_failed_plugin("[13:54:50 ERROR]: Error occurred while enabling Example v1.0 (Is it up to date?)")
+            .await
+            .unwrap(),
+        "Example"
+    );
+    assert!(my_server_type
+        .get_stopping()
+        .await
+        .unwrap()
+        .matches("[13:55:00 INFO]: Stopping server"));
+
+    cleanup();
+}
+#[tokio::test]
+async fn missing_optional_pattern() {
+    let my_server_type = start_regex_test().await;
+
+    assert_eq!(my_server_type.get_plugin_load_failed().await, None);
+    assert!(my_server_type
+        .get_failed_plugin("[13:54:50 ERROR]: Could not load 'plugins/Example.jar' in folder 'plugins'")
+        .await
+        .is_err());
+
+    cleanup();
+}
+#[tokio::test]
+async fn detect() {
+    let my_server_type = start_test().await;
+
+    assert_eq!(
+        my_server_type
+            .detect("[13:53:31 INFO]: This server is running Purpur version git-Purpur-2095 (MC: 1.20.4)")
+            .await
+            .as_deref(),
+        Some("purpur")
+    );
+    assert_eq!(
+        my_server_type
+            .detect("[13:53:31] [main/INFO]: Loading Minecraft 1.20.4 with Fabric Loader 0.15.6")
+            .await
+            .as_deref(),
+        Some("fabric")
+    );
+    assert_eq!(
+        my_server_type
+            .detect("[13:53:31] [Server thread/INFO]: Starting minecraft server version 1.20.4")
+            .await,
+        None
+    );
+
+    my_server_type.set("fabric");
+    assert_eq!(my_server_type.to_string(), "fabric");
+    assert!(my_server_type.exists("vanilla").await);
+    assert!(!my_server_type.exists("myUnknownType").await);
+
+    cleanup();
+}
+#[tokio::test]
+async fn server_types_cache() {
+    let my_server_type = start_regex_test().await;
+
+    // the file only gets parsed again once it changed
+    let server_types_toml = server_types("MyServer").await;
+    assert!(Arc::ptr_eq(&server_types_toml, &server_types("MyServer").await));
+    assert!(my_server_type.exists("myRegexType").await);
+
+    tokio::fs::write(
+        MCSERVER_TYPES_FILE.as_path(),
+        crate::generated_files::default_files::valid_server_types::VALID_MCSERVER_TYPES,
+    )
+    .await
+    .unwrap();
+    assert!(!my_server_type.exists("myRegexType").await);
+    assert!(my_server_type.exists("purpur").await);
+
+    cleanup();
+}
+
+/// Feed every line of the given log through a [`ServerType`] of the given type the same way the [`Server`](super::super::Server) does and return a
+/// description of everything recognized.
+async fn read_fixture(server_type: &str, log: &str) -> Vec<String> {
+    test_functions::start_test();
+    let my_server_type = ServerType::new(server_type, "MyServer");
+
+    let mut recognized = vec![];
+    let mut started = false;
+    for line in log.lines() {
+        if !started {
+            if let Some(detected_type) = my_server_type.detect(line).await {
+                recognized.push(format!("branding {detected_type}"));
+            }
+            if my_server_type.get_started().await.matches(line) {
+                recognized.push("started".to_string());
+                started = true;
+            }
+        }
+
+        if my_server_type.get_chat().await.matches(line) {
+            if let Ok((sender, message)) = my_server_type.get_chat_message(line).await {
+                recognized.push(format!("chat {sender}: {message}"));
+                continue;
+            }
+        }
+        if my_server_type.get_player_uuid().await.matches(line) {
+            let (player, uuid) = my_server_type.get_player_name_uuid(line).await.unwrap();
+            recognized.push(format!("uuid {player} {uuid}"));
+        } else if my_server_type.get_player_logged_in().await.matches(line) {
+            let (player, ip) = my_server_type.get_player_name_ip(line).await.unwrap();
+            recognized.push(format!("ip {player} {ip}"));
+        }
+        if my_server_type.get_player_joined().await.matches(line) {
+            let player = my_server_type.get_player_name_joined(line).await.unwrap();
+            recognized.push(format!("joined {player}"));
+        } else if my_server_type.get_player_left().await.matches(line) {
+            let player = my_server_type.get_player_name_left(line).await.unwrap();
+            recognized.push(format!("left {player}"));
+        }
+        if let Some(player_switched) = my_server_type.get_player_switched().await {
+            if player_switched.matches(line) {
+                let (player, server) = my_server_type.get_switched_server(line).await.unwrap();
+                recognized.push(format!("switched {player} {server}"));
+            }
+        }
+        if my_server_type.get_stopping().await.unwrap().matches(line) {
+            recognized.push("stopping".to_string());
+        }
+    }
+
+    cleanup();
+    recognized
+}
+/// Return what has to be recognized in every fixture. Every fixture records the same session: The server starts, the player Gooxey joins, writes two
+/// messages and leaves, and the server gets stopped.
+fn expected_session(branding: Option<&str>) -> Vec<String> {
+    let mut expected = vec![];
+    if let Some(branding) = branding {
+        expected.push(format!("branding {branding}"));
+    }
+    expected.extend(
+        [
+            "started",
+            "uuid Gooxey 069a79f4-44e9-4726-a5be-fca90e38aaf5",
+            "ip Gooxey 127.0.0.1",
+            "joined Gooxey",
+            "chat Gooxey: hello there",
+            "chat Gooxey: I left the game running",
+            "left Gooxey",
+            "stopping",
+        ]
+        .map(String::from),
+    );
+    expected
+}
+
+#[tokio::test]
+async fn fixture_vanilla() {
+    assert_eq!(
+        read_fixture("vanilla", include_str!("fixtures/vanilla.log")).await,
+        expected_session(None)
+    );
+}
+#[tokio::test]
+async fn fixture_paper() {
+    assert_eq!(
+        read_fixture("paper", include_str!("fixtures/paper.log")).await,
+        expected_session(Some("paper"))
+    );
+}
+#[tokio::test]
+async fn fixture_purpur() {
+    assert_eq!(
+        read_fixture("purpur", include_str!("fixtures/purpur.log")).await,
+        expected_session(Some("purpur"))
+    );
+}
+#[tokio::test]
+async fn fixture_spigot() {
+    assert_eq!(
+        read_fixture("spigot", include_str!("fixtures/spigot.log")).await,
+        expected_session(Some("spigot"))
+    );
+}
+#[tokio::test]
+async fn fixture_fabric() {
+    assert_eq!(
+        read_fixture("fabric", include_str!("fixtures/fabric.log")).await,
+        expected_session(Some("fabric"))
+    );
+}
+#[tokio::test]
+async fn fixture_quilt() {
+    assert_eq!(
+        read_fixture("quilt", include_str!("fixtures/quilt.log")).await,
+        expected_session(Some("quilt"))
+    );
+}
+#[tokio::test]
+async fn fixture_forge() {
+    // Forge prints its branding twice, once when launching and once when loading its mods
+    let mut expected = expected_session(Some("forge"));
+    expected.insert(0, "branding forge".to_string());
+    assert_eq!(
+        read_fixture("forge", include_str!("fixtures/forge.log")).await,
+        expected
+    );
+}
+#[tokio::test]
+async fn fixture_neoforge() {
+    let mut expected = expected_session(Some("neoforge"));
+    expected.insert(0, "branding neoforge".to_string());
+    assert_eq!(
+        read_fixture("neoforge", include_str!("fixtures/neoforge.log")).await,
+        expected
+    );
+}
+/// Return what has to be recognized in the fixture of a proxy. Every fixture records the same session: The proxy starts, the player Gooxey joins
+/// the lobby, switches to the survival server and leaves, and the proxy gets stopped.
+fn expected_proxy_session(branding: &str) -> Vec<String> {
+    let mut expected = vec![format!("branding {branding}")];
+    expected.extend(
+        [
+            "started",
+            "ip Gooxey 127.0.0.1",
+            "joined Gooxey",
+            "switched Gooxey lobby",
+            "switched Gooxey survival",
+            "left Gooxey",
+            "stopping",
+        ]
+        .map(String::from),
+    );
+    expected
+}
+
+#[tokio::test]
+async fn fixture_velocity() {
+    assert_eq!(
+        read_fixture("velocity", include_str!("fixtures/velocity.log")).await,
+        expected_proxy_session("velocity")
+    );
+}
+#[tokio::test]
+async fn fixture_bungeecord() {
+    assert_eq!(
+        read_fixture("bungeecord", include_str!("fixtures/bungeecord.log")).await,
+        expected_proxy_session("bungeecord")
+    );
+}
+#[tokio::test]
+async fn fixture_bedrock() {
+    // the Bedrock Dedicated Server logs neither chat messages nor addresses, and player names can contain spaces
+    assert_eq!(
+        read_fixture("bedrock", include_str!("fixtures/bedrock.log")).await,
+        [
+            "started",
+            "uuid Gooxey 00000000-0000-0000-0009-01f2a26db617",
+            "joined Gooxey",
+            "uuid Steve Miner 00000000-0000-0000-0009-000005f1b8c0",
+            "joined Steve Miner",
+            "left Gooxey",
+            "left Steve Miner",
+            "stopping",
+        ]
+        .map(String::from)
+    );
+}
+#[tokio::test]
+async fn bedrock_offline_xuid() {
+    test_functions::start_test();
+    let my_server_type = ServerType::new("bedrock", "MyServer");
+
+    // servers in offline mode print no xuid, so the offline uuid has to be used instead
+    let line = "[2024-01-20 13:54:02:318 INFO] Player connected: Gooxey, xuid: ";
+    assert!(my_server_type.get_player_uuid().await.matches(line));
+    assert!(matches!(
+        my_server_type.get_player_name_uuid(line).await,
+        Err(MCManageError::NotFound)
+    ));
+
+    cleanup();
+}
+#[tokio::test]
+async fn stop_command() {
+    test_functions::start_test();
+
+    assert_eq!(ServerType::new("purpur", "MyServer").get_stop_command().await, "stop");
+    assert_eq!(ServerType::new("velocity", "MyServer").get_stop_command().await, "end");
+    assert_eq!(ServerType::new("bungeecord", "MyServer").get_stop_command().await, "end");
+    assert_eq!(ServerType::new("bedrock", "MyServer").get_stop_command().await, "stop");
+
+    cleanup();
+}
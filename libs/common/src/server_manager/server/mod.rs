@@ -1,713 +1,1492 @@
-// //! This module provides the [`Server struct`](Server) which represents an API for one Minecraft server, which got assigned with the initiation of this struct.
-
-use std::time::Duration;
-
-// use std::{
-//     path::{
-//         Path,
-//         PathBuf,
-//     },
-//     process::Stdio,
-//     sync::Arc,
-//     time::Instant,
-// };
-// use chrono::prelude::*;
-
-// use async_recursion::async_recursion;
-// use futures_util::StreamExt;
-// use proc_macros::ConcurrentClass;
-// use tokio::{
-//     fs::{
-//         self,
-//         File,
-//         OpenOptions,
-//     },
-//     io::{
-//         self,
-//         AsyncBufReadExt,
-//         AsyncReadExt,
-//         AsyncWriteExt,
-//         BufReader,
-//     },
-//     process::{
-//         Child,
-//         ChildStdout,
-//         Command,
-//     },
-//     sync::{
-//         oneshot,
-//         Mutex,
-//     },
-//     time::sleep,
-// };
-// use goolog::*;
-
-// use self::server_type::ServerType;
-// use super::server_item::ServerItem;
-// use crate::{
-//     config::Config,
-//     generated_files::{
-//         load_toml_file::load_toml,
-//         paths::{
-//             SERVERS_DIR,
-//             SERVER_LIST_FILE,
-//             SERVER_LOGS_DIR,
-//         },
-//     },
-//     mcmanage_error::MCManageError,
-//     rest_api::server_data::ServerData,
-//     status::Status,
-//     types::ThreadJoinHandle,
-// };
-
-// pub mod server_type;
-// mod tests;
-
-// // TODO cancel download jar if stop or reset gets called
-
-use tokio::time::sleep;
+//! This module provides the [`Server struct`](Server) which represents an API for one Minecraft server, which got assigned with the initiation of this struct.
 
+use std::{
+    collections::HashMap,
+    path::{
+        Path,
+        PathBuf,
+    },
+    process::Stdio,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use async_recursion::async_recursion;
+use chrono::prelude::*;
+use goolog::*;
+use proc_macros::ConcurrentClass;
+use tokio::{
+    fs::{
+        self,
+        OpenOptions,
+    },
+    io::{
+        AsyncBufReadExt,
+        AsyncWriteExt,
+        BufReader,
+    },
+    process::{
+        Child,
+        ChildStdout,
+        Command,
+    },
+    spawn,
+    sync::{
+        oneshot,
+        Mutex,
+    },
+    time::{
+        sleep,
+        timeout,
+    },
+};
+use tokio_util::sync::CancellationToken;
+
+use self::{
+    detected_type::DetectedType,
+    eula_acceptance::EulaAcceptance,
+    installed_build::InstalledBuild,
+    mcserver_type::{
+        line_pattern::LinePattern,
+        ServerType,
+    },
+    network_role::NetworkRole,
+    server_kind::ServerKind,
+    update_policy::UpdatePolicy,
+};
+use super::server_item::ServerItem;
+use crate::{
+    bedrock::BedrockServer,
+    chat_log::ChatLog,
+    config::Config,
+    download_manager::{
+        download_event::DownloadEvent,
+        DownloadManager,
+        DownloadRequest,
+    },
+    event_bus::{
+        event::Event,
+        EventBus,
+    },
+    existing_server::{
+        property,
+        ExistingServer,
+    },
+    generated_files::paths::{
+        SERVERS_DIR,
+        SERVER_LOGS_DIR,
+    },
+    mcmanage_error::MCManageError,
+    mod_manager::{
+        mod_descriptor::ModLoader,
+        ModManager,
+    },
+    player_identities::PlayerIdentities,
+    player_sessions::{
+        session_close_reason::SessionCloseReason,
+        PlayerSessions,
+    },
+    plugin_manager::PluginManager,
+    proxy_network::ProxyNetwork,
+    rest_api::server_data::ServerData,
+    status::Status,
+    types::ThreadJoinHandle,
+    version_provider::{
+        get_provider,
+        resolved_build::ResolvedBuild,
+        server_installer::ServerInstaller,
+        VersionProvider,
+        LATEST,
+    },
+};
+
+pub mod detected_type;
+pub mod eula_acceptance;
+pub mod installed_build;
+pub mod mcserver_type;
+pub mod network_role;
+pub mod server_kind;
+pub mod update_policy;
+mod tests;
+
+const GOOLOG_CALLER: &str = "Server";
+/// The server type used for a Minecraft server without a configured server_type, until its type got detected
+const DEFAULT_SERVER_TYPE: &str = "vanilla";
+/// The server type used for a Bedrock Dedicated Server without a configured server_type
+const BEDROCK_SERVER_TYPE: &str = "bedrock";
+/// The file the Bedrock Dedicated Server archive gets downloaded to, before it gets extracted
+const BEDROCK_ARCHIVE: &str = "bedrock-server.zip";
+
+/// This struct represents an API for one Minecraft server, which got assigned with the initiation of this struct.
+#[derive(ConcurrentClass)]
 pub struct Server {
+    /// This struct's name
+    name: String,
+    /// The main thread of this struct
+    main_thread: Arc<Mutex<Option<ThreadJoinHandle>>>,
+    /// The [`Status`] of this struct
+    status: Mutex<Status>,
+    /// The last [`Status`] published to the [`EventBus`]
+    published_status: Mutex<Status>,
 
+    /// The arguments which should be passed to the Minecraft server
+    args: Mutex<Vec<String>>,
+    /// The url to download the minecraft server jar from
+    download_from: Mutex<String>,
+    /// The Minecraft version of this server. It is used to resolve the download link if none is set.
+    version: Mutex<Option<String>>,
+    /// The build of the server software. It is used to resolve the download link if none is set.
+    build: Mutex<Option<String>>,
+    /// The token to cancel the download of the Minecraft server jar, if one is currently running
+    download: Mutex<Option<CancellationToken>>,
+    /// What to do once a newer build of this server's Minecraft version is available
+    update_policy: UpdatePolicy,
+    /// A newer build, which will be installed at the next start or restart
+    pending_update: Mutex<Option<ResolvedBuild>>,
+    /// The person who accepted the Minecraft EULA for this server in its [`ServerItem`]
+    eula_accepted_by: Option<String>,
+    /// The [`type`](ServerType) of the Minecraft server
+    server_type: ServerType,
+    /// The server_type set in the [`ServerItem`] of this server. If it is empty, the [`ServerType`] will be detected.
+    configured_server_type: String,
+    /// The name of the [`ProxyNetwork`] this Server belongs to
+    network: Option<String>,
+    /// The role of this Server in its [`ProxyNetwork`]
+    network_role: Option<NetworkRole>,
+    /// The edition of Minecraft this Server runs
+    kind: ServerKind,
+    /// The names of the servers which have to be started before this Server
+    depends_on: Vec<String>,
+    /// The time to wait after the dependencies of this Server got started, before starting it
+    start_delay: Duration,
+    /// This holds the Minecraft server process
+    minecraft_server: Mutex<Option<Child>>,
+    /// The path to the Minecraft server
+    path: PathBuf,
+    /// A list of all players on the Minecraft server
+    players: Mutex<(Vec<String>, DateTime<Utc>)>,
+    /// The uuid and ip of every player who is online or about to join, keyed by the player's name
+    player_identities: Mutex<HashMap<String, (Option<String>, Option<String>)>>,
 }
 // actions
 impl Server {
-    pub fn new() -> Self {
+    /// Create a new [`Server`] instance. \
+    /// If the given [`ServerItem`] has no args, the [`default args`](crate::version_provider::VersionProvider::default_args) of its server_type will be
+    /// used. \
+    /// If it has no server_type, the last detected type will be used. If none got detected yet, the type will be read from the server jar. \
+    /// Bedrock servers have no jar and no default args. Their server_type defaults to `bedrock`.
+    pub async fn new(name: &str, server_item: ServerItem) -> Arc<Self> {
+        let path = SERVERS_DIR.join(name);
+        let server_type = ServerType::new(&server_item.server_type, name);
+        if let ServerKind::Bedrock = server_item.kind {
+            if server_item.server_type.is_empty() {
+                server_type.set(BEDROCK_SERVER_TYPE);
+            }
+        } else if server_item.server_type.is_empty() {
+            let detected_type = match DetectedType::load(&path).await {
+                Some(detected_type) => Some(detected_type.server_type),
+                None => ExistingServer::detect(&path)
+                    .await
+                    .ok()
+                    .map(|existing_server| existing_server.server_type),
+            };
+            match detected_type {
+                Some(detected_type) if server_type.exists(&detected_type).await => server_type.set(&detected_type),
+                _ => server_type.set(DEFAULT_SERVER_TYPE),
+            }
+        }
+
+        let mut args = server_item.args;
+        if args.trim().is_empty() && matches!(server_item.kind, ServerKind::Java) {
+            if let Some(default_args) = get_provider(&server_type.to_string())
+                .await
+                .and_then(|provider| provider.default_args())
+            {
+                args = default_args;
+            }
+        }
+
         Self {
+            name: name.to_owned(),
+            main_thread: Arc::new(None.into()),
+            status: Status::Stopped.into(),
+            published_status: Status::Stopped.into(),
+
+            args: Mutex::new(args.split(' ').filter(|arg| !arg.is_empty()).map(String::from).collect()),
+            download_from: server_item.download_from.into(),
+            version: server_item.version.into(),
+            build: server_item.build.into(),
+            download: None.into(),
+            update_policy: server_item.update_policy,
+            pending_update: None.into(),
+            eula_accepted_by: server_item.eula_accepted_by,
+            server_type,
+            configured_server_type: server_item.server_type,
+            network: server_item.network,
+            network_role: server_item.network_role,
+            kind: server_item.kind,
+            depends_on: server_item.depends_on,
+            start_delay: server_item.start_delay,
+            minecraft_server: None.into(),
+            path,
+            players: (vec![], Utc::now()).into(),
+            player_identities: HashMap::new().into(),
+        }
+        .into()
+    }
+    /// This is the blocking implementation to start a given struct. \
+    /// For a non-blocking mode use the [`start method`](Self::start). \
+    /// \
+    /// The `restart` parameter will be used by the [`restart method`](Self::impl_restart) to deactivate all non-fatal error messages of this method and to enable
+    /// this method to be executed during a restart.
+    pub async fn impl_start(self: Arc<Self>, restart: bool) -> Result<(), MCManageError> {
+        self.check_allowed_start(restart).await?;
+        self.publish_status().await;
+
+        if !restart {
+            info!(self.name; "Starting...");
+        }
+        let start_time = Instant::now();
+
+        let mut update = None;
+        let mut prepare_result = self.check_eula().await;
+        if prepare_result.is_ok() {
+            prepare_result = self.configure_network().await;
+        }
+        if prepare_result.is_ok() {
+            prepare_result = match self.kind {
+                ServerKind::Java => self.download_jar().await,
+                ServerKind::Bedrock => self.install_bedrock().await,
+            };
+        }
+        if prepare_result.is_ok() {
+            match self.apply_pending_update().await {
+                Ok(applied_update) => update = applied_update,
+                Err(error) => prepare_result = Err(error),
+            }
+        }
+        if let Err(error) = prepare_result {
+            // the start got aborted, so another start attempt has to be possible
+            if !restart {
+                self.set_status(Status::Stopped).await;
+            }
+            return Err(error);
+        }
+
+        self.check_jar_server_type().await;
+        self.spawn_minecraft_server().await;
+        let rx = self.start_main_thread().await;
+        if let Some(update) = update {
+            let start_timeout = Config::update_start_timeout().await;
+            if let Err(error) = self.finish_update(rx, update, start_timeout, restart).await {
+                // no Minecraft server is running anymore, so another start attempt has to be possible
+                if !restart {
+                    self.set_status(Status::Stopped).await;
+                }
+                return Err(error);
+            }
+        } else {
+            self.recv_start_result(rx, restart).await;
+        }
+        if !restart {
+            self.set_status(Status::Started).await;
+        }
 
+        if !restart {
+            info!(
+                self.name;
+                "Started in {:.3} secs!",
+                start_time.elapsed().as_secs_f64()
+            );
         }
+        Ok(())
     }
-    pub fn start(&self) {
+    /// This is the blocking implementation to stop a given struct. \
+    /// For a non-blocking mode use the [`stop method`](Self::stop). \
+    /// \
+    /// The `restart` parameter will be used by the [`restart method`](Self::impl_restart) to deactivate all non-fatal error messages of this method and to enable
+    /// this method to be executed during a restart. \
+    /// \
+    /// The `forced` parameter is used to wait for a given struct to start / stop to ensure a stop attempt. \
+    /// \
+    /// If the Minecraft server jar is currently being downloaded, the download will be cancelled instead. This will also abort the start and set the
+    /// status to stopped.
+    pub async fn impl_stop(
+        self: Arc<Self>,
+        restart: bool,
+        forced: bool,
+    ) -> Result<(), MCManageError> {
+        match self.check_allowed_stop(restart, forced).await {
+            Ok(()) => {}
+            // a start still downloading the jar can be aborted at any time
+            Err(MCManageError::NotReady) if self.cancel_download().await => {
+                if !restart {
+                    self.set_status(Status::Stopped).await;
+                    EventBus::publish(&self.name, Event::Stopped);
+                    info!(self.name; "The start got aborted.");
+                }
+                return Ok(());
+            }
+            Err(error) => return Err(error),
+        }
+        self.publish_status().await;
+
+        if !restart {
+            info!(self.name; "Shutting down...");
+        }
+        let stop_time = Instant::now();
+
+        if let Some(mut minecraft_server) = self.minecraft_server.lock().await.take() {
+            let stop_command = self.server_type.get_stop_command().await;
+            let send_stop_result = minecraft_server
+                .stdin
+                .as_mut()
+                .unwrap_or_else(|| {
+                    fatal!(self.name; "The Minecraft server process should have a stdin pipe.")
+                })
+                .write_all(format!("{stop_command}\n").as_bytes())
+                .await;
+            self.save_output(&format!(">> {stop_command}")).await;
+
+            if let Err(error) = send_stop_result {
+                if !restart {
+                    warn!(self.name; "An error occurred while writing the input `{stop_command}` to the Minecraft server. The process will be kill forcefully. Error: {error}");
+                }
+                if (minecraft_server.kill().await).is_err() {}
+            }
+            if minecraft_server.wait().await.is_err() {}
+        } else {
+            fatal!(self.name; "This Server should hold a Minecraft server process since it is in a started state.")
+        }
 
+        self.stop_main_thread().await;
+        self.close_sessions(SessionCloseReason::Stopped).await;
+        if !restart {
+            self.set_status(Status::Stopped).await;
+            EventBus::publish(&self.name, Event::Stopped);
+        }
+
+        if !restart {
+            info!(
+                self.name;
+                "Stopped in {:.3} secs!",
+                stop_time.elapsed().as_secs_f64()
+            );
+        }
+        Ok(())
     }
-    pub fn stop(&self) {
+    /// Send a given string to the Minecraft server as an input. \
+    /// It is guaranteed that the string given will be sent to the Server, but this can cause the blocking of the thread calling this function due to the Server restarting.
+    #[async_recursion]
+    pub async fn send_input(self: Arc<Self>, input: &str) {
+        loop {
+            if let Status::Started = *self.status.lock().await {
+                break;
+            }
+            sleep(Config::cooldown().await).await;
+        }
 
+        let send_input_result = self.minecraft_server.lock().await.as_mut()
+            .unwrap_or_else(|| fatal!(self.name; "This Server should hold a Minecraft server process since it is in a started state."))
+            .stdin.as_mut()
+            .unwrap_or_else(|| fatal!(self.name; "The Minecraft server process should have a stdin pipe."))
+            .write_all(format!("{input}\n").as_bytes()).await;
+
+        if let Err(error) = send_input_result {
+            warn!(self.name; "An error occurred while writing the input `{input}` to the Minecraft server. This Server will be restarted. Error: {error}");
+            while let Err(MCManageError::NotReady) = self.clone().impl_restart().await {
+                sleep(Config::cooldown().await).await;
+            }
+            self.clone().send_input(input).await;
+        }
+        self.save_output(&format!(">> {input}")).await;
     }
-    pub fn restart(&self) {
+    /// Record that the given person accepted the [Minecraft EULA](https://aka.ms/MinecraftEULA) for this Server and write its `eula.txt` file.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                   |
+    /// |-----------------------------------|-----------------------------------------------|
+    /// | `Ok(EulaAcceptance)`              | The EULA got accepted.                        |
+    /// | [`MCManageError::InvalidRequest`] | No name of the accepting person got provided. |
+    /// | `Err(...)`                        | The acceptance could not be saved.            |
+    pub async fn accept_eula(self: &Arc<Self>, accepted_by: &str) -> Result<EulaAcceptance, MCManageError> {
+        let accepted_by = accepted_by.trim();
+        if accepted_by.is_empty() {
+            return Err(MCManageError::InvalidRequest(
+                "The name of the person accepting the EULA is required.".to_string(),
+            ));
+        }
 
+        let eula_acceptance = EulaAcceptance {
+            server: self.name.clone(),
+            accepted_by: accepted_by.to_string(),
+            accepted_at: Utc::now(),
+        };
+        eula_acceptance.save(&self.path).await?;
+        eula_acceptance.write_eula_txt(&self.path).await?;
+
+        info!(self.name; "The Minecraft EULA (https://aka.ms/MinecraftEULA) got accepted by {accepted_by}.");
+        Ok(eula_acceptance)
+    }
+    /// Let a running Bedrock Dedicated Server reload its `allowlist.json` file, so changes made using the [`BedrockServer`] apply without a restart. \
+    /// Stopped servers and Java Edition servers will be left untouched.
+    pub async fn reload_allowlist(self: &Arc<Self>) {
+        if let (ServerKind::Bedrock, Status::Started) = (self.kind, self.status().await) {
+            self.clone().send_input("allowlist reload").await;
+        }
+    }
+    /// Reset a given struct to its starting values.
+    pub(super) async fn reset(self: &Arc<Self>) {
+        self.cancel_download().await;
+        if let Some(thread) = self.main_thread.lock().await.take() {
+            thread.abort();
+        }
+        self.set_status(Status::Stopped).await;
+        if let Some(mut server) = self.minecraft_server.lock().await.take() {
+            if (server.kill().await).is_err() {}
+        }
+        self.close_sessions(SessionCloseReason::Stopped).await;
     }
 }
 // info
 impl Server {
+    /// Get the name of this [`Server`].
+    pub fn name(self: &Arc<Self>) -> String {
+        self.name.clone()
+    }
+    /// Get the status of this [`Server`].
+    pub async fn status(self: &Arc<Self>) -> Status {
+        *self.status.lock().await
+    }
+    /// Get some general data about this Minecraft server. \
+    /// For more information on what will be returned, see the [`ServerData`] struct.
+    pub async fn get_data(self: &Arc<Self>) -> ServerData {
+        ServerData {
+            name: self.name.clone(),
+            version: "to be done".to_string(), // TODO Get server version
+            server_type: self.server_type.to_string(),
+            status: *self.status.lock().await,
+            player_count: self.players.lock().await.0.len() as u64,
+            player_cap: self.player_cap().await,
+        }
+    }
+    /// Return a list of every player who is currently on this Minecraft server.
+    pub async fn players(self: &Arc<Self>) -> Vec<String> {
+        self.players.lock().await.0.clone()
+    }
+    /// Return when the list of every player who is currently on this Minecraft server got updated.
+    pub async fn latest_players(self: &Arc<Self>) -> DateTime<Utc> {
+        self.players.lock().await.1
+    }
     /// Returns true if any player is on this server.
-    pub async fn used(&self) -> bool {
-        todo!("Return if this server is use by any player")
+    pub async fn used(self: &Arc<Self>) -> bool {
+        !self.players.lock().await.0.is_empty()
+    }
+    /// Return the Minecraft version this Server runs, if it is known. \
+    /// The version of an installed build will be preferred over the configured one.
+    pub async fn minecraft_version(self: &Arc<Self>) -> Option<String> {
+        if let Some(installed_build) = InstalledBuild::load(&self.path).await {
+            return Some(installed_build.version);
+        }
+        self.version
+            .lock()
+            .await
+            .clone()
+            .filter(|version| version != LATEST)
+    }
+    /// Return who accepted the Minecraft EULA for this Server, and when. If it has not been accepted yet, `None` will be returned.
+    pub async fn eula_acceptance(self: &Arc<Self>) -> Option<EulaAcceptance> {
+        EulaAcceptance::load(&self.path).await
+    }
+    /// Get the [`PluginManager`] of this Server. It manages the jars in the `plugins` directory of this Server.
+    pub fn plugin_manager(self: &Arc<Self>) -> PluginManager {
+        PluginManager::new(&self.path)
+    }
+    /// Get the [`ModManager`] of this Server. It manages the jars in the `mods` directory of this Server.
+    pub fn mod_manager(self: &Arc<Self>) -> ModManager {
+        ModManager::new(&self.path)
+    }
+    /// Return the name of the [`ProxyNetwork`] this Server belongs to, if it belongs to one.
+    pub fn network(self: &Arc<Self>) -> Option<String> {
+        self.network.clone()
+    }
+    /// Return the role of this Server in its [`ProxyNetwork`], if it belongs to one.
+    pub fn network_role(self: &Arc<Self>) -> Option<NetworkRole> {
+        self.network_role
+    }
+    /// Return the type of this Server, like `purpur` or `velocity`.
+    pub fn server_type(self: &Arc<Self>) -> String {
+        self.server_type.to_string()
+    }
+    /// Return the directory of this Server.
+    pub(crate) fn path(self: &Arc<Self>) -> &Path {
+        &self.path
+    }
+    /// Return the edition of Minecraft this Server runs.
+    pub fn kind(self: &Arc<Self>) -> ServerKind {
+        self.kind
+    }
+    /// Return the names of the servers which have to be started before this Server. See the [`StartOrder`](super::start_order::StartOrder).
+    pub fn depends_on(self: &Arc<Self>) -> Vec<String> {
+        self.depends_on.clone()
+    }
+    /// Return the time to wait after the dependencies of this Server got started, before starting it.
+    pub fn start_delay(self: &Arc<Self>) -> Duration {
+        self.start_delay
+    }
+    /// Get the [`BedrockServer`] of this Server. It manages the `server.properties` and `allowlist.json` files of a Bedrock Dedicated Server.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                        |
+    /// |-----------------------------------|------------------------------------|
+    /// | `Ok(BedrockServer)`               | This Server is a Bedrock server.   |
+    /// | [`MCManageError::InvalidRequest`] | This Server runs the Java Edition. |
+    pub fn bedrock(self: &Arc<Self>) -> Result<BedrockServer, MCManageError> {
+        match self.kind {
+            ServerKind::Bedrock => Ok(BedrockServer::new(&self.path)),
+            ServerKind::Java => Err(MCManageError::InvalidRequest(format!(
+                "The server {} is no Bedrock server.",
+                self.name
+            ))),
+        }
+    }
+    /// Return the mod loader this Server runs, if its type uses one.
+    pub fn mod_loader(self: &Arc<Self>) -> Option<ModLoader> {
+        ModLoader::from_server_type(&self.server_type.to_string())
+    }
+}
+// updates
+impl Server {
+    /// Check whether a newer build of this Server's Minecraft version is available. \
+    /// Depending on this Server's [`UpdatePolicy`], the newer build will either be reported or installed at the next start of this Server. \
+    /// MCManage does not schedule restarts yet, so the next start is the first moment the jar can be replaced without interrupting any player. Restarts
+    /// stop and start this Server, so they will install the newer build too.
+    ///
+    /// Only servers whose jar got resolved by a [`VersionProvider`](crate::version_provider::VersionProvider) and whose build is not pinned can be updated. \
+    /// Builds which already failed to start will be ignored.
+    ///
+    /// # Returns
+    ///
+    /// | Return                    | Description                                                                                     |
+    /// |---------------------------|-------------------------------------------------------------------------------------------------|
+    /// | `Ok(Some(ResolvedBuild))` | A newer build is available.                                                                     |
+    /// | `Ok(None)`                | No newer build is available or this Server can not be updated.                                  |
+    /// | `Err(...)`                | See the [`VersionProvider::resolve`](crate::version_provider::VersionProvider::resolve) method. |
+    pub async fn check_for_update(self: &Arc<Self>) -> Result<Option<ResolvedBuild>, MCManageError> {
+        let Some(provider) = get_provider(&self.server_type.to_string()).await else {
+            return Ok(None);
+        };
+        self.check_for_update_with(provider.as_ref()).await
+    }
+    /// Get the update, which will be installed at the next start of this Server.
+    pub async fn pending_update(self: &Arc<Self>) -> Option<ResolvedBuild> {
+        self.pending_update.lock().await.clone()
+    }
+    /// Check whether the given [`VersionProvider`] knows a newer build of this Server's Minecraft version. \
+    /// See the [`check_for_update`](Self::check_for_update) method.
+    async fn check_for_update_with(
+        self: &Arc<Self>,
+        provider: &dyn VersionProvider,
+    ) -> Result<Option<ResolvedBuild>, MCManageError> {
+        if let UpdatePolicy::Disabled = self.update_policy {
+            return Ok(None);
+        }
+        if !self.download_from.lock().await.is_empty() {
+            return Ok(None);
+        }
+        let build = self.build.lock().await.clone().unwrap_or(LATEST.to_string());
+        if build != LATEST {
+            return Ok(None);
+        }
+        let Some(installed_build) = InstalledBuild::load(&self.path).await else {
+            return Ok(None);
+        };
+
+        let newest_build = provider.resolve(&installed_build.version, LATEST).await?;
+        if newest_build.build == installed_build.build
+            || installed_build.failed_builds.contains(&newest_build.build)
+        {
+            return Ok(None);
+        }
+
+        match self.update_policy {
+            UpdatePolicy::Notify => {
+                info!(self.name; "The build {} is available. The build {} is currently installed.", newest_build.build, installed_build.build);
+            }
+            UpdatePolicy::Apply => {
+                let mut pending_update = self.pending_update.lock().await;
+                if pending_update.as_ref() != Some(&newest_build) {
+                    info!(self.name; "The build {} is available. It will be installed at the next start.", newest_build.build);
+                    *pending_update = Some(newest_build.clone());
+                }
+            }
+            UpdatePolicy::Disabled => {}
+        }
+        Ok(Some(newest_build))
+    }
+    /// Install the pending update, if there is one. The currently installed jar will be kept as `<jar>.previous`, so it can be restored if the
+    /// new build fails to start.
+    ///
+    /// # Returns
+    ///
+    /// | Return                                      | Description                                                                     |
+    /// |---------------------------------------------|---------------------------------------------------------------------------------|
+    /// | `Ok(Some((ResolvedBuild, InstalledBuild)))` | The new build got installed. The previously installed build is returned too.    |
+    /// | `Ok(None)`                                  | No update is pending.                                                           |
+    /// | `Err(...)`                                  | See the [`Download::finish`](crate::download_manager::Download::finish) method. |
+    async fn apply_pending_update(
+        self: &Arc<Self>,
+    ) -> Result<Option<(ResolvedBuild, InstalledBuild)>, MCManageError> {
+        let Some(new_build) = self.pending_update.lock().await.take() else {
+            return Ok(None);
+        };
+        let Some(previous_build) = InstalledBuild::load(&self.path).await else {
+            return Ok(None);
+        };
+        let jar_path = self.jar_path().await?;
+        let update_path = jar_path.with_extension("jar.update");
+
+        info!(self.name; "Updating from build {} to build {}...", previous_build.build, new_build.build);
+        if let Err(error) = self
+            .install_jar(new_build.download_request(), new_build.installer.as_ref(), &update_path)
+            .await
+        {
+            if fs::remove_file(&update_path).await.is_err() {}
+            // the update has to be retried at the next start
+            if let MCManageError::Cancelled = error {
+                *self.pending_update.lock().await = Some(new_build);
+            }
+            return Err(error);
+        }
+
+        fs::rename(&jar_path, jar_path.with_extension("jar.previous")).await?;
+        fs::rename(&update_path, &jar_path).await?;
+        InstalledBuild {
+            version: new_build.version.clone(),
+            build: new_build.build.clone(),
+            failed_builds: previous_build.failed_builds.clone(),
+        }
+        .save(&self.path)
+        .await?;
+
+        Ok(Some((new_build, previous_build)))
+    }
+    /// Restore the jar of the previous build after the given build failed to start. The failed build will not be installed again.
+    async fn roll_back_update(
+        self: &Arc<Self>,
+        failed_build: &ResolvedBuild,
+        mut previous_build: InstalledBuild,
+    ) -> Result<(), MCManageError> {
+        let jar_path = self.jar_path().await?;
+        fs::rename(jar_path.with_extension("jar.previous"), &jar_path).await?;
+
+        previous_build.failed_builds.push(failed_build.build.clone());
+        previous_build.save(&self.path).await?;
+
+        warn!(self.name; "Restored the build {}. The build {} will not be installed again.", previous_build.build, failed_build.build);
+        Ok(())
+    }
+    /// Wait for the build installed by the [`apply_pending_update`](Self::apply_pending_update) method to start. If it does not start within the given
+    /// time, the previous build will be restored and started instead.
+    ///
+    /// # Returns
+    ///
+    /// | Return     | Description                                                               |
+    /// |------------|---------------------------------------------------------------------------|
+    /// | `Ok(())`   | Either the new or the restored build got started.                         |
+    /// | `Err(...)` | The previous build could not be restored. No Minecraft server is running. |
+    async fn finish_update(
+        self: &Arc<Self>,
+        bootup_result: oneshot::Receiver<()>,
+        (new_build, previous_build): (ResolvedBuild, InstalledBuild),
+        start_timeout: Duration,
+        restart: bool,
+    ) -> Result<(), MCManageError> {
+        if let Ok(Ok(())) = timeout(start_timeout, bootup_result).await {
+            info!(self.name; "Successfully updated to build {}.", new_build.build);
+            EventBus::publish(
+                &self.name,
+                Event::UpdateApplied {
+                    previous_build: previous_build.build,
+                    new_build: new_build.build,
+                },
+            );
+            return Ok(());
+        }
+
+        error!(self.name; "The build {} failed to start in time. The build {} will be restored.", new_build.build, previous_build.build);
+        self.kill_minecraft_server().await;
+        self.roll_back_update(&new_build, previous_build).await?;
+
+        self.spawn_minecraft_server().await;
+        let rx = self.start_main_thread().await;
+        self.recv_start_result(rx, restart).await;
+        Ok(())
     }
 }
 // internal
 impl Server {
-    async fn main() {
+    /// Start the Minecraft server process using this Server's args. Java Edition servers get started using the `java` command and Bedrock servers
+    /// by running the binary of the Bedrock Dedicated Server.
+    async fn spawn_minecraft_server(self: &Arc<Self>) {
+        let mut command = match self.kind {
+            ServerKind::Java => Command::new("java"),
+            ServerKind::Bedrock => BedrockServer::new(&self.path).command(),
+        };
+        *self.minecraft_server.lock().await = Some(
+            command
+                .current_dir(&self.path)
+                .args(&*self.args.lock().await)
+                .stderr(Stdio::inherit())
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .spawn()
+                .unwrap_or_else(|error| {
+                    fatal!(self.name; "An error occurred while starting the Minecraft Server. Error: {error}")
+                }),
+        );
+    }
+    /// Kill the Minecraft server process and stop the [`main thread`](Self::main).
+    async fn kill_minecraft_server(self: &Arc<Self>) {
+        if let Some(mut minecraft_server) = self.minecraft_server.lock().await.take() {
+            if (minecraft_server.kill().await).is_err() {}
+        }
+        self.stop_main_thread().await;
+    }
+    /// This method will check if a server jar exists. If no jar file was detected, the one from the configured link will be downloaded using the
+    /// [`DownloadManager`]. If no link is configured, it will be resolved from this Server's type, version and build. \
+    /// \
+    /// The download can be cancelled by stopping or resetting this Server.
+    ///
+    /// # Returns
+    ///
+    /// | Return                         | Description                                                                     |
+    /// |--------------------------------|---------------------------------------------------------------------------------|
+    /// | `Ok(())`                       | The server jar is ready to be used.                                             |
+    /// | [`MCManageError::NotFound`]    | Neither a jar file nor a link to download one from is configured or resolvable. |
+    /// | [`MCManageError::InvalidFile`] | The installer of the resolved build failed.                                     |
+    /// | [`MCManageError::Cancelled`]   | The download got cancelled because this Server got stopped or reset.            |
+    /// | `Err(...)`                     | See the [`Download::finish`](crate::download_manager::Download::finish) method. |
+    async fn download_jar(self: &Arc<Self>) -> Result<(), MCManageError> {
+        // servers started using argument files ( for example modern Forge servers ) have no jar which could be downloaded
+        if self.args.lock().await.iter().any(|arg| arg.starts_with('@')) {
+            return Ok(());
+        }
+
+        let jar_path = self.jar_path().await?;
+        if jar_path.exists() {
+            return Ok(());
+        }
+
+        let (request, resolved_build) = if let Some(request) = self.download_request().await {
+            request
+        } else {
+            error!(self.name; "Could not find a jar file or a link to download the jar file from.");
+            error!(self.name; "Please copy a valid jar file to '{}' or set a download link or version for this server.", jar_path.display());
+            return Err(MCManageError::NotFound);
+        };
+        info!(self.name; "No jar file could be found. Downloading a new one...");
+
+        let installer = resolved_build.as_ref().and_then(|resolved_build| resolved_build.installer.as_ref());
+        self.install_jar(request, installer, &jar_path).await?;
+
+        if let Some(resolved_build) = resolved_build {
+            InstalledBuild {
+                version: resolved_build.version,
+                build: resolved_build.build,
+                failed_builds: vec![],
+            }
+            .save(&self.path)
+            .await?;
+        }
+        Ok(())
+    }
+    /// This method will check if the binary of the Bedrock Dedicated Server exists. If not, the zip archive of the Bedrock Dedicated Server will be
+    /// downloaded from the configured link and extracted to this Server's directory. \
+    /// \
+    /// The download can be cancelled by stopping or resetting this Server.
+    ///
+    /// # Returns
+    ///
+    /// | Return                         | Description                                                                     |
+    /// |--------------------------------|---------------------------------------------------------------------------------|
+    /// | `Ok(())`                       | The Bedrock Dedicated Server is ready to be used.                               |
+    /// | [`MCManageError::NotFound`]    | Neither the binary nor a link to download the server from is configured.        |
+    /// | [`MCManageError::InvalidFile`] | The downloaded archive contains no Bedrock Dedicated Server.                    |
+    /// | [`MCManageError::Cancelled`]   | The download got cancelled because this Server got stopped or reset.            |
+    /// | `Err(...)`                     | See the [`Download::finish`](crate::download_manager::Download::finish) method. |
+    async fn install_bedrock(self: &Arc<Self>) -> Result<(), MCManageError> {
+        let bedrock_server = BedrockServer::new(&self.path);
+        if bedrock_server.is_installed() {
+            return Ok(());
+        }
+
+        let download_from = self.download_from.lock().await.clone();
+        if download_from.is_empty() {
+            error!(self.name; "Could not find the Bedrock Dedicated Server or a link to download it from.");
+            error!(self.name; "Please extract the Bedrock Dedicated Server to '{}' or set a download link for this server.", self.path.display());
+            return Err(MCManageError::NotFound);
+        }
+        info!(self.name; "No Bedrock Dedicated Server could be found. Downloading a new one...");
+
+        let archive_path = self.path.join(BEDROCK_ARCHIVE);
+        self.download_file(DownloadRequest::new(&download_from), &archive_path).await?;
+        let install_result = bedrock_server.install(&archive_path).await;
+        if fs::remove_file(&archive_path).await.is_err() {}
+        if let Err(MCManageError::InvalidFile) = install_result {
+            error!(self.name; "The archive downloaded from '{download_from}' contains no Bedrock Dedicated Server.");
+        }
+        install_result
+    }
+    /// Return the maximum number of players set in this Server's `server.properties` file. If it is not set, the default of the Minecraft server
+    /// will be returned.
+    async fn player_cap(self: &Arc<Self>) -> u64 {
+        if let ServerKind::Bedrock = self.kind {
+            return BedrockServer::new(&self.path)
+                .properties()
+                .await
+                .map_or(10, |properties| properties.max_players);
+        }
+        fs::read_to_string(self.path.join("server.properties"))
+            .await
+            .ok()
+            .and_then(|properties| property(&properties, "max-players"))
+            .and_then(|max_players| max_players.parse().ok())
+            .unwrap_or(20)
+    }
+    /// Return the path to this Server's jar. The name of the jar is read from the args of this Server.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                                        |
+    /// |-----------------------------|----------------------------------------------------|
+    /// | `Ok(PathBuf)`               | The path to the jar. The jar itself may not exist. |
+    /// | [`MCManageError::NotFound`] | The args of this Server do not contain a jar name. |
+    async fn jar_path(self: &Arc<Self>) -> Result<PathBuf, MCManageError> {
+        let args = self.args.lock().await;
+        let jar_name = args
+            .iter()
+            .position(|arg| arg == "-jar")
+            .and_then(|jar_position| args.get(jar_position + 1))
+            .filter(|jar_name| jar_name.contains(".jar"));
+
+        if let Some(jar_name) = jar_name {
+            Ok(self.path.join(jar_name))
+        } else {
+            error!(self.name; "No Minecraft server jar name has been defined for this Server. Please add `-jar <name>.jar` to its args.");
+            Err(MCManageError::NotFound)
+        }
+    }
+    /// Download a file using the [`DownloadManager`] and report its progress. \
+    /// The download can be cancelled by stopping or resetting this Server.
+    async fn download_file(
+        self: &Arc<Self>,
+        request: DownloadRequest,
+        destination: &Path,
+    ) -> Result<(), MCManageError> {
+        let download_from = request.url.clone();
+        // the token has to be stored before the download starts, so that a stop can not slip through in between
+        let cancellation_token = CancellationToken::new();
+        *self.download.lock().await = Some(cancellation_token.clone());
+        let mut download = DownloadManager::download_cancellable(request, destination, cancellation_token);
+
+        // report the progress in steps of 25%
+        let mut events = download.subscribe();
+        let name = self.name.clone();
+        spawn(async move {
+            let mut reported = 0;
+            while let Ok(event) = events.recv().await {
+                match event {
+                    DownloadEvent::Cached => {
+                        info!(name; "Found the jar file in the downloads folder.");
+                    }
+                    DownloadEvent::Started { resumed_from, .. } if resumed_from > 0 => {
+                        info!(name; "Resuming an earlier download at {resumed_from} bytes...");
+                    }
+                    DownloadEvent::Progress { downloaded, total: Some(total) } if total > 0 => {
+                        let percent = downloaded * 100 / total;
+                        if percent >= reported + 25 {
+                            reported = percent - percent % 25;
+                            info!(name; "Downloaded {reported}% of the jar file.");
+                        }
+                    }
+                    DownloadEvent::Finished => break,
+                    _ => {}
+                }
+            }
+        });
+
+        let result = download.finish().await;
+        self.download.lock().await.take();
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(MCManageError::Cancelled) => {
+                info!(self.name; "The download of the jar file got cancelled.");
+                Err(MCManageError::Cancelled)
+            }
+            Err(error) => {
+                error!(self.name; "Failed to download the jar file from '{download_from}'. Error: {error}");
+                Err(error)
+            }
+        }
+    }
+    /// Download the jar requested by the given [`DownloadRequest`] to the given path. \
+    /// If a [`ServerInstaller`] is given, the request points to the installer instead. In that case, the installer will be downloaded and run
+    /// inside of the directory `{destination}.install`, the jar it created will be moved to the given path and every other file it created will be
+    /// added to this Server's directory, as long as it does not exist there already.
+    ///
+    /// # Returns
+    ///
+    /// | Return                         | Description                                                                     |
+    /// |--------------------------------|---------------------------------------------------------------------------------|
+    /// | `Ok(())`                       | The jar got saved to the given path.                                            |
+    /// | [`MCManageError::InvalidFile`] | The installer failed.                                                           |
+    /// | [`MCManageError::Cancelled`]   | The download got cancelled because this Server got stopped or reset.            |
+    /// | `Err(...)`                     | See the [`Download::finish`](crate::download_manager::Download::finish) method. |
+    async fn install_jar(
+        self: &Arc<Self>,
+        request: DownloadRequest,
+        installer: Option<&ServerInstaller>,
+        destination: &Path,
+    ) -> Result<(), MCManageError> {
+        let Some(installer) = installer else {
+            return self.download_file(request, destination).await;
+        };
+
+        let install_path = destination.with_extension("install");
+        if fs::remove_dir_all(&install_path).await.is_err() {}
+        fs::create_dir_all(&install_path).await?;
+        let result = async {
+            let installer_path = install_path.join("installer.jar");
+            self.download_file(request, &installer_path).await?;
+
+            info!(self.name; "Running the installer...");
+            let output = Command::new("java")
+                .current_dir(&install_path)
+                .arg("-jar")
+                .arg(&installer_path)
+                .args(&installer.args)
+                .stdin(Stdio::null())
+                .output()
+                .await?;
+            fs::remove_file(&installer_path).await?;
+            if !output.status.success() {
+                error!(
+                    self.name;
+                    "The installer failed with {}. Output: {}{}",
+                    output.status,
+                    String::from_utf8_lossy(&output.stdout),
+                    String::from_utf8_lossy(&output.stderr)
+                );
+                return Err(MCManageError::InvalidFile);
+            }
+
+            fs::rename(install_path.join(&installer.jar), destination).await?;
+            Self::move_missing(&install_path, &self.path).await
+        }
+        .await;
+        if fs::remove_dir_all(&install_path).await.is_err() {}
+        result
+    }
+    /// Move every file inside of the first directory to the same place inside of the second one, unless a file already exists there.
+    #[async_recursion]
+    async fn move_missing(from: &Path, to: &Path) -> Result<(), MCManageError> {
+        fs::create_dir_all(to).await?;
+        let mut entries = fs::read_dir(from).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let target = to.join(entry.file_name());
+            if entry.file_type().await?.is_dir() {
+                Self::move_missing(&entry.path(), &target).await?;
+            } else if !target.exists() {
+                fs::rename(entry.path(), target).await?;
+            }
+        }
+        Ok(())
+    }
+    /// Get the [`DownloadRequest`] for this Server's jar. \
+    /// The configured download link will be preferred. If none is set, the link will be resolved using the [`VersionProvider`](crate::version_provider::VersionProvider)
+    /// of this Server's type. In that case, the [`ResolvedBuild`] will be returned too.
+    async fn download_request(
+        self: &Arc<Self>,
+    ) -> Option<(DownloadRequest, Option<ResolvedBuild>)> {
+        let download_from = self.download_from.lock().await.clone();
+        if !download_from.is_empty() {
+            return Some((DownloadRequest::new(&download_from), None));
+        }
+
+        let version = self.version.lock().await.clone()?;
+        let build = self.build.lock().await.clone().unwrap_or(LATEST.to_string());
+        let Some(provider) = get_provider(&self.server_type.to_string()).await else {
+            error!(self.name; "The download link of the server_type {} can not be resolved automatically.", self.server_type.to_string());
+            return None;
+        };
+
+        match provider.resolve(&version, &build).await {
+            Ok(resolved_build) => {
+                info!(
+                    self.name;
+                    "Resolved {} {} build {}.",
+                    provider.name(),
+                    resolved_build.version,
+                    resolved_build.build
+                );
+                Some((resolved_build.download_request(), Some(resolved_build)))
+            }
+            Err(error) => {
+                error!(self.name; "Could not resolve the build {build} of {} {version}. Error: {error}", provider.name());
+                None
+            }
+        }
+    }
+    /// Cancel the download of the Minecraft server jar. \
+    /// Returns true if a download was running.
+    async fn cancel_download(self: &Arc<Self>) -> bool {
+        if let Some(download) = self.download.lock().await.take() {
+            download.cancel();
+            true
+        } else {
+            false
+        }
+    }
+    /// This represents the main loop of a given struct.
+    async fn main(
+        self: Arc<Self>,
+        mut bootup_result: Option<oneshot::Sender<()>>,
+    ) -> Result<(), MCManageError> {
+        let stdout = BufReader::new(self.get_stdout_pipe().await);
+
+        let mut lines = stdout.lines();
+        // the message of the last exception together with the stack trace printed after it
+        let mut exception: Option<(String, Vec<String>)> = None;
         loop {
-            sleep(Duration::new(11111111111, 0)).await;
+            let line;
+            if let Some(content) = lines.next_line().await.unwrap_or_else(|error| {
+                fatal!(self.name; "An error occurred while reading the output. Error: {error}")
+            }) {
+                line = content;
+            } else {
+                // It will only be None returned if the Child process got killed
+                if let Some((message, stack_trace)) = exception.take() {
+                    EventBus::publish(&self.name, Event::Exception { message, stack_trace });
+                }
+                if let Status::Started = *self.status.lock().await {
+                    error!(self.name; "The Minecraft server stopped unexpectedly.");
+                    EventBus::publish(&self.name, Event::Crashed);
+                    self.close_sessions(SessionCloseReason::Crashed).await;
+                }
+                return Ok(());
+            }
+
+            self.save_output(&line).await;
+
+            // the stack trace of an exception is complete, once a line not belonging to it got printed
+            if let Some((_, stack_trace)) = &mut exception {
+                let stack_trace_line = self
+                    .server_type
+                    .get_stack_trace()
+                    .await
+                    .map_or(false, |pattern| pattern.matches(&line));
+                if stack_trace_line {
+                    stack_trace.push(line.trim().to_string());
+                    continue;
+                }
+            }
+            if let Some((message, stack_trace)) = exception.take() {
+                EventBus::publish(&self.name, Event::Exception { message, stack_trace });
+            }
+
+            if let Some(bootup_result_inner) = bootup_result {
+                // the branding of a Minecraft server gets printed while it is starting
+                if let Some(detected_type) = self.server_type.detect(&line).await {
+                    self.detected_server_type(&detected_type, true).await;
+                }
+                bootup_result = self.check_started(&line, bootup_result_inner).await;
+            }
+
+            // a player could write a message looking like a player joining
+            if !self.check_chat(&line).await {
+                self.check_player_identity(&line).await;
+                self.check_player_activity(&line).await;
+                exception = self
+                    .check_events(&line)
+                    .await
+                    .map(|message| (message, vec![]));
+            }
         }
     }
-}
+    /// Save a given line to a log file saved under ' [`SERVER_LOGS_DIR`]/{Server.name}.txt '.
+    async fn save_output(self: &Arc<Self>, line: &str) {
+        fs::create_dir_all(SERVER_LOGS_DIR.as_path())
+            .await
+            .unwrap_or_else(|error| {
+                fatal!(
+                    self.name;
+                    "An error occurred while creating the dir `{}`. Error: {error}",
+                    SERVER_LOGS_DIR.display()
+                )
+            });
+
+        let destination = SERVER_LOGS_DIR.join(self.name.clone() + ".log");
+        let mut log_file = OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(destination.clone())
+            .await
+            .unwrap_or_else(|error| {
+                fatal!(
+                    self.name;
+                    "Could not open the log file at {}. Error: {error}",
+                    destination.display()
+                )
+            });
 
+        log_file
+            .write_all(format!("{line}\n").as_bytes())
+            .await
+            .unwrap_or_else(|error| {
+                fatal!(
+                    self.name;
+                    "An error occurred while writing a log message to the file {}. Error: {error}",
+                    destination.display()
+                )
+            });
+    }
+    /// Get the stdout pipe of the Minecraft server.
+    async fn get_stdout_pipe(self: &Arc<Self>) -> ChildStdout {
+        self.minecraft_server
+            .lock()
+            .await
+            .as_mut()
+            .unwrap_or_else(|| {
+                fatal!(self.name; "This method should only be called once the Minecraft server process got set.")
+            })
+            .stdout
+            .take()
+            .unwrap_or_else(|| {
+                fatal!(self.name; "The stdout pipe of this server only gets taken once.")
+            })
+    }
+    /// Check if the Minecraft server has started.
+    async fn check_started(
+        self: &Arc<Self>,
+        line: &str,
+        bootup_result: oneshot::Sender<()>,
+    ) -> Option<oneshot::Sender<()>> {
+        if !self.server_type.get_started().await.matches(line) {
+            return Some(bootup_result);
+        }
+        self.send_start_result(&mut Some(bootup_result)).await;
+        self.set_status(Status::Started).await;
+        EventBus::publish(&self.name, Event::Started);
+        None
+    }
+    /// Check for player activity ( connecting/disconnecting ) and save the name of the player who joined or delete the one who left.
+    async fn check_player_activity(self: &Arc<Self>, line: &str) {
+        // check if anyone joined / left
+        let player_joined = self.server_type.get_player_joined().await.matches(line);
+        let player_left = !player_joined && self.server_type.get_player_left().await.matches(line);
+
+        // save the detected state to this Server
+        let mut players = self.players.lock().await;
+        if player_joined {
+            let Ok(player_name) = self.server_type.get_player_name_joined(line).await else {
+                warn!(self.name; "Failed to read the name of the player who joined. Check the player_joined pattern of the server_type {}.", self.server_type.to_string());
+                return;
+            };
+            let (uuid, ip) = {
+                let mut player_identities = self.player_identities.lock().await;
+                let (uuid, ip) = player_identities.entry(player_name.clone()).or_default();
+                // without an announced uuid, the server runs in offline mode
+                let uuid = uuid
+                    .get_or_insert_with(|| PlayerIdentities::offline_uuid(&player_name))
+                    .clone();
+                (uuid, ip.clone())
+            };
+            let ip = if Config::store_player_ips().await { ip } else { None };
+            self.warn_history_error(
+                PlayerIdentities::seen(&uuid, &player_name, ip.as_deref()).await,
+                &format!("save the identity of the player {player_name}"),
+            );
+            self.warn_history_error(
+                PlayerSessions::open(&self.name, &uuid, &player_name).await,
+                &format!("save the session of the player {player_name}"),
+            );
+            EventBus::publish(
+                &self.name,
+                Event::PlayerJoined {
+                    uuid,
+                    player: player_name.clone(),
+                },
+            );
+            players.0.push(player_name);
+            players.1 = Utc::now();
+        } else if player_left {
+            let Ok(player_name) = self.server_type.get_player_name_left(line).await else {
+                warn!(self.name; "Failed to read the name of the player who left. Check the player_left pattern of the server_type {}.", self.server_type.to_string());
+                return;
+            };
+            if let Some(index) = players.0.iter().position(|player| *player == player_name) {
+                players.0.remove(index);
+                players.1 = Utc::now();
+                let uuid = self
+                    .player_identities
+                    .lock()
+                    .await
+                    .remove(&player_name)
+                    .and_then(|(uuid, _)| uuid)
+                    .unwrap_or_else(|| PlayerIdentities::offline_uuid(&player_name));
+                self.warn_history_error(
+                    PlayerSessions::close(&self.name, &uuid, SessionCloseReason::Left).await,
+                    &format!("close the session of the player {player_name}"),
+                );
+                EventBus::publish(
+                    &self.name,
+                    Event::PlayerLeft {
+                        uuid,
+                        player: player_name,
+                    },
+                );
+            } else {
+                error!(self.name; "The player {player_name} left without ever joining this server. This Server will restart.");
+                self.restart();
+            }
+        }
+    }
+    /// Set the [`Status`] of this Server and publish the change to the [`EventBus`].
+    async fn set_status(self: &Arc<Self>, status: Status) {
+        *self.status.lock().await = status;
+        self.publish_status().await;
+    }
+    /// Publish the [`Status`] of this Server to the [`EventBus`], if it changed since it got published the last time. \
+    /// This is needed since the status also gets changed by the methods of the [`ConcurrentClass`] macro.
+    async fn publish_status(self: &Arc<Self>) {
+        let status = *self.status.lock().await;
+        let mut published_status = self.published_status.lock().await;
+        if *published_status != status {
+            *published_status = status;
+            EventBus::publish(&self.name, Event::StatusChanged { status });
+        }
+    }
+    /// Check for a chat message and save it to the [`ChatLog`]. If the line is a chat message, `true` will be returned.
+    async fn check_chat(self: &Arc<Self>, line: &str) -> bool {
+        if !self.server_type.get_chat().await.matches(line) {
+            return false;
+        }
+        let Ok((sender, message)) = self.server_type.get_chat_message(line).await else {
+            return false;
+        };
 
+        let uuid = self
+            .player_identities
+            .lock()
+            .await
+            .get(&sender)
+            .and_then(|(uuid, _)| uuid.clone());
+        self.warn_history_error(
+            ChatLog::record(&self.name, uuid.as_deref(), &sender, &message).await,
+            &format!("save a chat message of the player {sender}"),
+        );
+        true
+    }
+    /// Detect the server type from the jar of the Minecraft server. See the [`detected_server_type method`](Self::detected_server_type).
+    async fn check_jar_server_type(self: &Arc<Self>) {
+        if let Ok(existing_server) = ExistingServer::detect(&self.path).await {
+            self.detected_server_type(&existing_server.server_type, false).await;
+        }
+    }
+    /// Handle a server type detected from the output or the jar of the Minecraft server. \
+    /// If a server_type got set for this Server and it differs from the detected one, a warning will be logged. Otherwise the
+    /// detected type will be used and saved as the [`DetectedType`]. A type detected from the jar will not replace one detected from the output,
+    /// since the output is more reliable.
+    async fn detected_server_type(self: &Arc<Self>, detected_type: &str, from_output: bool) {
+        if !self.configured_server_type.is_empty() {
+            if detected_type != self.configured_server_type {
+                warn!(
+                    self.name;
+                    "The server_type is set to {}, but the Minecraft server seems to be of the type {detected_type}. Change the server_type of this server using the server list api.",
+                    self.configured_server_type
+                );
+            }
+            return;
+        }
+        if !self.server_type.exists(detected_type).await {
+            warn!(self.name; "The detected server_type {detected_type} is not defined in the config/server_types.toml file.");
+            return;
+        }
 
-// /// This struct represents an API for one Minecraft server, which got assigned with the initiation of this struct.
-// #[derive(ConcurrentClass)]
-// pub struct Server {
-//     /// This struct's name
-//     name: String,
-//     /// The main thread of this struct
-//     main_thread: Arc<Mutex<Option<ThreadJoinHandle>>>,
-//     /// The [`Status`] of this struct
-//     status: Mutex<Status>,
-
-//     /// The arguments which should be passed to the Minecraft server
-//     args: Mutex<Vec<String>>,
-//     /// The url to download the minecraft server jar from
-//     download_from: Mutex<String>,
-//     /// The [`type`](ServerType) of the Minecraft server
-//     server_type: ServerType,
-//     /// This holds the Minecraft server process
-//     minecraft_server: Mutex<Option<Child>>,
-//     /// The path to the Minecraft server
-//     path: PathBuf,
-//     /// A list of all players on the Minecraft server
-//     players: Mutex<(Vec<String>, DateTime<Utc>)>,
-// }
-// impl Server {
-//     /// Create a new [`Server`] instance.
-//     pub async fn new(
-//         name: &str,
-//         server_item: ServerItem,
-//     ) -> Arc<Self> {
-//         Self {
-//             name: name.to_owned(),
-//             main_thread: Arc::new(None.into()),
-//             status: Status::Stopped.into(),
-
-//             args: Mutex::new(server_item.args.split(' ').map(String::from).collect()),
-//             download_from: server_item.download_from.into(),
-//             server_type: ServerType::new(&server_item.server_type, name),
-//             minecraft_server: None.into(),
-//             path: SERVERS_DIR.join(name),
-//             players: (vec![], Utc::now()).into(),
-//         }
-//         .into()
-//     }
-//     /// Get the name of this [`Server`].
-//     pub fn name(self: &Arc<Self>) -> String {
-//         self.name.clone()
-//     }
-//     /// Get the status of this [`Server`].
-//     pub async fn status(self: &Arc<Self>) -> Status {
-//         *self.status.lock().await
-//     }
-//     /// Get some general data about this Minecraft server. \
-//     /// For more information on what will be returned, see the [`ServerData`] struct.
-//     pub async fn get_data(self: &Arc<Self>) -> ServerData {
-//         ServerData {
-//             name: self.name.clone(),
-//             version: "to be done".to_string(), // TODO Get server version
-//             server_type: self.server_type.to_string(),
-//             status: *self.status.lock().await,
-//             player_count: self.players.lock().await.0.len() as u64,
-//             player_cap: 10, // TODO Get the player cap of a server
-//         }
-//     }
-//     /// This is the blocking implementation to start a given struct. \
-//     /// For a non-blocking mode use the [`start method`](Self::start). \
-//     /// \
-//     /// The `restart` parameter will be used by the [`restart method`](Self::impl_restart) to deactivate all non-fatal error messages of this method and to enable
-//     /// this method to be executed during a restart.
-//     pub async fn impl_start(self: Arc<Self>, restart: bool) -> Result<(), MCManageError> {
-//         self.check_allowed_start(restart).await?;
-
-//         if !restart {
-//             info!(self.name, "Starting...");
-//         }
-//         let start_time = Instant::now();
-//         self.download_jar().await;
-
-//         *self.minecraft_server.lock().await = Some(
-//             Command::new("java")
-//                 .current_dir(&self.path)
-//                 .args(&*self.args.lock().await)
-//                 .stderr(Stdio::inherit())
-//                 .stdin(Stdio::piped())
-//                 .stdout(Stdio::piped())
-//                 .spawn()
-//                 .unwrap_or_else(|error| {
-//                     panic!(
-//                         "An error occurred while starting the Minecraft Server {}. Error: {error}",
-//                         self.name
-//                     )
-//                 }),
-//         );
-
-//         let rx = self.start_main_thread().await;
-//         self.recv_start_result(rx, restart).await;
-//         if !restart {
-//             *self.status.lock().await = Status::Started;
-//         }
-
-//         if !restart {
-//             info!(
-//                 self.name,
-//                 "Started in {:.3} secs!",
-//                 start_time.elapsed().as_secs_f64()
-//             );
-//         }
-//         Ok(())
-//     }
-//     /// This method will check if a server jar exists. If no jar file was detected, the one from the configured link will be downloaded. In the event that neither a jar file
-//     /// nor a download lind exists, this method will block until one is set.
-//     async fn download_jar(self: &Arc<Self>) {
-//         /// This function returns the jar name from the args of a Minecraft server.
-//         fn get_jar_name(args: &Vec<String>) -> String {
-//             let mut jar_name = "".to_string();
-//             let args_len = args.len();
-//             for (i, arg) in args.iter().enumerate() {
-//                 if arg == "-jar" {
-//                     if i + 1 == args_len {
-//                         return "".to_string();
-//                     }
-//                     jar_name = args[i + 1].clone();
-//                     if !jar_name.contains(".jar") {
-//                         return "".to_string();
-//                     }
-//                     break;
-//                 }
-//             }
-//             jar_name
-//         }
-//         /// This function will read the `config/server_list.toml` file and return the current [`ServerItem`].
-//         async fn get_current_server_item(server: &Arc<Server>) -> Option<ServerItem> {
-//             let server_list_toml;
-//             if let Ok(toml) = load_toml(&SERVER_LIST_FILE, &server.name, false).await {
-//                 server_list_toml = toml;
-//             } else {
-//                 return None;
-//             }
-
-//             // create a list of Servers and return it
-//             let mut server_item: Option<ServerItem> = None;
-//             for key in server_list_toml.keys() {
-//                 if key == &server.name {
-//                     if let Some(server) = server_list_toml.get(key) {
-//                         if let Ok(server) = server.clone().try_into() {
-//                             server_item = Some(server)
-//                         }
-//                     }
-//                     break;
-//                 }
-//             }
-//             server_item
-//         }
-//         /// This function will wait for a file or download link to be set.
-//         async fn wait_for_jar_content(server: &Arc<Server>, jar_path: &Path) {
-//             loop {
-//                 // Scan for a jar file
-//                 if jar_path.exists() {
-//                     info!(server.name, "Registered a jar file. This Server will now continue its starting procedure.");
-//                     return;
-//                 }
-//                 // Scan for a download link
-//                 if let Some(server_item) = get_current_server_item(server).await {
-//                     if server_item.download_from != *"" {
-//                         info!(server.name, "Registered a download link. This Server will now continue its starting procedure.");
-//                         *server.download_from.lock().await = server_item.download_from;
-//                         break;
-//                     }
-//                 }
-
-//                 sleep(Config::cooldown().await).await;
-//             }
-//         }
-
-//         let mut jar_name = get_jar_name(&*self.args.lock().await);
-//         if jar_name == *"" {
-//             error!(self.name, "No Minecraft server jar name has been defined for this Server. Please configure one in the '{}' file.", SERVER_LIST_FILE.display());
-//             error!(
-//                 self.name,
-//                 "This Server will now wait for a jar name to be set."
-//             );
-
-//             loop {
-//                 if let Some(server_item) = get_current_server_item(self).await {
-//                     let args = server_item
-//                         .args
-//                         .split(' ')
-//                         .map(String::from)
-//                         .collect::<Vec<String>>();
-//                     if *self.args.lock().await != args {
-//                         jar_name = get_jar_name(&args);
-
-//                         if jar_name != *"" {
-//                             info!(self.name, "Registered a jar name. This Server will now continue its starting procedure.");
-//                             *self.args.lock().await = args;
-//                             break;
-//                         }
-//                     }
-//                 }
-
-//                 sleep(Config::cooldown().await).await;
-//             }
-//         }
-
-//         let jar_path = self.path.join(jar_name);
-//         if !Path::exists(Path::new(&jar_path)) {
-//             if *self.download_from.lock().await == *"" {
-//                 error!(
-//                     self.name,
-//                     "Could not find a jar file or a link to download the jar file from."
-//                 );
-//                 error!(self.name, "Please copy a valid jar file to '{}' or set a download link for this server in '{}'.", jar_path.display(), SERVER_LIST_FILE.display());
-//                 error!(
-//                     self.name,
-//                     "This Server will now wait for a file or download link to be set."
-//                 );
-
-//                 wait_for_jar_content(self, &jar_path).await;
-//                 if Path::exists(Path::new(&jar_path)) {
-//                     return;
-//                 }
-//             } else {
-//                 info!(
-//                     self.name,
-//                     "No jar file could be found. Downloading a new one..."
-//                 );
-//             }
-
-//             let mut server_jar_option = None;
-//             let max_tries = Config::max_tries().await;
-//             for i in 0..max_tries {
-//                 match reqwest::get(&*self.download_from.lock().await).await {
-//                     Ok(file) => {
-//                         server_jar_option = Some(file.bytes_stream());
-//                         break;
-//                     }
-//                     Err(error) => {
-//                         if error.is_request() {
-//                             error!(self.name, "Something seems to be wrong with the download link given. Error: {error}");
-//                             error!(self.name, "This Server will now wait for a file or download link to be set.");
-
-//                             wait_for_jar_content(self, &jar_path).await;
-//                             if Path::exists(Path::new(&jar_path)) {
-//                                 return;
-//                             }
-//                         } else {
-//                             warn!(
-//                                 self.name,
-//                                 "Failed to download the server jar. Error: {error}"
-//                             );
-//                             warn!(self.name, "This was attempt {i} out of {max_tries}.");
-
-//                             if i == max_tries {
-//                                 panic!("The server jar could not be downloaded after {max_tries} attempts.");
-//                             }
-//                         }
-//                     }
-//                 }
-//             }
-//             let mut server_jar = server_jar_option.unwrap_or_else(|| {
-//                 panic!("The server jar could not be downloaded after {max_tries} attempts.")
-//             });
-
-//             fs::create_dir_all(&self.path).await.unwrap_or_else(|error| {
-//                 panic!(
-//                     "Failed to create the path '{}'. Error: {error}",
-//                     self.path.display()
-//                 )
-//             });
-//             let mut jar_file = File::create(&jar_path).await.unwrap_or_else(|error| {
-//                 panic!(
-//                     "Failed to create the file '{}'. Error: {error}",
-//                     jar_path.display()
-//                 )
-//             });
-
-//             while let Some(item) = server_jar.next().await {
-//                 io::copy(
-//                     &mut item.unwrap_or_else(|error| panic!("An error occurred while coping the downloaded jar to a file. Error: {error}")).as_ref(),
-//                     &mut jar_file
-//                 ).await.unwrap_or_else(|error| panic!("An error occurred while coping the downloaded jar to a file. Error: {error}"));
-//             }
-//         }
-//     }
-//     /// This is the blocking implementation to stop a given struct. \
-//     /// For a non-blocking mode use the [`stop method`](Self::stop). \
-//     /// \
-//     /// The `restart` parameter will be used by the [`restart method`](Self::impl_restart) to deactivate all non-fatal error messages of this method and to enable
-//     /// this method to be executed during a restart. \
-//     /// \
-//     /// The `forced` parameter is used to wait for a given struct to start / stop to ensure a stop attempt.
-//     pub async fn impl_stop(
-//         self: Arc<Self>,
-//         restart: bool,
-//         forced: bool,
-//     ) -> Result<(), MCManageError> {
-//         self.check_allowed_stop(restart, forced).await?;
-
-//         if !restart {
-//             info!(self.name, "Shutting down...");
-//         }
-//         let stop_time = Instant::now();
-
-//         if let Some(mut minecraft_server) = self.minecraft_server.lock().await.take() {
-//             let send_stop_result = minecraft_server
-//                 .stdin
-//                 .as_mut()
-//                 .unwrap_or_else(|| {
-//                     panic!(
-//                         "The Minecraft server process of {} should have a stdin pipe.",
-//                         self.name
-//                     )
-//                 })
-//                 .write_all("stop\n".as_bytes())
-//                 .await;
-//             self.save_output(">> stop").await;
-
-//             if let Err(error) = send_stop_result {
-//                 if !restart {
-//                     warn!(self.name, "An error occurred while writing the input `stop` to the Minecraft server. The process will be kill forcefully. Error: {error}");
-//                 }
-//                 if (minecraft_server.kill().await).is_err() {}
-//             }
-//             if minecraft_server.wait().await.is_err() {}
-//         } else {
-//             panic!("The Server {} should hold a Minecraft server process since it is in a started state.", self.name)
-//         }
-
-//         self.stop_main_thread().await;
-//         if !restart {
-//             *self.status.lock().await = Status::Stopped;
-//         }
-
-//         if !restart {
-//             info!(
-//                 self.name,
-//                 "Stopped in {:.3} secs!",
-//                 stop_time.elapsed().as_secs_f64()
-//             );
-//         }
-//         Ok(())
-//     }
-
-//     /// Return a list of every player who is currently on this Minecraft server.
-//     pub async fn players(self: &Arc<Self>) -> Vec<String> {
-//         self.players.lock().await.0.clone()
-//     }
-//     /// Return when the list of every player who is currently on this Minecraft server got updated.
-//     pub async fn latest_players(self: &Arc<Self>) -> DateTime<Utc> {
-//         self.players.lock().await.1.clone()
-//     }
-//     /// Send a given string to the Minecraft server as an input. \
-//     /// It is guaranteed that the string given will be sent to the Server, but this can cause the blocking of the thread calling this function due to the Server restarting.
-//     #[async_recursion]
-//     pub async fn send_input(self: Arc<Self>, input: &str) {
-//         loop {
-//             if let Status::Started = *self.status.lock().await {
-//                 break;
-//             }
-//         }
-
-//         let send_input_result = self.minecraft_server.lock().await.as_mut()
-//             .unwrap_or_else(|| panic!("The Server {} should hold a Minecraft server process since it is in a started state.", self.name))
-//             .stdin.as_mut()
-//             .unwrap_or_else(|| panic!("The Minecraft server process of {} should have a stdin pipe.", self.name))
-//             .write_all(format!("{input}\n").as_bytes()).await;
-
-//         if let Err(error) = send_input_result {
-//             warn!(self.name, "An error occurred while writing the input `{input}` to the Minecraft server. This Server will be restarted. Error: {error}");
-//             while let Err(MCManageError::NotReady) = self.clone().impl_restart().await {
-//                 sleep(Config::cooldown().await).await;
-//             }
-//             self.clone().send_input(input).await;
-//         }
-//         self.save_output(&format!(">> {input}")).await;
-//     }
-
-//     /// Reset a given struct to its starting values.
-//     pub(super) async fn reset(self: &Arc<Self>) {
-//         if let Some(thread) = self.main_thread.lock().await.take() {
-//             thread.abort();
-//         }
-//         *self.status.lock().await = Status::Stopped;
-//         if let Some(mut server) = self.minecraft_server.lock().await.take() {
-//             if (server.kill().await).is_err() {}
-//         }
-//         self.players.lock().await.0.clear();
-//         self.players.lock().await.1 = Utc::now();
-//     }
-//     /// This represents the main loop of a given struct.
-//     async fn main(
-//         self: Arc<Self>,
-//         mut bootup_result: Option<oneshot::Sender<()>>,
-//     ) -> Result<(), MCManageError> {
-//         let mut agreed_to_eula = false;
-//         let stdout = BufReader::new(self.get_stdout_pipe().await);
-
-//         let mut lines = stdout.lines();
-//         loop {
-//             let line;
-//             if let Some(content) = lines.next_line().await.unwrap_or_else(|error| {
-//                 panic!(
-//                     "An error occurred while reading the output of {}. Error: {error}",
-//                     self.name
-//                 )
-//             }) {
-//                 line = content;
-//             } else {
-//                 // It will only be None returned if the Child process got killed
-//                 return Ok(());
-//             }
-
-//             self.save_output(&line).await;
-
-//             if !agreed_to_eula {
-//                 self.agree_to_eula().await;
-//                 agreed_to_eula = true;
-//             }
-
-//             if let Some(bootup_result_inner) = bootup_result {
-//                 bootup_result = self.check_started(&line, bootup_result_inner).await;
-//             }
-
-//             self.check_player_activity(&line).await;
-//         }
-//     }
-//     /// Save a given line to a log file saved under ' [`SERVER_LOGS_DIR`]/{Server.name}.txt '.
-//     async fn save_output(self: &Arc<Self>, line: &str) {
-//         fs::create_dir_all(SERVER_LOGS_DIR.as_path())
-//             .await
-//             .unwrap_or_else(|error| {
-//                 panic!(
-//                     "An error occurred while creating the dir `{}`. Error: {error}",
-//                     SERVER_LOGS_DIR.display()
-//                 )
-//             });
-
-//         let destination = SERVER_LOGS_DIR.join(self.name.clone() + ".log");
-//         let mut log_file;
-//         match OpenOptions::new()
-//             .append(true)
-//             .create(true)
-//             .open(destination.clone())
-//             .await
-//         {
-//             Ok(file) => log_file = file,
-//             Err(error) => {
-//                 panic!(
-//                     "Could not open the log file at {}. Error: {error}",
-//                     destination.display()
-//                 )
-//             }
-//         }
-
-//         log_file
-//             .write_all(format!("{line}\n").as_bytes())
-//             .await
-//             .unwrap_or_else(|error| {
-//                 panic!(
-//                     "An error occurred while writing a log message to the file {}. Error: {error}",
-//                     destination.display()
-//                 )
-//             });
-//     }
-//     /// Get the stdout pipe of the Minecraft server.
-//     async fn get_stdout_pipe(self: &Arc<Self>) -> ChildStdout {
-//         self.minecraft_server
-//             .lock()
-//             .await
-//             .as_mut()
-//             .expect("This method should only be called once the Minecraft server process got set.")
-//             .stdout
-//             .take()
-//             .expect("The stdout pipe of this server only gets taken once.")
-//     }
-//     /// Check if the Minecraft server has started.
-//     async fn check_started(
-//         self: &Arc<Self>,
-//         line: &str,
-//         bootup_result: oneshot::Sender<()>,
-//     ) -> Option<oneshot::Sender<()>> {
-//         for item in self.server_type.get_started().await {
-//             if !line.contains(&item) {
-//                 return Some(bootup_result);
-//             }
-//         }
-//         self.send_start_result(&mut Some(bootup_result)).await;
-//         *self.status.lock().await = Status::Started;
-//         None
-//     }
-//     /// Check for player activity ( connecting/disconnecting ) and save the name of the player who joined or delete the one who left.
-//     async fn check_player_activity(self: &Arc<Self>, line: &str) {
-//         // check if anyone joined / left
-//         let mut player_joined = true;
-//         for item in self.server_type.get_player_joined().await {
-//             if !line.contains(&item) {
-//                 player_joined = false;
-//                 break;
-//             }
-//         }
-//         let mut player_left = true;
-//         if !player_joined {
-//             for item in self.server_type.get_player_left().await {
-//                 if !line.contains(&item) {
-//                     player_left = false;
-//                     break;
-//                 }
-//             }
-//         }
-
-//         // save the detected state to this Server
-//         let mut players = self.players.lock().await;
-//         if player_joined {
-//             players.0.push(
-//                 self.server_type
-//                     .get_player_name_joined(line)
-//                     .await
-//                     .unwrap_or_else(|_| {
-//                         panic!("It has already been checked whether or not a player joined.")
-//                     }),
-//             );
-//             players.1 = Utc::now();
-//         } else if player_left {
-//             let player_name = self
-//                 .server_type
-//                 .get_player_name_left(line)
-//                 .await
-//                 .unwrap_or_else(|_| {
-//                     panic!("It has already been checked whether or not a player left.")
-//                 });
-//             if let Ok(index) = players.0.binary_search(&player_name) {
-//                 players.0.remove(index);
-//                 players.1 = Utc::now();
-//             } else {
-//                 error!(self.name, "The player {player_name} left without ever joining this server. This Server will restart.");
-//                 self.restart();
-//             }
-//         }
-//     }
-//     /// Automatically agree to the EULA if activated in the config. \
-//     /// If this setting is deactivated by the user, this function will send a message informing the user of the situation and then shut down the [`Server`] calling this
-//     /// function.
-//     async fn agree_to_eula(self: &Arc<Self>) {
-//         let eula_path = self.path.join("eula.txt");
-
-//         // check if the EULA has been accepted
-//         if eula_path.exists() {
-//             let mut eula_txt = "".to_string();
-//             if File::open(&eula_path)
-//                 .await
-//                 .unwrap_or_else(|_| {
-//                     panic!(
-//                         "It was already checked whether or not the file at {} exists.",
-//                         eula_path.display()
-//                     )
-//                 })
-//                 .read_to_string(&mut eula_txt)
-//                 .await
-//                 .is_err()
-//             {}
-
-//             if eula_txt.contains("eula=true") {
-//                 return;
-//             }
-//         }
-//         warn!(
-//             self.name,
-//             "The EULA has to be accepted to use this Server."
-//         );
-
-//         // agree to the EULA if configured
-//         if Config::agree_to_eula().await {
-//             File::create(eula_path)
-//                 .await
-//                 .unwrap_or_else(|error| {
-//                     panic!(
-//                         "Failed to open the EULA file of {}. Error: {error}",
-//                         self.name
-//                     )
-//                 })
-//                 .write_all(b"eula=true")
-//                 .await
-//                 .unwrap_or_else(|error| {
-//                     panic!("Failed to accept the EULA of {}. Error: {error}", self.name)
-//                 });
-
-//             info!(self.name, "#########################################################################################################################");
-//             info!(self.name, "# The following line is copied from the Minecraft Servers eula.txt file.                                                #");
-//             info!(self.name, "# `By changing the setting below to TRUE you are indicating your agreement to our EULA (https://aka.ms/MinecraftEULA).` #");
-//             info!(self.name, "# The EULA has been automatically accepted.                                                                             #");
-//             info!(self.name, "# To deactivate this behavior, change the ' agree_to_eula ' variable in the ' config/config.toml ' file to false.       #");
-//             info!(self.name, "#########################################################################################################################");
-//         } else {
-//             error!(self.name, "#########################################################################################################################");
-//             error!(self.name, "# The following line is copied from the Minecraft Servers eula.txt file.                                                #");
-//             error!(self.name, "# `By changing the setting below to TRUE you are indicating your agreement to our EULA (https://aka.ms/MinecraftEULA).` #");
-//             error!(self.name, "# The EULA has not yet been accepted. Please accept it to continue using this server.                                   #");
-//             error!(self.name, "# To automatically accept all EULAs in the future, change the ' agree_to_eula ' variable in the ' config/config.toml '  #");
-//             error!(self.name, "# file to true.                                                                                                         #");
-//             error!(self.name, "#                                                                                                                       #");
-//             error!(self.name, "# This Server will now shut down.                                                                                     #");
-//             error!(self.name, "#########################################################################################################################");
-
-//             self.stop();
-//         }
-//     }
-// }
+        let saved_type = DetectedType::load(&self.path).await;
+        if let Some(saved_type) = &saved_type {
+            if (saved_type.from_output && !from_output)
+                || (saved_type.server_type == detected_type && saved_type.from_output == from_output)
+            {
+                return;
+            }
+        }
+
+        if self.server_type.to_string() != detected_type {
+            info!(self.name; "Detected the server_type {detected_type}.");
+            self.server_type.set(detected_type);
+        }
+        let detected_type = DetectedType {
+            server_type: detected_type.to_string(),
+            from_output,
+            detected_at: Utc::now(),
+        };
+        if let Err(error) = detected_type.save(&self.path).await {
+            warn!(self.name; "Failed to save the detected server_type. Error: {error}");
+        }
+    }
+    /// Check for the optional messages of the [`ServerType`] ( stopping, player_death, etc. ) and publish the corresponding [`Event`]. \
+    /// If the line starts an exception, its message will be returned instead, so the stack trace following it can be collected before the event gets
+    /// published.
+    async fn check_events(self: &Arc<Self>, line: &str) -> Option<String> {
+        let server_type = &self.server_type;
+        let matches = |pattern: Option<LinePattern>| pattern.map_or(false, |pattern| pattern.matches(line));
+
+        let event = if matches(server_type.get_stopping().await) {
+            Event::Stopping
+        } else if matches(server_type.get_player_switched().await) {
+            let (player, server) = server_type.get_switched_server(line).await.ok()?;
+            Event::PlayerSwitched {
+                uuid: self.online_uuid(&player).await?,
+                player,
+                server,
+            }
+        } else if matches(server_type.get_save_complete().await) {
+            Event::SaveCompleted
+        } else if matches(server_type.get_player_death().await) {
+            let (player, message) = server_type.get_death_message(line).await.ok()?;
+            Event::PlayerDied {
+                uuid: self.online_uuid(&player).await?,
+                player,
+                message,
+            }
+        } else if matches(server_type.get_advancement().await) {
+            let (player, advancement) = server_type.get_advancement_name(line).await.ok()?;
+            Event::AdvancementMade {
+                uuid: self.online_uuid(&player).await?,
+                player,
+                advancement,
+            }
+        } else if matches(server_type.get_overloaded().await) {
+            let (milliseconds, ticks) = server_type.get_overload(line).await.ok()?;
+            Event::Overloaded { milliseconds, ticks }
+        } else if matches(server_type.get_plugin_load_failed().await) {
+            Event::PluginLoadFailed {
+                plugin: server_type.get_failed_plugin(line).await.ok()?,
+            }
+        } else if matches(server_type.get_exception().await) {
+            return server_type.get_exception_message(line).await.ok();
+        } else {
+            return None;
+        };
+        EventBus::publish(&self.name, event);
+        None
+    }
+    /// Return the uuid of the given player, if the player is online on this Server. \
+    /// This is used to make sure a message about a player is no message written by the server itself or a plugin.
+    async fn online_uuid(self: &Arc<Self>, player: &str) -> Option<String> {
+        if !self.players.lock().await.0.iter().any(|online_player| online_player == player) {
+            return None;
+        }
+        let uuid = self
+            .player_identities
+            .lock()
+            .await
+            .get(player)
+            .and_then(|(uuid, _)| uuid.clone());
+        Some(uuid.unwrap_or_else(|| PlayerIdentities::offline_uuid(player)))
+    }
+    /// Check for the uuid or ip of a player, which get announced before the player joins, and remember them until the player joins.
+    async fn check_player_identity(self: &Arc<Self>, line: &str) {
+        let server_type = &self.server_type;
+        if server_type.get_player_uuid().await.matches(line) {
+            if let Ok((player_name, uuid)) = server_type.get_player_name_uuid(line).await {
+                self.player_identities.lock().await.entry(player_name).or_default().0 = Some(uuid.to_lowercase());
+            }
+            return;
+        }
+        if server_type.get_player_logged_in().await.matches(line) {
+            if let Ok((player_name, ip)) = server_type.get_player_name_ip(line).await {
+                self.player_identities.lock().await.entry(player_name).or_default().1 = Some(ip);
+            }
+        }
+    }
+    /// Close the session of every player on this Server with the given reason and clear its list of players.
+    async fn close_sessions(self: &Arc<Self>, reason: SessionCloseReason) {
+        let mut players = self.players.lock().await;
+        self.player_identities.lock().await.clear();
+        self.warn_history_error(
+            PlayerSessions::close_all(&self.name, reason).await,
+            "close the sessions of the players on this server",
+        );
+        players.0.clear();
+        players.1 = Utc::now();
+    }
+    /// Warn about the given result of the player history or the chat log if it failed. The action describes what should have been done.
+    /// (For example: `save the session of the player Notch`) \
+    /// An [`MCManageError::NotReady`] will be ignored, since it only means that the player history and the chat log are disabled.
+    fn warn_history_error<T>(self: &Arc<Self>, result: Result<T, MCManageError>, action: &str) {
+        match result {
+            Ok(_) | Err(MCManageError::NotReady) => {}
+            Err(error) => {
+                warn!(self.name; "Failed to {action}. Error: {error}");
+            }
+        }
+    }
+    /// Configure the [`ProxyNetwork`] this Server belongs to, so its proxy knows every backend and the backends accept the players forwarded by it. \
+    /// Servers which are no part of any network will not be changed.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                                   |
+    /// |-----------------------------------|---------------------------------------------------------------|
+    /// | `Ok(())`                          | The network is ready to be started.                           |
+    /// | [`MCManageError::InvalidRequest`] | The network has no proxy, more than one proxy or no backends. |
+    /// | `Err(...)`                        | See the [`ProxyNetwork::configure`] method.                   |
+    async fn configure_network(self: &Arc<Self>) -> Result<(), MCManageError> {
+        let Some(network) = &self.network else {
+            return Ok(());
+        };
+        ProxyNetwork::get(network).await?.configure().await
+    }
+    /// Make sure the Minecraft EULA got accepted for this Server and write its `eula.txt` file. \
+    /// Proxies are no Minecraft servers, so they do not need to accept it. \
+    /// If no acceptance got recorded yet, but the `eula_accepted_by` field of this Server is set, the acceptance will be recorded now.
+    ///
+    /// # Returns
+    ///
+    /// | Return                             | Description                                                 |
+    /// |------------------------------------|-------------------------------------------------------------|
+    /// | `Ok(())`                           | The EULA got accepted and this Server is allowed to start.  |
+    /// | [`MCManageError::EulaNotAccepted`] | Nobody accepted the EULA for this Server yet.               |
+    /// | `Err(...)`                         | The acceptance or the `eula.txt` file could not be written. |
+    async fn check_eula(self: &Arc<Self>) -> Result<(), MCManageError> {
+        if let Some(NetworkRole::Proxy) = self.network_role {
+            return Ok(());
+        }
+        if let Some(eula_acceptance) = EulaAcceptance::load(&self.path).await {
+            // the eula.txt file could have been changed or deleted since the acceptance
+            return eula_acceptance.write_eula_txt(&self.path).await;
+        }
+        if let Some(accepted_by) = &self.eula_accepted_by {
+            self.accept_eula(accepted_by).await?;
+            return Ok(());
+        }
+
+        error!(self.name; "#########################################################################################################################");
+        error!(self.name; "# The following line is copied from the Minecraft Servers eula.txt file.                                                #");
+        error!(self.name; "# `By changing the setting below to TRUE you are indicating your agreement to our EULA (https://aka.ms/MinecraftEULA).` #");
+        error!(self.name; "# The EULA has not yet been accepted for this server. It will not be started until someone accepts it.                  #");
+        error!(self.name; "# To accept it, use the EULA api or set the ' eula_accepted_by ' field of this server to your name using the            #");
+        error!(self.name; "# server list api.                                                                                                      #");
+        error!(self.name; "#########################################################################################################################");
+        Err(MCManageError::EulaNotAccepted)
+    }
+}
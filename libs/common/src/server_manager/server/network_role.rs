@@ -0,0 +1,16 @@
+//! This module provides the [`NetworkRole`] enum.
+
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This enum describes the role of a [`Server`](super::Server) in its [`ProxyNetwork`](crate::proxy_network::ProxyNetwork).
+#[add_convert]
+#[derive(Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkRole {
+    /// The Velocity or BungeeCord proxy players connect to. It forwards them to the backends of the network.
+    Proxy,
+    /// A Minecraft server players can only join through the proxy of the network.
+    Backend,
+}
@@ -0,0 +1,17 @@
+//! This module provides the [`ServerKind`] enum.
+
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This enum describes which edition of Minecraft a [`Server`](super::Server) runs, and therefore how it gets installed and started.
+#[add_convert]
+#[derive(Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ServerKind {
+    /// A Minecraft: Java Edition server. It gets started using the `java` command and its args.
+    #[default]
+    Java,
+    /// A Minecraft: Bedrock Edition server. It gets started by running the native `bedrock_server` binary of the Bedrock Dedicated Server.
+    Bedrock,
+}
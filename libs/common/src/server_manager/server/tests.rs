@@ -2,41 +2,114 @@
 
 use std::time::Duration;
 
-use tokio::{
-    fs::OpenOptions,
-    spawn,
-    sync::oneshot::channel,
-};
+use async_trait::async_trait;
+use tokio::sync::oneshot::channel;
 
 use super::*;
 use crate::{
-    generated_files::{
-        default_files::get_example_content,
-        paths::{
-            SERVERS_DIR,
-            SERVER_LIST_FILE,
-            SERVER_LOGS_DIR,
-        },
-    },
-    test_functions::{
-        cleanup,
-        start_test,
+    generated_files::paths::{
+        SERVERS_DIR,
+        SERVER_LOGS_DIR,
     },
+    test_functions::*,
 };
 
-async fn new_server() -> Arc<Server> {
+/// A [`VersionProvider`] knowing the given builds of the Minecraft version `1.20.4`. Their jars are expected at `<base_url>/purpur-<build>.jar`.
+struct MockProvider {
+    base_url: String,
+    builds: Vec<String>,
+}
+#[async_trait]
+impl VersionProvider for MockProvider {
+    fn name(&self) -> &str {
+        "purpur"
+    }
+    async fn versions(&self) -> Result<Vec<String>, MCManageError> {
+        Ok(vec!["1.20.4".to_string()])
+    }
+    async fn builds(&self, _version: &str) -> Result<Vec<String>, MCManageError> {
+        Ok(self.builds.clone())
+    }
+    async fn resolve(&self, version: &str, build: &str) -> Result<ResolvedBuild, MCManageError> {
+        let version = self.resolve_version(version).await?;
+        let build = self.resolve_build(&version, build).await?;
+
+        Ok(ResolvedBuild {
+            url: format!("{}/purpur-{build}.jar", self.base_url),
+            version,
+            build,
+            checksum: None,
+            installer: None,
+        })
+    }
+}
+
+/// Create the [`ServerItem`] of a purpur server. Nobody accepted its EULA yet.
+fn server_item() -> ServerItem {
+    ServerItem {
+        args: "-jar purpur-1.19.3-1933.jar nogui".to_string(),
+        download_from: "https://api.purpurmc.org/v2/purpur/1.19.3/1933/download".to_string(),
+        version: None,
+        build: None,
+        update_policy: UpdatePolicy::Disabled,
+        server_type: "purpur".to_string(),
+        restart_time: Duration::ZERO,
+        eula_accepted_by: None,
+        network: None,
+        network_role: None,
+        kind: ServerKind::Java,
+        depends_on: vec![],
+        start_delay: Duration::ZERO,
+    }
+}
+/// Create a [`Server`] named `myMinecraftServer` using the given [`ServerItem`].
+async fn new_server_with(server_item: ServerItem) -> Arc<Server> {
     start_test();
+    init_config().await;
 
-    Server::new(
-        "myMinecraftServer",
-        ServerItem {
-            args: "-jar purpur-1.19.3-1933.jar nogui".to_string(),
-            download_from: "https://api.purpurmc.org/v2/purpur/1.19.3/1933/download".to_string(),
-            server_type: "purpur".to_string(),
-            restart_time: Duration::new(0, 0),
-        },
-    )
+    Server::new("myMinecraftServer", server_item).await
+}
+async fn new_server() -> Arc<Server> {
+    new_server_with(server_item()).await
+}
+/// Create a [`Server`] whose EULA got accepted, so that it can be started.
+async fn new_startable_server() -> Arc<Server> {
+    let server = new_server().await;
+    server.accept_eula("Jane Doe").await.unwrap();
+    server
+}
+/// Create a [`Server`] using the given [`UpdatePolicy`], whose jar `purpur.jar` of the build `2175` is already installed. \
+/// The jar of the build `2176` is served at `<returned url>/purpur-2176.jar`.
+async fn new_updatable_server(update_policy: UpdatePolicy) -> (Arc<Server>, MockProvider) {
+    let base_url = mock_http_server(vec![("/purpur-2176.jar".to_string(), b"newJar".to_vec())]).await;
+    let mut server_item = server_item();
+    server_item.args = "-jar purpur.jar nogui".to_string();
+    server_item.download_from = "".to_string();
+    server_item.update_policy = update_policy;
+    let server = new_server_with(server_item).await;
+
+    fs::create_dir_all(&server.path).await.unwrap();
+    fs::write(server.path.join("purpur.jar"), "oldJar").await.unwrap();
+    InstalledBuild {
+        version: "1.20.4".to_string(),
+        build: "2175".to_string(),
+        failed_builds: vec![],
+    }
+    .save(&server.path)
     .await
+    .unwrap();
+
+    let provider = MockProvider {
+        base_url,
+        builds: vec!["2175".to_string(), "2176".to_string()],
+    };
+    (server, provider)
+}
+/// Wait until the given [`Server`] reached the given [`Status`].
+async fn wait_for_status(server: &Arc<Server>, status: Status) {
+    while *server.status.lock().await != status {
+        sleep(Duration::from_millis(100)).await;
+    }
 }
 
 #[tokio::test]
@@ -55,107 +128,65 @@ async fn reset() {
 }
 
 #[tokio::test]
+#[ignore = "requires java and access to api.purpurmc.org"]
 async fn start() {
-    let server = new_server().await;
+    let server = new_startable_server().await;
 
     server.clone().impl_start(false).await.unwrap();
-    if let None = *server.minecraft_server.lock().await {
-        assert!(false, "Expected minecraft_server field to be filled.");
-    }
-    if let None = *server.main_thread.lock().await {
-        assert!(false, "Expected main_thread field to be filled.");
-    }
-    loop {
-        if let Status::Started = *server.status.lock().await {
-            break;
-        } else if let Status::Stopped = *server.status.lock().await {
-            assert!(
-                false,
-                "The Server canceled its startup because the EULA was not accepted."
-            );
-        }
-    }
+    assert!(server.minecraft_server.lock().await.is_some(), "Expected minecraft_server field to be filled.");
+    assert!(server.main_thread.lock().await.is_some(), "Expected main_thread field to be filled.");
+    wait_for_status(&server, Status::Started).await;
+
     server.impl_stop(false, false).await.unwrap();
     cleanup();
 }
 #[tokio::test]
+#[ignore = "requires java and access to api.purpurmc.org"]
 async fn stop() {
-    let server = new_server().await;
+    let server = new_startable_server().await;
 
     server.clone().impl_start(false).await.unwrap();
-    loop {
-        if let Err(_) = server.clone().impl_stop(false, false).await {
-        } else {
-            break;
-        }
-    }
+    while server.clone().impl_stop(false, false).await.is_err() {}
+
     assert_eq!(*server.status.lock().await, Status::Stopped);
-    if let Some(_) = *server.minecraft_server.lock().await {
-        assert!(false, "Expected minecraft_server field to be empty.");
-    }
-    if let Some(_) = *server.main_thread.lock().await {
-        assert!(false, "Expected main_thread field to be empty.");
-    }
+    assert!(server.minecraft_server.lock().await.is_none(), "Expected minecraft_server field to be empty.");
+    assert!(server.main_thread.lock().await.is_none(), "Expected main_thread field to be empty.");
     cleanup();
 }
 #[tokio::test]
+#[ignore = "requires java and access to api.purpurmc.org"]
 async fn restart() {
-    let server = new_server().await;
+    let server = new_startable_server().await;
 
     server.clone().impl_start(false).await.unwrap();
-    loop {
-        if let Status::Started = *server.status.lock().await {
-            break;
-        }
-    }
-    loop {
-        if let Err(_) = server.clone().impl_restart().await {
-        } else {
-            break;
-        }
-    }
-    if let None = *server.minecraft_server.lock().await {
-        assert!(false, "Expected minecraft_server field to be filled.");
-    }
-    if let None = *server.main_thread.lock().await {
-        assert!(false, "Expected main_thread field to be filled.");
-    }
-    if let Status::Started = *server.status.lock().await {
-    } else {
-        assert!(false, "Expected status field to be Status::Started.");
-    };
+    wait_for_status(&server, Status::Started).await;
+    while server.clone().impl_restart().await.is_err() {}
+
+    assert!(server.minecraft_server.lock().await.is_some(), "Expected minecraft_server field to be filled.");
+    assert!(server.main_thread.lock().await.is_some(), "Expected main_thread field to be filled.");
+    assert_eq!(*server.status.lock().await, Status::Started);
+
     server.impl_stop(false, false).await.unwrap();
     cleanup();
 }
 
 #[tokio::test]
+#[ignore = "requires java and access to api.purpurmc.org"]
 async fn send_input() {
-    let server = new_server().await;
+    let server = new_startable_server().await;
     let expected_string = r#" INFO]: Unknown command. Type "/help" for help."#;
 
     server.clone().impl_start(false).await.unwrap();
-    loop {
-        if let Status::Started = *server.status.lock().await {
-            break;
-        }
-    }
+    wait_for_status(&server, Status::Started).await;
 
     server.clone().send_input("invalid command").await;
-
     sleep(Duration::new(1, 0)).await;
 
-    let mut out = "".to_string();
-    if File::open(SERVER_LOGS_DIR.join("myMinecraftServer.log"))
+    let out = fs::read_to_string(SERVER_LOGS_DIR.join("myMinecraftServer.log"))
         .await
-        .unwrap()
-        .read_to_string(&mut out)
-        .await
-        .is_err()
-    {}
+        .unwrap_or_default();
+    assert!(out.contains(expected_string), "Expected `{expected_string}` in log. Found: {out}");
 
-    if !out.contains(expected_string) {
-        assert!(false, "Expected `{expected_string}` in log. Found: {out}")
-    }
     server.impl_stop(false, false).await.unwrap();
     cleanup();
 }
@@ -165,16 +196,9 @@ async fn save_output() {
 
     server.save_output("Test line").await;
 
-    let mut out = "".to_string();
-    if let Err(_) = OpenOptions::new()
-        .read(true)
-        .open(SERVER_LOGS_DIR.join("myMinecraftServer.log"))
+    let out = fs::read_to_string(SERVER_LOGS_DIR.join("myMinecraftServer.log"))
         .await
-        .unwrap()
-        .read_to_string(&mut out)
-        .await
-    {}
-
+        .unwrap();
     assert_eq!(out, "Test line\n");
     cleanup();
 }
@@ -184,19 +208,14 @@ async fn check_started() {
 
     let (tx, _rx) = channel();
 
-    if let Some(_) = server
-        .check_started(
-            "[16:54:34 INFO]: Done (3.152s)! For help, type \"help\"",
-            tx,
-        )
-        .await
-    {
-        assert!(false, "Expected function to detect a 'start'");
-    }
-    if let Status::Started = *server.status.lock().await {
-    } else {
-        assert!(false, "Expected status field to be Status::Started.");
-    };
+    assert!(
+        server
+            .check_started("[16:54:34 INFO]: Done (3.152s)! For help, type \"help\"", tx)
+            .await
+            .is_none(),
+        "Expected function to detect a 'start'"
+    );
+    assert_eq!(*server.status.lock().await, Status::Started);
     cleanup();
 }
 #[tokio::test]
@@ -232,140 +251,133 @@ async fn check_player_activity_disconnect() {
     cleanup();
 }
 #[tokio::test]
-async fn agree_to_eula_already_accepted() {
+async fn eula_not_accepted() {
     let server = new_server().await;
 
-    fs::create_dir_all(SERVERS_DIR.join("myMinecraftServer"))
-        .await
-        .unwrap();
-    let mut file = OpenOptions::new()
-        .write(true)
-        .create_new(true)
-        .open(SERVERS_DIR.join("myMinecraftServer").join("eula.txt"))
-        .await
-        .unwrap();
-    let text = "eula=true";
-    io::copy(&mut text.as_bytes(), &mut file).await.unwrap();
-
-    server.agree_to_eula().await;
-
-    let mut eula_txt = "".to_string();
-    if OpenOptions::new()
-        .read(true)
-        .open(server.path.join("eula.txt"))
-        .await
-        .unwrap()
-        .read_to_string(&mut eula_txt)
-        .await
-        .is_err()
-    {}
-
-    if !eula_txt.contains("eula=true") {
-        assert!(false, "the eula text has been changed")
-    }
+    assert!(matches!(
+        server.clone().impl_start(false).await,
+        Err(MCManageError::EulaNotAccepted)
+    ));
+    // the start got refused, so another start attempt has to be possible
+    assert_eq!(*server.status.lock().await, Status::Stopped);
+    assert!(server.minecraft_server.lock().await.is_none());
+    assert!(server.eula_acceptance().await.is_none());
+    assert!(!server.path.join("eula.txt").exists());
     cleanup();
 }
 #[tokio::test]
-async fn agree_to_eula_already_not_accepted() {
+async fn accept_eula() {
     let server = new_server().await;
 
-    fs::create_dir_all(SERVERS_DIR.join("myMinecraftServer"))
-        .await
-        .unwrap();
-    let mut file = OpenOptions::new()
-        .write(true)
-        .create_new(true)
-        .open(SERVERS_DIR.join("myMinecraftServer").join("eula.txt"))
-        .await
-        .unwrap();
-    let text = "eula=false";
-    io::copy(&mut text.as_bytes(), &mut file).await.unwrap();
+    assert!(matches!(
+        server.accept_eula(" ").await,
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    let eula_acceptance = server.accept_eula("Jane Doe").await.unwrap();
 
-    server.agree_to_eula().await;
+    assert_eq!(eula_acceptance.server, "myMinecraftServer");
+    assert_eq!(eula_acceptance.accepted_by, "Jane Doe");
+    assert_eq!(server.eula_acceptance().await, Some(eula_acceptance));
 
-    let mut eula_txt = "".to_string();
-    if OpenOptions::new()
-        .read(true)
-        .open(server.path.join("eula.txt"))
+    // a deleted eula.txt file gets restored from the recorded acceptance
+    fs::remove_file(server.path.join("eula.txt")).await.unwrap();
+    server.check_eula().await.unwrap();
+    assert!(fs::read_to_string(server.path.join("eula.txt"))
         .await
         .unwrap()
-        .read_to_string(&mut eula_txt)
-        .await
-        .is_err()
-    {}
-
-    if !eula_txt.contains("eula=true") {
-        assert!(false, "the eula text is still false")
-    }
+        .contains("eula=true"));
     cleanup();
 }
 #[tokio::test]
-async fn agree_to_eula_not_existing() {
-    let server = new_server().await;
-
-    fs::create_dir_all(SERVERS_DIR.join("myMinecraftServer"))
-        .await
-        .unwrap();
-
-    server.agree_to_eula().await;
+async fn eula_accepted_in_server_item() {
+    let mut server_item = server_item();
+    server_item.eula_accepted_by = Some("Jane Doe".to_string());
+    // without a jar or a download link, the start will be aborted right after the EULA got checked
+    server_item.download_from = "".to_string();
+    let server = new_server_with(server_item).await;
+
+    assert!(matches!(
+        server.clone().impl_start(false).await,
+        Err(MCManageError::NotFound)
+    ));
+    assert_eq!(*server.status.lock().await, Status::Stopped);
 
-    let mut eula_txt = "".to_string();
-    if OpenOptions::new()
-        .read(true)
-        .open(server.path.join("eula.txt"))
+    let eula_acceptance = server.eula_acceptance().await.unwrap();
+    assert_eq!(eula_acceptance.server, "myMinecraftServer");
+    assert_eq!(eula_acceptance.accepted_by, "Jane Doe");
+    assert!(fs::read_to_string(server.path.join("eula.txt"))
         .await
         .unwrap()
-        .read_to_string(&mut eula_txt)
-        .await
-        .is_err()
-    {}
-
-    if !eula_txt.contains("eula=true") {
-        assert!(false, "the eula text is still false")
+        .contains("eula=true"));
+    cleanup();
+}
+#[tokio::test]
+async fn stop_during_download() {
+    // the jar never gets served, so the start keeps downloading it until the download gets cancelled
+    let (base_url, _) = mock_server(|_| None).await;
+    let mut server_item = server_item();
+    server_item.download_from = format!("{base_url}/server.jar");
+    server_item.eula_accepted_by = Some("Jane Doe".to_string());
+    let server = new_server_with(server_item).await;
+
+    let start = spawn(server.clone().impl_start(false));
+    while server.download.lock().await.is_none() {
+        sleep(Duration::from_millis(10)).await;
     }
+    server.clone().impl_stop(false, false).await.unwrap();
+    assert_eq!(*server.status.lock().await, Status::Stopped);
+    assert_eq!(*server.published_status.lock().await, Status::Stopped);
+    assert!(matches!(start.await.unwrap(), Err(MCManageError::Cancelled)));
+
+    // without a download, the usual rules apply
+    assert!(matches!(
+        server.clone().impl_stop(false, false).await,
+        Err(MCManageError::AlreadyExecuted)
+    ));
+    *server.status.lock().await = Status::Starting;
+    assert!(matches!(
+        server.clone().impl_stop(false, false).await,
+        Err(MCManageError::NotReady)
+    ));
     cleanup();
 }
 #[tokio::test]
 async fn download_jar_jar_already_there() {
-    // This test tests whether or not any panic occurs
     let server = new_server().await;
 
-    let mut server_jar = reqwest::get("https://api.purpurmc.org/v2/purpur/1.19.3/1933/download")
-        .await
-        .unwrap()
-        .bytes_stream();
-    fs::create_dir_all(SERVERS_DIR.join("myMinecraftServer"))
+    fs::create_dir_all(&server.path).await.unwrap();
+    fs::write(server.path.join("purpur-1.19.3-1933.jar"), "myJar")
         .await
         .unwrap();
-    let mut jar_file = File::create(
-        SERVERS_DIR
-            .join("myMinecraftServer")
-            .join("purpur-1.19.3-1933.jar"),
-    )
-    .await
-    .unwrap();
-    while let Some(item) = server_jar.next().await {
-        io::copy(
-            &mut item.unwrap_or_else(|error| panic!("An error occurred while coping the downloaded jar to a file. Error: {error}")).as_ref(),
-            &mut jar_file
-        ).await.unwrap_or_else(|error| panic!("An error occurred while coping the downloaded jar to a file. Error: {error}"));
-    }
 
-    server.download_jar().await;
+    server.download_jar().await.unwrap();
+    assert_eq!(
+        fs::read_to_string(server.path.join("purpur-1.19.3-1933.jar"))
+            .await
+            .unwrap(),
+        "myJar"
+    );
 
     cleanup();
 }
 #[tokio::test]
 async fn download_jar_jar_not_there() {
-    let server = new_server().await;
+    let base_url = mock_http_server(vec![("/server.jar".to_string(), b"myJar".to_vec())]).await;
+    let mut server_item = server_item();
+    server_item.download_from = format!("{base_url}/server.jar");
+    let server = new_server_with(server_item).await;
 
-    server.download_jar().await;
+    server.download_jar().await.unwrap();
 
-    assert!(
-        SERVERS_DIR
-            .join("myMinecraftServer")
-            .join("purpur-1.19.3-1933.jar")
-            .exists(),
+    assert_eq!(
+        fs::read_to_string(
+            SERVERS_DIR
+                .join("myMinecraftServer")
+                .join("purpur-1.19.3-1933.jar")
+        )
+        .await
+        .unwrap(),
+        "myJar",
         "A server should have been downloaded."
     );
 
@@ -373,182 +385,170 @@ async fn download_jar_jar_not_there() {
 }
 #[tokio::test]
 async fn download_jar_no_jar_name_set() {
-    start_test();
-    let server = Server::new(
-        "myFirstServer",
-        ServerItem {
-            args: "-jar nogui".to_string(),
-            download_from: "https://api.purpurmc.org/v2/purpur/1.19.3/1933/download".to_string(),
-            server_type: "purpur".to_string(),
-            restart_time: Duration::new(0, 0),
-        },
-    )
-    .await;
+    let mut server_item = server_item();
+    server_item.args = "-jar nogui".to_string();
+    let server = new_server_with(server_item).await;
 
-    spawn(async {
-        sleep(Duration::new(1, 0)).await;
+    assert!(matches!(server.download_jar().await, Err(MCManageError::NotFound)));
 
-        let mut valid_file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .open(SERVER_LIST_FILE.as_path())
-            .await
-            .unwrap();
-        io::copy(
-            &mut get_example_content(&SERVER_LIST_FILE).1.as_bytes(),
-            &mut valid_file,
-        )
-        .await
-        .unwrap();
+    cleanup();
+}
+#[tokio::test]
+async fn download_jar_only_one_arg() {
+    let mut server_item = server_item();
+    server_item.args = "-jar".to_string();
+    let server = new_server_with(server_item).await;
 
-        let mut server_list = load_toml(&SERVER_LIST_FILE, "test", true).await.unwrap();
-        *server_list
-            .get_mut("myFirstServer")
-            .unwrap()
-            .as_table_mut()
-            .unwrap()
-            .get_mut("args")
-            .unwrap() = toml::Value::from("-jar purpur-1.19.3-1933.jar nogui");
-        server_list.remove("mySecondServer");
-
-        fs::write(
-            SERVER_LIST_FILE.as_path(),
-            toml::to_string(&server_list).unwrap().as_bytes(),
-        )
-        .await
-        .unwrap();
-    });
+    assert!(matches!(server.download_jar().await, Err(MCManageError::NotFound)));
 
-    server.download_jar().await;
+    cleanup();
+}
+#[tokio::test]
+async fn download_jar_no_download_from_set() {
+    let mut server_item = server_item();
+    server_item.download_from = "".to_string();
+    let server = new_server_with(server_item).await;
 
-    assert!(
-        SERVERS_DIR
-            .join("myFirstServer")
-            .join("purpur-1.19.3-1933.jar")
-            .exists(),
-        "A server should have been downloaded."
-    );
+    assert!(matches!(server.download_jar().await, Err(MCManageError::NotFound)));
+    assert!(!server.path.join("purpur-1.19.3-1933.jar").exists());
 
     cleanup();
 }
 #[tokio::test]
-async fn download_jar_only_one_arg() {
-    start_test();
-    let server = Server::new(
-        "myFirstServer",
-        ServerItem {
-            args: "-jar".to_string(),
-            download_from: "https://api.purpurmc.org/v2/purpur/1.19.3/1933/download".to_string(),
-            server_type: "purpur".to_string(),
-            restart_time: Duration::new(0, 0),
-        },
-    )
-    .await;
+async fn check_for_update_notify() {
+    let (server, provider) = new_updatable_server(UpdatePolicy::Notify).await;
 
-    spawn(async {
-        sleep(Duration::new(1, 0)).await;
+    let newest_build = server.check_for_update_with(&provider).await.unwrap().unwrap();
+    assert_eq!(newest_build.build, "2176");
+    // the newer build only gets reported
+    assert!(server.pending_update().await.is_none());
 
-        let mut valid_file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .open(SERVER_LIST_FILE.as_path())
-            .await
-            .unwrap();
-        io::copy(
-            &mut get_example_content(&SERVER_LIST_FILE).1.as_bytes(),
-            &mut valid_file,
-        )
-        .await
-        .unwrap();
+    cleanup();
+}
+#[tokio::test]
+async fn check_for_update_apply() {
+    let (server, provider) = new_updatable_server(UpdatePolicy::Apply).await;
 
-        let mut server_list = load_toml(&SERVER_LIST_FILE, "test", true).await.unwrap();
-        *server_list
-            .get_mut("myFirstServer")
-            .unwrap()
-            .as_table_mut()
-            .unwrap()
-            .get_mut("args")
-            .unwrap() = toml::Value::from("-jar purpur-1.19.3-1933.jar nogui");
-        server_list.remove("mySecondServer");
-
-        fs::write(
-            SERVER_LIST_FILE.as_path(),
-            toml::to_string(&server_list).unwrap().as_bytes(),
-        )
-        .await
-        .unwrap();
-    });
+    let newest_build = server.check_for_update_with(&provider).await.unwrap().unwrap();
+    assert_eq!(newest_build.build, "2176");
+    assert_eq!(server.pending_update().await, Some(newest_build));
 
-    server.download_jar().await;
+    cleanup();
+}
+#[tokio::test]
+async fn check_for_update_disabled() {
+    let (server, provider) = new_updatable_server(UpdatePolicy::Disabled).await;
 
-    assert!(
-        SERVERS_DIR
-            .join("myFirstServer")
-            .join("purpur-1.19.3-1933.jar")
-            .exists(),
-        "A server should have been downloaded."
-    );
+    assert!(server.check_for_update_with(&provider).await.unwrap().is_none());
+    assert!(server.pending_update().await.is_none());
 
     cleanup();
 }
 #[tokio::test]
-async fn download_jar_no_download_from_set() {
-    start_test();
-    let server = Server::new(
-        "myFirstServer",
-        ServerItem {
-            args: "-jar purpur-1.19.3-1933.jar nogui".to_string(),
-            download_from: "".to_string(),
-            server_type: "purpur".to_string(),
-            restart_time: Duration::new(0, 0),
-        },
-    )
-    .await;
+async fn check_for_update_pinned_build() {
+    let (server, provider) = new_updatable_server(UpdatePolicy::Apply).await;
+    *server.build.lock().await = Some("2175".to_string());
 
-    spawn(async {
-        sleep(Duration::new(1, 0)).await;
+    assert!(server.check_for_update_with(&provider).await.unwrap().is_none());
+    assert!(server.pending_update().await.is_none());
 
-        let mut valid_file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .open(SERVER_LIST_FILE.as_path())
+    cleanup();
+}
+#[tokio::test]
+async fn check_for_update_up_to_date() {
+    let (server, mut provider) = new_updatable_server(UpdatePolicy::Apply).await;
+    provider.builds.pop();
+
+    assert!(server.check_for_update_with(&provider).await.unwrap().is_none());
+    assert!(server.pending_update().await.is_none());
+
+    cleanup();
+}
+#[tokio::test]
+async fn apply_pending_update() {
+    let (server, provider) = new_updatable_server(UpdatePolicy::Apply).await;
+    assert!(server.apply_pending_update().await.unwrap().is_none());
+    server.check_for_update_with(&provider).await.unwrap();
+
+    let (new_build, previous_build) = server.apply_pending_update().await.unwrap().unwrap();
+
+    assert_eq!(new_build.build, "2176");
+    assert_eq!(previous_build.build, "2175");
+    assert!(server.pending_update().await.is_none());
+    assert_eq!(fs::read_to_string(server.path.join("purpur.jar")).await.unwrap(), "newJar");
+    assert_eq!(
+        fs::read_to_string(server.path.join("purpur.jar.previous"))
             .await
-            .unwrap();
-        io::copy(
-            &mut get_example_content(&SERVER_LIST_FILE).1.as_bytes(),
-            &mut valid_file,
-        )
+            .unwrap(),
+        "oldJar"
+    );
+    assert_eq!(InstalledBuild::load(&server.path).await.unwrap().build, "2176");
+
+    cleanup();
+}
+#[tokio::test]
+async fn roll_back_update() {
+    let (server, provider) = new_updatable_server(UpdatePolicy::Apply).await;
+    server.check_for_update_with(&provider).await.unwrap();
+    let (new_build, previous_build) = server.apply_pending_update().await.unwrap().unwrap();
+
+    server.roll_back_update(&new_build, previous_build).await.unwrap();
+
+    assert_eq!(fs::read_to_string(server.path.join("purpur.jar")).await.unwrap(), "oldJar");
+    let installed_build = InstalledBuild::load(&server.path).await.unwrap();
+    assert_eq!(installed_build.build, "2175");
+    assert_eq!(installed_build.failed_builds, vec!["2176".to_string()]);
+    // the failed build will not be offered again
+    assert!(server.check_for_update_with(&provider).await.unwrap().is_none());
+    assert!(server.pending_update().await.is_none());
+
+    cleanup();
+}
+/// An updated server, which does not start within the `update_start_timeout`, gets its previous build restored. \
+/// Instead of java, a script is started, which only reports a finished start if the jar it got started with is the previous one.
+#[cfg(unix)]
+#[tokio::test]
+async fn update_rolled_back_after_start_timeout() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let (server, provider) = new_updatable_server(UpdatePolicy::Apply).await;
+
+    let java_dir = server.path.join("fake_java");
+    fs::create_dir_all(&java_dir).await.unwrap();
+    fs::write(
+        java_dir.join("java"),
+        "#!/bin/sh\n\
+        grep -q oldJar \"$2\" && echo '[00:00:00 INFO]: Done (0.1s)! For help, type \"help\"'\n\
+        while read line; do [ \"$line\" = stop ] && exit 0; done\n",
+    )
+    .await
+    .unwrap();
+    fs::set_permissions(java_dir.join("java"), std::fs::Permissions::from_mode(0o755))
         .await
         .unwrap();
+    std::env::set_var(
+        "PATH",
+        format!("{}:{}", java_dir.display(), std::env::var("PATH").unwrap_or_default()),
+    );
 
-        let mut server_list = load_toml(&SERVER_LIST_FILE, "test", true).await.unwrap();
-        *server_list
-            .get_mut("myFirstServer")
-            .unwrap()
-            .as_table_mut()
-            .unwrap()
-            .get_mut("download_from")
-            .unwrap() =
-            toml::Value::from("https://api.purpurmc.org/v2/purpur/1.19.3/1933/download");
-        server_list.remove("mySecondServer");
-
-        fs::write(
-            SERVER_LIST_FILE.as_path(),
-            toml::to_string(&server_list).unwrap().as_bytes(),
-        )
+    server.check_for_update_with(&provider).await.unwrap();
+    let update = server.apply_pending_update().await.unwrap().unwrap();
+    *server.status.lock().await = Status::Starting;
+    server.spawn_minecraft_server().await;
+    let rx = server.start_main_thread().await;
+
+    server
+        .finish_update(rx, update, Duration::from_secs(1), false)
         .await
         .unwrap();
-    });
-
-    server.download_jar().await;
 
-    assert!(
-        SERVERS_DIR
-            .join("myFirstServer")
-            .join("purpur-1.19.3-1933.jar")
-            .exists(),
-        "A server should have been downloaded."
-    );
+    assert_eq!(*server.status.lock().await, Status::Started);
+    assert_eq!(fs::read_to_string(server.path.join("purpur.jar")).await.unwrap(), "oldJar");
+    let installed_build = InstalledBuild::load(&server.path).await.unwrap();
+    assert_eq!(installed_build.build, "2175");
+    assert_eq!(installed_build.failed_builds, vec!["2176".to_string()]);
 
+    *server.status.lock().await = Status::Stopping;
+    server.kill_minecraft_server().await;
     cleanup();
 }
@@ -0,0 +1,20 @@
+//! This module provides the [`UpdatePolicy`] enum.
+
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This enum describes what a [`Server`](super::Server) should do once a newer build of its Minecraft version is available.
+#[add_convert]
+#[derive(Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdatePolicy {
+    /// Never check for updates.
+    #[default]
+    Disabled,
+    /// Inform the user about a newer build, but do not install it.
+    Notify,
+    /// Install the newer build at the next start or restart of the Minecraft server. If it fails to start, the previous build will be restored. \
+    /// Restarts are not scheduled yet, so a running server keeps its build until it gets restarted or stopped and started again.
+    Apply,
+}
@@ -1,27 +1,106 @@
-//! This module provides the [`ServerItem`] struct which represents a [`Server`](super::server::Server) defined in the `config/server_list.toml` file.
+//! This module provides the [`ServerItem`] struct which represents a [`Server`](super::server::Server) saved in the [`ServerList`](super::server_list::ServerList).
 
 use std::time::Duration;
 
 use proc_macros::add_toml_convert;
 
-use crate::mcmanage_error::MCManageError;
+use super::server::{
+    network_role::NetworkRole,
+    server_kind::ServerKind,
+    update_policy::UpdatePolicy,
+};
+use crate::{
+    generated_files::paths::SERVERS_DIR,
+    mcmanage_error::MCManageError,
+};
 
-/// This struct represents a [`Server`](super::server::Server) defined in the `config/server_list.toml` file.
+/// This struct represents a [`Server`](super::server::Server) saved in the [`ServerList`](super::server_list::ServerList). Servers get changed using the
+/// [`ServerList::change`](super::server_list::ServerList::change) method.
 #[add_toml_convert]
 pub struct ServerItem {
     /// These are the args passed to the 'java' command.
     /// That means that this Minecraft server will be started using the command 'java -jar purpur-1.19.3-1876.jar nogui'
     ///
     /// Note: When specifying a ram limit like '-Xmx=4G', the Minecraft server will likely fail to start.
+    ///
+    /// If left empty, the default args of the server_type will be used. (For example: '-jar fabric-server-launch.jar nogui' for fabric)
     pub args: String,
     /// This is a link from which the Minecraft server should be downloaded if none can be found.
     /// A download can be avoided by leaving this field empty. (For example: download_from = "")
     pub download_from: String,
+    /// This is the Minecraft version of this server. (For example: version = "1.20.4")
+    /// If set and no download link is specified, the download link will be resolved automatically using the server_type.
+    pub version: Option<String>,
+    /// This is the build of the server software to use. (For example: build = "latest")
+    /// If not set, the latest build of the version will be used.
+    pub build: Option<String>,
+    /// This sets what should happen once a newer build of the version is available. (For example: update_policy = "apply")
+    /// If not set, no updates will be performed.
+    #[serde(default)]
+    pub update_policy: UpdatePolicy,
     /// This is the type of the Minecraft server. Depending on what value got set,
     /// the application will register events like the joining of a player based on different log messages.
     /// See the 'config/server_types.toml' file for all available types.
+    /// If left empty, the server_type will be detected from the server jar and the first lines the Minecraft server prints.
+    #[serde(default)]
     pub server_type: String,
     /// This is the amount of time the application should wait between restarts of this Minecraft server.
     /// If both the secs and nanos values are 0, no restarts will be performed.
     pub restart_time: Duration,
+    /// This is the name of the person accepting the Minecraft EULA (https://aka.ms/MinecraftEULA) for this server. (For example: eula_accepted_by = "Jane Doe")
+    /// The acceptance will be recorded at the next start. If not set, the EULA has to be accepted using the api before the server can be started.
+    #[serde(default)]
+    pub eula_accepted_by: Option<String>,
+    /// This is the name of the proxy network this server belongs to. (For example: network = "myNetwork")
+    /// Every network needs exactly one proxy and at least one backend. If not set, this server is no part of any network.
+    #[serde(default)]
+    pub network: Option<String>,
+    /// This is the role of this server in its network. Either "proxy" for the Velocity or BungeeCord server players connect to,
+    /// or "backend" for the Minecraft servers behind it. (For example: network_role = "backend")
+    /// If not set, a server belonging to a network will be a backend.
+    #[serde(default)]
+    pub network_role: Option<NetworkRole>,
+    /// This is the edition of Minecraft this server runs. Either "java" or "bedrock". (For example: kind = "bedrock")
+    /// Bedrock servers get started by running the 'bedrock_server' binary of the Bedrock Dedicated Server instead of the 'java' command,
+    /// and their server_type defaults to "bedrock". If not set, the server runs the Java Edition.
+    #[serde(default)]
+    pub kind: ServerKind,
+    /// These are the names of the servers which have to be started before this server. (For example: depends_on = ["auth"])
+    /// The ServerManager starts servers in an order where every server waits for its dependencies to be started, and stops them in reverse.
+    /// A server depending on an unknown server or on itself, directly or through other servers, will be rejected.
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    /// This is the amount of time the ServerManager should wait after the dependencies of this server got started, before starting it.
+    /// If not set, this server will be started right away.
+    #[serde(default)]
+    pub start_delay: Duration,
+}
+impl ServerItem {
+    /// Check that the given name can be used for a new server. The name is also the name of the server's directory, so only letters, digits, `-`
+    /// and `_` are allowed. \
+    /// If the name is invalid or a server directory with this name already exists, an [`MCManageError::InvalidRequest`] will be returned.
+    pub fn check_name(name: &str) -> Result<(), MCManageError> {
+        Self::check_name_format(name)?;
+        if SERVERS_DIR.join(name).exists() {
+            return Err(MCManageError::InvalidRequest(format!(
+                "A server named `{name}` already exists."
+            )));
+        }
+        Ok(())
+    }
+    /// Check that the given name only contains letters, digits, `-` and `_`, since it is also the name of the server's directory. \
+    /// Unlike the [`check_name`](Self::check_name) method, an existing server directory with this name is allowed. \
+    /// If the name is invalid, an [`MCManageError::InvalidRequest`] will be returned.
+    pub fn check_name_format(name: &str) -> Result<(), MCManageError> {
+        let valid_name = !name.is_empty()
+            && name
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || character == '-' || character == '_');
+        if !valid_name {
+            return Err(MCManageError::InvalidRequest(format!(
+                "`{name}` is no valid server name. Only letters, digits, `-` and `_` are allowed."
+            )));
+        }
+        Ok(())
+    }
 }
@@ -1,46 +1,186 @@
+//! This module provides the [`ServerData`] struct.
+
 use std::time::Duration;
 
-use crate::mcmanage_error::MCManageError;
+use proc_macros::add_convert;
 
-pub struct ServerData {
-    pub id: Option<usize>,
+use crate::{
+    mcmanage_error::MCManageError,
+    server_manager::{
+        server::{
+            network_role::NetworkRole,
+            server_kind::ServerKind,
+            update_policy::UpdatePolicy,
+        },
+        server_item::ServerItem,
+    },
+};
 
+/// This struct represents a server saved in the [`ServerList`](super::ServerList). It is used to add, change and return servers. \
+/// When changing a server, only the fields which are set will be changed. See the [`ServerItem`] for more information on every field.
+///
+/// Durations are saved in seconds, so their nanoseconds will be dropped.
+#[add_convert]
+#[derive(Default)]
+pub struct ServerData {
+    /// The id of the server in the [`ServerList`](super::ServerList). It gets assigned when the server gets added.
+    #[serde(default)]
+    pub id: Option<i64>,
+    /// The name of the server, which is also the name of its directory. Only letters, digits, `-` and `_` are allowed.
+    #[serde(default)]
     pub name: Option<String>,
-    /// These are the args passed to the 'java' command.
-    /// That means that this Minecraft server will be started using the command 'java -jar purpur-1.19.3-1876.jar nogui'
-    ///
-    /// Note: When specifying a ram limit like '-Xmx=4G', the Minecraft server will likely fail to start.
+    /// See [`ServerItem::args`].
+    #[serde(default)]
     pub args: Option<String>,
-    /// This is a link from which the Minecraft server should be downloaded if none can be found.
-    /// A download can be avoided by leaving this field empty. (For example: download_from = "")
+    /// See [`ServerItem::download_from`].
+    #[serde(default)]
     pub download_from: Option<String>,
-    /// This is the type of the Minecraft server. Depending on what value got set,
-    /// the application will register events like the joining of a player based on different log messages.
-    /// See the 'config/server_types.toml' file for all available types.
+    /// See [`ServerItem::version`].
+    #[serde(default)]
+    pub version: Option<String>,
+    /// See [`ServerItem::build`].
+    #[serde(default)]
+    pub build: Option<String>,
+    /// See [`ServerItem::update_policy`].
+    #[serde(default)]
+    pub update_policy: Option<UpdatePolicy>,
+    /// See [`ServerItem::server_type`].
+    #[serde(default)]
     pub server_type: Option<String>,
-    /// This is the amount of time the application should wait between restarts of this Minecraft server.
-    /// If both the secs and nanos values are 0, no restarts will be performed.
+    /// See [`ServerItem::restart_time`].
+    #[serde(default)]
     pub restart_time: Option<Duration>,
+    /// See [`ServerItem::eula_accepted_by`].
+    #[serde(default)]
+    pub eula_accepted_by: Option<String>,
+    /// See [`ServerItem::network`].
+    #[serde(default)]
+    pub network: Option<String>,
+    /// See [`ServerItem::network_role`].
+    #[serde(default)]
+    pub network_role: Option<NetworkRole>,
+    /// See [`ServerItem::kind`].
+    #[serde(default)]
+    pub kind: Option<ServerKind>,
+    /// See [`ServerItem::depends_on`].
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// See [`ServerItem::start_delay`].
+    #[serde(default)]
+    pub start_delay: Option<Duration>,
 }
-// impl ServerData {
-//     pub fn to_add_params(&self, id: usize) -> Result<(usize, String, String, Option<String>, String, Option<u64>), MCManageError> {
-//         let name = self.name.clone().unwrap_or("Minecraft Server".into());
-//         let args;
-//         let server_type = self.server_type.clone().unwrap_or("vanilla".into());
-
-//         if let Some(value) = self.args.clone() {
-//             args = value;
-//         } else {
-//             return Err(MCManageError::InvalidRequest("Can not add a Minecraft server without any arguments specified.".into()));
-//         }
+impl ServerData {
+    /// Create the [`ServerData`] of the given server. Every field will be set.
+    pub fn new(id: i64, name: &str, server_item: ServerItem) -> Self {
+        Self {
+            id: Some(id),
+            ..Self::from_server_item(name, server_item)
+        }
+    }
+    /// Create the [`ServerData`] of a server which has not been added to the [`ServerList`](super::ServerList) yet. Every field except the id
+    /// will be set.
+    pub fn from_server_item(name: &str, server_item: ServerItem) -> Self {
+        Self {
+            id: None,
+            name: Some(name.to_string()),
+            args: Some(server_item.args),
+            download_from: Some(server_item.download_from),
+            version: server_item.version,
+            build: server_item.build,
+            update_policy: Some(server_item.update_policy),
+            server_type: Some(server_item.server_type),
+            restart_time: Some(server_item.restart_time),
+            eula_accepted_by: server_item.eula_accepted_by,
+            network: server_item.network,
+            network_role: server_item.network_role,
+            kind: Some(server_item.kind),
+            depends_on: Some(server_item.depends_on),
+            start_delay: Some(server_item.start_delay),
+        }
+    }
+    /// Create the name and the [`ServerItem`] of a new server from this data. Fields which are not set will use their defaults.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                     |
+    /// |-----------------------------------|---------------------------------|
+    /// | `Ok((String, ServerItem))`        | The data describes a server.    |
+    /// | [`MCManageError::InvalidRequest`] | The name is missing or invalid. |
+    pub fn to_server_item(&self) -> Result<(String, ServerItem), MCManageError> {
+        let Some(name) = &self.name else {
+            return Err(MCManageError::InvalidRequest(
+                "Can not add a Minecraft server without a name.".to_string(),
+            ));
+        };
+        let server_item = ServerItem {
+            args: String::new(),
+            download_from: String::new(),
+            version: None,
+            build: None,
+            update_policy: UpdatePolicy::default(),
+            server_type: String::new(),
+            restart_time: Duration::ZERO,
+            eula_accepted_by: None,
+            network: None,
+            network_role: None,
+            kind: ServerKind::default(),
+            depends_on: vec![],
+            start_delay: Duration::ZERO,
+        };
+        self.apply(name, server_item)
+    }
+    /// Apply every field set in this data to the given server.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                      |
+    /// |-----------------------------------|----------------------------------|
+    /// | `Ok((String, ServerItem))`        | The name and the changed server. |
+    /// | [`MCManageError::InvalidRequest`] | The new name is invalid.         |
+    pub fn apply(&self, name: &str, mut server_item: ServerItem) -> Result<(String, ServerItem), MCManageError> {
+        let name = self.name.clone().unwrap_or(name.to_string());
+        ServerItem::check_name_format(&name)?;
 
-//         Ok((
-//             id.clone(),
-//             name.clone(),
-//             args.clone(),
-//             self.download_from.clone(),
-//             server_type.clone(),
-//             self.restart_time.and_then(|restart_time| restart_time.as_secs().into()).clone()
-//         ))
-//     }
-// }
\ No newline at end of file
+        let data = self.clone();
+        if let Some(args) = data.args {
+            server_item.args = args;
+        }
+        if let Some(download_from) = data.download_from {
+            server_item.download_from = download_from;
+        }
+        if data.version.is_some() {
+            server_item.version = data.version;
+        }
+        if data.build.is_some() {
+            server_item.build = data.build;
+        }
+        if let Some(update_policy) = data.update_policy {
+            server_item.update_policy = update_policy;
+        }
+        if let Some(server_type) = data.server_type {
+            server_item.server_type = server_type;
+        }
+        if let Some(restart_time) = data.restart_time {
+            server_item.restart_time = Duration::from_secs(restart_time.as_secs());
+        }
+        if data.eula_accepted_by.is_some() {
+            server_item.eula_accepted_by = data.eula_accepted_by;
+        }
+        if data.network.is_some() {
+            server_item.network = data.network;
+        }
+        if data.network_role.is_some() {
+            server_item.network_role = data.network_role;
+        }
+        if let Some(kind) = data.kind {
+            server_item.kind = kind;
+        }
+        if let Some(depends_on) = data.depends_on {
+            server_item.depends_on = depends_on;
+        }
+        if let Some(start_delay) = data.start_delay {
+            server_item.start_delay = Duration::from_secs(start_delay.as_secs());
+        }
+        Ok((name, server_item))
+    }
+}
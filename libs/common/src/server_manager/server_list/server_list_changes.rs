@@ -0,0 +1,72 @@
+//! This module provides the [`ServerListChanges`] struct.
+
+use std::collections::BTreeMap;
+
+use proc_macros::add_convert;
+
+use super::server_data::ServerData;
+use crate::{
+    mcmanage_error::MCManageError,
+    server_manager::server_item::ServerItem,
+    storage::{
+        server_change::ServerChange,
+        server_change_kind::ServerChangeKind,
+    },
+};
+
+/// This struct describes every change to the [`ServerList`](super::ServerList) since a given revision. \
+/// Every server appears at most once, so a client knowing the list at the given revision only has to apply these changes to be up to date. A server
+/// which got added and removed since then does not appear at all.
+#[add_convert]
+pub struct ServerListChanges {
+    /// The current revision of the server list. Pass it to the next request to only receive changes made afterwards.
+    pub revision: i64,
+    /// The servers which got added. Every field of their data is set.
+    pub added: Vec<ServerData>,
+    /// The servers which got changed. Every field of their data is set. Renamed servers keep their id.
+    pub modified: Vec<ServerData>,
+    /// The servers which got removed. Only their id and their last name are set.
+    pub removed: Vec<ServerData>,
+}
+impl ServerListChanges {
+    /// Combine the given changes with the current servers of the list. \
+    /// The changes have to be ordered by their revisions, and the servers have to be the servers of the list at the given revision.
+    pub fn new(revision: i64, changes: &[ServerChange], servers: Vec<(i64, String, ServerItem)>) -> Self {
+        // the first change of every server since the requested revision, together with its last name
+        let mut changed_servers: BTreeMap<i64, (ServerChangeKind, String)> = BTreeMap::new();
+        for change in changes {
+            changed_servers
+                .entry(change.server)
+                .and_modify(|(_, name)| *name = change.name.clone())
+                .or_insert((change.kind, change.name.clone()));
+        }
+        let mut servers: BTreeMap<i64, (String, ServerItem)> = servers
+            .into_iter()
+            .map(|(id, name, server_item)| (id, (name, server_item)))
+            .collect();
+
+        let mut server_list_changes = Self {
+            revision,
+            added: vec![],
+            modified: vec![],
+            removed: vec![],
+        };
+        for (id, (first_change, last_name)) in changed_servers {
+            match (servers.remove(&id), first_change) {
+                (Some((name, server_item)), ServerChangeKind::Added) => {
+                    server_list_changes.added.push(ServerData::new(id, &name, server_item));
+                }
+                (Some((name, server_item)), _) => {
+                    server_list_changes.modified.push(ServerData::new(id, &name, server_item));
+                }
+                (None, ServerChangeKind::Added) => {}
+                (None, _) => server_list_changes.removed.push(ServerData {
+                    id: Some(id),
+                    name: Some(last_name),
+                    ..Default::default()
+                }),
+            }
+        }
+        server_list_changes
+    }
+}
@@ -0,0 +1,229 @@
+#![cfg(test)]
+
+use std::time::Duration;
+
+use super::*;
+use crate::{
+    generated_files::default_files::example_server_list::EXAMPLE_SERVER_LIST,
+    server_manager::server::{
+        server_kind::ServerKind,
+        update_policy::UpdatePolicy,
+    },
+    storage::{
+        server_change::ServerChange,
+        server_change_kind::ServerChangeKind,
+    },
+    test_functions::*,
+};
+
+#[test]
+fn server_data_to_server_item() {
+    let server_data = ServerData::try_from(
+        r#"{"name": "myServer", "kind": "bedrock", "depends_on": ["auth"], "restart_time": {"secs": 60, "nanos": 500}}"#.to_string(),
+    )
+    .unwrap();
+    let (name, server_item) = server_data.to_server_item().unwrap();
+    assert_eq!(name, "myServer");
+    assert_eq!(server_item.kind, ServerKind::Bedrock);
+    assert_eq!(server_item.depends_on, vec!["auth".to_string()]);
+    assert_eq!(server_item.update_policy, UpdatePolicy::default());
+    assert!(server_item.args.is_empty());
+    // durations are saved in seconds
+    assert_eq!(server_item.restart_time, Duration::from_secs(60));
+    assert_eq!(server_item.start_delay, Duration::ZERO);
+
+    assert!(matches!(
+        ServerData::default().to_server_item(),
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    let server_data = ServerData {
+        name: Some("my server".to_string()),
+        ..Default::default()
+    };
+    assert!(matches!(server_data.to_server_item(), Err(MCManageError::InvalidRequest(_))));
+}
+#[test]
+fn server_data_apply() {
+    let (_, server_item) = ServerData {
+        name: Some("myServer".to_string()),
+        args: Some("-jar server.jar nogui".to_string()),
+        version: Some("1.20.4".to_string()),
+        ..Default::default()
+    }
+    .to_server_item()
+    .unwrap();
+
+    // only the fields which are set get changed
+    let server_data = ServerData {
+        download_from: Some("https://example.com/server.jar".to_string()),
+        start_delay: Some(Duration::from_secs(10)),
+        ..Default::default()
+    };
+    let (name, changed_item) = server_data.apply("myServer", server_item.clone()).unwrap();
+    assert_eq!(name, "myServer");
+    assert_eq!(changed_item.args, server_item.args);
+    assert_eq!(changed_item.version, server_item.version);
+    assert_eq!(changed_item.download_from, "https://example.com/server.jar");
+    assert_eq!(changed_item.start_delay, Duration::from_secs(10));
+
+    let server_data = ServerData {
+        name: Some("myRenamedServer".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(server_data.apply("myServer", server_item.clone()).unwrap().0, "myRenamedServer");
+    let server_data = ServerData {
+        name: Some("../myServer".to_string()),
+        ..Default::default()
+    };
+    assert!(matches!(server_data.apply("myServer", server_item), Err(MCManageError::InvalidRequest(_))));
+}
+#[test]
+fn server_data_new() {
+    let (name, server_item) = ServerData {
+        name: Some("myServer".to_string()),
+        eula_accepted_by: Some("Jane Doe".to_string()),
+        ..Default::default()
+    }
+    .to_server_item()
+    .unwrap();
+    let server_data = ServerData::new(3, &name, server_item.clone());
+    assert_eq!(server_data.id, Some(3));
+    assert_eq!(server_data.to_server_item().unwrap(), (name, server_item));
+}
+#[test]
+fn server_list_changes_new() {
+    let (_, server_item) = ServerData {
+        name: Some("myServer".to_string()),
+        ..Default::default()
+    }
+    .to_server_item()
+    .unwrap();
+    let change = |revision, server, name: &str, kind| ServerChange {
+        revision,
+        server,
+        name: name.to_string(),
+        kind,
+    };
+    let changes = vec![
+        change(4, 1, "myFirstServer", ServerChangeKind::Modified),
+        change(5, 2, "mySecondServer", ServerChangeKind::Added),
+        change(6, 2, "myRenamedServer", ServerChangeKind::Modified),
+        change(7, 3, "myThirdServer", ServerChangeKind::Modified),
+        change(8, 3, "myThirdServer", ServerChangeKind::Removed),
+        change(9, 4, "myFourthServer", ServerChangeKind::Added),
+        change(10, 4, "myFourthServer", ServerChangeKind::Removed),
+    ];
+    let servers = vec![
+        (1, "myFirstServer".to_string(), server_item.clone()),
+        (2, "myRenamedServer".to_string(), server_item.clone()),
+        (5, "myFifthServer".to_string(), server_item.clone()),
+    ];
+
+    let server_list_changes = ServerListChanges::new(10, &changes, servers);
+    assert_eq!(server_list_changes.revision, 10);
+    // a server added and changed since the revision counts as added
+    assert_eq!(server_list_changes.added, vec![ServerData::new(2, "myRenamedServer", server_item.clone())]);
+    assert_eq!(server_list_changes.modified, vec![ServerData::new(1, "myFirstServer", server_item)]);
+    // a server added and removed since the revision does not appear at all
+    assert_eq!(
+        server_list_changes.removed,
+        vec![ServerData {
+            id: Some(3),
+            name: Some("myThirdServer".to_string()),
+            ..Default::default()
+        }]
+    );
+}
+#[tokio::test]
+async fn import_server_list_file() {
+    start_test();
+    let storage = SqliteStorage::in_memory().await.unwrap();
+    fs::create_dir_all(CONFIG_DIR.as_path()).await.unwrap();
+    let server_list = format!("{EXAMPLE_SERVER_LIST}\n\n[\"my server\"]\nargs = \"\"\n\n[myThirdServer]\nargs = 5\n");
+
+    fs::write(SERVER_LIST_FILE.as_path(), &server_list).await.unwrap();
+    assert_eq!(ServerList::import_server_list_file(&storage).await.unwrap(), 2);
+    let servers = storage.servers().await.unwrap();
+    assert_eq!(servers.len(), 2);
+    let (_, name, server_item) = &servers[1];
+    assert_eq!(name, "mySecondServer");
+    assert_eq!(server_item.args, "-jar purpur-1.19.3-1933.jar nogui");
+    assert_eq!(server_item.download_from, "https://api.purpurmc.org/v2/purpur/1.19.3/1933/download");
+    assert_eq!(server_item.server_type, "purpur");
+    assert_eq!(server_item.restart_time, Duration::from_secs(86400));
+    assert_eq!(server_item.depends_on, vec!["myFirstServer".to_string()]);
+    assert_eq!(server_item.start_delay, Duration::from_secs(10));
+    assert!(!SERVER_LIST_FILE.exists());
+    assert!(CONFIG_DIR.join("server_list.toml.migrated").exists());
+
+    // servers which already exist will be skipped, and the first renamed file will be kept
+    fs::write(SERVER_LIST_FILE.as_path(), &server_list).await.unwrap();
+    assert_eq!(ServerList::import_server_list_file(&storage).await.unwrap(), 0);
+    assert!(CONFIG_DIR.join("server_list(1).toml.migrated").exists());
+
+    // if the dependencies can not be met, nothing will be imported
+    fs::write(
+        SERVER_LIST_FILE.as_path(),
+        "[myFourthServer]\nargs = \"\"\ndownload_from = \"\"\ndepends_on = [\"auth\"]\n[myFourthServer.restart_time]\nsecs = 0\nnanos = 0\n",
+    )
+    .await
+    .unwrap();
+    assert!(matches!(
+        ServerList::import_server_list_file(&storage).await,
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    assert!(SERVER_LIST_FILE.exists());
+    assert_eq!(storage.servers().await.unwrap().len(), 2);
+
+    fs::remove_file(SERVER_LIST_FILE.as_path()).await.unwrap();
+    assert_eq!(ServerList::import_server_list_file(&storage).await.unwrap(), 0);
+
+    cleanup();
+}
+#[tokio::test]
+async fn change_renames_directory() {
+    start_test();
+    init_server_list().await;
+    let server_data = ServerList::add(ServerData {
+        name: Some("myChangedServer".to_string()),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    let id = server_data.id.unwrap();
+    fs::create_dir_all(SERVERS_DIR.join("myChangedServer")).await.unwrap();
+
+    // the directory gets renamed back if the server could not be saved
+    let invalid_data = ServerData {
+        name: Some("myRenamedServer".to_string()),
+        restart_time: Some(Duration::MAX),
+        ..Default::default()
+    };
+    assert!(matches!(
+        ServerList::change(id, invalid_data).await,
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    assert!(SERVERS_DIR.join("myChangedServer").exists());
+    assert!(!SERVERS_DIR.join("myRenamedServer").exists());
+    assert!(ServerList::get("myChangedServer").await.is_ok());
+
+    let server_data = ServerList::change(
+        id,
+        ServerData {
+            name: Some("myRenamedServer".to_string()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(server_data.name.as_deref(), Some("myRenamedServer"));
+    assert!(!SERVERS_DIR.join("myChangedServer").exists());
+    assert!(SERVERS_DIR.join("myRenamedServer").exists());
+    assert!(matches!(
+        ServerList::get("myChangedServer").await,
+        Err(MCManageError::NotFound)
+    ));
+    assert!(ServerList::get("myRenamedServer").await.is_ok());
+
+    cleanup();
+}
@@ -0,0 +1,130 @@
+//! This module provides the [`StartOrder`] struct.
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
+
+use super::server::{
+    network_role::NetworkRole,
+    Server,
+};
+use crate::mcmanage_error::MCManageError;
+
+mod tests;
+
+/// A server sorted by the [`StartOrder::with_networks`] method. It is given by its name, the names of the servers it depends on, the network it
+/// belongs to and its role in there.
+pub type StartOrderServer = (String, Vec<String>, Option<String>, Option<NetworkRole>);
+
+/// This struct represents the order in which servers have to be started, so every server starts after the servers it depends on. \
+/// Servers get stopped in the reverse order, so no server loses a dependency while it is still running.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StartOrder {
+    /// The names of the servers in the order they have to be started in
+    order: Vec<String>,
+    /// The names of the servers every server depends on
+    dependencies: HashMap<String, Vec<String>>,
+}
+impl StartOrder {
+    /// Sort the given servers topologically. Every server is given by its name and the names of the servers it depends on. \
+    /// Servers which do not depend on each other keep the order they got given in.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                                                            |
+    /// |-----------------------------------|----------------------------------------------------------------------------------------|
+    /// | `Ok(StartOrder)`                  | Every dependency exists and no server depends on itself, directly or not.              |
+    /// | [`MCManageError::InvalidRequest`] | A server depends on a server which does not exist or the dependencies contain a cycle. |
+    pub fn new(servers: &[(String, Vec<String>)]) -> Result<Self, MCManageError> {
+        for (name, depends_on) in servers {
+            if let Some(dependency) = depends_on
+                .iter()
+                .find(|dependency| !servers.iter().any(|(server, _)| server == *dependency))
+            {
+                return Err(MCManageError::InvalidRequest(format!(
+                    "The server {name} depends on the server {dependency}, which does not exist."
+                )));
+            }
+        }
+
+        let mut order: Vec<String> = vec![];
+        while order.len() < servers.len() {
+            let next = servers.iter().find(|(name, depends_on)| {
+                !order.contains(name) && depends_on.iter().all(|dependency| order.contains(dependency))
+            });
+            match next {
+                Some((name, _)) => order.push(name.clone()),
+                None => {
+                    let unordered: Vec<&str> = servers
+                        .iter()
+                        .filter(|(name, _)| !order.contains(name))
+                        .map(|(name, _)| name.as_str())
+                        .collect();
+                    return Err(MCManageError::InvalidRequest(format!(
+                        "The dependencies of the servers {} contain a cycle.",
+                        unordered.join(", ")
+                    )));
+                }
+            }
+        }
+        Ok(Self {
+            order,
+            dependencies: servers.iter().cloned().collect(),
+        })
+    }
+    /// Sort the given servers topologically like the [`new`](Self::new) method does, but additionally let the proxy of every network depend on
+    /// the backends of it. This way players only get forwarded to backends which are already running. Servers belonging to a network without a
+    /// `network_role` are backends.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                                                            |
+    /// |-----------------------------------|----------------------------------------------------------------------------------------|
+    /// | `Ok(StartOrder)`                  | Every dependency exists and no server depends on itself, directly or not.              |
+    /// | [`MCManageError::InvalidRequest`] | A server depends on a server which does not exist or the dependencies contain a cycle. |
+    pub fn with_networks(servers: &[StartOrderServer]) -> Result<Self, MCManageError> {
+        Self::new(
+            &servers
+                .iter()
+                .map(|(name, depends_on, network, network_role)| {
+                    let mut depends_on = depends_on.clone();
+                    if let (Some(network), Some(NetworkRole::Proxy)) = (network, network_role) {
+                        for (backend, _, backend_network, backend_role) in servers {
+                            if backend_network.as_ref() == Some(network)
+                                && !matches!(backend_role, Some(NetworkRole::Proxy))
+                                && !depends_on.contains(backend)
+                            {
+                                depends_on.push(backend.clone());
+                            }
+                        }
+                    }
+                    (name.clone(), depends_on)
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+    /// Sort the given [`Servers`](Server) topologically using their `depends_on` fields and the networks they belong to. See the
+    /// [`with_networks`](Self::with_networks) method for more information.
+    pub fn from_servers(servers: &[Arc<Server>]) -> Result<Self, MCManageError> {
+        Self::with_networks(
+            &servers
+                .iter()
+                .map(|server| (server.name(), server.depends_on(), server.network(), server.network_role()))
+                .collect::<Vec<_>>(),
+        )
+    }
+    /// Return the names of the servers in the order they have to be started in.
+    pub fn start_order(&self) -> &[String] {
+        &self.order
+    }
+    /// Return the names of the servers the given server depends on. For proxies, this includes the backends of their network. See the
+    /// [`with_networks`](Self::with_networks) method.
+    pub fn dependencies(&self, name: &str) -> &[String] {
+        self.dependencies.get(name).map_or(&[], |dependencies| dependencies.as_slice())
+    }
+    /// Return the names of the servers in the order they have to be stopped in.
+    pub fn stop_order(&self) -> Vec<String> {
+        self.order.iter().rev().cloned().collect()
+    }
+}
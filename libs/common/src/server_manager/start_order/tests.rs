@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use super::*;
+
+/// Create the list of servers passed to the [`StartOrder::new`] method. The dependencies of every server are separated by spaces.
+fn servers(servers: &[(&str, &str)]) -> Vec<(String, Vec<String>)> {
+    servers
+        .iter()
+        .map(|(name, depends_on)| (name.to_string(), depends_on.split_whitespace().map(String::from).collect()))
+        .collect()
+}
+
+#[test]
+fn topological_order() {
+    let start_order = StartOrder::new(&servers(&[
+        ("lobby", "auth proxy"),
+        ("survival", ""),
+        ("proxy", "auth"),
+        ("auth", "database"),
+        ("database", ""),
+    ]))
+    .unwrap();
+
+    assert_eq!(start_order.start_order(), ["survival", "database", "auth", "proxy", "lobby"]);
+    assert_eq!(start_order.stop_order(), ["lobby", "proxy", "auth", "database", "survival"]);
+}
+#[test]
+fn independent_servers_keep_their_order() {
+    let start_order = StartOrder::new(&servers(&[("b", ""), ("a", ""), ("c", "")])).unwrap();
+    assert_eq!(start_order.start_order(), ["b", "a", "c"]);
+
+    assert_eq!(StartOrder::new(&[]).unwrap().start_order(), Vec::<String>::new());
+}
+#[test]
+fn invalid_dependencies() {
+    assert!(matches!(
+        StartOrder::new(&servers(&[("lobby", "auth")])),
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    assert!(matches!(
+        StartOrder::new(&servers(&[("lobby", "lobby")])),
+        Err(MCManageError::InvalidRequest(_))
+    ));
+
+    assert!(matches!(
+        StartOrder::new(&servers(&[
+            ("survival", ""),
+            ("lobby", "auth"),
+            ("auth", "proxy"),
+            ("proxy", "lobby"),
+        ])),
+        Err(MCManageError::InvalidRequest(message))
+            if message == "The dependencies of the servers lobby, auth, proxy contain a cycle."
+    ));
+}
+#[test]
+fn proxies_depend_on_their_backends() {
+    let server = |name: &str, depends_on: &[&str], network: Option<&str>, network_role: Option<NetworkRole>| -> StartOrderServer {
+        (
+            name.to_string(),
+            depends_on.iter().map(|dependency| dependency.to_string()).collect(),
+            network.map(String::from),
+            network_role,
+        )
+    };
+
+    let start_order = StartOrder::with_networks(&[
+        server("proxy", &[], Some("myNetwork"), Some(NetworkRole::Proxy)),
+        server("otherProxy", &[], Some("otherNetwork"), Some(NetworkRole::Proxy)),
+        server("lobby", &["auth"], Some("myNetwork"), None),
+        server("survival", &[], Some("myNetwork"), Some(NetworkRole::Backend)),
+        server("auth", &[], None, None),
+        server("creative", &[], Some("otherNetwork"), None),
+    ])
+    .unwrap();
+    assert_eq!(
+        start_order.start_order(),
+        ["survival", "auth", "lobby", "proxy", "creative", "otherProxy"]
+    );
+    assert_eq!(start_order.dependencies("proxy"), ["lobby", "survival"]);
+    assert_eq!(start_order.dependencies("lobby"), ["auth"]);
+    assert!(start_order.dependencies("myUnknownServer").is_empty());
+
+    // a backend can not depend on the proxy of its network
+    assert!(matches!(
+        StartOrder::with_networks(&[
+            server("proxy", &[], Some("myNetwork"), Some(NetworkRole::Proxy)),
+            server("lobby", &["proxy"], Some("myNetwork"), None),
+        ]),
+        Err(MCManageError::InvalidRequest(_))
+    ));
+}
@@ -1,183 +1,191 @@
-// #![cfg(test)]
-
-// use tokio::{
-//     fs::{
-//         self,
-//         OpenOptions,
-//     },
-//     io,
-//     spawn,
-// };
-
-// use super::*;
-// use crate::{
-//     generated_files::{
-//         default_files::get_example_content,
-//         paths::{
-//             CONFIG_DIR,
-//             SERVER_LIST_FILE,
-//         },
-//     },
-//     test_functions::*,
-// };
-
-// // TODO fix tests
-
-// async fn test_start() {
-//     start_test();
-//     generate_server_list().await;
-//     ServerManager::init().await;
-// }
-// // TODO use 2 servers here as soon as the properties of a server become configurable
-// async fn generate_server_list() {
-//     let content = r#"
-//         [myFirstServer]
-//         download_from = "https://api.purpurmc.org/v2/purpur/1.19.3/1933/download"
-//         args = "-jar purpur-1.19.3-1933.jar nogui"
-//         server_type = "purpur"
-//         [myFirstServer.restart_time]
-//         secs = 60
-//         nanos = 0
-//     "#;
-
-//     fs::create_dir(CONFIG_DIR.as_path()).await.unwrap();
-//     let mut server_list_file = OpenOptions::new()
-//         .write(true)
-//         .create_new(true)
-//         .open(SERVER_LIST_FILE.as_path())
-//         .await
-//         .unwrap();
-//     io::copy(&mut content.as_bytes(), &mut server_list_file)
-//         .await
-//         .unwrap();
-// }
-
-// // the following two functions will also test `get_server_parameter` and `generate_valid_server_list_file`
-// #[tokio::test]
-// async fn load_server_list_valid_file() {
-//     test_start().await;
-
-//     ServerManager::load_server_list().await;
-
-//     assert_eq!(
-//         server_manager().server_list.lock().await.0.len(),
-//         1,
-//         "The function should have captured one server."
-//     );
-//     cleanup();
-// }
-// #[tokio::test]
-// async fn load_server_list_invalid_file() {
-//     start_test();
-//     let content = r#"
-//         [0]
-//         name = "myFirstServer"
-//         args = "-jar purpur-1.19.3-1876.jar nogui"
-//     }"#;
-
-//     fs::create_dir(CONFIG_DIR.as_path()).await.unwrap();
-//     let mut server_list_file = OpenOptions::new()
-//         .write(true)
-//         .create_new(true)
-//         .open(SERVER_LIST_FILE.as_path())
-//         .await
-//         .unwrap();
-//     io::copy(&mut content.as_bytes(), &mut server_list_file)
-//         .await
-//         .unwrap();
-
-//     let server_manager = Arc::new(ServerManager {
-//         main_thread: Arc::new(None.into()),
-//         status: Status::Stopped.into(),
-
-//         server_list: (vec![], Utc::now()).into(),
-//         restart_times: vec![].into(),
-//     });
-
-//     spawn(async {
-//         sleep(Duration::new(1, 0)).await;
-
-//         let mut valid_file = OpenOptions::new()
-//             .write(true)
-//             .create(true)
-//             .open(SERVER_LIST_FILE.as_path())
-//             .await
-//             .unwrap();
-//         io::copy(
-//             &mut get_example_content(&SERVER_LIST_FILE).1.as_bytes(),
-//             &mut valid_file,
-//         )
-//         .await
-//         .unwrap();
-
-//         let mut server_list = load_toml(&SERVER_LIST_FILE, "Test", true).await.unwrap();
-//         server_list.remove("mySecondServer");
-
-//         fs::write(
-//             SERVER_LIST_FILE.as_path(),
-//             toml::to_string(&server_list).unwrap().as_bytes(),
-//         )
-//         .await
-//         .unwrap();
-//     });
-
-//     server_manager.load_server_list().await;
-
-//     OpenOptions::new()
-//         .write(true)
-//         .create_new(true)
-//         .open(CONFIG_DIR.join("example_server_list.toml"))
-//         .await
-//         .unwrap_err();
-//     cleanup();
-// }
-
-// #[tokio::test]
-// async fn get_server() {
-//     let server_manager = test_start().await;
-
-//     server_manager.clone().impl_start(false).await.unwrap();
-
-//     let server = server_manager
-//         .get_server("myFirstServer")
-//         .await
-//         .unwrap();
-
-//     assert_eq!(server.name(), "myFirstServer");
-
-//     server_manager.impl_stop(false, false).await.unwrap();
-//     cleanup();
-// }
-// // set the `src/config::Config::shutdown_time` field to 1min to test the shutdown of the own machine
-// // the `src/config::AGREE_TO_EULA` const needs to be true
-// // the `src/config::Config::server_restart_time` const needs to be 1min
-// #[tokio::test]
-// async fn main() {
-//     // this is a test for almost every function in the ServerManager struct
-//     let server_manager = test_start().await;
-
-//     server_manager.clone().impl_start(false).await.unwrap();
-
-//     let server = server_manager
-//         .get_server("myFirstServer")
-//         .await
-//         .unwrap();
-//     let start_time = Instant::now();
-//     loop {
-//         if Status::Restarting == server.status().await {
-//             break;
-//         }
-//         if Instant::now() - start_time > Duration::new(100, 0) {
-//             assert!(false, "The ServerManager took to long to restart.");
-//         }
-//         sleep(Duration::new(1, 0)).await;
-//     }
-//     loop {
-//         if Status::Started == server.status().await {
-//             break;
-//         }
-//         sleep(Duration::new(1, 0)).await;
-//     }
-//     server_manager.impl_stop(false, false).await.unwrap();
-//     cleanup();
-// }
+#![cfg(test)]
+#![cfg(unix)]
+
+use std::{
+    os::unix::fs::PermissionsExt,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use tokio::fs;
+
+use super::*;
+use crate::{
+    generated_files::paths::ROOT_DIR,
+    server_manager::{
+        server::server_kind::ServerKind,
+        server_list::server_data::ServerData,
+        start_order::StartOrder,
+    },
+    test_functions::*,
+};
+
+/// Return the path to the log the servers of the given test write to. Every line of it is either `started <name>` or `stopped <name>`.
+async fn test_log(test: &str) -> PathBuf {
+    let log = ROOT_DIR.join(format!("{test}.log"));
+    if fs::remove_file(&log).await.is_err() {}
+    log
+}
+/// Read the lines of the given log.
+async fn read_log(log: &Path) -> Vec<String> {
+    fs::read_to_string(log)
+        .await
+        .unwrap_or_default()
+        .lines()
+        .map(String::from)
+        .collect()
+}
+/// Add a Bedrock server with the given name, dependencies and start delay to the [`ServerList`] and return it. \
+/// Instead of the Bedrock Dedicated Server, a script gets installed, which finishes its start after the given time and notes its start and stop in
+/// the given log. If `eula_accepted` is false, the server will fail to start.
+async fn add_server(
+    name: &str,
+    depends_on: &[&str],
+    start_delay: Duration,
+    start_time: Duration,
+    eula_accepted: bool,
+    log: &Path,
+) -> Arc<Server> {
+    init_server_list().await;
+    ServerList::add(ServerData {
+        name: Some(name.to_string()),
+        kind: Some(ServerKind::Bedrock),
+        eula_accepted_by: eula_accepted.then(|| "Jane Doe".to_string()),
+        depends_on: Some(depends_on.iter().map(|dependency| dependency.to_string()).collect()),
+        start_delay: Some(start_delay),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    let server = ServerList::get(name).await.unwrap();
+
+    let binary_path = server.bedrock().unwrap().binary_path();
+    fs::create_dir_all(server.path()).await.unwrap();
+    fs::write(
+        &binary_path,
+        format!(
+            "#!/bin/sh\n\
+            sleep {}\n\
+            echo 'started {name}' >> '{log}'\n\
+            echo '[2024-01-01 00:00:00:000 INFO] Server started.'\n\
+            while read line; do [ \"$line\" = stop ] && echo 'stopped {name}' >> '{log}' && exit 0; done\n",
+            start_time.as_secs_f64(),
+            log = log.display()
+        ),
+    )
+    .await
+    .unwrap();
+    fs::set_permissions(&binary_path, std::fs::Permissions::from_mode(0o755))
+        .await
+        .unwrap();
+    server
+}
+
+#[tokio::test]
+async fn start_and_stop_in_order() {
+    let log = test_log("start_and_stop_in_order").await;
+    let lobby = add_server("myOrderedLobby", &[], Duration::ZERO, Duration::ZERO, true, &log).await;
+    let survival = add_server("myOrderedSurvival", &["myOrderedLobby"], Duration::from_secs(1), Duration::ZERO, true, &log).await;
+    let creative = add_server("myOrderedCreative", &[], Duration::ZERO, Duration::ZERO, true, &log).await;
+    let start_order = StartOrder::from_servers(&[survival.clone(), lobby.clone(), creative.clone()]).unwrap();
+
+    // the survival server waits for its start delay after the lobby got started
+    let start_time = Instant::now();
+    ServerManager::start_servers(&start_order).await.unwrap();
+    assert!(start_time.elapsed() >= Duration::from_secs(1));
+    assert_eq!(
+        read_log(&log).await,
+        ["started myOrderedLobby", "started myOrderedSurvival", "started myOrderedCreative"]
+    );
+    for server in [&lobby, &survival, &creative] {
+        assert_eq!(server.status().await, Status::Started);
+    }
+
+    // servers which are already running are left untouched
+    ServerManager::start_servers(&start_order).await.unwrap();
+    assert_eq!(read_log(&log).await.len(), 3);
+
+    ServerManager::stop_servers(&start_order).await.unwrap();
+    assert_eq!(
+        read_log(&log).await[3..],
+        ["stopped myOrderedCreative", "stopped myOrderedSurvival", "stopped myOrderedLobby"]
+    );
+    for server in [&lobby, &survival, &creative] {
+        assert_eq!(server.status().await, Status::Stopped);
+    }
+}
+#[tokio::test]
+async fn start_skips_dependents_of_failed_servers() {
+    let log = test_log("start_skips_dependents_of_failed_servers").await;
+    let auth = add_server("myFailingAuth", &[], Duration::ZERO, Duration::ZERO, false, &log).await;
+    let lobby = add_server("myFailingLobby", &["myFailingAuth"], Duration::ZERO, Duration::ZERO, true, &log).await;
+    let survival = add_server("myFailingSurvival", &["myFailingLobby"], Duration::ZERO, Duration::ZERO, true, &log).await;
+    let creative = add_server("myFailingCreative", &[], Duration::ZERO, Duration::ZERO, true, &log).await;
+    let start_order = StartOrder::from_servers(&[auth, lobby.clone(), survival.clone(), creative.clone()]).unwrap();
+
+    // the failed server and its transitive dependents are reported, while every other server still gets started
+    match ServerManager::start_servers(&start_order).await {
+        Err(MCManageError::ServersFailed(failed)) => {
+            assert_eq!(failed.len(), 3);
+            assert!(matches!(&failed[0], (name, MCManageError::EulaNotAccepted) if name == "myFailingAuth"));
+            assert!(matches!(&failed[1], (name, MCManageError::NotReady) if name == "myFailingLobby"));
+            assert!(matches!(&failed[2], (name, MCManageError::NotReady) if name == "myFailingSurvival"));
+        }
+        result => panic!("Expected the servers to fail, but got {result:?}."),
+    }
+    assert_eq!(read_log(&log).await, ["started myFailingCreative"]);
+    assert_eq!(lobby.status().await, Status::Stopped);
+    assert_eq!(survival.status().await, Status::Stopped);
+
+    creative.impl_stop(false, false).await.unwrap();
+}
+#[tokio::test]
+async fn start_waits_for_dependencies() {
+    let log = test_log("start_waits_for_dependencies").await;
+    let lobby = add_server("myWaitingLobby", &[], Duration::ZERO, Duration::from_secs(1), true, &log).await;
+    let survival = add_server("myWaitingSurvival", &["myWaitingLobby"], Duration::ZERO, Duration::ZERO, true, &log).await;
+
+    // a dependency which is still starting gets waited for
+    let lobby_start = spawn(lobby.clone().impl_start(false));
+    while lobby.status().await != Status::Starting {
+        sleep(Duration::from_millis(10)).await;
+    }
+    ServerManager::start_server("myWaitingSurvival", &survival.depends_on())
+        .await
+        .unwrap();
+    assert_eq!(lobby.status().await, Status::Started);
+    assert_eq!(read_log(&log).await, ["started myWaitingLobby", "started myWaitingSurvival"]);
+    lobby_start.await.unwrap().unwrap();
+
+    survival.clone().impl_stop(false, false).await.unwrap();
+    lobby.impl_stop(false, false).await.unwrap();
+
+    // a stopped dependency will not be started anytime soon
+    assert!(matches!(
+        ServerManager::start_server("myWaitingSurvival", &survival.depends_on()).await,
+        Err(MCManageError::NotReady)
+    ));
+    assert_eq!(survival.status().await, Status::Stopped);
+}
+#[tokio::test]
+async fn wait_until_started() {
+    let log = test_log("wait_until_started").await;
+    let server = add_server("myWaitedServer", &[], Duration::ZERO, Duration::from_secs(1), true, &log).await;
+
+    assert!(matches!(
+        ServerManager::wait_until_started(&server).await,
+        Err(MCManageError::NotReady)
+    ));
+
+    let start = spawn(server.clone().impl_start(false));
+    while server.status().await != Status::Starting {
+        sleep(Duration::from_millis(10)).await;
+    }
+    ServerManager::wait_until_started(&server).await.unwrap();
+    assert_eq!(server.status().await, Status::Started);
+    start.await.unwrap().unwrap();
+
+    server.impl_stop(false, false).await.unwrap();
+}
@@ -0,0 +1,18 @@
+//! This module provides the [`DatabaseBackend`] enum.
+
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This enum describes which database the [`ServerList`](crate::server_manager::server_list::ServerList) saves its servers to. It gets selected
+/// in the [`Config`](crate::config::Config).
+#[add_convert]
+#[derive(Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseBackend {
+    /// An embedded PostgreSQL server. See the [`PostgresStorage`](super::postgres_storage::PostgresStorage) struct.
+    #[default]
+    Postgres,
+    /// A single SQLite file, which needs no database server. See the [`SqliteStorage`](super::sqlite_storage::SqliteStorage) struct.
+    Sqlite,
+}
@@ -0,0 +1,234 @@
+//! This module provides the structs representing the rows of the tables of the player history, the chat log and the webhooks.
+
+use std::time::Duration;
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use sqlx::FromRow;
+
+use crate::{
+    chat_log::chat_message::ChatMessage,
+    mcmanage_error::MCManageError,
+    player_sessions::{
+        player_session::PlayerSession,
+        player_statistics::ServerStatistics,
+        session_close_reason::SessionCloseReason,
+    },
+    webhooks::{
+        webhook::Webhook,
+        webhook_delivery::WebhookDelivery,
+    },
+};
+
+/// This struct represents a row of the `player_identities` table.
+#[derive(FromRow)]
+pub(super) struct IdentityRow {
+    /// See [`PlayerIdentity::uuid`](crate::player_identities::player_identity::PlayerIdentity::uuid).
+    pub uuid: String,
+    /// See [`PlayerIdentity::name`](crate::player_identities::player_identity::PlayerIdentity::name).
+    pub name: String,
+    /// See [`PlayerIdentity::first_seen`](crate::player_identities::player_identity::PlayerIdentity::first_seen).
+    pub first_seen: DateTime<Utc>,
+    /// See [`PlayerIdentity::last_seen`](crate::player_identities::player_identity::PlayerIdentity::last_seen).
+    pub last_seen: DateTime<Utc>,
+    /// See [`PlayerIdentity::last_ip`](crate::player_identities::player_identity::PlayerIdentity::last_ip).
+    pub last_ip: Option<String>,
+}
+
+/// This struct represents a row of the `player_sessions` table.
+#[derive(FromRow)]
+pub(super) struct SessionRow {
+    /// See [`PlayerSession::id`].
+    id: i64,
+    /// See [`PlayerSession::server`].
+    server: String,
+    /// See [`PlayerSession::uuid`].
+    uuid: String,
+    /// See [`PlayerSession::player`].
+    player: String,
+    /// See [`PlayerSession::joined_at`].
+    joined_at: DateTime<Utc>,
+    /// See [`PlayerSession::left_at`].
+    left_at: Option<DateTime<Utc>>,
+    /// See [`PlayerSession::close_reason`].
+    close_reason: Option<String>,
+}
+impl SessionRow {
+    /// Convert this row into a [`PlayerSession`]. An unknown close reason will return an [`MCManageError::InvalidFile`].
+    pub fn into_session(self) -> Result<PlayerSession, MCManageError> {
+        let close_reason = match self.close_reason {
+            Some(close_reason) => {
+                Some(SessionCloseReason::from_name(&close_reason).ok_or(MCManageError::InvalidFile)?)
+            }
+            None => None,
+        };
+        Ok(PlayerSession {
+            id: self.id,
+            server: self.server,
+            uuid: self.uuid,
+            player: self.player,
+            joined_at: self.joined_at,
+            left_at: self.left_at,
+            close_reason,
+        })
+    }
+}
+
+/// This struct represents a row of the statistics query of the [`server_statistics`](super::HistoryStorage::server_statistics) method.
+#[derive(FromRow)]
+pub(super) struct StatisticsRow {
+    /// The uuid of the player
+    uuid: String,
+    /// The current name of the player
+    player: String,
+    /// The name of the server
+    server: String,
+    /// The number of sessions
+    sessions: i64,
+    /// See [`ServerStatistics::first_seen`].
+    first_seen: DateTime<Utc>,
+    /// See [`ServerStatistics::last_seen`].
+    last_seen: DateTime<Utc>,
+    /// The playtime in seconds
+    playtime: i64,
+    /// See [`ServerStatistics::online`].
+    online: bool,
+}
+impl StatisticsRow {
+    /// Convert this row into the uuid and the current name of the player together with its [`ServerStatistics`].
+    pub fn into_statistics(self) -> (String, String, ServerStatistics) {
+        (
+            self.uuid,
+            self.player,
+            ServerStatistics {
+                server: self.server,
+                sessions: self.sessions.max(0) as u64,
+                first_seen: self.first_seen,
+                last_seen: self.last_seen,
+                playtime: Duration::from_secs(self.playtime.max(0) as u64),
+                online: self.online,
+            },
+        )
+    }
+}
+
+/// This struct represents a row of the `chat_messages` table.
+#[derive(FromRow)]
+pub(super) struct ChatMessageRow {
+    /// See [`ChatMessage::id`].
+    id: i64,
+    /// See [`ChatMessage::server`].
+    server: String,
+    /// See [`ChatMessage::uuid`].
+    uuid: Option<String>,
+    /// See [`ChatMessage::sender`].
+    sender: String,
+    /// See [`ChatMessage::message`].
+    message: String,
+    /// See [`ChatMessage::sent_at`].
+    sent_at: DateTime<Utc>,
+}
+impl From<ChatMessageRow> for ChatMessage {
+    fn from(row: ChatMessageRow) -> Self {
+        Self {
+            id: Some(row.id),
+            server: row.server,
+            uuid: row.uuid,
+            sender: row.sender,
+            message: row.message,
+            sent_at: row.sent_at,
+        }
+    }
+}
+
+/// This struct represents a row of the `webhooks` table.
+#[derive(FromRow)]
+pub(super) struct WebhookRow {
+    /// See [`Webhook::id`].
+    pub id: i64,
+    /// See [`Webhook::name`].
+    pub name: String,
+    /// See [`Webhook::url`].
+    pub url: String,
+    /// The [`Webhook::filter`] as json
+    pub filter: String,
+    /// See [`Webhook::secret`].
+    pub secret: Option<String>,
+    /// The [`Webhook::template`] as json
+    pub template: String,
+    /// See [`Webhook::enabled`].
+    pub enabled: bool,
+}
+impl WebhookRow {
+    /// Create the row of the given webhook.
+    pub fn new(webhook: &Webhook) -> Result<Self, MCManageError> {
+        Ok(Self {
+            id: webhook.id,
+            name: webhook.name.clone(),
+            url: webhook.url.clone(),
+            filter: serde_json::to_string(&webhook.filter)?,
+            secret: webhook.secret.clone(),
+            template: serde_json::to_string(&webhook.template)?,
+            enabled: webhook.enabled,
+        })
+    }
+    /// Convert this row into a [`Webhook`].
+    pub fn into_webhook(self) -> Result<Webhook, MCManageError> {
+        Ok(Webhook {
+            id: self.id,
+            name: self.name,
+            url: self.url,
+            filter: serde_json::from_str(&self.filter)?,
+            secret: self.secret,
+            template: serde_json::from_str(&self.template)?,
+            enabled: self.enabled,
+        })
+    }
+}
+
+/// This struct represents a row of the `webhook_deliveries` table.
+#[derive(FromRow)]
+pub(super) struct DeliveryRow {
+    /// See [`WebhookDelivery::webhook`].
+    pub webhook: i64,
+    /// The [`WebhookDelivery::event`] as json
+    pub event: String,
+    /// See [`WebhookDelivery::success`].
+    pub success: bool,
+    /// See [`WebhookDelivery::attempts`].
+    pub attempts: i64,
+    /// See [`WebhookDelivery::status_code`].
+    pub status_code: Option<i32>,
+    /// See [`WebhookDelivery::error`].
+    pub error: Option<String>,
+    /// See [`WebhookDelivery::delivered_at`].
+    pub delivered_at: DateTime<Utc>,
+}
+impl DeliveryRow {
+    /// Create the row of the given delivery.
+    pub fn new(delivery: &WebhookDelivery) -> Result<Self, MCManageError> {
+        Ok(Self {
+            webhook: delivery.webhook,
+            event: serde_json::to_string(&delivery.event)?,
+            success: delivery.success,
+            attempts: i64::try_from(delivery.attempts).unwrap_or(i64::MAX),
+            status_code: delivery.status_code.map(i32::from),
+            error: delivery.error.clone(),
+            delivered_at: delivery.delivered_at,
+        })
+    }
+    /// Convert this row into a [`WebhookDelivery`].
+    pub fn into_delivery(self) -> Result<WebhookDelivery, MCManageError> {
+        Ok(WebhookDelivery {
+            webhook: self.webhook,
+            event: serde_json::from_str(&self.event)?,
+            success: self.success,
+            attempts: self.attempts.max(0) as u64,
+            status_code: self.status_code.and_then(|status_code| u16::try_from(status_code).ok()),
+            error: self.error,
+            delivered_at: self.delivered_at,
+        })
+    }
+}
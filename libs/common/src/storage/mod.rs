@@ -0,0 +1,201 @@
+//! This module provides the [`ServerStorage`] and [`HistoryStorage`] traits and their implementations, which save the servers of the
+//! [`ServerList`](crate::server_manager::server_list::ServerList), the player history, the chat log and the webhooks to a database.
+
+use async_trait::async_trait;
+use chrono::{
+    DateTime,
+    Utc,
+};
+
+use self::server_change::ServerChange;
+use crate::{
+    chat_log::{
+        chat_message::ChatMessage,
+        chat_query::ChatQuery,
+    },
+    mcmanage_error::MCManageError,
+    player_identities::player_identity::PlayerIdentity,
+    player_sessions::{
+        player_session::PlayerSession,
+        player_statistics::ServerStatistics,
+        session_close_reason::SessionCloseReason,
+    },
+    server_manager::server_item::ServerItem,
+    webhooks::{
+        webhook::Webhook,
+        webhook_delivery::WebhookDelivery,
+    },
+};
+
+pub mod database_backend;
+mod history_row;
+mod postgres_history;
+pub mod postgres_storage;
+pub mod server_change;
+pub mod server_change_kind;
+mod server_row;
+mod sqlite_history;
+pub mod sqlite_storage;
+mod tests;
+
+/// This trait describes a database the servers of the [`ServerList`](crate::server_manager::server_list::ServerList) get saved to. \
+/// Every server gets identified by an id assigned by the database. Server names are unique. \
+/// Every change gets recorded together with a new revision, which is higher than every revision before. Recording the change and applying it
+/// happens at once, so either both or none of them get saved.
+#[async_trait]
+pub trait ServerStorage: Send + Sync {
+    /// Return the id, the name and the [`ServerItem`] of every saved server, ordered by their ids.
+    ///
+    /// # Returns
+    ///
+    /// | Return                               | Description                    |
+    /// |--------------------------------------|--------------------------------|
+    /// | `Ok(Vec<(i64, String, ServerItem)>)` | The servers got read.          |
+    /// | [`MCManageError::InvalidFile`]       | A saved server is invalid.     |
+    /// | `Err(...)`                           | The servers could not be read. |
+    async fn servers(&self) -> Result<Vec<(i64, String, ServerItem)>, MCManageError>;
+    /// Return the name and the [`ServerItem`] of the server with the given id.
+    ///
+    /// # Returns
+    ///
+    /// | Return                         | Description                         |
+    /// |--------------------------------|-------------------------------------|
+    /// | `Ok((String, ServerItem))`     | The server got read.                |
+    /// | [`MCManageError::NotFound`]    | No server with the given id exists. |
+    /// | [`MCManageError::InvalidFile`] | The saved server is invalid.        |
+    /// | `Err(...)`                     | The server could not be read.       |
+    async fn server(&self, id: i64) -> Result<(String, ServerItem), MCManageError>;
+    /// Return the current revision, which is the revision of the last recorded change. Without any change, this is 0.
+    async fn revision(&self) -> Result<i64, MCManageError>;
+    /// Return every change recorded after the given revision, ordered by their revisions.
+    ///
+    /// # Returns
+    ///
+    /// | Return                         | Description                    |
+    /// |--------------------------------|--------------------------------|
+    /// | `Ok(Vec<ServerChange>)`        | The changes got read.          |
+    /// | [`MCManageError::InvalidFile`] | A recorded change is invalid.  |
+    /// | `Err(...)`                     | The changes could not be read. |
+    async fn changes(&self, since: i64) -> Result<Vec<ServerChange>, MCManageError>;
+    /// Save a new server and return its id.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                              |
+    /// |-----------------------------------|----------------------------------------------------------|
+    /// | `Ok(i64)`                         | The server got saved.                                    |
+    /// | [`MCManageError::InvalidRequest`] | A duration of the server is too long to be saved.        |
+    /// | `Err(...)`                        | The server could not be saved. (e.g. its name is in use) |
+    async fn add(&self, name: &str, server_item: &ServerItem) -> Result<i64, MCManageError>;
+    /// Replace the server with the given id.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                              |
+    /// |-----------------------------------|----------------------------------------------------------|
+    /// | `Ok(())`                          | The server got saved.                                    |
+    /// | [`MCManageError::NotFound`]       | No server with the given id exists.                      |
+    /// | [`MCManageError::InvalidRequest`] | A duration of the server is too long to be saved.        |
+    /// | `Err(...)`                        | The server could not be saved. (e.g. its name is in use) |
+    async fn change(&self, id: i64, name: &str, server_item: &ServerItem) -> Result<(), MCManageError>;
+    /// Remove the server with the given id.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                         |
+    /// |-----------------------------|-------------------------------------|
+    /// | `Ok(())`                    | The server got removed.             |
+    /// | [`MCManageError::NotFound`] | No server with the given id exists. |
+    /// | `Err(...)`                  | The server could not be removed.    |
+    async fn remove(&self, id: i64) -> Result<(), MCManageError>;
+}
+
+/// This trait describes a database the [`PlayerIdentities`](crate::player_identities::PlayerIdentities), the
+/// [`PlayerSessions`](crate::player_sessions::PlayerSessions), the [`ChatLog`](crate::chat_log::ChatLog) and the
+/// [`Webhooks`](crate::webhooks::Webhooks) get saved to. \
+/// Every database backend implementing the [`ServerStorage`] trait implements this trait as well, so these features work with every backend.
+#[async_trait]
+pub trait HistoryStorage: Send + Sync {
+    /// Record that the player with the given uuid joined using the given name at the given time and return the name the player used before. \
+    /// The name will be added to the names of the player. The ip will only replace the saved one if it is set.
+    async fn seen_player(
+        &self,
+        uuid: &str,
+        name: &str,
+        ip: Option<&str>,
+        seen_at: DateTime<Utc>,
+    ) -> Result<Option<String>, MCManageError>;
+    /// Return the [`PlayerIdentity`] of the given player. The player can either be given by its uuid or by its current name, which is compared
+    /// case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                                        |
+    /// |-----------------------------|----------------------------------------------------|
+    /// | `Ok(PlayerIdentity)`        | The player got found.                              |
+    /// | [`MCManageError::NotFound`] | No player with the given uuid or name ever joined. |
+    /// | `Err(...)`                  | The database could not be queried.                 |
+    async fn player_identity(&self, player: &str) -> Result<PlayerIdentity, MCManageError>;
+    /// Open a new session for the player with the given uuid and name on the given server.
+    async fn open_session(&self, server: &str, uuid: &str, player: &str, joined_at: DateTime<Utc>) -> Result<(), MCManageError>;
+    /// Close every open session matching the given server and player uuid at the given time and return how many got closed. A filter set to
+    /// `None` matches everything.
+    async fn close_sessions(
+        &self,
+        server: Option<&str>,
+        uuid: Option<&str>,
+        reason: SessionCloseReason,
+        left_at: DateTime<Utc>,
+    ) -> Result<u64, MCManageError>;
+    /// Move every session on the server with the given old name to the server with the given new name and return how many got moved.
+    async fn rename_sessions(&self, old_server: &str, new_server: &str) -> Result<u64, MCManageError>;
+    /// Return the sessions of every player currently online on any server, sorted by the time they joined.
+    async fn online_sessions(&self) -> Result<Vec<PlayerSession>, MCManageError>;
+    /// Return every session on the given server, starting with the latest one.
+    async fn sessions(&self, server: &str) -> Result<Vec<PlayerSession>, MCManageError>;
+    /// Return the uuid and the current name of the player with the given uuid, or of every player if `None` got provided, together with the
+    /// [`ServerStatistics`] of every server the player played on. They are sorted by the uuids and then by the names of the servers. \
+    /// Sessions which are still open count up to the given time.
+    async fn server_statistics(
+        &self,
+        uuid: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<(String, String, ServerStatistics)>, MCManageError>;
+    /// Save the given message and return its id.
+    async fn add_chat_message(&self, chat_message: &ChatMessage) -> Result<i64, MCManageError>;
+    /// Return at most the given number of messages matching the given [`ChatQuery`], starting with the latest one. The limit of the query gets
+    /// ignored.
+    async fn chat_messages(&self, query: &ChatQuery, limit: i64) -> Result<Vec<ChatMessage>, MCManageError>;
+    /// Move every message sent on the server with the given old name to the server with the given new name and return how many got moved.
+    async fn rename_chat_messages(&self, old_server: &str, new_server: &str) -> Result<u64, MCManageError>;
+    /// Delete every message sent before the given time and return how many got deleted.
+    async fn remove_chat_messages(&self, sent_before: DateTime<Utc>) -> Result<u64, MCManageError>;
+    /// Return every saved webhook including its secret, ordered by their ids.
+    async fn webhooks(&self) -> Result<Vec<Webhook>, MCManageError>;
+    /// Save the given webhook and return its id. The id of the given webhook gets ignored.
+    async fn add_webhook(&self, webhook: &Webhook) -> Result<i64, MCManageError>;
+    /// Replace the webhook with the given id. If the secret of the given webhook is not set, the saved secret will be kept.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                          |
+    /// |-----------------------------|--------------------------------------|
+    /// | `Ok(())`                    | The webhook got saved.               |
+    /// | [`MCManageError::NotFound`] | No webhook with the given id exists. |
+    /// | `Err(...)`                  | The webhook could not be saved.      |
+    async fn change_webhook(&self, id: i64, webhook: &Webhook) -> Result<(), MCManageError>;
+    /// Remove the webhook with the given id together with its deliveries.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                          |
+    /// |-----------------------------|--------------------------------------|
+    /// | `Ok(())`                    | The webhook got removed.             |
+    /// | [`MCManageError::NotFound`] | No webhook with the given id exists. |
+    /// | `Err(...)`                  | The webhook could not be removed.    |
+    async fn remove_webhook(&self, id: i64) -> Result<(), MCManageError>;
+    /// Return the deliveries of the webhook with the given id, starting with the latest one.
+    async fn deliveries(&self, webhook: i64) -> Result<Vec<WebhookDelivery>, MCManageError>;
+    /// Save the given delivery and delete the oldest deliveries of its webhook, so only the given number of them is kept.
+    async fn add_delivery(&self, delivery: &WebhookDelivery, kept: i64) -> Result<(), MCManageError>;
+}
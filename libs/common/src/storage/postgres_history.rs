@@ -0,0 +1,326 @@
+//! This module implements the [`HistoryStorage`] trait for the [`PostgresStorage`].
+
+use async_trait::async_trait;
+use chrono::{
+    DateTime,
+    Utc,
+};
+
+use super::{
+    history_row::{
+        ChatMessageRow,
+        DeliveryRow,
+        IdentityRow,
+        SessionRow,
+        StatisticsRow,
+        WebhookRow,
+    },
+    postgres_storage::PostgresStorage,
+    HistoryStorage,
+};
+use crate::{
+    chat_log::{
+        chat_message::ChatMessage,
+        chat_query::ChatQuery,
+    },
+    mcmanage_error::MCManageError,
+    player_identities::player_identity::{
+        PlayerIdentity,
+        PlayerName,
+    },
+    player_sessions::{
+        player_session::PlayerSession,
+        player_statistics::ServerStatistics,
+        session_close_reason::SessionCloseReason,
+    },
+    webhooks::{
+        webhook::Webhook,
+        webhook_delivery::WebhookDelivery,
+    },
+};
+
+#[async_trait]
+impl HistoryStorage for PostgresStorage {
+    async fn seen_player(
+        &self,
+        uuid: &str,
+        name: &str,
+        ip: Option<&str>,
+        seen_at: DateTime<Utc>,
+    ) -> Result<Option<String>, MCManageError> {
+        let mut transaction = self.pool.begin().await?;
+
+        let previous_name: Option<String> = sqlx::query_scalar("SELECT name FROM player_identities WHERE uuid = $1")
+            .bind(uuid)
+            .fetch_optional(&mut transaction)
+            .await?;
+        sqlx::query(
+            "INSERT INTO player_identities (uuid, name, first_seen, last_seen, last_ip) VALUES ($1, $2, $3, $3, $4)
+            ON CONFLICT (uuid) DO UPDATE
+            SET name = EXCLUDED.name, last_seen = EXCLUDED.last_seen, last_ip = COALESCE(EXCLUDED.last_ip, player_identities.last_ip)",
+        )
+        .bind(uuid)
+        .bind(name)
+        .bind(seen_at)
+        .bind(ip)
+        .execute(&mut transaction)
+        .await?;
+        sqlx::query(
+            "INSERT INTO player_names (uuid, name, first_seen, last_seen) VALUES ($1, $2, $3, $3)
+            ON CONFLICT (uuid, name) DO UPDATE SET last_seen = EXCLUDED.last_seen",
+        )
+        .bind(uuid)
+        .bind(name)
+        .bind(seen_at)
+        .execute(&mut transaction)
+        .await?;
+
+        transaction.commit().await?;
+        Ok(previous_name)
+    }
+    async fn player_identity(&self, player: &str) -> Result<PlayerIdentity, MCManageError> {
+        let row = sqlx::query_as::<_, IdentityRow>(
+            "SELECT * FROM player_identities
+            WHERE uuid = LOWER($1) OR LOWER(name) = LOWER($1)
+            ORDER BY uuid = LOWER($1) DESC, last_seen DESC
+            LIMIT 1",
+        )
+        .bind(player)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(MCManageError::NotFound)?;
+
+        let names = sqlx::query_as::<_, PlayerName>(
+            "SELECT name, first_seen, last_seen FROM player_names WHERE uuid = $1 ORDER BY first_seen",
+        )
+        .bind(&row.uuid)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(PlayerIdentity {
+            uuid: row.uuid,
+            name: row.name,
+            first_seen: row.first_seen,
+            last_seen: row.last_seen,
+            last_ip: row.last_ip,
+            names,
+        })
+    }
+    async fn open_session(&self, server: &str, uuid: &str, player: &str, joined_at: DateTime<Utc>) -> Result<(), MCManageError> {
+        sqlx::query("INSERT INTO player_sessions (server, uuid, player, joined_at) VALUES ($1, $2, $3, $4)")
+            .bind(server)
+            .bind(uuid)
+            .bind(player)
+            .bind(joined_at)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+    async fn close_sessions(
+        &self,
+        server: Option<&str>,
+        uuid: Option<&str>,
+        reason: SessionCloseReason,
+        left_at: DateTime<Utc>,
+    ) -> Result<u64, MCManageError> {
+        Ok(sqlx::query(
+            "UPDATE player_sessions SET left_at = $1, close_reason = $2
+            WHERE left_at IS NULL AND ($3::TEXT IS NULL OR server = $3) AND ($4::TEXT IS NULL OR uuid = $4)",
+        )
+        .bind(left_at)
+        .bind(reason.as_str())
+        .bind(server)
+        .bind(uuid)
+        .execute(&self.pool)
+        .await?
+        .rows_affected())
+    }
+    async fn rename_sessions(&self, old_server: &str, new_server: &str) -> Result<u64, MCManageError> {
+        Ok(sqlx::query("UPDATE player_sessions SET server = $2 WHERE server = $1")
+            .bind(old_server)
+            .bind(new_server)
+            .execute(&self.pool)
+            .await?
+            .rows_affected())
+    }
+    async fn online_sessions(&self) -> Result<Vec<PlayerSession>, MCManageError> {
+        sqlx::query_as::<_, SessionRow>("SELECT * FROM player_sessions WHERE left_at IS NULL ORDER BY joined_at")
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(SessionRow::into_session)
+            .collect()
+    }
+    async fn sessions(&self, server: &str) -> Result<Vec<PlayerSession>, MCManageError> {
+        sqlx::query_as::<_, SessionRow>("SELECT * FROM player_sessions WHERE server = $1 ORDER BY joined_at DESC, id DESC")
+            .bind(server)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(SessionRow::into_session)
+            .collect()
+    }
+    async fn server_statistics(
+        &self,
+        uuid: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<(String, String, ServerStatistics)>, MCManageError> {
+        let rows = sqlx::query_as::<_, StatisticsRow>(
+            "SELECT
+                sessions.uuid,
+                COALESCE(MAX(player_identities.name), MAX(sessions.player)) AS player,
+                sessions.server,
+                COUNT(*) AS sessions,
+                MIN(sessions.joined_at) AS first_seen,
+                MAX(COALESCE(sessions.left_at, $2)) AS last_seen,
+                CAST(EXTRACT(EPOCH FROM SUM(COALESCE(sessions.left_at, $2) - sessions.joined_at)) AS BIGINT) AS playtime,
+                BOOL_OR(sessions.left_at IS NULL) AS online
+            FROM player_sessions sessions
+            LEFT JOIN player_identities ON player_identities.uuid = sessions.uuid
+            WHERE $1::TEXT IS NULL OR sessions.uuid = $1
+            GROUP BY sessions.uuid, sessions.server
+            ORDER BY sessions.uuid, sessions.server",
+        )
+        .bind(uuid)
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(StatisticsRow::into_statistics).collect())
+    }
+    async fn add_chat_message(&self, chat_message: &ChatMessage) -> Result<i64, MCManageError> {
+        Ok(sqlx::query_scalar(
+            "INSERT INTO chat_messages (server, uuid, sender, message, sent_at) VALUES ($1, $2, $3, $4, $5) RETURNING id",
+        )
+        .bind(&chat_message.server)
+        .bind(&chat_message.uuid)
+        .bind(&chat_message.sender)
+        .bind(&chat_message.message)
+        .bind(chat_message.sent_at)
+        .fetch_one(&self.pool)
+        .await?)
+    }
+    async fn chat_messages(&self, query: &ChatQuery, limit: i64) -> Result<Vec<ChatMessage>, MCManageError> {
+        let rows = sqlx::query_as::<_, ChatMessageRow>(
+            "SELECT * FROM chat_messages
+            WHERE ($1::TEXT IS NULL OR server = $1)
+                AND ($2::TEXT IS NULL OR LOWER(sender) = LOWER($2) OR uuid = LOWER($2))
+                AND ($3::TEXT IS NULL OR STRPOS(LOWER(message), LOWER($3)) > 0)
+                AND ($4::TIMESTAMPTZ IS NULL OR sent_at >= $4)
+                AND ($5::TIMESTAMPTZ IS NULL OR sent_at < $5)
+            ORDER BY sent_at DESC, id DESC
+            LIMIT $6",
+        )
+        .bind(&query.server)
+        .bind(&query.sender)
+        .bind(&query.text)
+        .bind(query.since)
+        .bind(query.until)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(ChatMessage::from).collect())
+    }
+    async fn rename_chat_messages(&self, old_server: &str, new_server: &str) -> Result<u64, MCManageError> {
+        Ok(sqlx::query("UPDATE chat_messages SET server = $2 WHERE server = $1")
+            .bind(old_server)
+            .bind(new_server)
+            .execute(&self.pool)
+            .await?
+            .rows_affected())
+    }
+    async fn remove_chat_messages(&self, sent_before: DateTime<Utc>) -> Result<u64, MCManageError> {
+        Ok(sqlx::query("DELETE FROM chat_messages WHERE sent_at < $1")
+            .bind(sent_before)
+            .execute(&self.pool)
+            .await?
+            .rows_affected())
+    }
+    async fn webhooks(&self) -> Result<Vec<Webhook>, MCManageError> {
+        sqlx::query_as::<_, WebhookRow>("SELECT * FROM webhooks ORDER BY id")
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(WebhookRow::into_webhook)
+            .collect()
+    }
+    async fn add_webhook(&self, webhook: &Webhook) -> Result<i64, MCManageError> {
+        let row = WebhookRow::new(webhook)?;
+        Ok(sqlx::query_scalar(
+            "INSERT INTO webhooks (name, url, filter, secret, template, enabled) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id",
+        )
+        .bind(row.name)
+        .bind(row.url)
+        .bind(row.filter)
+        .bind(row.secret)
+        .bind(row.template)
+        .bind(row.enabled)
+        .fetch_one(&self.pool)
+        .await?)
+    }
+    async fn change_webhook(&self, id: i64, webhook: &Webhook) -> Result<(), MCManageError> {
+        let row = WebhookRow::new(webhook)?;
+        let changed = sqlx::query(
+            "UPDATE webhooks SET name = $2, url = $3, filter = $4, secret = COALESCE($5, secret), template = $6, enabled = $7 WHERE id = $1",
+        )
+        .bind(id)
+        .bind(row.name)
+        .bind(row.url)
+        .bind(row.filter)
+        .bind(row.secret)
+        .bind(row.template)
+        .bind(row.enabled)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+        if changed == 0 {
+            return Err(MCManageError::NotFound);
+        }
+        Ok(())
+    }
+    async fn remove_webhook(&self, id: i64) -> Result<(), MCManageError> {
+        let removed = sqlx::query("DELETE FROM webhooks WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?
+            .rows_affected();
+        if removed == 0 {
+            return Err(MCManageError::NotFound);
+        }
+        Ok(())
+    }
+    async fn deliveries(&self, webhook: i64) -> Result<Vec<WebhookDelivery>, MCManageError> {
+        sqlx::query_as::<_, DeliveryRow>("SELECT * FROM webhook_deliveries WHERE webhook = $1 ORDER BY id DESC")
+            .bind(webhook)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(DeliveryRow::into_delivery)
+            .collect()
+    }
+    async fn add_delivery(&self, delivery: &WebhookDelivery, kept: i64) -> Result<(), MCManageError> {
+        let row = DeliveryRow::new(delivery)?;
+        sqlx::query(
+            "INSERT INTO webhook_deliveries (webhook, event, success, attempts, status_code, error, delivered_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(row.webhook)
+        .bind(row.event)
+        .bind(row.success)
+        .bind(row.attempts)
+        .bind(row.status_code)
+        .bind(row.error)
+        .bind(row.delivered_at)
+        .execute(&self.pool)
+        .await?;
+        sqlx::query(
+            "DELETE FROM webhook_deliveries WHERE webhook = $1 AND id NOT IN (
+                SELECT id FROM webhook_deliveries WHERE webhook = $1 ORDER BY id DESC LIMIT $2
+            )",
+        )
+        .bind(delivery.webhook)
+        .bind(kept)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
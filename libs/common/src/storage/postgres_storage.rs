@@ -0,0 +1,134 @@
+//! This module provides the [`PostgresStorage`] struct.
+
+use async_trait::async_trait;
+use sqlx::{
+    migrate::Migrator,
+    Pool,
+    Postgres,
+    Transaction,
+};
+
+use super::{
+    server_change::ServerChange,
+    server_change_kind::ServerChangeKind,
+    server_row::{
+        bind_server_row,
+        ServerChangeRow,
+        ServerRow,
+    },
+    ServerStorage,
+};
+use crate::{
+    mcmanage_error::MCManageError,
+    server_manager::server_item::ServerItem,
+};
+
+/// The migrations creating the tables of the `server_list` database. They get embedded into the binary.
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations/postgres");
+
+/// This struct saves the servers to the `servers` table of a PostgreSQL database. \
+/// Usually, this is the `server_list` database of the database server started by the [`init_db_server`](crate::database_server::init_db_server)
+/// function. \
+/// It also saves the player history, the chat log and the webhooks. See the [`HistoryStorage`](super::HistoryStorage) trait.
+#[derive(Clone)]
+pub struct PostgresStorage {
+    /// The pool of the database the servers get saved to
+    pub(super) pool: Pool<Postgres>,
+}
+impl PostgresStorage {
+    /// Apply every missing migration to the given database and create a new [`PostgresStorage`] using it.
+    pub async fn new(pool: Pool<Postgres>) -> Result<Self, MCManageError> {
+        MIGRATOR.run(&pool).await.map_err(sqlx::Error::from)?;
+        Ok(Self {
+            pool,
+        })
+    }
+}
+// internal
+impl PostgresStorage {
+    /// Record a change of the given server in the given transaction.
+    async fn record(
+        transaction: &mut Transaction<'_, Postgres>,
+        server: i64,
+        name: &str,
+        kind: ServerChangeKind,
+    ) -> Result<(), MCManageError> {
+        sqlx::query("INSERT INTO server_changes (server, name, change) VALUES ($1, $2, $3)")
+            .bind(server)
+            .bind(name.to_string())
+            .bind(ServerChangeRow::change(kind)?)
+            .execute(transaction)
+            .await?;
+        Ok(())
+    }
+}
+#[async_trait]
+impl ServerStorage for PostgresStorage {
+    async fn servers(&self) -> Result<Vec<(i64, String, ServerItem)>, MCManageError> {
+        let rows: Vec<ServerRow> = sqlx::query_as("SELECT * FROM servers ORDER BY id").fetch_all(&self.pool).await?;
+        rows.into_iter().map(ServerRow::into_server).collect()
+    }
+    async fn server(&self, id: i64) -> Result<(String, ServerItem), MCManageError> {
+        let row: ServerRow = sqlx::query_as("SELECT * FROM servers WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(MCManageError::NotFound)?;
+        row.into_server().map(|(_, name, server_item)| (name, server_item))
+    }
+    async fn revision(&self) -> Result<i64, MCManageError> {
+        Ok(sqlx::query_scalar("SELECT COALESCE(MAX(revision), 0) FROM server_changes")
+            .fetch_one(&self.pool)
+            .await?)
+    }
+    async fn changes(&self, since: i64) -> Result<Vec<ServerChange>, MCManageError> {
+        let rows: Vec<ServerChangeRow> = sqlx::query_as("SELECT * FROM server_changes WHERE revision > $1 ORDER BY revision")
+            .bind(since)
+            .fetch_all(&self.pool)
+            .await?;
+        rows.into_iter().map(ServerChangeRow::into_change).collect()
+    }
+    async fn add(&self, name: &str, server_item: &ServerItem) -> Result<i64, MCManageError> {
+        let mut transaction = self.pool.begin().await?;
+        let query = sqlx::query_scalar(
+            "INSERT INTO servers (name, args, download_from, version, build, update_policy, server_type, restart_time, eula_accepted_by, network, \
+             network_role, kind, depends_on, start_delay) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14) RETURNING id",
+        );
+        let id = bind_server_row!(query, ServerRow::new(name, server_item)?)
+            .fetch_one(&mut transaction)
+            .await?;
+        Self::record(&mut transaction, id, name, ServerChangeKind::Added).await?;
+        transaction.commit().await?;
+        Ok(id)
+    }
+    async fn change(&self, id: i64, name: &str, server_item: &ServerItem) -> Result<(), MCManageError> {
+        let mut transaction = self.pool.begin().await?;
+        let query = sqlx::query(
+            "UPDATE servers SET name = $1, args = $2, download_from = $3, version = $4, build = $5, update_policy = $6, server_type = $7, \
+             restart_time = $8, eula_accepted_by = $9, network = $10, network_role = $11, kind = $12, depends_on = $13, start_delay = $14 \
+             WHERE id = $15",
+        );
+        let changed = bind_server_row!(query, ServerRow::new(name, server_item)?)
+            .bind(id)
+            .execute(&mut transaction)
+            .await?
+            .rows_affected();
+        if changed == 0 {
+            return Err(MCManageError::NotFound);
+        }
+        Self::record(&mut transaction, id, name, ServerChangeKind::Modified).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn remove(&self, id: i64) -> Result<(), MCManageError> {
+        let mut transaction = self.pool.begin().await?;
+        let name: String = sqlx::query_scalar("DELETE FROM servers WHERE id = $1 RETURNING name")
+            .bind(id)
+            .fetch_optional(&mut transaction)
+            .await?
+            .ok_or(MCManageError::NotFound)?;
+        Self::record(&mut transaction, id, &name, ServerChangeKind::Removed).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+//! This module provides the [`ServerChange`] struct.
+
+use proc_macros::add_convert;
+
+use super::server_change_kind::ServerChangeKind;
+use crate::mcmanage_error::MCManageError;
+
+/// This struct represents a single change recorded by a [`ServerStorage`](super::ServerStorage). \
+/// Every change increases the revision of the [`ServerList`](crate::server_manager::server_list::ServerList) by at least one.
+#[add_convert]
+pub struct ServerChange {
+    /// The revision of the server list created by this change
+    pub revision: i64,
+    /// The id of the changed server
+    pub server: i64,
+    /// The name of the server after this change. For removed servers, this is the name it had.
+    pub name: String,
+    /// How the server got changed
+    pub kind: ServerChangeKind,
+}
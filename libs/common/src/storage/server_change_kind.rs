@@ -0,0 +1,18 @@
+//! This module provides the [`ServerChangeKind`] enum.
+
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This enum describes how a server in the [`ServerList`](crate::server_manager::server_list::ServerList) got changed.
+#[add_convert]
+#[derive(Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ServerChangeKind {
+    /// The server got added.
+    Added,
+    /// The server got changed. This includes renaming it.
+    Modified,
+    /// The server got removed.
+    Removed,
+}
@@ -0,0 +1,170 @@
+//! This module provides the [`ServerRow`] and [`ServerChangeRow`] structs, which represent the rows of the `servers` and `server_changes` tables.
+
+use std::time::Duration;
+
+use sqlx::FromRow;
+
+use super::{
+    server_change::ServerChange,
+    server_change_kind::ServerChangeKind,
+};
+use crate::{
+    mcmanage_error::MCManageError,
+    server_manager::server_item::ServerItem,
+};
+
+/// Bind every column of the given [`ServerRow`] except its id to the given query, in the order of the columns of the `servers` table. \
+/// This works for the queries of every database backend.
+macro_rules! bind_server_row {
+    ($query:expr, $row:expr) => {{
+        let row = $row;
+        $query
+            .bind(row.name)
+            .bind(row.args)
+            .bind(row.download_from)
+            .bind(row.version)
+            .bind(row.build)
+            .bind(row.update_policy)
+            .bind(row.server_type)
+            .bind(row.restart_time)
+            .bind(row.eula_accepted_by)
+            .bind(row.network)
+            .bind(row.network_role)
+            .bind(row.kind)
+            .bind(row.depends_on)
+            .bind(row.start_delay)
+    }};
+}
+pub(super) use bind_server_row;
+
+/// This struct represents a row of the `servers` table. \
+/// The update_policy, network_role, kind and depends_on columns are saved as json, while durations are saved in seconds.
+#[derive(FromRow)]
+pub(super) struct ServerRow {
+    pub id: i64,
+    pub name: String,
+    pub args: String,
+    pub download_from: String,
+    pub version: Option<String>,
+    pub build: Option<String>,
+    pub update_policy: String,
+    pub server_type: String,
+    pub restart_time: i64,
+    pub eula_accepted_by: Option<String>,
+    pub network: Option<String>,
+    pub network_role: Option<String>,
+    pub kind: String,
+    pub depends_on: String,
+    pub start_delay: i64,
+}
+impl ServerRow {
+    /// Create the row of the given server. Its id will be assigned by the database.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                         |
+    /// |-----------------------------------|-------------------------------------|
+    /// | `Ok(ServerRow)`                   | The row got created.                |
+    /// | [`MCManageError::InvalidRequest`] | A duration is too long to be saved. |
+    pub fn new(name: &str, server_item: &ServerItem) -> Result<Self, MCManageError> {
+        Ok(Self {
+            id: 0,
+            name: name.to_string(),
+            args: server_item.args.clone(),
+            download_from: server_item.download_from.clone(),
+            version: server_item.version.clone(),
+            build: server_item.build.clone(),
+            update_policy: serde_json::to_string(&server_item.update_policy)?,
+            server_type: server_item.server_type.clone(),
+            restart_time: Self::to_secs(server_item.restart_time)?,
+            eula_accepted_by: server_item.eula_accepted_by.clone(),
+            network: server_item.network.clone(),
+            network_role: match &server_item.network_role {
+                Some(network_role) => Some(serde_json::to_string(network_role)?),
+                None => None,
+            },
+            kind: serde_json::to_string(&server_item.kind)?,
+            depends_on: serde_json::to_string(&server_item.depends_on)?,
+            start_delay: Self::to_secs(server_item.start_delay)?,
+        })
+    }
+    /// Convert this row into the id, the name and the [`ServerItem`] of the server it represents.
+    ///
+    /// # Returns
+    ///
+    /// | Return                          | Description                        |
+    /// |---------------------------------|------------------------------------|
+    /// | `Ok((i64, String, ServerItem))` | The row got converted.             |
+    /// | [`MCManageError::InvalidFile`]  | The row contains an invalid value. |
+    pub fn into_server(self) -> Result<(i64, String, ServerItem), MCManageError> {
+        let server_item = ServerItem {
+            args: self.args,
+            download_from: self.download_from,
+            version: self.version,
+            build: self.build,
+            update_policy: Self::from_json(&self.update_policy)?,
+            server_type: self.server_type,
+            restart_time: Self::from_secs(self.restart_time)?,
+            eula_accepted_by: self.eula_accepted_by,
+            network: self.network,
+            network_role: match &self.network_role {
+                Some(network_role) => Some(Self::from_json(network_role)?),
+                None => None,
+            },
+            kind: Self::from_json(&self.kind)?,
+            depends_on: Self::from_json(&self.depends_on)?,
+            start_delay: Self::from_secs(self.start_delay)?,
+        };
+        Ok((self.id, self.name, server_item))
+    }
+}
+// internal
+impl ServerRow {
+    /// Read a value saved as json.
+    fn from_json<T: serde::de::DeserializeOwned>(value: &str) -> Result<T, MCManageError> {
+        serde_json::from_str(value).map_err(|_| MCManageError::InvalidFile)
+    }
+    /// Read a duration saved in seconds.
+    fn from_secs(secs: i64) -> Result<Duration, MCManageError> {
+        u64::try_from(secs)
+            .map(Duration::from_secs)
+            .map_err(|_| MCManageError::InvalidFile)
+    }
+    /// Convert the given duration into the seconds saved in the database.
+    fn to_secs(duration: Duration) -> Result<i64, MCManageError> {
+        i64::try_from(duration.as_secs()).map_err(|_| {
+            MCManageError::InvalidRequest(format!("The duration of {} seconds is too long.", duration.as_secs()))
+        })
+    }
+}
+
+/// This struct represents a row of the `server_changes` table. The change column is saved as json.
+#[derive(FromRow)]
+pub(super) struct ServerChangeRow {
+    pub revision: i64,
+    pub server: i64,
+    pub name: String,
+    pub change: String,
+}
+impl ServerChangeRow {
+    /// Serialize the given [`ServerChangeKind`] the way it gets saved in the change column.
+    pub fn change(kind: ServerChangeKind) -> Result<String, MCManageError> {
+        Ok(serde_json::to_string(&kind)?)
+    }
+    /// Convert this row into the [`ServerChange`] it represents.
+    ///
+    /// # Returns
+    ///
+    /// | Return                         | Description                   |
+    /// |--------------------------------|-------------------------------|
+    /// | `Ok(ServerChange)`             | The row got converted.        |
+    /// | [`MCManageError::InvalidFile`] | The change column is invalid. |
+    pub fn into_change(self) -> Result<ServerChange, MCManageError> {
+        Ok(ServerChange {
+            revision: self.revision,
+            server: self.server,
+            name: self.name,
+            kind: serde_json::from_str(&self.change).map_err(|_| MCManageError::InvalidFile)?,
+        })
+    }
+}
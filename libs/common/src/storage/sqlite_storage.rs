@@ -0,0 +1,164 @@
+//! This module provides the [`SqliteStorage`] struct.
+
+use std::{
+    path::Path,
+    str::FromStr,
+};
+
+use async_trait::async_trait;
+use sqlx::{
+    migrate::Migrator,
+    sqlite::{
+        SqliteConnectOptions,
+        SqlitePoolOptions,
+    },
+    Pool,
+    Sqlite,
+    Transaction,
+};
+
+use super::{
+    server_change::ServerChange,
+    server_change_kind::ServerChangeKind,
+    server_row::{
+        bind_server_row,
+        ServerChangeRow,
+        ServerRow,
+    },
+    ServerStorage,
+};
+use crate::{
+    mcmanage_error::MCManageError,
+    server_manager::server_item::ServerItem,
+};
+
+/// The migrations creating the tables of the SQLite database. They get embedded into the binary.
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations/sqlite");
+
+/// This struct saves the servers to the `servers` table of a SQLite database. \
+/// Unlike the [`PostgresStorage`](super::postgres_storage::PostgresStorage), it needs no database server, which makes it a good fit for small
+/// installs. \
+/// It also saves the player history, the chat log and the webhooks. See the [`HistoryStorage`](super::HistoryStorage) trait.
+#[derive(Clone)]
+pub struct SqliteStorage {
+    /// The pool of the database the servers get saved to
+    pub(super) pool: Pool<Sqlite>,
+}
+impl SqliteStorage {
+    /// Open the SQLite database at the given path, apply every missing migration and create a new [`SqliteStorage`] using it. \
+    /// If the database does not exist yet, it will be created.
+    pub async fn open(path: &Path) -> Result<Self, MCManageError> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(5)
+            .connect_with(SqliteConnectOptions::new().filename(path).create_if_missing(true))
+            .await?;
+        Self::new(pool).await
+    }
+    /// Create a new [`SqliteStorage`] using a database which only exists in memory. Everything saved to it gets lost once it gets dropped.
+    pub async fn in_memory() -> Result<Self, MCManageError> {
+        // every connection to an in-memory database opens a new database, so the pool has to keep exactly one connection open
+        let pool = SqlitePoolOptions::new()
+            .min_connections(1)
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(SqliteConnectOptions::from_str("sqlite::memory:")?)
+            .await?;
+        Self::new(pool).await
+    }
+}
+// internal
+impl SqliteStorage {
+    /// Apply every missing migration to the given database and create a new [`SqliteStorage`] using it.
+    async fn new(pool: Pool<Sqlite>) -> Result<Self, MCManageError> {
+        MIGRATOR.run(&pool).await.map_err(sqlx::Error::from)?;
+        Ok(Self {
+            pool,
+        })
+    }
+    /// Record a change of the given server in the given transaction.
+    async fn record(
+        transaction: &mut Transaction<'_, Sqlite>,
+        server: i64,
+        name: &str,
+        kind: ServerChangeKind,
+    ) -> Result<(), MCManageError> {
+        sqlx::query("INSERT INTO server_changes (server, name, change) VALUES (?, ?, ?)")
+            .bind(server)
+            .bind(name.to_string())
+            .bind(ServerChangeRow::change(kind)?)
+            .execute(transaction)
+            .await?;
+        Ok(())
+    }
+}
+#[async_trait]
+impl ServerStorage for SqliteStorage {
+    async fn servers(&self) -> Result<Vec<(i64, String, ServerItem)>, MCManageError> {
+        let rows: Vec<ServerRow> = sqlx::query_as("SELECT * FROM servers ORDER BY id").fetch_all(&self.pool).await?;
+        rows.into_iter().map(ServerRow::into_server).collect()
+    }
+    async fn server(&self, id: i64) -> Result<(String, ServerItem), MCManageError> {
+        let row: ServerRow = sqlx::query_as("SELECT * FROM servers WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(MCManageError::NotFound)?;
+        row.into_server().map(|(_, name, server_item)| (name, server_item))
+    }
+    async fn revision(&self) -> Result<i64, MCManageError> {
+        Ok(sqlx::query_scalar("SELECT COALESCE(MAX(revision), 0) FROM server_changes")
+            .fetch_one(&self.pool)
+            .await?)
+    }
+    async fn changes(&self, since: i64) -> Result<Vec<ServerChange>, MCManageError> {
+        let rows: Vec<ServerChangeRow> = sqlx::query_as("SELECT * FROM server_changes WHERE revision > ? ORDER BY revision")
+            .bind(since)
+            .fetch_all(&self.pool)
+            .await?;
+        rows.into_iter().map(ServerChangeRow::into_change).collect()
+    }
+    async fn add(&self, name: &str, server_item: &ServerItem) -> Result<i64, MCManageError> {
+        let mut transaction = self.pool.begin().await?;
+        let query = sqlx::query_scalar(
+            "INSERT INTO servers (name, args, download_from, version, build, update_policy, server_type, restart_time, eula_accepted_by, network, \
+             network_role, kind, depends_on, start_delay) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) RETURNING id",
+        );
+        let id = bind_server_row!(query, ServerRow::new(name, server_item)?)
+            .fetch_one(&mut transaction)
+            .await?;
+        Self::record(&mut transaction, id, name, ServerChangeKind::Added).await?;
+        transaction.commit().await?;
+        Ok(id)
+    }
+    async fn change(&self, id: i64, name: &str, server_item: &ServerItem) -> Result<(), MCManageError> {
+        let mut transaction = self.pool.begin().await?;
+        let query = sqlx::query(
+            "UPDATE servers SET name = ?, args = ?, download_from = ?, version = ?, build = ?, update_policy = ?, server_type = ?, \
+             restart_time = ?, eula_accepted_by = ?, network = ?, network_role = ?, kind = ?, depends_on = ?, start_delay = ? \
+             WHERE id = ?",
+        );
+        let changed = bind_server_row!(query, ServerRow::new(name, server_item)?)
+            .bind(id)
+            .execute(&mut transaction)
+            .await?
+            .rows_affected();
+        if changed == 0 {
+            return Err(MCManageError::NotFound);
+        }
+        Self::record(&mut transaction, id, name, ServerChangeKind::Modified).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+    async fn remove(&self, id: i64) -> Result<(), MCManageError> {
+        let mut transaction = self.pool.begin().await?;
+        let name: String = sqlx::query_scalar("DELETE FROM servers WHERE id = ? RETURNING name")
+            .bind(id)
+            .fetch_optional(&mut transaction)
+            .await?
+            .ok_or(MCManageError::NotFound)?;
+        Self::record(&mut transaction, id, &name, ServerChangeKind::Removed).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+}
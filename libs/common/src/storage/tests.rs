@@ -0,0 +1,464 @@
+#![cfg(test)]
+
+use std::time::Duration;
+
+use chrono::TimeZone;
+
+use super::{
+    postgres_storage::PostgresStorage,
+    server_change_kind::ServerChangeKind,
+    sqlite_storage::SqliteStorage,
+    *,
+};
+use crate::{
+    event_bus::{
+        event::Event,
+        event_filter::EventFilter,
+        server_event::ServerEvent,
+    },
+    server_manager::server::{
+        network_role::NetworkRole,
+        server_kind::ServerKind,
+        update_policy::UpdatePolicy,
+    },
+    test_functions::postgres_pool,
+    webhooks::webhook_template::WebhookTemplate,
+};
+
+/// The uuid of the player `Notch`
+const NOTCH: &str = "069a79f4-44e9-4726-a5be-fca90e38aaf5";
+/// The uuid of the player `jeb_`
+const JEB: &str = "853c80ef-3c37-49fd-aa49-938b674adae6";
+
+/// Create a [`ServerItem`] with every field set.
+fn server_item() -> ServerItem {
+    ServerItem {
+        args: "-jar purpur-1.19.3-1876.jar nogui".to_string(),
+        download_from: "https://api.purpurmc.org/v2/purpur/1.19.3/1876/download".to_string(),
+        version: Some("1.19.3".to_string()),
+        build: Some("1876".to_string()),
+        update_policy: UpdatePolicy::Apply,
+        server_type: "purpur".to_string(),
+        restart_time: Duration::from_secs(86400),
+        eula_accepted_by: Some("Jane Doe".to_string()),
+        network: Some("myNetwork".to_string()),
+        network_role: Some(NetworkRole::Backend),
+        kind: ServerKind::Java,
+        depends_on: vec!["auth".to_string()],
+        start_delay: Duration::from_secs(10),
+    }
+}
+
+/// Run every test against the given [`ServerStorage`]. The storage has to be empty.
+async fn storage_suite(storage: &dyn ServerStorage) {
+    assert_eq!(storage.servers().await.unwrap(), vec![]);
+    assert_eq!(storage.revision().await.unwrap(), 0);
+
+    let first_id = storage.add("myFirstServer", &server_item()).await.unwrap();
+    let mut bedrock_item = server_item();
+    bedrock_item.kind = ServerKind::Bedrock;
+    bedrock_item.network_role = None;
+    bedrock_item.depends_on = vec![];
+    let second_id = storage.add("mySecondServer", &bedrock_item).await.unwrap();
+    assert!(second_id > first_id);
+    // names are unique
+    assert!(storage.add("myFirstServer", &server_item()).await.is_err());
+
+    assert_eq!(storage.server(first_id).await.unwrap(), ("myFirstServer".to_string(), server_item()));
+    assert_eq!(
+        storage.servers().await.unwrap(),
+        vec![
+            (first_id, "myFirstServer".to_string(), server_item()),
+            (second_id, "mySecondServer".to_string(), bedrock_item.clone()),
+        ]
+    );
+
+    bedrock_item.start_delay = Duration::ZERO;
+    storage.change(second_id, "myRenamedServer", &bedrock_item).await.unwrap();
+    assert_eq!(storage.server(second_id).await.unwrap(), ("myRenamedServer".to_string(), bedrock_item.clone()));
+    assert!(matches!(
+        storage.change(second_id + 1, "myThirdServer", &bedrock_item).await,
+        Err(MCManageError::NotFound)
+    ));
+
+    let mut invalid_item = server_item();
+    invalid_item.restart_time = Duration::MAX;
+    assert!(matches!(
+        storage.add("myThirdServer", &invalid_item).await,
+        Err(MCManageError::InvalidRequest(_))
+    ));
+
+    storage.remove(first_id).await.unwrap();
+    assert!(matches!(storage.remove(first_id).await, Err(MCManageError::NotFound)));
+    assert!(matches!(storage.server(first_id).await, Err(MCManageError::NotFound)));
+    assert_eq!(storage.servers().await.unwrap().len(), 1);
+
+    // every change got recorded, while failed changes were not
+    let changes = storage.changes(0).await.unwrap();
+    assert_eq!(
+        changes
+            .iter()
+            .map(|change| (change.server, change.name.as_str(), change.kind))
+            .collect::<Vec<_>>(),
+        vec![
+            (first_id, "myFirstServer", ServerChangeKind::Added),
+            (second_id, "mySecondServer", ServerChangeKind::Added),
+            (second_id, "myRenamedServer", ServerChangeKind::Modified),
+            (first_id, "myFirstServer", ServerChangeKind::Removed),
+        ]
+    );
+    assert!(changes.windows(2).all(|changes| changes[0].revision < changes[1].revision));
+    let revision = storage.revision().await.unwrap();
+    assert_eq!(revision, changes[3].revision);
+    assert_eq!(storage.changes(changes[1].revision).await.unwrap(), changes[2..].to_vec());
+    assert_eq!(storage.changes(revision).await.unwrap(), vec![]);
+}
+
+/// Return the given number of minutes after the start of 2024. Whole seconds are used, since PostgreSQL only saves microseconds.
+fn at(minutes: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap() + chrono::Duration::minutes(minutes)
+}
+/// Return a webhook with the given name.
+fn webhook(name: &str) -> Webhook {
+    Webhook {
+        id: 0,
+        name: name.to_string(),
+        url: "https://example.com/hook".to_string(),
+        filter: EventFilter::default(),
+        secret: Some("mySecret".to_string()),
+        template: WebhookTemplate::Json,
+        enabled: true,
+    }
+}
+
+/// Run every test against the given [`HistoryStorage`]. The storage has to be empty.
+async fn history_suite(storage: &dyn HistoryStorage) {
+    sessions_suite(storage).await;
+    chat_suite(storage).await;
+    webhooks_suite(storage).await;
+}
+/// Open and close sessions and calculate their statistics.
+async fn sessions_suite(storage: &dyn HistoryStorage) {
+    storage.open_session("myLobby", NOTCH, "Notch", at(0)).await.unwrap();
+    storage.open_session("myLobby", JEB, "jeb_", at(10)).await.unwrap();
+    storage.open_session("mySurvival", NOTCH, "Notch", at(20)).await.unwrap();
+    let online = storage.online_sessions().await.unwrap();
+    assert_eq!(
+        online
+            .iter()
+            .map(|session| (session.server.as_str(), session.uuid.as_str()))
+            .collect::<Vec<_>>(),
+        vec![("myLobby", NOTCH), ("myLobby", JEB), ("mySurvival", NOTCH)]
+    );
+
+    // filters
+    assert_eq!(
+        storage
+            .close_sessions(Some("myLobby"), Some(NOTCH), SessionCloseReason::Left, at(60))
+            .await
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        storage
+            .close_sessions(Some("myLobby"), None, SessionCloseReason::Crashed, at(70))
+            .await
+            .unwrap(),
+        1
+    );
+    // closed sessions stay closed
+    assert_eq!(
+        storage
+            .close_sessions(Some("myLobby"), None, SessionCloseReason::Stopped, at(80))
+            .await
+            .unwrap(),
+        0
+    );
+
+    let sessions = storage.sessions("myLobby").await.unwrap();
+    assert_eq!(sessions.len(), 2);
+    assert_eq!((sessions[0].uuid.as_str(), sessions[0].left_at), (JEB, Some(at(70))));
+    assert_eq!(sessions[0].close_reason, Some(SessionCloseReason::Crashed));
+    assert_eq!((sessions[1].uuid.as_str(), sessions[1].joined_at), (NOTCH, at(0)));
+    assert_eq!(sessions[1].close_reason, Some(SessionCloseReason::Left));
+
+    // the open session on the survival server counts up to the given time
+    storage.open_session("myLobby", NOTCH, "Notch", at(90)).await.unwrap();
+    storage
+        .close_sessions(Some("myLobby"), Some(NOTCH), SessionCloseReason::Left, at(100))
+        .await
+        .unwrap();
+    assert_eq!(
+        storage.server_statistics(Some(NOTCH), at(120)).await.unwrap(),
+        vec![
+            (
+                NOTCH.to_string(),
+                "Notch".to_string(),
+                ServerStatistics {
+                    server: "myLobby".to_string(),
+                    sessions: 2,
+                    first_seen: at(0),
+                    last_seen: at(100),
+                    playtime: Duration::from_secs(70 * 60),
+                    online: false,
+                }
+            ),
+            (
+                NOTCH.to_string(),
+                "Notch".to_string(),
+                ServerStatistics {
+                    server: "mySurvival".to_string(),
+                    sessions: 1,
+                    first_seen: at(20),
+                    last_seen: at(120),
+                    playtime: Duration::from_secs(100 * 60),
+                    online: true,
+                }
+            ),
+        ]
+    );
+    // the current name of a player comes from its identity
+    storage.seen_player(JEB, "jeb", None, at(130)).await.unwrap();
+    let statistics = storage.server_statistics(None, at(140)).await.unwrap();
+    assert_eq!(
+        statistics
+            .iter()
+            .map(|(uuid, player, server_statistics)| (uuid.as_str(), player.as_str(), server_statistics.server.as_str()))
+            .collect::<Vec<_>>(),
+        vec![(NOTCH, "Notch", "myLobby"), (NOTCH, "Notch", "mySurvival"), (JEB, "jeb", "myLobby")]
+    );
+
+    // renaming a server moves its open and closed sessions
+    assert_eq!(storage.rename_sessions("myLobby", "myHub").await.unwrap(), 3);
+    assert!(storage.sessions("myLobby").await.unwrap().is_empty());
+    assert_eq!(storage.sessions("myHub").await.unwrap().len(), 3);
+    assert_eq!(storage.rename_sessions("myLobby", "myHub").await.unwrap(), 0);
+
+    storage
+        .close_sessions(None, None, SessionCloseReason::Interrupted, at(150))
+        .await
+        .unwrap();
+    assert!(storage.online_sessions().await.unwrap().is_empty());
+}
+/// Save, search and delete chat messages.
+async fn chat_suite(storage: &dyn HistoryStorage) {
+    let chat_message = |server: &str, uuid: Option<&str>, sender: &str, message: &str, sent_at| ChatMessage {
+        id: None,
+        server: server.to_string(),
+        uuid: uuid.map(str::to_string),
+        sender: sender.to_string(),
+        message: message.to_string(),
+        sent_at,
+    };
+    let first_id = storage
+        .add_chat_message(&chat_message("myLobby", Some(NOTCH), "Notch", "Hello World!", at(0)))
+        .await
+        .unwrap();
+    storage
+        .add_chat_message(&chat_message("myLobby", None, "jeb_", "hello there", at(1)))
+        .await
+        .unwrap();
+    storage
+        .add_chat_message(&chat_message("mySurvival", Some(NOTCH), "Notch", "Bye", at(2)))
+        .await
+        .unwrap();
+    let search = |query: ChatQuery, limit| async move {
+        storage
+            .chat_messages(&query, limit)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|chat_message| chat_message.message)
+            .collect::<Vec<String>>()
+    };
+
+    // the latest message comes first
+    let found = storage.chat_messages(&ChatQuery::default(), 100).await.unwrap();
+    assert_eq!(
+        found[2],
+        ChatMessage {
+            id: Some(first_id),
+            ..chat_message("myLobby", Some(NOTCH), "Notch", "Hello World!", at(0))
+        }
+    );
+    assert_eq!(search(ChatQuery::default(), 100).await, vec!["Bye", "hello there", "Hello World!"]);
+    assert_eq!(search(ChatQuery::default(), 1).await, vec!["Bye"]);
+
+    // filters
+    assert_eq!(
+        search(
+            ChatQuery {
+                server: Some("myLobby".to_string()),
+                ..Default::default()
+            },
+            100
+        )
+        .await,
+        vec!["hello there", "Hello World!"]
+    );
+    assert_eq!(
+        search(
+            ChatQuery {
+                sender: Some("notch".to_string()),
+                ..Default::default()
+            },
+            100
+        )
+        .await,
+        vec!["Bye", "Hello World!"]
+    );
+    assert_eq!(
+        search(
+            ChatQuery {
+                sender: Some(NOTCH.to_uppercase()),
+                server: Some("myLobby".to_string()),
+                ..Default::default()
+            },
+            100
+        )
+        .await,
+        vec!["Hello World!"]
+    );
+    assert_eq!(
+        search(
+            ChatQuery {
+                text: Some("HELLO".to_string()),
+                ..Default::default()
+            },
+            100
+        )
+        .await,
+        vec!["hello there", "Hello World!"]
+    );
+    assert_eq!(
+        search(
+            ChatQuery {
+                since: Some(at(1)),
+                until: Some(at(2)),
+                ..Default::default()
+            },
+            100
+        )
+        .await,
+        vec!["hello there"]
+    );
+
+    assert_eq!(storage.remove_chat_messages(at(1)).await.unwrap(), 1);
+    assert_eq!(search(ChatQuery::default(), 100).await, vec!["Bye", "hello there"]);
+
+    // renaming a server moves its messages
+    assert_eq!(storage.rename_chat_messages("myLobby", "myHub").await.unwrap(), 1);
+    assert_eq!(
+        search(
+            ChatQuery {
+                server: Some("myHub".to_string()),
+                ..Default::default()
+            },
+            100
+        )
+        .await,
+        vec!["hello there"]
+    );
+}
+/// Add, change and remove webhooks and record their deliveries.
+async fn webhooks_suite(storage: &dyn HistoryStorage) {
+    let first_id = storage.add_webhook(&webhook("myFirstWebhook")).await.unwrap();
+    let mut second_webhook = webhook("mySecondWebhook");
+    second_webhook.secret = None;
+    second_webhook.template = WebhookTemplate::Custom {
+        body: "{summary}".to_string(),
+    };
+    let second_id = storage.add_webhook(&second_webhook).await.unwrap();
+    assert!(second_id > first_id);
+    assert_eq!(
+        storage.webhooks().await.unwrap(),
+        vec![
+            Webhook {
+                id: first_id,
+                ..webhook("myFirstWebhook")
+            },
+            Webhook {
+                id: second_id,
+                ..second_webhook.clone()
+            },
+        ]
+    );
+
+    // the saved secret is kept if no new one is set
+    let mut changed_webhook = webhook("myChangedWebhook");
+    changed_webhook.secret = None;
+    changed_webhook.enabled = false;
+    storage.change_webhook(first_id, &changed_webhook).await.unwrap();
+    assert_eq!(
+        storage.webhooks().await.unwrap()[0],
+        Webhook {
+            id: first_id,
+            secret: Some("mySecret".to_string()),
+            ..changed_webhook.clone()
+        }
+    );
+    assert!(matches!(
+        storage.change_webhook(second_id + 1, &changed_webhook).await,
+        Err(MCManageError::NotFound)
+    ));
+
+    // only the given number of deliveries is kept
+    for attempts in 1..=3 {
+        storage
+            .add_delivery(
+                &WebhookDelivery {
+                    webhook: first_id,
+                    event: ServerEvent {
+                        server: "MCManage".to_string(),
+                        time: at(0),
+                        event: Event::Test,
+                    },
+                    success: attempts == 3,
+                    attempts,
+                    status_code: Some(500),
+                    error: None,
+                    delivered_at: at(attempts as i64),
+                },
+                2,
+            )
+            .await
+            .unwrap();
+    }
+    let deliveries = storage.deliveries(first_id).await.unwrap();
+    assert_eq!(
+        deliveries
+            .iter()
+            .map(|delivery| (delivery.attempts, delivery.success))
+            .collect::<Vec<_>>(),
+        vec![(3, true), (2, false)]
+    );
+    assert_eq!(deliveries[0].delivered_at, at(3));
+    assert_eq!(deliveries[0].status_code, Some(500));
+
+    // the deliveries get removed together with their webhook
+    storage.remove_webhook(first_id).await.unwrap();
+    assert!(matches!(storage.remove_webhook(first_id).await, Err(MCManageError::NotFound)));
+    assert!(storage.deliveries(first_id).await.unwrap().is_empty());
+    assert_eq!(storage.webhooks().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn sqlite_storage() {
+    storage_suite(&SqliteStorage::in_memory().await.unwrap()).await;
+}
+#[tokio::test]
+#[ignore = "requires a PostgreSQL database set in MCMANAGE_TEST_POSTGRES_URL"]
+async fn postgres_storage() {
+    let pool = postgres_pool("postgres_storage").await;
+    storage_suite(&PostgresStorage::new(pool).await.unwrap()).await;
+}
+#[tokio::test]
+async fn sqlite_history() {
+    history_suite(&SqliteStorage::in_memory().await.unwrap()).await;
+}
+#[tokio::test]
+#[ignore = "requires a PostgreSQL database set in MCMANAGE_TEST_POSTGRES_URL"]
+async fn postgres_history() {
+    let pool = postgres_pool("postgres_history").await;
+    history_suite(&PostgresStorage::new(pool).await.unwrap()).await;
+}
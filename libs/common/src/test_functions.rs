@@ -18,6 +18,68 @@ pub fn start_test() {
     Config::init();
 }
 
+/// Initialize the [`Config`] for every test of this process. Only the first call will read the config file, every later call will do nothing.
+///
+/// # Panics
+///
+/// This method will panic when called outside of the test configuration.
+pub async fn init_config() {
+    static CONFIG_INITIATED: tokio::sync::OnceCell<()> = tokio::sync::OnceCell::const_new();
+    CONFIG_INITIATED.get_or_init(Config::init).await;
+}
+
+/// Initialize the [`ServerList`](crate::server_manager::server_list::ServerList) for every test of this process using a
+/// [`SqliteStorage`](crate::storage::sqlite_storage::SqliteStorage) which only exists in memory. Only the first call will initialize it, every
+/// later call will do nothing. \
+/// Since the list is shared by every test, each test has to use its own server names.
+///
+/// # Panics
+///
+/// This method will panic when called outside of the test configuration.
+pub async fn init_server_list() {
+    static SERVER_LIST_INITIATED: tokio::sync::OnceCell<()> = tokio::sync::OnceCell::const_new();
+    SERVER_LIST_INITIATED
+        .get_or_init(|| async {
+            init_config().await;
+            let storage = crate::storage::sqlite_storage::SqliteStorage::in_memory()
+                .await
+                .unwrap_or_else(|error| fatal!("ServerList"; "Failed to create the test database. Error: {error}"));
+            crate::server_manager::server_list::ServerList::init_with_storage(Box::new(storage)).await;
+        })
+        .await;
+}
+
+/// The environment variable holding the url of an empty PostgreSQL database. Every test needing a PostgreSQL database is ignored by default and
+/// can be run with `cargo test -- --ignored` once this variable is set.
+pub const POSTGRES_URL_VAR: &str = "MCMANAGE_TEST_POSTGRES_URL";
+
+/// Connect to the PostgreSQL database set in the [`POSTGRES_URL_VAR`] environment variable and recreate the schema with the given name, so the
+/// calling test starts with an empty database no other test is using.
+///
+/// # Panics
+///
+/// This method will panic when called outside of the test configuration or if the variable is not set.
+pub async fn postgres_pool(schema: &str) -> sqlx::Pool<sqlx::Postgres> {
+    let url = std::env::var(POSTGRES_URL_VAR)
+        .unwrap_or_else(|_| panic!("Set the {POSTGRES_URL_VAR} environment variable to the url of an empty PostgreSQL database."));
+    // the only connection of the pool keeps the search path for the whole test
+    let pool = sqlx::postgres::PgPoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect(&url)
+        .await
+        .unwrap();
+    for query in [
+        format!("DROP SCHEMA IF EXISTS {schema} CASCADE"),
+        format!("CREATE SCHEMA {schema}"),
+        format!("SET search_path TO {schema}"),
+    ] {
+        sqlx::query(&query).execute(&pool).await.unwrap();
+    }
+    pool
+}
+
 /// This method will delete everything inside [`struct@ROOT_DIR`](crate::generated_files::paths::ROOT_DIR).
 ///
 /// # Panics
@@ -48,3 +110,209 @@ pub fn cleanup() {
     cleanup_dir(ROOT_DIR.as_path())
         .unwrap_or_else(|error| fatal!("Cleanup"; "Failed to remove the testing directory. Error: {error}"));
 }
+
+/// This struct represents a request received by the [`mock_server`].
+#[derive(Clone, Debug)]
+pub struct ReceivedRequest {
+    /// The method of the request. (For example: `GET`)
+    pub method: String,
+    /// The path of the request. (For example: `/server.jar`)
+    pub path: String,
+    /// The headers of the request. Their names are lowercase.
+    pub headers: std::collections::HashMap<String, String>,
+    /// The body of the request
+    pub body: Vec<u8>,
+}
+/// This struct represents the response the [`mock_server`] answers a request with.
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    /// The status code of the response
+    pub status_code: u16,
+    /// The headers of the response. The `Content-Length` header will be added automatically.
+    pub headers: Vec<(String, String)>,
+    /// The body of the response
+    pub body: Vec<u8>,
+}
+impl MockResponse {
+    /// Create a response with the given status code and body.
+    pub fn new(status_code: u16, body: &[u8]) -> Self {
+        Self {
+            status_code,
+            headers: vec![],
+            body: body.to_vec(),
+        }
+    }
+    /// Add the given header to this response.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+/// Accept requests on a local port until the test ends and return the base url of the server (For example: `http://127.0.0.1:12345`) together
+/// with a list of every received request. \
+/// Every request gets answered with the response returned by the given handler. If the handler returns `None`, the request will never be
+/// answered. Every `{base_url}` inside of a response body will be replaced by the base url of the server, so that responses can link to other
+/// paths.
+///
+/// # Panics
+///
+/// This method will panic when called outside of the test configuration.
+pub async fn mock_server<H>(handler: H) -> (String, std::sync::Arc<tokio::sync::Mutex<Vec<ReceivedRequest>>>)
+where
+    H: Fn(&ReceivedRequest) -> Option<MockResponse> + Send + Sync + 'static,
+{
+    use tokio::{
+        io::{
+            AsyncBufReadExt,
+            AsyncReadExt,
+            AsyncWriteExt,
+            BufReader,
+        },
+        net::TcpListener,
+    };
+
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .unwrap_or_else(|error| fatal!("MockServer"; "Failed to bind to a local port. Error: {error}"));
+    let base_url = format!(
+        "http://{}",
+        listener
+            .local_addr()
+            .unwrap_or_else(|error| fatal!("MockServer"; "Failed to get the local address. Error: {error}"))
+    );
+    let requests = std::sync::Arc::new(tokio::sync::Mutex::new(vec![]));
+
+    let received = requests.clone();
+    let handler = std::sync::Arc::new(handler);
+    let server_base_url = base_url.clone();
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let received = received.clone();
+            let handler = handler.clone();
+            let base_url = server_base_url.clone();
+            tokio::spawn(async move {
+                let (reader, mut writer) = stream.split();
+                let mut reader = BufReader::new(reader);
+
+                let mut request_line = None;
+                let mut headers = std::collections::HashMap::new();
+                let mut line = String::new();
+                while let Ok(length) = reader.read_line(&mut line).await {
+                    let header = line.trim_end();
+                    if length == 0 || header.is_empty() {
+                        break;
+                    }
+                    if request_line.is_none() {
+                        request_line = Some(header.to_string());
+                    } else if let Some((name, value)) = header.split_once(':') {
+                        headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+                    }
+                    line.clear();
+                }
+                let request_line = request_line.unwrap_or_default();
+                let mut request_line = request_line.split(' ');
+                let content_length = headers
+                    .get("content-length")
+                    .and_then(|length| length.parse().ok())
+                    .unwrap_or(0);
+                let mut body = vec![0; content_length];
+                if reader.read_exact(&mut body).await.is_err() {
+                    return;
+                }
+                let request = ReceivedRequest {
+                    method: request_line.next().unwrap_or_default().to_string(),
+                    path: request_line.next().unwrap_or_default().to_string(),
+                    headers,
+                    body,
+                };
+                received.lock().await.push(request.clone());
+
+                let Some(mut response) = handler(&request) else {
+                    // keep the connection open without ever answering
+                    std::future::pending::<()>().await;
+                    return;
+                };
+                if let Ok(text) = String::from_utf8(response.body.clone()) {
+                    response.body = text.replace("{base_url}", &base_url).into_bytes();
+                }
+
+                let mut raw_response = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n",
+                    response.status_code,
+                    response.body.len()
+                );
+                for (name, value) in &response.headers {
+                    raw_response += &format!("{name}: {value}\r\n");
+                }
+                raw_response += "\r\n";
+                let mut raw_response = raw_response.into_bytes();
+                raw_response.extend_from_slice(&response.body);
+                if writer.write_all(&raw_response).await.is_err() {}
+            });
+        }
+    });
+
+    (base_url, requests)
+}
+/// Serve the given routes using the [`mock_server`] function and return its base url. \
+/// Every route consists of a path and the body returned for it. Requests to any other path will be answered with a `404 Not Found`.
+///
+/// # Panics
+///
+/// This method will panic when called outside of the test configuration.
+pub async fn mock_http_server(routes: Vec<(String, Vec<u8>)>) -> String {
+    mock_server(move |request| {
+        Some(match routes.iter().find(|(route, _)| *route == request.path) {
+            Some((_, body)) => MockResponse::new(200, body),
+            None => MockResponse::new(404, b""),
+        })
+    })
+    .await
+    .0
+}
+/// Receive webhooks using the [`mock_server`] function and return the url of the receiver together with a list of every received request. \
+/// The given status codes will be used to answer the first requests. Every request afterwards will be answered with a `200 OK`.
+///
+/// # Panics
+///
+/// This method will panic when called outside of the test configuration.
+pub async fn mock_webhook_receiver(
+    status_codes: Vec<u16>,
+) -> (String, std::sync::Arc<tokio::sync::Mutex<Vec<ReceivedRequest>>>) {
+    let status_codes = std::sync::Mutex::new(status_codes.into_iter());
+    let (base_url, requests) = mock_server(move |_| {
+        let status_code = status_codes.lock().unwrap().next().unwrap_or(200);
+        Some(MockResponse::new(status_code, b""))
+    })
+    .await;
+
+    (format!("{base_url}/webhook"), requests)
+}
+/// Create a jar containing the given entries. Every entry consists of a file name and the content of that file.
+///
+/// # Panics
+///
+/// This method will panic when called outside of the test configuration.
+pub fn jar(entries: &[(&str, &str)]) -> Vec<u8> {
+    use std::io::Write;
+
+    use zip::{
+        write::FileOptions,
+        CompressionMethod,
+        ZipWriter,
+    };
+
+    let mut writer = ZipWriter::new(std::io::Cursor::new(vec![]));
+    for (name, content) in entries {
+        writer
+            .start_file(*name, FileOptions::default().compression_method(CompressionMethod::Stored))
+            .unwrap_or_else(|error| fatal!("Jar"; "Failed to add the entry {name}. Error: {error}"));
+        writer
+            .write_all(content.as_bytes())
+            .unwrap_or_else(|error| fatal!("Jar"; "Failed to write the entry {name}. Error: {error}"));
+    }
+    writer
+        .finish()
+        .unwrap_or_else(|error| fatal!("Jar"; "Failed to finish the jar. Error: {error}"))
+        .into_inner()
+}
@@ -0,0 +1,128 @@
+//! This module provides the [`FabricProvider`], which resolves Fabric server launchers using the Fabric meta api.
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::{
+    get_json,
+    resolved_build::ResolvedBuild,
+    VersionProvider,
+    LATEST,
+};
+use crate::mcmanage_error::MCManageError;
+
+/// The name of the jar the Fabric server launcher gets saved as.
+pub const FABRIC_LAUNCHER_JAR: &str = "fabric-server-launch.jar";
+
+/// One entry served at `{base_url}/v2/versions/game` or `{base_url}/v2/versions/installer`
+#[derive(Deserialize)]
+struct Version {
+    /// The Minecraft or installer version
+    version: String,
+    /// Whether this version is no snapshot or beta
+    stable: bool,
+}
+/// One entry served at `{base_url}/v2/versions/loader/{version}`
+#[derive(Deserialize)]
+struct LoaderEntry {
+    /// The loader supporting the requested Minecraft version
+    loader: Version,
+}
+
+/// This struct resolves Fabric server launchers using the Fabric meta api. \
+/// The builds of a Minecraft version are the versions of the Fabric loader. The launcher downloads the vanilla server jar by itself on its first start.
+pub struct FabricProvider {
+    /// The base url of the Fabric meta api
+    base_url: String,
+}
+impl FabricProvider {
+    /// Create a new [`FabricProvider`] using the api at the given base url. (For example: `https://meta.fabricmc.net`)
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+    /// Get the loaders supporting the given Minecraft version, starting with the oldest.
+    async fn get_loaders(&self, version: &str) -> Result<Vec<Version>, MCManageError> {
+        let loaders: Vec<Version> = get_json::<Vec<LoaderEntry>>(&format!("{}/v2/versions/loader/{version}", self.base_url))
+            .await?
+            .into_iter()
+            .rev()
+            .map(|entry| entry.loader)
+            .collect();
+
+        // the api answers unknown versions with an empty list
+        if loaders.is_empty() {
+            return Err(MCManageError::NotFound);
+        }
+        Ok(loaders)
+    }
+    /// Get the newest stable version of the Fabric installer. The launcher jar gets generated by it.
+    async fn installer_version(&self) -> Result<String, MCManageError> {
+        get_json::<Vec<Version>>(&format!("{}/v2/versions/installer", self.base_url))
+            .await?
+            .into_iter()
+            .find(|installer| installer.stable)
+            .map(|installer| installer.version)
+            .ok_or(MCManageError::NotFound)
+    }
+}
+#[async_trait]
+impl VersionProvider for FabricProvider {
+    fn name(&self) -> &str {
+        "fabric"
+    }
+    fn default_args(&self) -> Option<String> {
+        Some(format!("-jar {FABRIC_LAUNCHER_JAR} nogui"))
+    }
+    async fn versions(&self) -> Result<Vec<String>, MCManageError> {
+        Ok(get_json::<Vec<Version>>(&format!("{}/v2/versions/game", self.base_url))
+            .await?
+            .into_iter()
+            .rev()
+            .filter(|version| version.stable)
+            .map(|version| version.version)
+            .collect())
+    }
+    async fn builds(&self, version: &str) -> Result<Vec<String>, MCManageError> {
+        let version = self.resolve_version(version).await?;
+        Ok(self
+            .get_loaders(&version)
+            .await?
+            .into_iter()
+            .map(|loader| loader.version)
+            .collect())
+    }
+    async fn resolve_build(&self, version: &str, build: &str) -> Result<String, MCManageError> {
+        let loaders = self.get_loaders(version).await?;
+        if build == LATEST {
+            loaders
+                .into_iter()
+                .rev()
+                .find(|loader| loader.stable)
+                .map(|loader| loader.version)
+                .ok_or(MCManageError::NotFound)
+        } else if loaders.iter().any(|loader| loader.version == build) {
+            Ok(build.to_string())
+        } else {
+            Err(MCManageError::NotFound)
+        }
+    }
+    async fn resolve(&self, version: &str, build: &str) -> Result<ResolvedBuild, MCManageError> {
+        let version = self.resolve_version(version).await?;
+        let build = self.resolve_build(&version, build).await?;
+        let installer = self.installer_version().await?;
+
+        Ok(ResolvedBuild {
+            url: format!(
+                "{}/v2/versions/loader/{version}/{build}/{installer}/server/jar",
+                self.base_url
+            ),
+            version,
+            build,
+            // the launcher gets generated on request, so the api does not provide a checksum
+            checksum: None,
+            installer: None,
+        })
+    }
+}
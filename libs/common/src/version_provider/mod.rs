@@ -0,0 +1,115 @@
+//! This module provides the [`VersionProvider`] trait and its implementations, which are used to find the download link and checksum of a Minecraft server jar
+//! from its type, version and build.
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+
+use self::{
+    fabric::FabricProvider,
+    paper::PaperProvider,
+    purpur::PurpurProvider,
+    quilt::QuiltProvider,
+    resolved_build::ResolvedBuild,
+    vanilla::VanillaProvider,
+};
+use crate::{
+    config::Config,
+    mcmanage_error::MCManageError,
+};
+
+pub mod fabric;
+pub mod paper;
+pub mod purpur;
+pub mod quilt;
+pub mod resolved_build;
+pub mod server_installer;
+pub mod vanilla;
+mod tests;
+
+/// The build, which will be used if no other got specified.
+pub const LATEST: &str = "latest";
+
+/// The client used by every [`VersionProvider`].
+static CLIENT: Lazy<Client> = Lazy::new(Client::new);
+
+/// This trait describes an api which can be asked for the download links of a Minecraft server software. \
+/// Builds and versions are always represented as strings. Every version and build can also be set to [`LATEST`].
+#[async_trait]
+pub trait VersionProvider: Send + Sync {
+    /// Return the name of the server type this provider is meant for. (For example: `paper`)
+    fn name(&self) -> &str;
+    /// Return the args a server of this type should be started with if it got configured without any. (For example: `-jar fabric-server-launch.jar nogui`) \
+    /// By default, no args will be provided.
+    fn default_args(&self) -> Option<String> {
+        None
+    }
+    /// Return every Minecraft version this provider knows, starting with the oldest.
+    async fn versions(&self) -> Result<Vec<String>, MCManageError>;
+    /// Return every build of the given Minecraft version, starting with the oldest.
+    ///
+    /// # Returns
+    ///
+    /// | Return                          | Description                                   |
+    /// |---------------------------------|-----------------------------------------------|
+    /// | `Ok(Vec<String>)`               | Every build of the given version.             |
+    /// | [`MCManageError::NotFound`]     | The given version does not exist.             |
+    /// | [`MCManageError::ReqwestError`] | The api could not be reached or is not valid. |
+    async fn builds(&self, version: &str) -> Result<Vec<String>, MCManageError>;
+    /// Resolve the download link and checksum of the given build.
+    ///
+    /// # Returns
+    ///
+    /// | Return                          | Description                                   |
+    /// |---------------------------------|-----------------------------------------------|
+    /// | `Ok(ResolvedBuild)`             | The build could be found.                     |
+    /// | [`MCManageError::NotFound`]     | The given version or build does not exist.    |
+    /// | [`MCManageError::ReqwestError`] | The api could not be reached or is not valid. |
+    async fn resolve(&self, version: &str, build: &str) -> Result<ResolvedBuild, MCManageError>;
+
+    /// Return the given version, or the newest one if the given version is [`LATEST`].
+    async fn resolve_version(&self, version: &str) -> Result<String, MCManageError> {
+        if version != LATEST {
+            return Ok(version.to_string());
+        }
+        self.versions()
+            .await?
+            .pop()
+            .ok_or(MCManageError::NotFound)
+    }
+    /// Return the given build, or the newest one of the given version if the given build is [`LATEST`].
+    async fn resolve_build(&self, version: &str, build: &str) -> Result<String, MCManageError> {
+        let builds = self.builds(version).await?;
+        if build == LATEST {
+            builds.last().cloned().ok_or(MCManageError::NotFound)
+        } else if builds.iter().any(|known_build| known_build == build) {
+            Ok(build.to_string())
+        } else {
+            Err(MCManageError::NotFound)
+        }
+    }
+}
+
+/// Return the [`VersionProvider`] for the given server type. The base urls of the providers are read from the [`Config`]. \
+/// If no provider exists for the given type, `None` will be returned.
+pub async fn get_provider(server_type: &str) -> Option<Box<dyn VersionProvider>> {
+    match server_type {
+        "vanilla" => Some(Box::new(VanillaProvider::new(&Config::mojang_api_url().await))),
+        "paper" => Some(Box::new(PaperProvider::new(&Config::papermc_api_url().await))),
+        "purpur" => Some(Box::new(PurpurProvider::new(&Config::purpur_api_url().await))),
+        "fabric" => Some(Box::new(FabricProvider::new(&Config::fabric_meta_url().await))),
+        "quilt" => Some(Box::new(QuiltProvider::new(&Config::quilt_meta_url().await))),
+        _ => None,
+    }
+}
+
+/// Request the given url and parse its json response. \
+/// A `404 Not Found` will be returned as [`MCManageError::NotFound`].
+async fn get_json<T: DeserializeOwned>(url: &str) -> Result<T, MCManageError> {
+    let response = CLIENT.get(url).send().await?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err(MCManageError::NotFound);
+    }
+    Ok(response.error_for_status()?.json().await?)
+}
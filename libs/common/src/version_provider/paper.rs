@@ -0,0 +1,116 @@
+//! This module provides the [`PaperProvider`], which resolves paper server jars using the PaperMC api.
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::{
+    get_json,
+    resolved_build::ResolvedBuild,
+    VersionProvider,
+};
+use crate::{
+    download_manager::checksum::Checksum,
+    mcmanage_error::MCManageError,
+};
+
+/// The project served at `{base_url}/v2/projects/{project}`
+#[derive(Deserialize)]
+struct Project {
+    /// Every version of this project, starting with the oldest
+    versions: Vec<String>,
+}
+/// The builds served at `{base_url}/v2/projects/{project}/versions/{version}/builds`
+#[derive(Deserialize)]
+struct Builds {
+    /// Every build of a version, starting with the oldest
+    builds: Vec<Build>,
+}
+/// One build listed in [`Builds`]
+#[derive(Deserialize)]
+struct Build {
+    /// The number of this build
+    build: u64,
+    /// The files of this build
+    downloads: BuildDownloads,
+}
+/// The files of one [`Build`]
+#[derive(Deserialize)]
+struct BuildDownloads {
+    /// The server jar
+    application: BuildDownload,
+}
+/// One file of a [`Build`]
+#[derive(Deserialize)]
+struct BuildDownload {
+    /// The name of this file
+    name: String,
+    /// The sha256 hash of this file
+    sha256: String,
+}
+
+/// This struct resolves paper server jars using the PaperMC api.
+pub struct PaperProvider {
+    /// The base url of the PaperMC api
+    base_url: String,
+}
+impl PaperProvider {
+    /// Create a new [`PaperProvider`] using the api at the given base url. (For example: `https://api.papermc.io`)
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+    /// Get every build of the given version.
+    async fn get_builds(&self, version: &str) -> Result<Vec<Build>, MCManageError> {
+        Ok(get_json::<Builds>(&format!(
+            "{}/v2/projects/paper/versions/{version}/builds",
+            self.base_url
+        ))
+        .await?
+        .builds)
+    }
+}
+#[async_trait]
+impl VersionProvider for PaperProvider {
+    fn name(&self) -> &str {
+        "paper"
+    }
+    async fn versions(&self) -> Result<Vec<String>, MCManageError> {
+        Ok(get_json::<Project>(&format!("{}/v2/projects/paper", self.base_url))
+            .await?
+            .versions)
+    }
+    async fn builds(&self, version: &str) -> Result<Vec<String>, MCManageError> {
+        let version = self.resolve_version(version).await?;
+        Ok(self
+            .get_builds(&version)
+            .await?
+            .into_iter()
+            .map(|build| build.build.to_string())
+            .collect())
+    }
+    async fn resolve(&self, version: &str, build: &str) -> Result<ResolvedBuild, MCManageError> {
+        let version = self.resolve_version(version).await?;
+        let build = self.resolve_build(&version, build).await?;
+
+        let application = self
+            .get_builds(&version)
+            .await?
+            .into_iter()
+            .find(|known_build| known_build.build.to_string() == build)
+            .ok_or(MCManageError::NotFound)?
+            .downloads
+            .application;
+
+        Ok(ResolvedBuild {
+            url: format!(
+                "{}/v2/projects/paper/versions/{version}/builds/{build}/downloads/{}",
+                self.base_url, application.name
+            ),
+            version,
+            build,
+            checksum: Some(Checksum::Sha256(application.sha256)),
+            installer: None,
+        })
+    }
+}
@@ -0,0 +1,103 @@
+//! This module provides the [`PurpurProvider`], which resolves purpur server jars using the Purpur api.
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::{
+    get_json,
+    resolved_build::ResolvedBuild,
+    VersionProvider,
+    LATEST,
+};
+use crate::{
+    download_manager::checksum::Checksum,
+    mcmanage_error::MCManageError,
+};
+
+/// The project served at `{base_url}/v2/purpur`
+#[derive(Deserialize)]
+struct Project {
+    /// Every version of purpur, starting with the oldest
+    versions: Vec<String>,
+}
+/// The version served at `{base_url}/v2/purpur/{version}`
+#[derive(Deserialize)]
+struct Version {
+    /// The builds of this version
+    builds: VersionBuilds,
+}
+/// The builds of a [`Version`]
+#[derive(Deserialize)]
+struct VersionBuilds {
+    /// The newest build
+    latest: String,
+    /// Every build, starting with the oldest
+    all: Vec<String>,
+}
+/// The build served at `{base_url}/v2/purpur/{version}/{build}`
+#[derive(Deserialize)]
+struct Build {
+    /// The md5 hash of the server jar
+    md5: String,
+}
+
+/// This struct resolves purpur server jars using the Purpur api.
+pub struct PurpurProvider {
+    /// The base url of the Purpur api
+    base_url: String,
+}
+impl PurpurProvider {
+    /// Create a new [`PurpurProvider`] using the api at the given base url. (For example: `https://api.purpurmc.org`)
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+    /// Get the builds of the given version.
+    async fn get_builds(&self, version: &str) -> Result<VersionBuilds, MCManageError> {
+        Ok(get_json::<Version>(&format!("{}/v2/purpur/{version}", self.base_url))
+            .await?
+            .builds)
+    }
+}
+#[async_trait]
+impl VersionProvider for PurpurProvider {
+    fn name(&self) -> &str {
+        "purpur"
+    }
+    async fn versions(&self) -> Result<Vec<String>, MCManageError> {
+        Ok(get_json::<Project>(&format!("{}/v2/purpur", self.base_url))
+            .await?
+            .versions)
+    }
+    async fn builds(&self, version: &str) -> Result<Vec<String>, MCManageError> {
+        let version = self.resolve_version(version).await?;
+        Ok(self.get_builds(&version).await?.all)
+    }
+    async fn resolve_build(&self, version: &str, build: &str) -> Result<String, MCManageError> {
+        let builds = self.get_builds(version).await?;
+        if build == LATEST {
+            Ok(builds.latest)
+        } else if builds.all.iter().any(|known_build| known_build == build) {
+            Ok(build.to_string())
+        } else {
+            Err(MCManageError::NotFound)
+        }
+    }
+    async fn resolve(&self, version: &str, build: &str) -> Result<ResolvedBuild, MCManageError> {
+        let version = self.resolve_version(version).await?;
+        let build = self.resolve_build(&version, build).await?;
+
+        let md5 = get_json::<Build>(&format!("{}/v2/purpur/{version}/{build}", self.base_url))
+            .await?
+            .md5;
+
+        Ok(ResolvedBuild {
+            url: format!("{}/v2/purpur/{version}/{build}/download", self.base_url),
+            version,
+            build,
+            checksum: Some(Checksum::Md5(md5)),
+            installer: None,
+        })
+    }
+}
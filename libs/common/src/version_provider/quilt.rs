@@ -0,0 +1,143 @@
+//! This module provides the [`QuiltProvider`], which resolves Quilt server launchers using the Quilt meta api.
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::{
+    get_json,
+    resolved_build::ResolvedBuild,
+    server_installer::ServerInstaller,
+    VersionProvider,
+    LATEST,
+};
+use crate::mcmanage_error::MCManageError;
+
+/// The name of the jar the Quilt installer saves the server launcher as.
+pub const QUILT_LAUNCHER_JAR: &str = "quilt-server-launch.jar";
+
+/// One entry served at `{base_url}/v3/versions/game`
+#[derive(Deserialize)]
+struct GameVersion {
+    /// The Minecraft version
+    version: String,
+    /// Whether this version is no snapshot
+    stable: bool,
+}
+/// One entry served at `{base_url}/v3/versions/loader/{version}`
+#[derive(Deserialize)]
+struct LoaderEntry {
+    /// The loader supporting the requested Minecraft version
+    loader: Loader,
+}
+/// The loader of a [`LoaderEntry`]
+#[derive(Deserialize)]
+struct Loader {
+    /// The version of the loader. Betas are marked by a suffix. (For example: `0.20.0-beta.9`)
+    version: String,
+}
+/// One entry served at `{base_url}/v3/versions/installer`
+#[derive(Deserialize)]
+struct Installer {
+    /// The url of the installer jar
+    url: String,
+}
+
+/// This struct resolves Quilt server launchers using the Quilt meta api. \
+/// Unlike the Fabric meta api, the Quilt meta api can not generate the launcher, so the resolved url points to the Quilt installer, which
+/// installs the launcher together with its libraries and the vanilla server jar. The builds of a Minecraft version are the versions of the Quilt
+/// loader.
+pub struct QuiltProvider {
+    /// The base url of the Quilt meta api
+    base_url: String,
+}
+impl QuiltProvider {
+    /// Create a new [`QuiltProvider`] using the api at the given base url. (For example: `https://meta.quiltmc.org`)
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+    /// Get the versions of the loaders supporting the given Minecraft version, starting with the oldest.
+    async fn get_loaders(&self, version: &str) -> Result<Vec<String>, MCManageError> {
+        let loaders: Vec<String> = get_json::<Vec<LoaderEntry>>(&format!("{}/v3/versions/loader/{version}", self.base_url))
+            .await?
+            .into_iter()
+            .rev()
+            .map(|entry| entry.loader.version)
+            .collect();
+
+        // the api answers unknown versions with an empty list
+        if loaders.is_empty() {
+            return Err(MCManageError::NotFound);
+        }
+        Ok(loaders)
+    }
+    /// Get the url of the newest Quilt installer.
+    async fn installer_url(&self) -> Result<String, MCManageError> {
+        get_json::<Vec<Installer>>(&format!("{}/v3/versions/installer", self.base_url))
+            .await?
+            .into_iter()
+            .next()
+            .map(|installer| installer.url)
+            .ok_or(MCManageError::NotFound)
+    }
+}
+#[async_trait]
+impl VersionProvider for QuiltProvider {
+    fn name(&self) -> &str {
+        "quilt"
+    }
+    fn default_args(&self) -> Option<String> {
+        Some(format!("-jar {QUILT_LAUNCHER_JAR} nogui"))
+    }
+    async fn versions(&self) -> Result<Vec<String>, MCManageError> {
+        Ok(get_json::<Vec<GameVersion>>(&format!("{}/v3/versions/game", self.base_url))
+            .await?
+            .into_iter()
+            .rev()
+            .filter(|version| version.stable)
+            .map(|version| version.version)
+            .collect())
+    }
+    async fn builds(&self, version: &str) -> Result<Vec<String>, MCManageError> {
+        let version = self.resolve_version(version).await?;
+        self.get_loaders(&version).await
+    }
+    async fn resolve_build(&self, version: &str, build: &str) -> Result<String, MCManageError> {
+        let loaders = self.get_loaders(version).await?;
+        if build == LATEST {
+            loaders
+                .into_iter()
+                .rev()
+                .find(|loader| !loader.contains('-'))
+                .ok_or(MCManageError::NotFound)
+        } else if loaders.iter().any(|loader| loader == build) {
+            Ok(build.to_string())
+        } else {
+            Err(MCManageError::NotFound)
+        }
+    }
+    async fn resolve(&self, version: &str, build: &str) -> Result<ResolvedBuild, MCManageError> {
+        let version = self.resolve_version(version).await?;
+        let build = self.resolve_build(&version, build).await?;
+
+        Ok(ResolvedBuild {
+            url: self.installer_url().await?,
+            installer: Some(ServerInstaller {
+                args: vec![
+                    "install".to_string(),
+                    "server".to_string(),
+                    version.clone(),
+                    build.clone(),
+                    "--install-dir=.".to_string(),
+                    "--download-server".to_string(),
+                ],
+                jar: QUILT_LAUNCHER_JAR.to_string(),
+            }),
+            version,
+            build,
+            // the api does not provide a checksum of the installer
+            checksum: None,
+        })
+    }
+}
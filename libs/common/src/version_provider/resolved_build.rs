@@ -0,0 +1,39 @@
+//! This module provides the [`ResolvedBuild`] struct.
+
+use proc_macros::add_convert;
+
+use super::server_installer::ServerInstaller;
+use crate::{
+    download_manager::{
+        checksum::Checksum,
+        DownloadRequest,
+    },
+    mcmanage_error::MCManageError,
+};
+
+/// This struct represents one build of a Minecraft server software, as resolved by a [`VersionProvider`](super::VersionProvider).
+#[add_convert]
+pub struct ResolvedBuild {
+    /// The Minecraft version of this build.
+    pub version: String,
+    /// The build number. For software without builds, like vanilla, this is the same as the version.
+    pub build: String,
+    /// The url to download the server jar from. If an installer is set, this is the url of the installer instead.
+    pub url: String,
+    /// The checksum of the server jar, if published by the api.
+    pub checksum: Option<Checksum>,
+    /// The installer creating the server jar, if it can not be downloaded directly.
+    #[serde(default)]
+    pub installer: Option<ServerInstaller>,
+}
+impl ResolvedBuild {
+    /// Create a [`DownloadRequest`] for the server jar of this build, or for its installer if it has one.
+    pub fn download_request(&self) -> DownloadRequest {
+        let request = DownloadRequest::new(&self.url);
+        if let Some(checksum) = &self.checksum {
+            request.with_checksum(checksum.clone())
+        } else {
+            request
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! This module provides the [`ServerInstaller`] struct.
+
+use proc_macros::add_convert;
+
+use crate::mcmanage_error::MCManageError;
+
+/// This struct describes an installer creating the server jar of a [`ResolvedBuild`](super::resolved_build::ResolvedBuild), which can not be
+/// downloaded directly. (For example: the Quilt installer) \
+/// The installer gets started with `java -jar <installer> <args>` inside of an empty directory. Every file it creates next to the server jar, like
+/// libraries, will be moved to the server directory.
+#[add_convert]
+pub struct ServerInstaller {
+    /// The args the installer gets started with
+    pub args: Vec<String>,
+    /// The name of the server jar the installer creates
+    pub jar: String,
+}
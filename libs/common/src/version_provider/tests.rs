@@ -0,0 +1,288 @@
+#![cfg(test)]
+
+use super::{
+    server_installer::ServerInstaller,
+    *,
+};
+use crate::{
+    download_manager::checksum::Checksum,
+    test_functions::mock_http_server,
+};
+
+/// Serve the given routes using the [`mock_http_server`] function.
+async fn serve(routes: Vec<(&str, &str)>) -> String {
+    mock_http_server(
+        routes
+            .into_iter()
+            .map(|(path, body)| (path.to_string(), body.as_bytes().to_vec()))
+            .collect(),
+    )
+    .await
+}
+
+/// Serve a version manifest with two releases and one snapshot.
+async fn serve_mojang() -> String {
+    serve(vec![
+        (
+            "/mc/game/version_manifest_v2.json",
+            r#"{
+                "latest": { "release": "1.20.4", "snapshot": "24w03a" },
+                "versions": [
+                    { "id": "24w03a", "type": "snapshot", "url": "{base_url}/v1/packages/24w03a.json", "sha1": "" },
+                    { "id": "1.20.4", "type": "release", "url": "{base_url}/v1/packages/1.20.4.json", "sha1": "" },
+                    { "id": "1.20.3", "type": "release", "url": "{base_url}/v1/packages/1.20.3.json", "sha1": "" }
+                ]
+            }"#,
+        ),
+        (
+            "/v1/packages/1.20.4.json",
+            r#"{ "downloads": { "server": { "sha1": "C9DF48EF", "size": 49150256, "url": "{base_url}/server-1.20.4.jar" } } }"#,
+        ),
+    ])
+    .await
+}
+/// Serve the paper project with two builds for the version 1.20.4.
+async fn serve_papermc() -> String {
+    serve(vec![
+        (
+            "/v2/projects/paper",
+            r#"{ "project_id": "paper", "versions": ["1.20.2", "1.20.4"] }"#,
+        ),
+        (
+            "/v2/projects/paper/versions/1.20.4/builds",
+            r#"{
+                "builds": [
+                    { "build": 496, "channel": "default", "downloads": { "application": { "name": "paper-1.20.4-496.jar", "sha256": "aaaa" } } },
+                    { "build": 497, "channel": "default", "downloads": { "application": { "name": "paper-1.20.4-497.jar", "sha256": "bbbb" } } }
+                ]
+            }"#,
+        ),
+    ])
+    .await
+}
+/// Serve the purpur project with two builds for the version 1.20.4.
+async fn serve_purpur() -> String {
+    serve(vec![
+        ("/v2/purpur", r#"{ "project": "purpur", "versions": ["1.20.2", "1.20.4"] }"#),
+        (
+            "/v2/purpur/1.20.4",
+            r#"{ "builds": { "latest": "2176", "all": ["2175", "2176"] }, "project": "purpur", "version": "1.20.4" }"#,
+        ),
+        ("/v2/purpur/1.20.4/2175", r#"{ "build": "2175", "md5": "1111", "result": "SUCCESS" }"#),
+        ("/v2/purpur/1.20.4/2176", r#"{ "build": "2176", "md5": "2222", "result": "SUCCESS" }"#),
+    ])
+    .await
+}
+/// Serve the Fabric meta api with two loaders for the version 1.20.4.
+async fn serve_fabric() -> String {
+    serve(vec![
+        (
+            "/v2/versions/game",
+            r#"[{ "version": "24w03a", "stable": false }, { "version": "1.20.4", "stable": true }, { "version": "1.20.3", "stable": true }]"#,
+        ),
+        (
+            "/v2/versions/loader/1.20.4",
+            r#"[
+                { "loader": { "separator": ".", "build": 7, "maven": "net.fabricmc:fabric-loader:0.15.7-beta", "version": "0.15.7-beta", "stable": false } },
+                { "loader": { "separator": ".", "build": 6, "maven": "net.fabricmc:fabric-loader:0.15.6", "version": "0.15.6", "stable": true } }
+            ]"#,
+        ),
+        ("/v2/versions/loader/1.7.10", "[]"),
+        (
+            "/v2/versions/installer",
+            r#"[{ "url": "", "maven": "", "version": "1.0.1-beta", "stable": false }, { "url": "", "maven": "", "version": "1.0.0", "stable": true }]"#,
+        ),
+    ])
+    .await
+}
+
+/// Serve the Quilt meta api with two loaders for the version 1.20.4.
+async fn serve_quilt() -> String {
+    serve(vec![
+        (
+            "/v3/versions/game",
+            r#"[{ "version": "24w03a", "stable": false }, { "version": "1.20.4", "stable": true }, { "version": "1.20.3", "stable": true }]"#,
+        ),
+        (
+            "/v3/versions/loader/1.20.4",
+            r#"[
+                { "loader": { "separator": ".", "build": 80, "maven": "org.quiltmc:quilt-loader:0.24.0-beta.1", "version": "0.24.0-beta.1" } },
+                { "loader": { "separator": ".", "build": 79, "maven": "org.quiltmc:quilt-loader:0.23.1", "version": "0.23.1" } }
+            ]"#,
+        ),
+        (
+            "/v3/versions/installer",
+            r#"[{ "url": "{base_url}/quilt-installer-0.9.1.jar", "maven": "org.quiltmc:quilt-installer:0.9.1", "version": "0.9.1" }]"#,
+        ),
+    ])
+    .await
+}
+
+#[tokio::test]
+async fn vanilla_versions() {
+    let provider = VanillaProvider::new(&serve_mojang().await);
+
+    assert_eq!(
+        provider.versions().await.unwrap(),
+        vec!["1.20.3", "1.20.4", "24w03a"]
+    );
+    assert_eq!(provider.builds("1.20.4").await.unwrap(), vec!["1.20.4"]);
+}
+#[tokio::test]
+async fn vanilla_resolve_latest() {
+    let base_url = serve_mojang().await;
+    let provider = VanillaProvider::new(&base_url);
+
+    assert_eq!(
+        provider.resolve(LATEST, LATEST).await.unwrap(),
+        ResolvedBuild {
+            version: "1.20.4".to_string(),
+            build: "1.20.4".to_string(),
+            url: format!("{base_url}/server-1.20.4.jar"),
+            checksum: Some(Checksum::Sha1("C9DF48EF".to_string())),
+            installer: None,
+        }
+    );
+}
+#[tokio::test]
+async fn vanilla_resolve_unknown_version() {
+    let provider = VanillaProvider::new(&serve_mojang().await);
+
+    assert!(matches!(
+        provider.resolve("1.7.10", LATEST).await,
+        Err(MCManageError::NotFound)
+    ));
+}
+#[tokio::test]
+async fn paper_resolve_latest() {
+    let base_url = serve_papermc().await;
+    let provider = PaperProvider::new(&base_url);
+
+    assert_eq!(
+        provider.resolve(LATEST, LATEST).await.unwrap(),
+        ResolvedBuild {
+            version: "1.20.4".to_string(),
+            build: "497".to_string(),
+            url: format!("{base_url}/v2/projects/paper/versions/1.20.4/builds/497/downloads/paper-1.20.4-497.jar"),
+            checksum: Some(Checksum::Sha256("bbbb".to_string())),
+            installer: None,
+        }
+    );
+}
+#[tokio::test]
+async fn paper_resolve_specific_build() {
+    let provider = PaperProvider::new(&serve_papermc().await);
+
+    let build = provider.resolve("1.20.4", "496").await.unwrap();
+
+    assert_eq!(build.build, "496");
+    assert_eq!(build.checksum, Some(Checksum::Sha256("aaaa".to_string())));
+    assert!(matches!(
+        provider.resolve("1.20.4", "1").await,
+        Err(MCManageError::NotFound)
+    ));
+}
+#[tokio::test]
+async fn purpur_resolve_latest() {
+    let base_url = serve_purpur().await;
+    let provider = PurpurProvider::new(&base_url);
+
+    assert_eq!(
+        provider.resolve("1.20.4", LATEST).await.unwrap(),
+        ResolvedBuild {
+            version: "1.20.4".to_string(),
+            build: "2176".to_string(),
+            url: format!("{base_url}/v2/purpur/1.20.4/2176/download"),
+            checksum: Some(Checksum::Md5("2222".to_string())),
+            installer: None,
+        }
+    );
+}
+#[tokio::test]
+async fn purpur_unknown_version() {
+    let provider = PurpurProvider::new(&serve_purpur().await);
+
+    assert!(matches!(
+        provider.builds("1.8.8").await,
+        Err(MCManageError::NotFound)
+    ));
+}
+#[tokio::test]
+async fn fabric_resolve_latest() {
+    let base_url = serve_fabric().await;
+    let provider = FabricProvider::new(&base_url);
+
+    assert_eq!(provider.versions().await.unwrap(), vec!["1.20.3", "1.20.4"]);
+    assert_eq!(
+        provider.resolve(LATEST, LATEST).await.unwrap(),
+        ResolvedBuild {
+            version: "1.20.4".to_string(),
+            build: "0.15.6".to_string(),
+            url: format!("{base_url}/v2/versions/loader/1.20.4/0.15.6/1.0.0/server/jar"),
+            checksum: None,
+            installer: None,
+        }
+    );
+    assert_eq!(
+        provider.default_args(),
+        Some(format!("-jar {} nogui", fabric::FABRIC_LAUNCHER_JAR))
+    );
+}
+#[tokio::test]
+async fn fabric_resolve_specific_build() {
+    let provider = FabricProvider::new(&serve_fabric().await);
+
+    assert_eq!(
+        provider.resolve("1.20.4", "0.15.7-beta").await.unwrap().build,
+        "0.15.7-beta"
+    );
+    assert!(matches!(
+        provider.resolve("1.20.4", "0.1.0").await,
+        Err(MCManageError::NotFound)
+    ));
+    assert!(matches!(
+        provider.builds("1.7.10").await,
+        Err(MCManageError::NotFound)
+    ));
+}
+#[tokio::test]
+async fn quilt_resolve_latest() {
+    let base_url = serve_quilt().await;
+    let provider = QuiltProvider::new(&base_url);
+
+    assert_eq!(provider.versions().await.unwrap(), vec!["1.20.3", "1.20.4"]);
+    assert_eq!(provider.builds("1.20.4").await.unwrap(), vec!["0.23.1", "0.24.0-beta.1"]);
+    // the launcher has to be installed by the Quilt installer
+    assert_eq!(
+        provider.resolve(LATEST, LATEST).await.unwrap(),
+        ResolvedBuild {
+            version: "1.20.4".to_string(),
+            build: "0.23.1".to_string(),
+            url: format!("{base_url}/quilt-installer-0.9.1.jar"),
+            checksum: None,
+            installer: Some(ServerInstaller {
+                args: vec![
+                    "install".to_string(),
+                    "server".to_string(),
+                    "1.20.4".to_string(),
+                    "0.23.1".to_string(),
+                    "--install-dir=.".to_string(),
+                    "--download-server".to_string(),
+                ],
+                jar: quilt::QUILT_LAUNCHER_JAR.to_string(),
+            }),
+        }
+    );
+    assert_eq!(
+        provider.resolve("1.20.4", "0.24.0-beta.1").await.unwrap().build,
+        "0.24.0-beta.1"
+    );
+    assert!(matches!(
+        provider.resolve("1.20.4", "0.1.0").await,
+        Err(MCManageError::NotFound)
+    ));
+    assert_eq!(
+        provider.default_args(),
+        Some(format!("-jar {} nogui", quilt::QUILT_LAUNCHER_JAR))
+    );
+}
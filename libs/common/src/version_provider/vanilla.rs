@@ -0,0 +1,139 @@
+//! This module provides the [`VanillaProvider`], which resolves vanilla server jars using Mojang's version manifest.
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::{
+    get_json,
+    resolved_build::ResolvedBuild,
+    VersionProvider,
+    LATEST,
+};
+use crate::{
+    download_manager::checksum::Checksum,
+    mcmanage_error::MCManageError,
+};
+
+/// The version manifest served at `{base_url}/mc/game/version_manifest_v2.json`
+#[derive(Deserialize)]
+struct VersionManifest {
+    /// The newest versions
+    latest: LatestVersions,
+    /// Every version, starting with the newest
+    versions: Vec<ManifestVersion>,
+}
+/// The newest versions listed in the [`VersionManifest`]
+#[derive(Deserialize)]
+struct LatestVersions {
+    /// The newest release
+    release: String,
+}
+/// One version listed in the [`VersionManifest`]
+#[derive(Deserialize)]
+struct ManifestVersion {
+    /// The name of this version
+    id: String,
+    /// The url to the details of this version
+    url: String,
+}
+/// The details of one version
+#[derive(Deserialize)]
+struct VersionDetails {
+    /// The files of this version
+    downloads: VersionDownloads,
+}
+/// The files of one version
+#[derive(Deserialize)]
+struct VersionDownloads {
+    /// The server jar. Very old versions do not have one.
+    server: Option<VersionDownload>,
+}
+/// One file of a version
+#[derive(Deserialize)]
+struct VersionDownload {
+    /// The sha1 hash of this file
+    sha1: String,
+    /// The url to download this file from
+    url: String,
+}
+
+/// This struct resolves vanilla server jars using Mojang's version manifest. \
+/// Vanilla servers have no builds. Therefore the only build of every version is the version itself.
+pub struct VanillaProvider {
+    /// The base url of the api serving the version manifest
+    base_url: String,
+}
+impl VanillaProvider {
+    /// Create a new [`VanillaProvider`] using the api at the given base url. (For example: `https://piston-meta.mojang.com`)
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+    /// Get the version manifest.
+    async fn manifest(&self) -> Result<VersionManifest, MCManageError> {
+        get_json(&format!("{}/mc/game/version_manifest_v2.json", self.base_url)).await
+    }
+}
+#[async_trait]
+impl VersionProvider for VanillaProvider {
+    fn name(&self) -> &str {
+        "vanilla"
+    }
+    async fn versions(&self) -> Result<Vec<String>, MCManageError> {
+        Ok(self
+            .manifest()
+            .await?
+            .versions
+            .into_iter()
+            .rev()
+            .map(|version| version.id)
+            .collect())
+    }
+    async fn resolve_version(&self, version: &str) -> Result<String, MCManageError> {
+        if version == LATEST {
+            Ok(self.manifest().await?.latest.release)
+        } else {
+            Ok(version.to_string())
+        }
+    }
+    async fn builds(&self, version: &str) -> Result<Vec<String>, MCManageError> {
+        let version = self.resolve_version(version).await?;
+        if self.versions().await?.contains(&version) {
+            Ok(vec![version])
+        } else {
+            Err(MCManageError::NotFound)
+        }
+    }
+    async fn resolve(&self, version: &str, build: &str) -> Result<ResolvedBuild, MCManageError> {
+        let manifest = self.manifest().await?;
+        let version = if version == LATEST {
+            manifest.latest.release
+        } else {
+            version.to_string()
+        };
+        if build != LATEST && build != version {
+            return Err(MCManageError::NotFound);
+        }
+
+        let details_url = manifest
+            .versions
+            .into_iter()
+            .find(|manifest_version| manifest_version.id == version)
+            .ok_or(MCManageError::NotFound)?
+            .url;
+        let server = get_json::<VersionDetails>(&details_url)
+            .await?
+            .downloads
+            .server
+            .ok_or(MCManageError::NotFound)?;
+
+        Ok(ResolvedBuild {
+            build: version.clone(),
+            version,
+            url: server.url,
+            checksum: Some(Checksum::Sha1(server.sha1)),
+            installer: None,
+        })
+    }
+}
@@ -0,0 +1,209 @@
+//! This module provides the [`Webhooks`] struct, which sends the events of every Minecraft server to the webhooks configured by the user.
+
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+
+use chrono::Utc;
+use goolog::*;
+use tokio::{
+    spawn,
+    sync::{
+        Mutex,
+        OnceCell,
+    },
+};
+
+use self::{
+    webhook::Webhook,
+    webhook_delivery::WebhookDelivery,
+};
+use crate::{
+    config::Config,
+    event_bus::{
+        event::Event,
+        event_filter::EventFilter,
+        server_event::ServerEvent,
+        EventBus,
+    },
+    mcmanage_error::MCManageError,
+    storage::HistoryStorage,
+};
+
+pub mod webhook;
+pub mod webhook_delivery;
+pub mod webhook_template;
+mod tests;
+
+const GOOLOG_CALLER: &str = "Webhooks";
+/// The delay before the first retry of a failed delivery. It doubles with every retry.
+const RETRY_BACKOFF: Duration = Duration::from_secs(1);
+/// The number of deliveries kept for every webhook
+const KEPT_DELIVERIES: i64 = 100;
+/// The storage the webhooks get saved to. It gets set by the [`init`](Webhooks::init) method.
+static WEBHOOKS_STORAGE: OnceCell<Arc<dyn HistoryStorage>> = OnceCell::const_new();
+/// Every saved webhook. This list gets reloaded on every change, so the database does not have to be queried for every event.
+static WEBHOOKS: Mutex<Vec<Webhook>> = Mutex::const_new(Vec::new());
+
+/// This struct provides access to the `webhooks` and `webhook_deliveries` tables. \
+/// Once initialized, every event of the [`EventBus`] gets sent to every enabled webhook whose filter it passes. The result of every delivery gets
+/// recorded.
+pub struct Webhooks;
+// actions
+impl Webhooks {
+    /// Start sending events to the webhooks saved in the given storage. \
+    /// This method can only be called once. Every other method will return an [`MCManageError::NotReady`] until then.
+    pub async fn init(storage: Arc<dyn HistoryStorage>) -> Result<(), MCManageError> {
+        if WEBHOOKS_STORAGE.set(storage).is_err() {
+            fatal!("The webhooks have already been initialized.")
+        }
+        Self::reload().await?;
+
+        // subscribe before returning, so no event published from now on gets missed
+        let mut subscription = EventBus::subscribe(EventFilter::default());
+        spawn(async move {
+            loop {
+                let server_event = subscription.recv().await;
+                for webhook in WEBHOOKS.lock().await.iter() {
+                    if webhook.enabled && webhook.filter.matches(&server_event) {
+                        spawn(Self::deliver(webhook.clone(), server_event.clone()));
+                    }
+                }
+            }
+        });
+        Ok(())
+    }
+    /// Save the given webhook and return it together with its new id.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                                   |
+    /// |-----------------------------------|---------------------------------------------------------------|
+    /// | `Ok(Webhook)`                     | The webhook got added. Its secret is hidden.                  |
+    /// | [`MCManageError::InvalidRequest`] | The webhook is invalid. See the [`Webhook::validate`] method. |
+    /// | `Err(...)`                        | The webhook could not be saved.                               |
+    pub async fn add(mut webhook: Webhook) -> Result<Webhook, MCManageError> {
+        webhook.validate()?;
+
+        webhook.id = Self::storage()?.add_webhook(&webhook).await?;
+        Self::reload().await?;
+
+        info!("Added the webhook {} ( {} ).", webhook.name, webhook.id);
+        Ok(webhook.redacted())
+    }
+    /// Replace the webhook with the given id. If the secret of the given webhook is not set, the saved secret will be kept.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                                   |
+    /// |-----------------------------------|---------------------------------------------------------------|
+    /// | `Ok(Webhook)`                     | The webhook got changed. Its secret is hidden.                |
+    /// | [`MCManageError::NotFound`]       | No webhook with the given id exists.                          |
+    /// | [`MCManageError::InvalidRequest`] | The webhook is invalid. See the [`Webhook::validate`] method. |
+    /// | `Err(...)`                        | The webhook could not be saved.                               |
+    pub async fn update(id: i64, mut webhook: Webhook) -> Result<Webhook, MCManageError> {
+        webhook.validate()?;
+        webhook.id = id;
+
+        Self::storage()?.change_webhook(id, &webhook).await?;
+        Self::reload().await?;
+
+        Self::get(id).await.map(|webhook| webhook.redacted())
+    }
+    /// Remove the webhook with the given id together with its deliveries.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                          |
+    /// |-----------------------------|--------------------------------------|
+    /// | `Ok(())`                    | The webhook got removed.             |
+    /// | [`MCManageError::NotFound`] | No webhook with the given id exists. |
+    /// | `Err(...)`                  | The webhook could not be removed.    |
+    pub async fn remove(id: i64) -> Result<(), MCManageError> {
+        Self::storage()?.remove_webhook(id).await?;
+        Self::reload().await?;
+
+        info!("Removed the webhook {id}.");
+        Ok(())
+    }
+    /// Send a [`test event`](Event::Test) to the webhook with the given id and return the recorded delivery. \
+    /// The event gets sent even if the webhook is disabled or its filter does not include test events.
+    pub async fn test(id: i64) -> Result<WebhookDelivery, MCManageError> {
+        let webhook = Self::get(id).await?;
+        let server_event = ServerEvent {
+            server: "MCManage".to_string(),
+            time: Utc::now(),
+            event: Event::Test,
+        };
+        Ok(Self::deliver(webhook, server_event).await)
+    }
+}
+// info
+impl Webhooks {
+    /// Return every saved webhook. Their secrets are hidden.
+    pub async fn webhooks() -> Result<Vec<Webhook>, MCManageError> {
+        Self::storage()?;
+        Ok(WEBHOOKS.lock().await.iter().map(Webhook::redacted).collect())
+    }
+    /// Return the deliveries of the webhook with the given id, starting with the latest one. Only the last 100 deliveries are kept.
+    pub async fn deliveries(id: i64) -> Result<Vec<WebhookDelivery>, MCManageError> {
+        Self::storage()?.deliveries(id).await
+    }
+}
+// internal
+impl Webhooks {
+    /// Return the storage the webhooks get saved to.
+    ///
+    /// # Returns
+    ///
+    /// | Return                      | Description                                              |
+    /// |-----------------------------|----------------------------------------------------------|
+    /// | `Ok(&dyn HistoryStorage)`   | The webhooks got initialized.                            |
+    /// | [`MCManageError::NotReady`] | The [`init`](Self::init) method has not been called yet. |
+    fn storage() -> Result<&'static dyn HistoryStorage, MCManageError> {
+        WEBHOOKS_STORAGE
+            .get()
+            .map(|storage| storage.as_ref())
+            .ok_or(MCManageError::NotReady)
+    }
+    /// Return the saved webhook with the given id, including its secret.
+    async fn get(id: i64) -> Result<Webhook, MCManageError> {
+        WEBHOOKS
+            .lock()
+            .await
+            .iter()
+            .find(|webhook| webhook.id == id)
+            .cloned()
+            .ok_or(MCManageError::NotFound)
+    }
+    /// Load every webhook from the database into the [`WEBHOOKS`] list.
+    async fn reload() -> Result<(), MCManageError> {
+        let webhooks = Self::storage()?.webhooks().await?;
+        *WEBHOOKS.lock().await = webhooks;
+        Ok(())
+    }
+    /// Send the given event to the given webhook and record the delivery. Only the last [`KEPT_DELIVERIES`] deliveries of every webhook are kept.
+    async fn deliver(webhook: Webhook, server_event: ServerEvent) -> WebhookDelivery {
+        let delivery = webhook
+            .deliver(&server_event, Config::max_tries().await, RETRY_BACKOFF)
+            .await;
+        if !delivery.success {
+            warn!(
+                "Failed to send an event to the webhook {} after {} attempts. Error: {}",
+                webhook.name,
+                delivery.attempts,
+                delivery.error.as_deref().unwrap_or_default()
+            );
+        }
+
+        if let Err(error) = Self::record(&delivery).await {
+            warn!("Failed to record a delivery of the webhook {}. Error: {error}", webhook.name);
+        }
+        delivery
+    }
+    /// Save the given delivery and delete the oldest deliveries of its webhook.
+    async fn record(delivery: &WebhookDelivery) -> Result<(), MCManageError> {
+        Self::storage()?.add_delivery(delivery, KEPT_DELIVERIES).await
+    }
+}
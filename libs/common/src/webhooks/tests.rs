@@ -0,0 +1,138 @@
+#![cfg(test)]
+
+use chrono::Utc;
+
+use super::{
+    webhook::{
+        sign,
+        EVENT_HEADER,
+        SIGNATURE_HEADER,
+    },
+    webhook_template::WebhookTemplate,
+    *,
+};
+use crate::test_functions::*;
+
+/// The backoff used by the tests, so retries do not slow them down
+const TEST_BACKOFF: Duration = Duration::from_millis(10);
+
+/// Return a webhook sending to the given url.
+fn webhook(url: &str, template: WebhookTemplate) -> Webhook {
+    Webhook {
+        id: 1,
+        name: "myWebhook".to_string(),
+        url: url.to_string(),
+        filter: EventFilter::default(),
+        secret: None,
+        template,
+        enabled: true,
+    }
+}
+/// Return an event of a player joining the server `myWebhookServer`.
+fn player_joined() -> ServerEvent {
+    ServerEvent {
+        server: "myWebhookServer".to_string(),
+        time: Utc::now(),
+        event: Event::PlayerJoined {
+            uuid: "069a79f4-44e9-4726-a5be-fca90e38aaf5".to_string(),
+            player: "Notch".to_string(),
+        },
+    }
+}
+
+#[tokio::test]
+async fn deliver_json_with_signature() {
+    start_test();
+    let (url, requests) = mock_webhook_receiver(vec![]).await;
+    let mut webhook = webhook(&url, WebhookTemplate::Json);
+    webhook.secret = Some("mySecret".to_string());
+    let server_event = player_joined();
+
+    let delivery = webhook.deliver(&server_event, 3, TEST_BACKOFF).await;
+
+    assert!(delivery.success);
+    assert_eq!(delivery.attempts, 1);
+    assert_eq!(delivery.status_code, Some(200));
+    let requests = requests.lock().await;
+    assert_eq!(requests.len(), 1);
+    let body = String::from_utf8(requests[0].body.clone()).unwrap();
+    assert_eq!(ServerEvent::try_from(body.clone()).unwrap(), server_event);
+    assert_eq!(
+        requests[0].headers.get(&EVENT_HEADER.to_lowercase()).map(String::as_str),
+        Some("player_joined")
+    );
+    assert_eq!(
+        requests[0].headers.get(&SIGNATURE_HEADER.to_lowercase()),
+        Some(&format!("sha256={}", sign("mySecret", &body)))
+    );
+
+    cleanup();
+}
+#[tokio::test]
+async fn retry_until_success() {
+    start_test();
+    let (url, requests) = mock_webhook_receiver(vec![500, 503]).await;
+
+    let delivery = webhook(&url, WebhookTemplate::Json)
+        .deliver(&player_joined(), 3, TEST_BACKOFF)
+        .await;
+
+    assert!(delivery.success);
+    assert_eq!(delivery.attempts, 3);
+    assert_eq!(delivery.error, None);
+    assert_eq!(requests.lock().await.len(), 3);
+
+    cleanup();
+}
+#[tokio::test]
+async fn no_retry_on_client_error() {
+    start_test();
+    let (url, requests) = mock_webhook_receiver(vec![400]).await;
+
+    let delivery = webhook(&url, WebhookTemplate::Json)
+        .deliver(&player_joined(), 3, TEST_BACKOFF)
+        .await;
+
+    assert!(!delivery.success);
+    assert_eq!(delivery.attempts, 1);
+    assert_eq!(delivery.status_code, Some(400));
+    assert_eq!(requests.lock().await.len(), 1);
+
+    cleanup();
+}
+#[tokio::test]
+async fn templates() {
+    start_test();
+    let server_event = player_joined();
+
+    let discord: serde_json::Value =
+        serde_json::from_str(&WebhookTemplate::Discord.payload(&server_event).unwrap()).unwrap();
+    assert_eq!(discord["content"], "Notch joined the server myWebhookServer.");
+    let slack: serde_json::Value =
+        serde_json::from_str(&WebhookTemplate::Slack.payload(&server_event).unwrap()).unwrap();
+    assert_eq!(slack["text"], "Notch joined the server myWebhookServer.");
+
+    let custom = WebhookTemplate::Custom {
+        body: r#"{"server": "{server}", "kind": "{kind}", "event": {event}}"#.to_string(),
+    }
+    .payload(&server_event)
+    .unwrap();
+    let custom: serde_json::Value = serde_json::from_str(&custom).unwrap();
+    assert_eq!(custom["server"], "myWebhookServer");
+    assert_eq!(custom["kind"], "player_joined");
+    assert_eq!(custom["event"]["player"], "Notch");
+
+    cleanup();
+}
+#[test]
+fn validate() {
+    assert!(webhook("https://example.com/hook", WebhookTemplate::Json).validate().is_ok());
+    assert!(matches!(
+        webhook("ftp://example.com/hook", WebhookTemplate::Json).validate(),
+        Err(MCManageError::InvalidRequest(_))
+    ));
+    assert!(matches!(
+        webhook("https://example.com/hook", WebhookTemplate::Custom { body: "".to_string() }).validate(),
+        Err(MCManageError::InvalidRequest(_))
+    ));
+}
@@ -0,0 +1,182 @@
+//! This module provides the [`Webhook`] struct.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use goolog::*;
+use hmac::{
+    Hmac,
+    Mac,
+};
+use once_cell::sync::Lazy;
+use proc_macros::add_convert;
+use reqwest::{
+    header::CONTENT_TYPE,
+    Client,
+    StatusCode,
+};
+use sha2::Sha256;
+use tokio::time::sleep;
+
+use super::{
+    webhook_delivery::WebhookDelivery,
+    webhook_template::WebhookTemplate,
+};
+use crate::{
+    event_bus::{
+        event_filter::EventFilter,
+        server_event::ServerEvent,
+    },
+    mcmanage_error::MCManageError,
+};
+
+const GOOLOG_CALLER: &str = "Webhook";
+/// The header containing the kind of the event sent
+pub const EVENT_HEADER: &str = "X-MCManage-Event";
+/// The header containing the HMAC-SHA256 signature of the body, formatted like `sha256={hex}`. It only gets sent if the webhook has a secret.
+pub const SIGNATURE_HEADER: &str = "X-MCManage-Signature";
+/// How long the receiver of a webhook has to answer
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// The client used to send every webhook
+static CLIENT: Lazy<Client> = Lazy::new(|| {
+    Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .unwrap_or_else(|error| fatal!("Failed to create the webhook client. Error: {error}"))
+});
+
+/// This struct represents a url every [`ServerEvent`] passing its [`EventFilter`] gets sent to.
+#[add_convert]
+pub struct Webhook {
+    /// The id of this webhook. It gets assigned once the webhook got added.
+    #[serde(default)]
+    pub id: i64,
+    /// The name of this webhook
+    pub name: String,
+    /// The url the events get sent to
+    pub url: String,
+    /// The events which should be sent
+    #[serde(default)]
+    pub filter: EventFilter,
+    /// The secret used to sign the body of every request. The signature gets sent in the [`SIGNATURE_HEADER`].
+    #[serde(default)]
+    pub secret: Option<String>,
+    /// The body sent for every event
+    #[serde(default)]
+    pub template: WebhookTemplate,
+    /// Whether or not events should be sent to this webhook
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+}
+impl Webhook {
+    /// Check whether this webhook can be added.
+    ///
+    /// # Returns
+    ///
+    /// | Return                            | Description                                                                                  |
+    /// |-----------------------------------|----------------------------------------------------------------------------------------------|
+    /// | `Ok(())`                          | The webhook is valid.                                                                        |
+    /// | [`MCManageError::InvalidRequest`] | The name is empty, the url uses no http(s) scheme or the body of a custom template is empty. |
+    pub fn validate(&self) -> Result<(), MCManageError> {
+        if self.name.trim().is_empty() {
+            return Err(MCManageError::InvalidRequest("The webhook needs a name.".to_string()));
+        }
+        if !(self.url.starts_with("http://") || self.url.starts_with("https://")) {
+            return Err(MCManageError::InvalidRequest(format!(
+                "The url `{}` of the webhook has to start with http:// or https://.",
+                self.url
+            )));
+        }
+        if let WebhookTemplate::Custom { body } = &self.template {
+            if body.trim().is_empty() {
+                return Err(MCManageError::InvalidRequest(
+                    "The body of a custom template can not be empty.".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+    /// Return a copy of this webhook with its secret hidden, so it can be shown to users.
+    pub fn redacted(&self) -> Self {
+        Self {
+            secret: self.secret.as_ref().map(|_| "********".to_string()),
+            ..self.clone()
+        }
+    }
+    /// Send the given event to this webhook. \
+    /// Failed attempts will be retried until the given number of tries is reached. The delay between two attempts starts at the given backoff and doubles
+    /// after every attempt. Answers with a status code of `4xx`, except for `429 Too Many Requests`, will not be retried.
+    pub async fn deliver(&self, server_event: &ServerEvent, tries: u64, backoff: Duration) -> WebhookDelivery {
+        let mut delivery = WebhookDelivery {
+            webhook: self.id,
+            event: server_event.clone(),
+            success: false,
+            attempts: 0,
+            status_code: None,
+            error: None,
+            delivered_at: Utc::now(),
+        };
+        let body = match self.template.payload(server_event) {
+            Ok(body) => body,
+            Err(error) => {
+                delivery.error = Some(format!("The body could not be created. Error: {error}"));
+                return delivery;
+            }
+        };
+
+        let mut delay = backoff;
+        loop {
+            delivery.attempts += 1;
+            let retry = match self.send(server_event, &body).await {
+                Ok(status_code) => {
+                    delivery.status_code = Some(status_code.as_u16());
+                    if status_code.is_success() {
+                        delivery.success = true;
+                        delivery.error = None;
+                        false
+                    } else {
+                        delivery.error = Some(format!("The receiver answered with `{status_code}`."));
+                        !status_code.is_client_error() || status_code == StatusCode::TOO_MANY_REQUESTS
+                    }
+                }
+                Err(error) => {
+                    delivery.status_code = None;
+                    delivery.error = Some(error.to_string());
+                    true
+                }
+            };
+            delivery.delivered_at = Utc::now();
+
+            if !retry || delivery.attempts >= tries {
+                return delivery;
+            }
+            sleep(delay).await;
+            delay *= 2;
+        }
+    }
+    /// Send the given body for the given event to the url of this webhook once and return the status code of the answer.
+    async fn send(&self, server_event: &ServerEvent, body: &str) -> Result<StatusCode, MCManageError> {
+        let kind = serde_json::to_value(server_event.event.kind())?;
+        let mut request = CLIENT
+            .post(&self.url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, kind.as_str().unwrap_or_default())
+            .body(body.to_string());
+        if let Some(secret) = &self.secret {
+            request = request.header(SIGNATURE_HEADER, format!("sha256={}", sign(secret, body)));
+        }
+        Ok(request.send().await?.status())
+    }
+}
+
+/// Return the default value of the [`Webhook::enabled`] field.
+fn enabled_by_default() -> bool {
+    true
+}
+/// Return the HMAC-SHA256 signature of the given body as hex.
+pub fn sign(secret: &str, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .unwrap_or_else(|_| fatal!("HMAC accepts keys of any length."));
+    mac.update(body.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
@@ -0,0 +1,31 @@
+//! This module provides the [`WebhookDelivery`] struct.
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use proc_macros::add_convert;
+
+use crate::{
+    event_bus::server_event::ServerEvent,
+    mcmanage_error::MCManageError,
+};
+
+/// This struct records the result of sending an event to a [`Webhook`](super::webhook::Webhook).
+#[add_convert]
+pub struct WebhookDelivery {
+    /// The id of the webhook the event got sent to
+    pub webhook: i64,
+    /// The event sent
+    pub event: ServerEvent,
+    /// Whether or not the receiver accepted the event
+    pub success: bool,
+    /// The number of attempts made to send the event
+    pub attempts: u64,
+    /// The status code the receiver answered the last attempt with, if it answered at all
+    pub status_code: Option<u16>,
+    /// The reason the last attempt failed
+    pub error: Option<String>,
+    /// The time the last attempt finished at
+    pub delivered_at: DateTime<Utc>,
+}
@@ -0,0 +1,58 @@
+//! This module provides the [`WebhookTemplate`] enum.
+
+use proc_macros::add_convert;
+use serde_json::json;
+
+use crate::{
+    event_bus::server_event::ServerEvent,
+    mcmanage_error::MCManageError,
+};
+
+/// This enum describes the body a [`Webhook`](super::webhook::Webhook) sends for an event.
+#[add_convert]
+#[derive(Default)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum WebhookTemplate {
+    /// Send the [`ServerEvent`] as json.
+    #[default]
+    Json,
+    /// Send a message compatible with Discord webhooks.
+    Discord,
+    /// Send a message compatible with Slack webhooks.
+    Slack,
+    /// Send the given body, after replacing the placeholders `{server}`, `{kind}`, `{time}`, `{summary}` and `{event}`. \
+    /// Every placeholder except `{event}`, which is the [`ServerEvent`] as json, gets escaped, so it can be used inside a json string.
+    Custom {
+        /// The body to send
+        body: String,
+    },
+}
+impl WebhookTemplate {
+    /// Create the body to send for the given event.
+    pub fn payload(&self, server_event: &ServerEvent) -> Result<String, MCManageError> {
+        match self {
+            Self::Json => Ok(serde_json::to_string(server_event)?),
+            Self::Discord => Ok(json!({
+                "username": "MCManage",
+                "content": server_event.summary(),
+            })
+            .to_string()),
+            Self::Slack => Ok(json!({ "text": server_event.summary() }).to_string()),
+            Self::Custom { body } => {
+                let kind = serde_json::to_value(server_event.event.kind())?;
+                Ok(body
+                    .replace("{server}", &escape(&server_event.server)?)
+                    .replace("{kind}", kind.as_str().unwrap_or_default())
+                    .replace("{time}", &server_event.time.to_rfc3339())
+                    .replace("{summary}", &escape(&server_event.summary())?)
+                    .replace("{event}", &serde_json::to_string(server_event)?))
+            }
+        }
+    }
+}
+
+/// Escape the given text, so it can be placed inside a json string.
+fn escape(text: &str) -> Result<String, MCManageError> {
+    let quoted = serde_json::to_string(text)?;
+    Ok(quoted[1..quoted.len() - 1].to_string())
+}
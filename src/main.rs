@@ -43,7 +43,6 @@ lazy_static! {
     };
 }
 
-// TODO Save all files downloaded from the internet to the downloads folder; Save paths to files with their origin; on every use or start refresh this list in case any file got deleted
 // TODO Create trace logs
 // TODO Add a force shutdown on second Ctrl+C
 
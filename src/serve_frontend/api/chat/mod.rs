@@ -0,0 +1,8 @@
+use goohttp::*;
+
+router! {
+    chat {
+        search_chat, get;
+        stream_chat, get;
+    }
+}
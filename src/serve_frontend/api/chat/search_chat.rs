@@ -0,0 +1,16 @@
+use common::chat_log::{
+    chat_query::ChatQuery,
+    ChatLog,
+};
+use goohttp::axum::{
+    extract::Query,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Return the chat messages matching the filters of the query string, starting with the latest one. \
+/// The filters are `server`, `sender` ( a name or uuid ), `text`, `since`, `until` and `limit`. See the [`ChatQuery`] for more information.
+pub async fn search_chat(Query(query): Query<ChatQuery>) -> impl IntoResponse {
+    json_response(ChatLog::search(&query).await)
+}
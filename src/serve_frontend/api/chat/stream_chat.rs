@@ -0,0 +1,33 @@
+use common::event_bus::{
+    event::Event,
+    event_filter::EventFilter,
+    event_kind::EventKind,
+};
+use goohttp::axum::{
+    extract::Query,
+    response::IntoResponse,
+};
+use serde::Deserialize;
+
+use crate::serve_frontend::api::events::event_stream;
+
+/// This struct describes which chat messages the [`stream_chat`] function should send.
+#[derive(Deserialize)]
+pub struct StreamFilter {
+    /// Only send messages of the server with this name.
+    server: Option<String>,
+}
+
+/// Send every new chat message as a server-sent event of the kind `chat`, whose data is the message as json. \
+/// If a `server` is specified in the query string, only its messages will be sent. Messages a slow client could not keep up with will be skipped.
+pub async fn stream_chat(Query(filter): Query<StreamFilter>) -> impl IntoResponse {
+    let mut event_filter = EventFilter::default().with_kind(EventKind::Chat);
+    if let Some(server) = &filter.server {
+        event_filter = event_filter.with_server(server);
+    }
+
+    event_stream(event_filter, |server_event| match server_event.event {
+        Event::Chat { message } => Some(("chat".to_string(), serde_json::to_string(&message).ok()?)),
+        _ => None,
+    })
+}
@@ -0,0 +1,45 @@
+use std::convert::Infallible;
+
+use common::event_bus::{
+    event_filter::EventFilter,
+    server_event::ServerEvent,
+    EventBus,
+};
+use futures::stream::{
+    self,
+    Stream,
+};
+use goohttp::{
+    axum::response::sse::{
+        Event,
+        KeepAlive,
+        Sse,
+    },
+    *,
+};
+
+router! {
+    events {
+        stream_events, get;
+    }
+}
+
+/// Send every [`ServerEvent`] passing the given filter as a server-sent event. \
+/// The given function converts an event to the name and data of the server-sent event. Events it returns `None` for will be skipped.
+pub fn event_stream<F>(filter: EventFilter, convert: F) -> Sse<impl Stream<Item = Result<Event, Infallible>>>
+where
+    F: Fn(ServerEvent) -> Option<(String, String)> + Clone + Send + 'static,
+{
+    let stream = stream::unfold(EventBus::subscribe(filter), move |mut subscription| {
+        let convert = convert.clone();
+        async move {
+            loop {
+                if let Some((name, data)) = convert(subscription.recv().await) {
+                    return Some((Ok(Event::default().event(name).data(data)), subscription));
+                }
+            }
+        }
+    });
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
@@ -0,0 +1,65 @@
+use common::{
+    event_bus::{
+        event_filter::EventFilter,
+        event_kind::EventKind,
+    },
+    mcmanage_error::MCManageError,
+};
+use goohttp::axum::{
+    extract::Query,
+    http::StatusCode,
+    response::{
+        IntoResponse,
+        Response,
+    },
+};
+use serde::Deserialize;
+
+use crate::serve_frontend::api::events::event_stream;
+
+/// This struct describes which events the [`stream_events`] function should send.
+#[derive(Deserialize)]
+pub struct StreamFilter {
+    /// A comma separated list of the servers whose events should be sent
+    servers: Option<String>,
+    /// A comma separated list of the kinds of events which should be sent, like `started,player_joined`
+    kinds: Option<String>,
+}
+impl StreamFilter {
+    /// Convert this filter into an [`EventFilter`].
+    fn event_filter(&self) -> Result<EventFilter, MCManageError> {
+        let mut event_filter = EventFilter::default();
+        for server in list(&self.servers) {
+            event_filter = event_filter.with_server(server);
+        }
+        for kind in list(&self.kinds) {
+            let kind: EventKind = serde_json::from_value(serde_json::Value::String(kind.to_string()))
+                .map_err(|_| MCManageError::InvalidRequest(format!("The event kind `{kind}` does not exist.")))?;
+            event_filter = event_filter.with_kind(kind);
+        }
+        Ok(event_filter)
+    }
+}
+
+/// Send every event of every server as a server-sent event named after the kind of the event, whose data is the event as json. \
+/// The events can be filtered using the `servers` and `kinds` parameters of the query string, which take comma separated lists.
+pub async fn stream_events(Query(filter): Query<StreamFilter>) -> Response {
+    match filter.event_filter() {
+        Ok(event_filter) => event_stream(event_filter, |server_event| {
+            let name = serde_json::to_value(server_event.event.kind()).ok()?.as_str()?.to_string();
+            Some((name, serde_json::to_string(&server_event).ok()?))
+        })
+        .into_response(),
+        Err(error) => (StatusCode::BAD_REQUEST, error.to_string()).into_response(),
+    }
+}
+
+/// Split the given comma separated list into its items.
+fn list(items: &Option<String>) -> impl Iterator<Item = &str> {
+    items
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
@@ -1,7 +1,73 @@
-use goohttp::*;
+use common::mcmanage_error::MCManageError;
+use goohttp::{
+    axum::{
+        body::HttpBody,
+        extract::RawBody,
+        http::StatusCode,
+        response::{
+            IntoResponse,
+            Response,
+        },
+        Json,
+    },
+    *,
+};
+use serde::Serialize;
 
 router! {
     api {
+        chat;
+        events;
+        networks;
+        players;
         server;
+        server_list;
+        webhooks;
     }
-}
\ No newline at end of file
+}
+mod tests;
+
+/// The maximum size of a file uploaded to the api. Axum limits request bodies to 2 MiB by default, which a lot of mods and modpacks exceed.
+const MAX_UPLOAD_SIZE: usize = 1024 * 1024 * 1024;
+
+/// Convert the result of an api call to a response. \
+/// Successful results get serialized as json. Errors get converted to a fitting status code together with their message.
+pub fn json_response<T: Serialize>(result: Result<T, MCManageError>) -> Response {
+    match result {
+        Ok(value) => Json(value).into_response(),
+        Err(error) => {
+            let status = match error {
+                MCManageError::NotFound => StatusCode::NOT_FOUND,
+                MCManageError::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+                MCManageError::EulaNotAccepted => StatusCode::CONFLICT,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (status, error.to_string()).into_response()
+        }
+    }
+}
+/// Read the body of a request uploading a file. Unlike the [`Bytes`](goohttp::axum::body::Bytes) extractor, this is not limited to 2 MiB but to
+/// [`MAX_UPLOAD_SIZE`].
+///
+/// # Returns
+///
+/// | Return                            | Description                                     |
+/// |-----------------------------------|-------------------------------------------------|
+/// | `Ok(Vec<u8>)`                     | The body got read.                              |
+/// | [`MCManageError::InvalidRequest`] | The body is too large or could not be received. |
+pub async fn read_upload(RawBody(mut body): RawBody) -> Result<Vec<u8>, MCManageError> {
+    let mut upload = vec![];
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk.map_err(|error| {
+            MCManageError::InvalidRequest(format!("The uploaded file could not be received. Error: {error}"))
+        })?;
+        if upload.len() + chunk.len() > MAX_UPLOAD_SIZE {
+            return Err(MCManageError::InvalidRequest(format!(
+                "The uploaded file can not be larger than {} MiB.",
+                MAX_UPLOAD_SIZE / 1024 / 1024
+            )));
+        }
+        upload.extend_from_slice(&chunk);
+    }
+    Ok(upload)
+}
@@ -0,0 +1,15 @@
+use common::proxy_network::ProxyNetwork;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Get the proxy and the backends of the specified network.
+pub async fn get_network(Path(network): Path<String>) -> impl IntoResponse {
+    let result = ProxyNetwork::get(&network)
+        .await
+        .map(|network| network.members());
+    json_response(result)
+}
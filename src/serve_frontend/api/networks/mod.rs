@@ -0,0 +1,9 @@
+use goohttp::*;
+
+router! {
+    networks {
+        get_network, get, ":network";
+        start_network, put, ":network/start";
+        stop_network, put, ":network/stop";
+    }
+}
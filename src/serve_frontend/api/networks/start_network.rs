@@ -0,0 +1,17 @@
+use common::proxy_network::ProxyNetwork;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Start every server of the specified network. The backends get started first and the proxy last. \
+/// The response gets sent once the proxy started.
+pub async fn start_network(Path(network): Path<String>) -> impl IntoResponse {
+    let result = match ProxyNetwork::get(&network).await {
+        Ok(network) => network.start().await,
+        Err(error) => Err(error),
+    };
+    json_response(result)
+}
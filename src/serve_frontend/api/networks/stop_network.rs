@@ -0,0 +1,16 @@
+use common::proxy_network::ProxyNetwork;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Stop every server of the specified network. The proxy gets stopped first and the backends afterwards.
+pub async fn stop_network(Path(network): Path<String>) -> impl IntoResponse {
+    let result = match ProxyNetwork::get(&network).await {
+        Ok(network) => network.stop().await,
+        Err(error) => Err(error),
+    };
+    json_response(result)
+}
@@ -0,0 +1,12 @@
+use common::player_identities::PlayerIdentities;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Return the uuid of the specified player and every name it used. The player can either be specified by its uuid or by its current name.
+pub async fn get_identity(Path(player): Path<String>) -> impl IntoResponse {
+    json_response(PlayerIdentities::get(&player).await)
+}
@@ -0,0 +1,9 @@
+use common::player_sessions::PlayerSessions;
+use goohttp::axum::response::IntoResponse;
+
+use crate::serve_frontend::api::json_response;
+
+/// Return the session of every player currently online on any Minecraft server.
+pub async fn get_online() -> impl IntoResponse {
+    json_response(PlayerSessions::online().await)
+}
@@ -0,0 +1,13 @@
+use common::player_sessions::PlayerSessions;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Return the playtime, first and last time seen of the specified player, in total and for every Minecraft server the player joined. \
+/// The player can either be specified by its uuid or by its current name.
+pub async fn get_player(Path(player): Path<String>) -> impl IntoResponse {
+    json_response(PlayerSessions::statistics(&player).await)
+}
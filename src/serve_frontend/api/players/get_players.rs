@@ -0,0 +1,9 @@
+use common::player_sessions::PlayerSessions;
+use goohttp::axum::response::IntoResponse;
+
+use crate::serve_frontend::api::json_response;
+
+/// Return the playtime, first and last time seen of every player who ever joined any Minecraft server.
+pub async fn get_players() -> impl IntoResponse {
+    json_response(PlayerSessions::all_statistics().await)
+}
@@ -0,0 +1,12 @@
+use common::player_sessions::PlayerSessions;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Return every player session of the specified Minecraft server, starting with the latest one.
+pub async fn get_sessions(Path(server): Path<String>) -> impl IntoResponse {
+    json_response(PlayerSessions::sessions(&server).await)
+}
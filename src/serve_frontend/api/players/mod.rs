@@ -0,0 +1,11 @@
+use goohttp::*;
+
+router! {
+    players {
+        get_players, get;
+        get_player, get, ":player";
+        get_identity, get, ":player";
+        get_online, get;
+        get_sessions, get, ":server";
+    }
+}
@@ -0,0 +1,22 @@
+use std::path::PathBuf;
+
+use common::existing_server::{
+    adopt_mode::AdoptMode,
+    ExistingServer,
+};
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Adopt the Minecraft server in an existing directory as a new server with the specified name. The path to the directory has to be sent as the
+/// request body. Depending on the mode ( `move` or `symlink` ), the directory will be moved to the `servers` directory or linked from there. \
+/// The server will be added to the [`ServerList`](common::server_manager::server_list::ServerList) right away.
+pub async fn adopt_server(
+    Path((server, mode)): Path<(String, AdoptMode)>,
+    path: String,
+) -> impl IntoResponse {
+    json_response(ExistingServer::adopt(&PathBuf::from(path.trim()), &server, mode).await)
+}
@@ -0,0 +1,11 @@
+use std::path::PathBuf;
+
+use common::existing_server::ExistingServer;
+use goohttp::axum::response::IntoResponse;
+
+use crate::serve_frontend::api::json_response;
+
+/// Detect the Minecraft server in an existing directory without adopting it. The path to the directory has to be sent as the request body.
+pub async fn detect_server(path: String) -> impl IntoResponse {
+    json_response(ExistingServer::detect(&PathBuf::from(path.trim())).await)
+}
@@ -0,0 +1,8 @@
+use goohttp::*;
+
+router! {
+    adopt {
+        detect_server, post, "detect";
+        adopt_server, post, ":server/:mode";
+    }
+}
@@ -0,0 +1,26 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Add the specified player to the allowlist of the specified Bedrock server. A running server will reload its allowlist right away. \
+/// If the request body is `true`, the player will be able to join even if the server is full.
+pub async fn allow_player(Path((server, player)): Path<(String, String)>, ignores_player_limit: String) -> impl IntoResponse {
+    let result = match ServerList::get(&server).await {
+        Ok(server) => match server.bedrock() {
+            Ok(bedrock_server) => {
+                let result = bedrock_server.allow(&player, ignores_player_limit.trim() == "true").await;
+                if result.is_ok() {
+                    server.reload_allowlist().await;
+                }
+                result
+            }
+            Err(error) => Err(error),
+        },
+        Err(error) => Err(error),
+    };
+    json_response(result)
+}
@@ -0,0 +1,25 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Remove the specified player from the allowlist of the specified Bedrock server. A running server will reload its allowlist right away.
+pub async fn disallow_player(Path((server, player)): Path<(String, String)>) -> impl IntoResponse {
+    let result = match ServerList::get(&server).await {
+        Ok(server) => match server.bedrock() {
+            Ok(bedrock_server) => {
+                let result = bedrock_server.disallow(&player).await;
+                if result.is_ok() {
+                    server.reload_allowlist().await;
+                }
+                result
+            }
+            Err(error) => Err(error),
+        },
+        Err(error) => Err(error),
+    };
+    json_response(result)
+}
@@ -0,0 +1,16 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Return every player in the `allowlist.json` file of the specified Bedrock server.
+pub async fn get_allowlist(Path(server): Path<String>) -> impl IntoResponse {
+    let result = match ServerList::get(&server).await.and_then(|server| server.bedrock()) {
+        Ok(bedrock_server) => bedrock_server.allowlist().await,
+        Err(error) => Err(error),
+    };
+    json_response(result)
+}
@@ -0,0 +1,16 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Return the settings of the specified Bedrock server read from its `server.properties` file.
+pub async fn get_properties(Path(server): Path<String>) -> impl IntoResponse {
+    let result = match ServerList::get(&server).await.and_then(|server| server.bedrock()) {
+        Ok(bedrock_server) => bedrock_server.properties().await,
+        Err(error) => Err(error),
+    };
+    json_response(result)
+}
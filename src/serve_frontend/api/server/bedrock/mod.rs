@@ -0,0 +1,10 @@
+use goohttp::*;
+
+router! {
+    bedrock {
+        get_properties, get, ":server/properties";
+        get_allowlist, get, ":server/allowlist";
+        allow_player, post, ":server/allowlist/:player";
+        disallow_player, delete, ":server/allowlist/:player";
+    }
+}
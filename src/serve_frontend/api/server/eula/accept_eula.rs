@@ -0,0 +1,17 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Accept the Minecraft EULA (https://aka.ms/MinecraftEULA) for the specified Minecraft server. \
+/// The name of the person accepting it has to be sent as the request body. It will be recorded together with the current time.
+pub async fn accept_eula(Path(server): Path<String>, accepted_by: String) -> impl IntoResponse {
+    let result = match ServerList::get(&server).await {
+        Ok(server) => server.accept_eula(&accepted_by).await,
+        Err(error) => Err(error),
+    };
+    json_response(result)
+}
@@ -0,0 +1,16 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Return who accepted the Minecraft EULA for the specified Minecraft server, and when. If nobody accepted it yet, `null` will be returned.
+pub async fn get_eula(Path(server): Path<String>) -> impl IntoResponse {
+    let result = match ServerList::get(&server).await {
+        Ok(server) => Ok(server.eula_acceptance().await),
+        Err(error) => Err(error),
+    };
+    json_response(result)
+}
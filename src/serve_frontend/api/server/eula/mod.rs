@@ -0,0 +1,8 @@
+use goohttp::*;
+
+router! {
+    eula {
+        get_eula, get, ":server";
+        accept_eula, post, ":server";
+    }
+}
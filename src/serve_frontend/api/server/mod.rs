@@ -3,7 +3,13 @@ use goohttp::*;
 router! {
     server {
         actions;
+        adopt;
+        bedrock;
         console;
+        eula;
         info;
+        modpacks;
+        mods;
+        plugins;
     }
 }
\ No newline at end of file
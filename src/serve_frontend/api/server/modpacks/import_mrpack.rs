@@ -0,0 +1,43 @@
+use std::time::{
+    SystemTime,
+    UNIX_EPOCH,
+};
+
+use common::modrinth_pack::ModrinthPack;
+use goohttp::axum::{
+    extract::{
+        Path,
+        RawBody,
+    },
+    response::IntoResponse,
+};
+use tokio::fs;
+
+use crate::serve_frontend::api::{
+    json_response,
+    read_upload,
+};
+
+/// Import a Modrinth modpack as a new Minecraft server with the specified name. \
+/// The `.mrpack` archive has to be sent as the request body and can be up to 1 GiB large. \
+/// The server will be added to the [`ServerList`](common::server_manager::server_list::ServerList) right away. The added server will be returned
+/// together with its new id.
+pub async fn import_mrpack(Path(server): Path<String>, body: RawBody) -> impl IntoResponse {
+    let mrpack_path = std::env::temp_dir().join(format!(
+        "mcmanage-{}.mrpack",
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos()
+    ));
+
+    let result = match read_upload(body).await {
+        Ok(mrpack) => match fs::write(&mrpack_path, &mrpack).await {
+            Ok(_) => ModrinthPack::import(&mrpack_path, &server).await,
+            Err(error) => Err(error.into()),
+        },
+        Err(error) => Err(error),
+    };
+    if fs::remove_file(&mrpack_path).await.is_err() {}
+    json_response(result)
+}
@@ -0,0 +1,7 @@
+use goohttp::*;
+
+router! {
+    modpacks {
+        import_mrpack, post, ":server";
+    }
+}
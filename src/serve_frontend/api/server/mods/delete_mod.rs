@@ -0,0 +1,16 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Delete the specified mod of the specified Minecraft server.
+pub async fn delete_mod(Path((server, mod_file)): Path<(String, String)>) -> impl IntoResponse {
+    let result = match ServerList::get(&server).await {
+        Ok(server) => server.mod_manager().delete(&mod_file).await,
+        Err(error) => Err(error),
+    };
+    json_response(result)
+}
@@ -0,0 +1,16 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Disable the specified mod of the specified Minecraft server. It will be moved to the `mods/.disabled` directory.
+pub async fn disable_mod(Path((server, mod_file)): Path<(String, String)>) -> impl IntoResponse {
+    let result = match ServerList::get(&server).await {
+        Ok(server) => server.mod_manager().disable(&mod_file).await,
+        Err(error) => Err(error),
+    };
+    json_response(result)
+}
@@ -0,0 +1,16 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Enable the specified mod of the specified Minecraft server. It will be loaded at the next start.
+pub async fn enable_mod(Path((server, mod_file)): Path<(String, String)>) -> impl IntoResponse {
+    let result = match ServerList::get(&server).await {
+        Ok(server) => server.mod_manager().enable(&mod_file).await,
+        Err(error) => Err(error),
+    };
+    json_response(result)
+}
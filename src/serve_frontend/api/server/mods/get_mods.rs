@@ -0,0 +1,16 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Get every mod installed on the specified Minecraft server, together with the warnings detected for them.
+pub async fn get_mods(Path(server): Path<String>) -> impl IntoResponse {
+    let result = match ServerList::get(&server).await {
+        Ok(server) => server.mod_manager().mods(server.mod_loader()).await,
+        Err(error) => Err(error),
+    };
+    json_response(result)
+}
@@ -0,0 +1,11 @@
+use goohttp::*;
+
+router! {
+    mods {
+        get_mods, get, ":server";
+        enable_mod, put, ":server/:mod_file";
+        disable_mod, put, ":server/:mod_file";
+        upload_mod, post, ":server/:mod_file";
+        delete_mod, delete, ":server/:mod_file";
+    }
+}
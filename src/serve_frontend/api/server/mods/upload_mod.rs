@@ -0,0 +1,26 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::{
+        Path,
+        RawBody,
+    },
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::{
+    json_response,
+    read_upload,
+};
+
+/// Upload a mod jar to the specified Minecraft server. The jar has to be sent as the request body and can be up to 1 GiB large. \
+/// An existing mod with the same file name will be replaced.
+pub async fn upload_mod(
+    Path((server, mod_file)): Path<(String, String)>,
+    body: RawBody,
+) -> impl IntoResponse {
+    let result = match (ServerList::get(&server).await, read_upload(body).await) {
+        (Ok(server), Ok(jar)) => server.mod_manager().upload(&mod_file, &jar).await,
+        (Err(error), _) | (_, Err(error)) => Err(error),
+    };
+    json_response(result)
+}
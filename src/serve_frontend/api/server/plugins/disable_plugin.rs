@@ -0,0 +1,16 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Disable the specified plugin of the specified Minecraft server. It will be moved to the `plugins/.disabled` directory.
+pub async fn disable_plugin(Path((server, plugin)): Path<(String, String)>) -> impl IntoResponse {
+    let result = match ServerList::get(&server).await {
+        Ok(server) => server.plugin_manager().disable(&plugin).await,
+        Err(error) => Err(error),
+    };
+    json_response(result)
+}
@@ -0,0 +1,21 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Get every plugin installed on the specified Minecraft server, together with the warnings detected for them.
+pub async fn get_plugins(Path(server): Path<String>) -> impl IntoResponse {
+    let result = match ServerList::get(&server).await {
+        Ok(server) => {
+            server
+                .plugin_manager()
+                .plugins(server.minecraft_version().await.as_deref())
+                .await
+        }
+        Err(error) => Err(error),
+    };
+    json_response(result)
+}
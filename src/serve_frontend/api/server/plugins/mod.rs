@@ -0,0 +1,11 @@
+use goohttp::*;
+
+router! {
+    plugins {
+        get_plugins, get, ":server";
+        enable_plugin, put, ":server/:plugin";
+        disable_plugin, put, ":server/:plugin";
+        upload_plugin, post, ":server/:plugin";
+        delete_plugin, delete, ":server/:plugin";
+    }
+}
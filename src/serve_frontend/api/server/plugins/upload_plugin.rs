@@ -0,0 +1,26 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::{
+        Path,
+        RawBody,
+    },
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::{
+    json_response,
+    read_upload,
+};
+
+/// Upload a plugin jar to the specified Minecraft server. The jar has to be sent as the request body and can be up to 1 GiB large. \
+/// An existing plugin with the same file name will be replaced.
+pub async fn upload_plugin(
+    Path((server, plugin)): Path<(String, String)>,
+    body: RawBody,
+) -> impl IntoResponse {
+    let result = match (ServerList::get(&server).await, read_upload(body).await) {
+        (Ok(server), Ok(jar)) => server.plugin_manager().upload(&plugin, &jar).await,
+        (Err(error), _) | (_, Err(error)) => Err(error),
+    };
+    json_response(result)
+}
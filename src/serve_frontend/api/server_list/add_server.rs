@@ -0,0 +1,19 @@
+use common::{
+    mcmanage_error::MCManageError,
+    server_manager::server_list::{
+        server_data::ServerData,
+        ServerList,
+    },
+};
+use goohttp::axum::response::IntoResponse;
+
+use crate::serve_frontend::api::json_response;
+
+/// Add a server to the server list. The [`ServerData`] has to be sent as json in the request body. Its id will be ignored. \
+/// The added server will be returned together with its new id.
+pub async fn add_server(server_data: String) -> impl IntoResponse {
+    json_response(match ServerData::try_from(server_data) {
+        Ok(server_data) => ServerList::add(server_data).await,
+        Err(error) => Err(MCManageError::InvalidRequest(format!("The server is invalid. Error: {error}"))),
+    })
+}
@@ -0,0 +1,22 @@
+use common::{
+    mcmanage_error::MCManageError,
+    server_manager::server_list::{
+        server_data::ServerData,
+        ServerList,
+    },
+};
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Change the server with the specified id. The [`ServerData`] has to be sent as json in the request body. Only the fields which are set will be
+/// changed.
+pub async fn change_server(Path(id): Path<i64>, server_data: String) -> impl IntoResponse {
+    json_response(match ServerData::try_from(server_data) {
+        Ok(server_data) => ServerList::change(id, server_data).await,
+        Err(error) => Err(MCManageError::InvalidRequest(format!("The server is invalid. Error: {error}"))),
+    })
+}
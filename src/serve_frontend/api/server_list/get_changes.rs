@@ -0,0 +1,13 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Return every change to the server list since the specified revision, together with its current revision. \
+/// Pass a revision of 0 to receive every server in the list.
+pub async fn get_changes(Path(revision): Path<i64>) -> impl IntoResponse {
+    json_response(ServerList::changes(revision).await)
+}
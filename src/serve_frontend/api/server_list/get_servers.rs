@@ -0,0 +1,9 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::response::IntoResponse;
+
+use crate::serve_frontend::api::json_response;
+
+/// Return the saved data of every server in the server list.
+pub async fn get_servers() -> impl IntoResponse {
+    json_response(ServerList::server_data().await)
+}
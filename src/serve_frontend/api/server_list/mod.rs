@@ -0,0 +1,11 @@
+use goohttp::*;
+
+router! {
+    server_list {
+        get_servers, get;
+        get_changes, get, "changes/:revision";
+        add_server, post;
+        change_server, put, ":id";
+        remove_server, delete, ":id";
+    }
+}
@@ -0,0 +1,12 @@
+use common::server_manager::server_list::ServerList;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Remove the server with the specified id from the server list. Its directory will be kept.
+pub async fn remove_server(Path(id): Path<i64>) -> impl IntoResponse {
+    json_response(ServerList::remove(id).await)
+}
@@ -0,0 +1,15 @@
+#![cfg(test)]
+
+use goohttp::axum::body::Body;
+
+use super::*;
+
+#[tokio::test]
+async fn read_upload_larger_than_default_limit() {
+    // the `Bytes` extractor would reject this body, since it is larger than 2 MiB
+    let upload = vec![7; 3 * 1024 * 1024];
+    let chunks: Vec<Result<Vec<u8>, std::io::Error>> = upload.chunks(64 * 1024).map(|chunk| Ok(chunk.to_vec())).collect();
+
+    let body = Body::wrap_stream(futures::stream::iter(chunks));
+    assert_eq!(read_upload(RawBody(body)).await.unwrap(), upload);
+}
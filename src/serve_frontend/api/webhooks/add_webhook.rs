@@ -0,0 +1,19 @@
+use common::{
+    mcmanage_error::MCManageError,
+    webhooks::{
+        webhook::Webhook,
+        Webhooks,
+    },
+};
+use goohttp::axum::response::IntoResponse;
+
+use crate::serve_frontend::api::json_response;
+
+/// Add a webhook. The [`Webhook`] has to be sent as json in the request body. Its id will be ignored. \
+/// The added webhook will be returned together with its new id.
+pub async fn add_webhook(webhook: String) -> impl IntoResponse {
+    json_response(match Webhook::try_from(webhook) {
+        Ok(webhook) => Webhooks::add(webhook).await,
+        Err(error) => Err(MCManageError::InvalidRequest(format!("The webhook is invalid. Error: {error}"))),
+    })
+}
@@ -0,0 +1,12 @@
+use common::webhooks::Webhooks;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Return the last deliveries of the webhook with the specified id, starting with the latest one.
+pub async fn get_deliveries(Path(id): Path<i64>) -> impl IntoResponse {
+    json_response(Webhooks::deliveries(id).await)
+}
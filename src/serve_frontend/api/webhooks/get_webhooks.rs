@@ -0,0 +1,9 @@
+use common::webhooks::Webhooks;
+use goohttp::axum::response::IntoResponse;
+
+use crate::serve_frontend::api::json_response;
+
+/// Return every configured webhook. Their secrets are hidden.
+pub async fn get_webhooks() -> impl IntoResponse {
+    json_response(Webhooks::webhooks().await)
+}
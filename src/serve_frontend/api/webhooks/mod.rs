@@ -0,0 +1,12 @@
+use goohttp::*;
+
+router! {
+    webhooks {
+        get_webhooks, get;
+        add_webhook, post;
+        update_webhook, put, ":id";
+        remove_webhook, delete, ":id";
+        test_webhook, post, ":id/test";
+        get_deliveries, get, ":id/deliveries";
+    }
+}
@@ -0,0 +1,12 @@
+use common::webhooks::Webhooks;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Remove the webhook with the specified id together with its deliveries.
+pub async fn remove_webhook(Path(id): Path<i64>) -> impl IntoResponse {
+    json_response(Webhooks::remove(id).await)
+}
@@ -0,0 +1,12 @@
+use common::webhooks::Webhooks;
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Send a test event to the webhook with the specified id and return the result of the delivery.
+pub async fn test_webhook(Path(id): Path<i64>) -> impl IntoResponse {
+    json_response(Webhooks::test(id).await)
+}
@@ -0,0 +1,22 @@
+use common::{
+    mcmanage_error::MCManageError,
+    webhooks::{
+        webhook::Webhook,
+        Webhooks,
+    },
+};
+use goohttp::axum::{
+    extract::Path,
+    response::IntoResponse,
+};
+
+use crate::serve_frontend::api::json_response;
+
+/// Replace the webhook with the specified id. The [`Webhook`] has to be sent as json in the request body. If its secret is not set, the current
+/// secret will be kept.
+pub async fn update_webhook(Path(id): Path<i64>, webhook: String) -> impl IntoResponse {
+    json_response(match Webhook::try_from(webhook) {
+        Ok(webhook) => Webhooks::update(id, webhook).await,
+        Err(error) => Err(MCManageError::InvalidRequest(format!("The webhook is invalid. Error: {error}"))),
+    })
+}